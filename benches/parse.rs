@@ -0,0 +1,38 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion, Throughput};
+
+use uri_parser::{uri::host::ip_or_hostname_parser, uri_parser};
+
+const HOSTS: [&str; 4] = [
+    "localhost",
+    "en.wikipedia.org",
+    "some-subsite.some-domain.example.org",
+    "192.168.0.1",
+];
+
+const URIS: [&str; 4] = [
+    "http://localhost",
+    "https://en.wikipedia.org/wiki/Uniform_Resource_Identifier",
+    "https://user:pw@some-subsite.example.org:8080/some/path?key=value&other=1#frag",
+    "mailto:someone@example.org",
+];
+
+fn bench_hosts(c: &mut Criterion) {
+    let mut group = c.benchmark_group("host");
+    for host in HOSTS {
+        group.throughput(Throughput::Bytes(host.len() as u64));
+        group.bench_function(host, |b| b.iter(|| ip_or_hostname_parser(black_box(host))));
+    }
+    group.finish();
+}
+
+fn bench_uris(c: &mut Criterion) {
+    let mut group = c.benchmark_group("uri");
+    for uri in URIS {
+        group.throughput(Throughput::Bytes(uri.len() as u64));
+        group.bench_function(uri, |b| b.iter(|| uri_parser(black_box(uri))));
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench_hosts, bench_uris);
+criterion_main!(benches);
@@ -0,0 +1,41 @@
+//! A URI parser built with [nom](https://github.com/Geal/nom).
+//!
+//! ```
+//! use uri_parser::{uri_parser, Host, Scheme};
+//!
+//! let (_, uri) = uri_parser("https://user:pw@example.org:8080/some/path?key=value#frag").unwrap();
+//!
+//! assert_eq!(uri.scheme(), &Scheme::HTTPS);
+//! assert_eq!(uri.host(), Some(&Host::HOST("example.org".into())));
+//! assert_eq!(uri.port(), Some(8080));
+//! assert_eq!(uri.path_segments().unwrap().collect::<Vec<_>>(), ["some", "path"]);
+//! assert_eq!(uri.fragment(), Some("frag"));
+//! ```
+
+// HTTP, HTTPS, URI and friends are spelled the way the RFCs spell them
+#![allow(clippy::upper_case_acronyms)]
+
+pub mod uri;
+
+pub use uri::{
+    authority::Authority,
+    buf::UriBuf,
+    builder::{BuildError, URIBuilder},
+    bytes::{uri_bytes_parser, uri_reference_bytes_parser, UriBytes},
+    diagnostic::Diagnostic,
+    domain::{DomainError, DomainName},
+    error::{ParseError, UriError},
+    form::QueryParams,
+    host::Host,
+    idna::IdnaError,
+    ipv4::{parse_ipv4, Ipv4Error, Ipv4Mode},
+    options::ParserOptions,
+    path::Path,
+    query::QueryParam,
+    reference::{relative_ref_parser, uri_reference_parser, RelativeRef, UriReference},
+    scheme::{InvalidScheme, Scheme},
+    uri_parser, uri_parser_with, CustomResult, URI,
+};
+
+#[cfg(feature = "serde")]
+pub use uri::serialization::Structured;
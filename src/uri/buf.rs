@@ -0,0 +1,284 @@
+//! An owned URI in a single buffer: the URI as it is written, and the
+//! positions of its components. It can be stored or sent to another thread,
+//! and lent as a `URI` that borrows from the buffer.
+
+use std::{
+    convert::TryFrom,
+    fmt::{self, Write},
+    net::Ipv6Addr,
+    ops::Range,
+    str::FromStr,
+};
+
+use crate::uri::{
+    authority::Authority, error::ParseError, host::Host, path::Path, scheme::Scheme, write_path,
+    URI,
+};
+
+/// An owned URI, detached from the input it was parsed from.
+///
+/// ```
+/// use std::thread;
+/// use uri_parser::{UriBuf, URI};
+///
+/// let uri: UriBuf = {
+///     let input = String::from("https://user@example.org:8080/a/b?c=d#e");
+///     input.parse().unwrap()
+/// };
+/// let uri = thread::spawn(move || uri).join().unwrap();
+///
+/// assert_eq!(uri.as_str(), "https://user@example.org:8080/a/b?c=d#e");
+/// let borrowed: URI<'_> = uri.as_uri();
+/// assert_eq!(borrowed.path_segments().unwrap().collect::<Vec<_>>(), ["a", "b"]);
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct UriBuf {
+    // the URI as written by Display, the ranges below point into it
+    buffer: String,
+    scheme: Scheme<'static>,
+    user: Option<Range<usize>>,
+    password: Option<Range<usize>>,
+    host: Option<HostBuf>,
+    port: Option<u16>,
+    // the segments joined by "/", without what starts the path
+    path: Option<(bool, Range<usize>)>,
+    query: Option<Range<usize>>,
+    fragment: Option<Range<usize>>,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+enum HostBuf {
+    Name(Range<usize>),
+    IP([u8; 4]),
+    IPV6 {
+        address: [u8; 16],
+        zone: Option<Range<usize>>,
+    },
+    IPVFUTURE(Range<usize>),
+}
+
+// Appends a component to the buffer and gives where it is
+fn push(buffer: &mut String, component: &str) -> Range<usize> {
+    let start = buffer.len();
+    buffer.push_str(component);
+    start..buffer.len()
+}
+
+impl UriBuf {
+    /// Parses a URI that has to be the whole input, as `URI::parse` does
+    pub fn parse(input: &str) -> Result<Self, ParseError> {
+        URI::parse(input).map(|uri| UriBuf::from(&uri))
+    }
+
+    /// The URI as it is written
+    pub fn as_str(&self) -> &str {
+        &self.buffer
+    }
+
+    /// Lends the URI, its components borrowing from the buffer.
+    /// Nothing is allocated.
+    pub fn as_uri(&self) -> URI<'_> {
+        let text = |range: &Range<usize>| self.buffer[range.clone()].into();
+        let authority = self.user.as_ref().map(|user| Authority {
+            user: text(user),
+            password: self.password.as_ref().map(text),
+        });
+        let host = self.host.as_ref().map(|host| match host {
+            HostBuf::Name(name) => Host::HOST(text(name)),
+            HostBuf::IP(octets) => Host::IP(*octets),
+            HostBuf::IPV6 { address, zone } => Host::IPV6 {
+                address: *address,
+                zone: zone.as_ref().map(text),
+            },
+            HostBuf::IPVFUTURE(address) => Host::IPVFUTURE(text(address)),
+        });
+        let path = self.path.as_ref().map(|(absolute, range)| {
+            let segments = text(range);
+            if *absolute {
+                Path::Absolute(segments)
+            } else {
+                Path::Relative(segments)
+            }
+        });
+
+        URI {
+            scheme: self.scheme.by_ref(),
+            authority,
+            host,
+            port: self.port,
+            path,
+            query: self.query.as_ref().map(text),
+            fragment: self.fragment.as_ref().map(text),
+        }
+    }
+
+    /// Gives the buffer back, without the positions
+    pub fn into_string(self) -> String {
+        self.buffer
+    }
+}
+
+/// Writes the URI the way Display does, noting where each component goes
+impl From<&URI<'_>> for UriBuf {
+    fn from(uri: &URI<'_>) -> Self {
+        let mut buffer = String::new();
+        let scheme = uri.scheme().clone().into_owned();
+        buffer.push_str(scheme.as_str());
+        buffer.push(':');
+
+        let (mut user, mut password, mut host) = (None, None, None);
+        if let Some(uri_host) = uri.host() {
+            buffer.push_str("//");
+            if let Some(authority) = uri.userinfo() {
+                user = Some(push(&mut buffer, &authority.user));
+                if let Some(pw) = &authority.password {
+                    buffer.push(':');
+                    password = Some(push(&mut buffer, pw));
+                }
+                buffer.push('@');
+            }
+            host = Some(match uri_host {
+                Host::HOST(name) => HostBuf::Name(push(&mut buffer, name)),
+                Host::IP(octets) => {
+                    let _ = write!(buffer, "{}", uri_host);
+                    HostBuf::IP(*octets)
+                }
+                Host::IPV6 { address, zone } => {
+                    let _ = write!(buffer, "[{}", Ipv6Addr::from(*address));
+                    let zone = zone.as_ref().map(|zone| {
+                        buffer.push_str("%25");
+                        push(&mut buffer, zone)
+                    });
+                    buffer.push(']');
+                    HostBuf::IPV6 {
+                        address: *address,
+                        zone,
+                    }
+                }
+                Host::IPVFUTURE(address) => {
+                    buffer.push('[');
+                    let address = push(&mut buffer, address);
+                    buffer.push(']');
+                    HostBuf::IPVFUTURE(address)
+                }
+            });
+            if let Some(port) = uri.port() {
+                let _ = write!(buffer, ":{}", port);
+            }
+        }
+
+        let path = uri.path().map(|path| {
+            let _ = write_path(&mut buffer, path, uri.host().is_some());
+            // the segments come last, after "/" or "/."
+            let length = path.as_str().len();
+            (path.is_absolute(), buffer.len() - length..buffer.len())
+        });
+
+        let query = uri.query().map(|query| {
+            buffer.push('?');
+            push(&mut buffer, query)
+        });
+        let fragment = uri.fragment().map(|fragment| {
+            buffer.push('#');
+            push(&mut buffer, fragment)
+        });
+
+        UriBuf {
+            buffer,
+            scheme,
+            user,
+            password,
+            host,
+            port: uri.port(),
+            path,
+            query,
+            fragment,
+        }
+    }
+}
+
+impl From<URI<'_>> for UriBuf {
+    fn from(uri: URI<'_>) -> Self {
+        UriBuf::from(&uri)
+    }
+}
+
+impl FromStr for UriBuf {
+    type Err = ParseError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        UriBuf::parse(input)
+    }
+}
+
+impl TryFrom<&str> for UriBuf {
+    type Error = ParseError;
+
+    fn try_from(input: &str) -> Result<Self, Self::Error> {
+        UriBuf::parse(input)
+    }
+}
+
+impl AsRef<str> for UriBuf {
+    fn as_ref(&self) -> &str {
+        &self.buffer
+    }
+}
+
+impl fmt::Display for UriBuf {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.buffer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_round_trip() {
+        for input in [
+            "https://user:pw@example.org:8080/a/b?c=d#e",
+            "http://user:@example.org",
+            "http://[fe80::1%25eth0]/",
+            "http://[v1.fe80::a+en1]:80/a",
+            "http://192.168.0.1/a//b/",
+            "mailto:someone@example.org",
+            "file:/.//a",
+            "file:/etc/hosts",
+            "http://example.org?",
+            "urn:isbn:0451450523",
+            "https://münchen.de/",
+        ] {
+            let uri = URI::parse(input).unwrap();
+            let buf = UriBuf::from(&uri);
+            assert_eq!(buf.as_uri(), uri, "{}", input);
+            assert_eq!(buf.as_str(), uri.to_string());
+            assert_eq!(UriBuf::parse(buf.as_str()), Ok(buf));
+        }
+    }
+
+    #[test]
+    fn test_borrowed_components() {
+        let buf: UriBuf = "https://user:pw@example.org/a?q#f".parse().unwrap();
+        let uri = buf.as_uri();
+        let inside = |component: &str| {
+            let range = buf.as_str().as_bytes().as_ptr_range();
+            range.contains(&component.as_ptr())
+        };
+        assert!(inside(&uri.userinfo().unwrap().user));
+        assert!(inside(uri.path_segments().unwrap().next().unwrap()));
+        assert!(inside(uri.query().unwrap()));
+        assert!(inside(uri.fragment().unwrap()));
+        match uri.host() {
+            Some(Host::HOST(name)) => assert!(inside(name)),
+            other => panic!("unexpected host: {:?}", other),
+        }
+
+        assert_eq!(
+            UriBuf::parse("http://a.com/x y"),
+            Err(ParseError::TrailingInput { span: 14..16 })
+        );
+        assert_eq!(buf.into_string(), "https://user:pw@example.org/a?q#f");
+    }
+}
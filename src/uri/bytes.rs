@@ -0,0 +1,429 @@
+//! Parsing from bytes, as read from a socket or a log file, which may not be
+//! valid UTF-8. `UriBytes` keeps the components as bytes of the input: the
+//! grammar is checked on their ASCII bytes, and the bytes beyond ASCII are kept
+//! as they are, to be checked as UTF-8 only when a component is read as text.
+//! The host is read right away, as a `Host`, so an internationalized name has
+//! to be UTF-8. A URI ends at the first byte that its grammar does not allow,
+//! like a space, and what follows is left with the rest of the input.
+
+use std::{
+    borrow::Cow,
+    fmt::Write,
+    ops::Range,
+    str::{self, Utf8Error},
+};
+
+use nom::Offset;
+
+use crate::uri::{
+    authority::is_userinfo_char,
+    error::{ParseError, UriError},
+    host::{host_parser, Host},
+    options::ParserOptions,
+    percent::{is_fragment_char, is_pchar, is_query_char},
+    port::port_parser,
+    reference::{uri_reference_parser, UriReference},
+    scheme::{scheme_parser, Scheme},
+    CustomResult, URI,
+};
+
+/// A URI or a relative reference, whose components are bytes of the input
+///
+/// ```
+/// use uri_parser::uri_bytes_parser;
+///
+/// let line = b"http://example.org/caf\xe9?b=c HTTP/1.1\r\n";
+/// let (rest, uri) = uri_bytes_parser(line).unwrap();
+/// assert_eq!(rest, b" HTTP/1.1\r\n");
+/// assert_eq!(uri.path(), b"/caf\xe9");
+/// assert!(uri.path_str().is_err());
+/// assert_eq!(uri.query_str(), Some(Ok("b=c")));
+/// assert_eq!(uri.to_uri().unwrap().to_string(), "http://example.org/caf%E9?b=c");
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct UriBytes<'a> {
+    text: &'a [u8], // the bytes of the whole reference
+    scheme: Option<Scheme<'a>>,
+    userinfo: Option<Range<usize>>, // the ranges are positions in text
+    host: Option<(Host<'a>, Range<usize>)>,
+    port: Option<u16>,
+    path: Range<usize>,
+    query: Option<Range<usize>>,
+    fragment: Option<Range<usize>>,
+}
+
+impl<'a> UriBytes<'a> {
+    /// The bytes of the whole URI or reference
+    pub fn as_bytes(&self) -> &'a [u8] {
+        self.text
+    }
+
+    /// `None` for a relative reference
+    pub fn scheme(&self) -> Option<&Scheme<'a>> {
+        self.scheme.as_ref()
+    }
+
+    /// The "user:password" part, if any
+    pub fn userinfo(&self) -> Option<&'a [u8]> {
+        self.userinfo.clone().map(|range| &self.text[range])
+    }
+
+    pub fn userinfo_str(&self) -> Option<Result<&'a str, Utf8Error>> {
+        self.userinfo().map(str::from_utf8)
+    }
+
+    pub fn host(&self) -> Option<&Host<'a>> {
+        self.host.as_ref().map(|(host, _)| host)
+    }
+
+    pub fn port(&self) -> Option<u16> {
+        self.port
+    }
+
+    /// The path as it is written, maybe empty: "/a/b", "../a" or ""
+    pub fn path(&self) -> &'a [u8] {
+        &self.text[self.path.clone()]
+    }
+
+    pub fn path_str(&self) -> Result<&'a str, Utf8Error> {
+        str::from_utf8(self.path())
+    }
+
+    /// The query without the "?"
+    pub fn query(&self) -> Option<&'a [u8]> {
+        self.query.clone().map(|range| &self.text[range])
+    }
+
+    pub fn query_str(&self) -> Option<Result<&'a str, Utf8Error>> {
+        self.query().map(str::from_utf8)
+    }
+
+    /// The fragment without the "#"
+    pub fn fragment(&self) -> Option<&'a [u8]> {
+        self.fragment.clone().map(|range| &self.text[range])
+    }
+
+    pub fn fragment_str(&self) -> Option<Result<&'a str, Utf8Error>> {
+        self.fragment().map(str::from_utf8)
+    }
+
+    /// Reads it as a `URI`, which is an error for a relative reference.
+    /// The bytes beyond ASCII are percent-encoded, but for those of the host.
+    pub fn to_uri(&self) -> Result<URI<'a>, ParseError> {
+        match self.encoded() {
+            Cow::Borrowed(text) => URI::parse(text),
+            Cow::Owned(text) => URI::parse(&text).map(URI::into_owned),
+        }
+    }
+
+    /// Reads it as a `UriReference`, encoded as `to_uri` does
+    pub fn to_reference(&self) -> Result<UriReference<'a>, ParseError> {
+        match self.encoded() {
+            Cow::Borrowed(text) => parse_reference(text),
+            Cow::Owned(text) => parse_reference(&text).map(UriReference::into_owned),
+        }
+    }
+
+    // The text with its bytes beyond ASCII percent-encoded, but for those of the
+    // host, which are UTF-8
+    fn encoded(&self) -> Cow<'a, str> {
+        let host = self.host.as_ref().map_or(0..0, |(_, range)| range.clone());
+        let to_encode = |i: usize, byte: u8| !byte.is_ascii() && !host.contains(&i);
+        let first = self
+            .text
+            .iter()
+            .enumerate()
+            .position(|(i, &byte)| to_encode(i, byte));
+        let Some(first) = first else {
+            // ASCII or a UTF-8 host, that the parser checked
+            return Cow::Borrowed(str::from_utf8(self.text).unwrap_or_default());
+        };
+
+        let mut encoded = String::from_utf8_lossy(&self.text[..first]).into_owned();
+        let mut start = first;
+        for (i, &byte) in self.text.iter().enumerate().skip(first) {
+            if to_encode(i, byte) {
+                encoded.push_str(&String::from_utf8_lossy(&self.text[start..i]));
+                let _ = write!(encoded, "%{:02X}", byte);
+                start = i + 1;
+            }
+        }
+        encoded.push_str(&String::from_utf8_lossy(&self.text[start..]));
+        Cow::Owned(encoded)
+    }
+}
+
+fn parse_reference(text: &str) -> Result<UriReference<'_>, ParseError> {
+    match uri_reference_parser(text) {
+        Ok(("", reference)) => Ok(reference),
+        Ok((rest, _)) => Err(ParseError::trailing(text, rest)),
+        Err(e) => Err(ParseError::from_nom(text, e)),
+    }
+}
+
+/// Parses a URI from bytes, what is left is given as bytes too.
+pub fn uri_bytes_parser(input: &[u8]) -> CustomResult<&[u8], UriBytes<'_>> {
+    let (rest, scheme) = parse_text(input, scheme_parser)?;
+    components_parser(input, rest, Some(scheme))
+}
+
+/// Parses a URI or a relative reference from bytes, like the "/a?b=c" target
+/// of an HTTP request line
+pub fn uri_reference_bytes_parser(input: &[u8]) -> CustomResult<&[u8], UriBytes<'_>> {
+    match parse_text(input, scheme_parser) {
+        Ok((rest, scheme)) => components_parser(input, rest, Some(scheme)),
+        Err(nom::Err::Error(_)) => components_parser(input, input, None),
+        Err(e) => Err(e),
+    }
+}
+
+// What follows the scheme, or the whole of a relative reference
+fn components_parser<'a>(
+    input: &'a [u8],
+    mut rest: &'a [u8],
+    scheme: Option<Scheme<'a>>,
+) -> CustomResult<&'a [u8], UriBytes<'a>> {
+    // where a part of the input starts
+    let position = |part: &[u8]| input.offset(part);
+    let range = |component: &[u8]| position(component)..position(component) + component.len();
+    let mut userinfo = None;
+    let mut host = None;
+    let mut port = None;
+
+    let path = if let Some(authority) = rest.strip_prefix(b"//") {
+        let (after, user) = component_parser(is_userinfo_char)(authority)?;
+        rest = match after.strip_prefix(b"@") {
+            Some(after) => {
+                userinfo = Some(range(user));
+                after
+            }
+            None => authority,
+        };
+
+        let (after, parsed) =
+            parse_text(rest, host_parser(ParserOptions::default())).map_err(|e| match e {
+                // what follows the "//" has to be an authority
+                nom::Err::Error(_) => {
+                    let length = authority
+                        .iter()
+                        .position(|b| b"/?#".contains(b))
+                        .unwrap_or(authority.len());
+                    let error = ParseError::InvalidAuthority { span: 0..length };
+                    nom::Err::Failure(UriError::new(authority, error))
+                }
+                e => e,
+            })?;
+        host = Some((parsed, position(rest)..position(after)));
+        rest = after;
+        if rest.first() == Some(&b':') {
+            let (after, parsed) = parse_text(rest, port_parser)?;
+            port = parsed;
+            rest = after;
+        }
+
+        // after an authority, a path starts with a slash
+        match rest.first() {
+            Some(b'/') => component_parser(|c| is_pchar(c) || c == '/')(rest)?.1,
+            _ => &rest[..0],
+        }
+    } else {
+        let (_, path) = component_parser(|c| is_pchar(c) || c == '/')(rest)?;
+        // the first segment of a relative path can not hold a colon, "a:b" would be a scheme
+        let first_segment = path.iter().position(|&b| b == b'/').unwrap_or(path.len());
+        match path[..first_segment].iter().position(|&b| b == b':') {
+            Some(colon) if scheme.is_none() => &path[..colon],
+            _ => path,
+        }
+    };
+    let path_end = position(path) + path.len();
+    let path = position(path)..path_end;
+    rest = &input[path_end..];
+
+    let mut query = None;
+    if let Some(after) = rest.strip_prefix(b"?") {
+        let (after, component) = component_parser(is_query_char)(after)?;
+        query = Some(range(component));
+        rest = after;
+    }
+    let mut fragment = None;
+    if let Some(after) = rest.strip_prefix(b"#") {
+        let (after, component) = component_parser(is_fragment_char)(after)?;
+        fragment = Some(range(component));
+        rest = after;
+    }
+
+    let uri = UriBytes {
+        text: &input[..position(rest)],
+        scheme,
+        userinfo,
+        host,
+        port,
+        path,
+        query,
+        fragment,
+    };
+    Ok((rest, uri))
+}
+
+// The bytes of a component, maybe none: the ASCII characters that the grammar
+// allows, percent-encoded triplets, and the bytes beyond ASCII
+fn component_parser(is_allowed: fn(char) -> bool) -> impl Fn(&[u8]) -> CustomResult<&[u8], &[u8]> {
+    move |input| {
+        let mut end = 0;
+        while let Some(&byte) = input.get(end) {
+            if byte == b'%' {
+                let hex = input.get(end + 1..end + 3);
+                if !hex.is_some_and(|hex| hex.iter().all(u8::is_ascii_hexdigit)) {
+                    // a "%" can only start a triplet
+                    let span = 0..input.len().min(end + 3) - end;
+                    let error = ParseError::InvalidPercentEncoding { span };
+                    return Err(nom::Err::Failure(UriError::new(&input[end..], error)));
+                }
+                end += 3;
+            } else if !byte.is_ascii() || is_allowed(byte as char) {
+                end += 1;
+            } else {
+                break;
+            }
+        }
+        Ok((&input[end..], &input[..end]))
+    }
+}
+
+// Runs a parser of text, like the host one, on the bytes as far as they are
+// UTF-8, then points what is left and the errors back into the bytes
+fn parse_text<'a, O, P>(input: &'a [u8], mut parser: P) -> CustomResult<&'a [u8], O>
+where
+    P: FnMut(&'a str) -> CustomResult<&'a str, O>,
+{
+    let end = input
+        .iter()
+        .position(|b| b.is_ascii_control() || *b == b' ')
+        .unwrap_or(input.len());
+    let (text, invalid) = match str::from_utf8(&input[..end]) {
+        Ok(text) => (text, 0),
+        // valid up to the first invalid sequence
+        Err(e) => (
+            str::from_utf8(&input[..e.valid_up_to()]).unwrap_or_default(),
+            e.error_len().unwrap_or(end - e.valid_up_to()),
+        ),
+    };
+
+    let to_bytes = |rest: &str| &input[text.len() - rest.len()..];
+    parser(text)
+        .and_then(|(next_input, output)| {
+            // the host goes on up to the invalid sequence, "m\xfcnchen.de" is not "m"
+            if invalid > 0 && next_input.is_empty() {
+                let error = ParseError::InvalidUtf8 { span: 0..invalid };
+                return Err(nom::Err::Failure(UriError::new(next_input, error)));
+            }
+            Ok((next_input, output))
+        })
+        .map(|(next_input, output)| (to_bytes(next_input), output))
+        .map_err(|e| e.map(|e| e.map_input(to_bytes)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_uri_bytes_parser() {
+        let (rest, uri) = uri_bytes_parser(b"https://user@example.org:8080/a/b?q#top").unwrap();
+        assert!(rest.is_empty());
+        assert_eq!(uri.scheme(), Some(&Scheme::HTTPS));
+        assert_eq!(uri.userinfo(), Some(&b"user"[..]));
+        assert_eq!(uri.host(), Some(&Host::HOST("example.org".into())));
+        assert_eq!(uri.port(), Some(8080));
+        assert_eq!(uri.path_str(), Ok("/a/b"));
+        assert_eq!(uri.query(), Some(&b"q"[..]));
+        assert_eq!(uri.fragment_str(), Some(Ok("top")));
+
+        // UTF-8 in the host, control bytes after the URI
+        let (rest, uri) = uri_bytes_parser("http://münchen.de/\t\u{0}".as_bytes()).unwrap();
+        assert_eq!(rest, b"\t\0");
+        assert_eq!(uri.host(), Some(&Host::HOST("xn--mnchen-3ya.de".into())));
+        assert_eq!(
+            uri.to_uri().unwrap().to_string(),
+            "http://xn--mnchen-3ya.de/"
+        );
+
+        // the other components take any byte beyond ASCII, checked when read as text
+        let input = b"http://us\xe9r@example.org/caf\xe9/x?a=\xc3\x28#\xff";
+        let (rest, uri) = uri_bytes_parser(input).unwrap();
+        assert!(rest.is_empty());
+        assert_eq!(uri.path(), b"/caf\xe9/x");
+        assert!(uri.userinfo_str().unwrap().is_err());
+        assert!(uri.path_str().is_err());
+        assert!(uri.query_str().unwrap().is_err());
+        assert!(uri.fragment_str().unwrap().is_err());
+        assert_eq!(
+            uri.to_uri().unwrap().to_string(),
+            "http://us%E9r@example.org/caf%E9/x?a=%C3(#%FF"
+        );
+
+        // but the host has to be UTF-8, the error points at the invalid sequence
+        let input = b"http://m\xfcnchen.de/";
+        assert_eq!(
+            ParseError::from_nom(&input[..], uri_bytes_parser(input).unwrap_err()),
+            ParseError::InvalidUtf8 { span: 8..9 }
+        );
+
+        // the URI ends at the first byte its grammar does not allow
+        let (rest, uri) = uri_bytes_parser(b"http://example.org/a\"\xff").unwrap();
+        assert_eq!(rest, b"\"\xff");
+        assert_eq!(uri.path(), b"/a");
+        assert_eq!(uri.as_bytes(), b"http://example.org/a");
+        let (rest, uri) = uri_bytes_parser(b"http://exa mple.org/").unwrap();
+        assert_eq!(rest, b" mple.org/");
+        assert_eq!(uri.host(), Some(&Host::HOST("exa".into())));
+
+        // errors point into the bytes
+        match uri_bytes_parser(b"1http://example.org") {
+            Err(nom::Err::Error(e)) => assert_eq!(e.input, b"1http://example.org"),
+            other => panic!("unexpected result: {:?}", other),
+        }
+        let input = b"http://example.org/\xff%zz";
+        assert_eq!(
+            ParseError::from_nom(&input[..], uri_bytes_parser(input).unwrap_err()),
+            ParseError::InvalidPercentEncoding { span: 20..23 }
+        );
+        let input = b"http://\xff.org/a";
+        assert_eq!(
+            ParseError::from_nom(&input[..], uri_bytes_parser(input).unwrap_err()),
+            ParseError::InvalidAuthority { span: 7..12 }
+        );
+        assert!(uri_bytes_parser(b"\xffhttp://example.org").is_err());
+    }
+
+    #[test]
+    fn test_uri_reference_bytes_parser() {
+        let line = b"/search?q=caf\xe9 HTTP/1.1";
+        let (rest, reference) = uri_reference_bytes_parser(line).unwrap();
+        assert_eq!(rest, b" HTTP/1.1");
+        assert_eq!(reference.scheme(), None);
+        assert_eq!(reference.path_str(), Ok("/search"));
+        assert_eq!(reference.query(), Some(&b"q=caf\xe9"[..]));
+        assert_eq!(
+            reference.to_reference().unwrap().to_string(),
+            "/search?q=caf%E9"
+        );
+        assert!(reference.to_uri().is_err());
+
+        let (rest, reference) = uri_reference_bytes_parser(b"//cdn.example.org/x.js").unwrap();
+        assert!(rest.is_empty());
+        assert_eq!(
+            reference.host(),
+            Some(&Host::HOST("cdn.example.org".into()))
+        );
+
+        // a colon in the first segment ends a relative path
+        let (rest, reference) = uri_reference_bytes_parser(b"1a:b/c").unwrap();
+        assert_eq!(rest, b":b/c");
+        assert_eq!(reference.path(), b"1a");
+
+        let (_, reference) = uri_reference_bytes_parser(b"mailto:a@b.org").unwrap();
+        assert_eq!(reference.scheme(), Some(&Scheme::Other("mailto".into())));
+        assert_eq!(reference.path_str(), Ok("a@b.org"));
+    }
+}
@@ -0,0 +1,219 @@
+//! Parse errors written for humans: the input, a caret under the culprit,
+//! and what the parser was reading, from the contexts of the nom parsers.
+//!
+//! ```text
+//! error: invalid host: domain label starts with a hyphen
+//!   http://-example.org/
+//!          ^^^^^^^^^^^^
+//!   while reading the domain name, in the host, in the URI
+//! ```
+
+use std::fmt;
+
+use crate::uri::error::{ParseError, UriError};
+
+const RED: &str = "\x1b[1;31m";
+const BLUE: &str = "\x1b[34m";
+const RESET: &str = "\x1b[0m";
+
+/// A parse error ready to be printed, with ANSI colours if asked.
+///
+/// ```
+/// use uri_parser::{uri_parser, Diagnostic};
+///
+/// let input = "http://192.168.0.256/";
+/// let diagnostic = Diagnostic::from_nom(input, &uri_parser(input).unwrap_err());
+/// assert_eq!(
+///     diagnostic.to_string(),
+///     "error: IPv4 address part out of range: 256\n\
+///     \x20 http://192.168.0.256/\n\
+///     \x20                  ^^^\n\
+///     \x20 while reading the IPv4 address, in the host, in the URI\n"
+/// );
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Diagnostic<'a> {
+    input: &'a str,
+    error: ParseError,
+    // from the innermost to the outermost
+    contexts: Vec<&'static str>,
+    color: bool,
+}
+
+impl<'a> Diagnostic<'a> {
+    /// Explains the error of a parser that was given `input`
+    pub fn from_nom(input: &'a str, error: &nom::Err<UriError<&str>>) -> Self {
+        let mut contexts = Vec::new();
+        if let nom::Err::Error(e) | nom::Err::Failure(e) = error {
+            for context in e.contexts() {
+                if !contexts.contains(&context) {
+                    contexts.push(context);
+                }
+            }
+        }
+        Diagnostic {
+            input,
+            error: ParseError::from_nom(input, error.clone()),
+            contexts,
+            color: false,
+        }
+    }
+
+    /// Explains a `ParseError`, without the contexts of the parsers that gave it
+    pub fn from_error(input: &'a str, error: ParseError) -> Self {
+        let context = match &error {
+            ParseError::InvalidScheme { .. } => Some("scheme parsing error"),
+            ParseError::InvalidAuthority { .. } => Some("authority"),
+            ParseError::InvalidHost { .. } | ParseError::InvalidOctet { .. } => Some("ip or host"),
+            ParseError::PortOutOfRange { .. } => Some("port"),
+            ParseError::InvalidPercentEncoding { .. } => Some("percent encoding"),
+            ParseError::InvalidUtf8 { .. } | ParseError::TrailingInput { .. } => None,
+            ParseError::Unexpected { context, .. } => Some(*context),
+        };
+        Diagnostic {
+            input,
+            error,
+            contexts: context.into_iter().collect(),
+            color: false,
+        }
+    }
+
+    /// Writes with ANSI colours, for a terminal
+    pub fn with_color(mut self, color: bool) -> Self {
+        self.color = color;
+        self
+    }
+
+    pub fn error(&self) -> &ParseError {
+        &self.error
+    }
+
+    // "while reading the port, in the URI", the contexts that are not parsers,
+    // like the reasons of the host errors, are already in the message
+    fn explanation(&self) -> Option<String> {
+        let parts: Vec<&str> = self.contexts.iter().filter_map(|c| describe(c)).collect();
+        if parts.is_empty() {
+            return None;
+        }
+        Some(format!("while reading {}", parts.join(", in ")))
+    }
+
+    fn paint<'s>(&self, code: &'s str) -> &'s str {
+        if self.color {
+            code
+        } else {
+            ""
+        }
+    }
+}
+
+// The plain-language meaning of the context labels of the parsers
+fn describe(context: &str) -> Option<&'static str> {
+    Some(match context {
+        "uri" => "the URI",
+        "uri reference" | "relative reference" => "the URI reference",
+        "scheme parsing error" => "the scheme, letters followed by \":\" as in \"https:\"",
+        "authority" => "the userinfo, the \"user:password\" before \"@\"",
+        "ip or host" => "the host",
+        "host" => "the domain name",
+        "ip" => "the IPv4 address",
+        "ip literal" => "the IP address within brackets",
+        "ipv6" => "the IPv6 address",
+        "zone" => "the IPv6 zone, after \"%25\"",
+        "ipvfuture" => "the future IP address",
+        "port" => "the port, a number up to 65535",
+        "path" | "rootless path" | "relative path" => "the path",
+        "query" => "the query, after \"?\"",
+        "fragment" => "the fragment, after \"#\"",
+        "percent encoding" => "a percent-encoded byte, like \"%20\"",
+        _ => return None,
+    })
+}
+
+impl fmt::Display for Diagnostic<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (red, blue, reset) = (self.paint(RED), self.paint(BLUE), self.paint(RESET));
+        writeln!(f, "{}error{}: {}", red, reset, self.error.message())?;
+
+        // control characters, like tabs, would move the caret
+        let printable: String = self
+            .input
+            .chars()
+            .map(|c| if c.is_control() { ' ' } else { c })
+            .collect();
+        writeln!(f, "  {}", printable)?;
+
+        let span = self.error.span();
+        let start = span.start.min(self.input.len());
+        let end = span.end.clamp(start, self.input.len());
+        let column = self.input.get(..start).map_or(0, |s| s.chars().count());
+        let width = self.input.get(start..end).map_or(0, |s| s.chars().count());
+        writeln!(
+            f,
+            "  {}{}{}{}",
+            " ".repeat(column),
+            red,
+            "^".repeat(width.max(1)),
+            reset
+        )?;
+
+        if let Some(explanation) = self.explanation() {
+            writeln!(f, "  {}{}{}", blue, explanation, reset)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::uri::uri_parser;
+
+    fn diagnostic(input: &str) -> Diagnostic<'_> {
+        match uri_parser(input) {
+            Ok((rest, _)) => Diagnostic::from_error(input, ParseError::trailing(input, rest)),
+            Err(e) => Diagnostic::from_nom(input, &e),
+        }
+    }
+
+    #[test]
+    fn test_render() {
+        assert_eq!(
+            diagnostic("http://-example.org/").to_string(),
+            "error: invalid host: domain label starts with a hyphen\n  \
+            http://-example.org/\n         \
+            ^^^^^^^^^^^^\n  \
+            while reading the domain name, in the host, in the URI\n"
+        );
+        assert_eq!(
+            diagnostic("1http://example.org").to_string(),
+            "error: invalid scheme\n  \
+            1http://example.org\n  \
+            ^^^^^\n  \
+            while reading the scheme, letters followed by \":\" as in \"https:\", in the URI\n"
+        );
+        assert_eq!(
+            diagnostic("http://example.org:99999").to_string(),
+            "error: port out of range\n  \
+            http://example.org:99999\n                     \
+            ^^^^^\n  \
+            while reading the port, a number up to 65535, in the URI\n"
+        );
+        // the caret counts characters, not bytes, and nothing explains trailing input
+        assert_eq!(
+            diagnostic("http://münchen.de/a b").to_string(),
+            "error: unexpected input after the URI\n  \
+            http://münchen.de/a b\n                     \
+            ^^\n"
+        );
+    }
+
+    #[test]
+    fn test_color() {
+        let rendered = diagnostic("http://a.com/x%zz").with_color(true).to_string();
+        assert!(rendered.starts_with("\x1b[1;31merror\x1b[0m: "));
+        assert!(rendered.contains("\x1b[1;31m^^^\x1b[0m"));
+        assert!(rendered.contains("\x1b[34mwhile reading a percent-encoded byte"));
+        assert!(!diagnostic("http://a.com/x%zz").to_string().contains('\x1b'));
+    }
+}
@@ -0,0 +1,279 @@
+//! Domain names, checked against the DNS rules of
+//! [RFC 1035](https://www.rfc-editor.org/rfc/rfc1035#section-2.3.1) and
+//! [RFC 1123](https://www.rfc-editor.org/rfc/rfc1123#section-2.1):
+//! labels of letters, digits and hyphens, up to 63 bytes, in a name up to 253 bytes.
+
+use std::{error::Error, fmt};
+
+const MAX_LABEL_LENGTH: usize = 63;
+const MAX_NAME_LENGTH: usize = 253;
+
+/// A valid domain name, like "en.wikipedia.org", or "example.org." with the
+/// trailing dot of a fully qualified domain name.
+///
+/// ```
+/// use uri_parser::{DomainError, DomainName};
+///
+/// let name = DomainName::parse("en.wikipedia.org.").unwrap();
+/// assert_eq!(name.as_str(), "en.wikipedia.org");
+/// assert!(name.is_fqdn());
+///
+/// assert_eq!(DomainName::parse("-example.org"), Err(DomainError::LeadingHyphen));
+/// // service labels need the lenient mode
+/// assert!(DomainName::parse("_sip._tcp.example.com").is_err());
+/// assert!(DomainName::parse_lenient("_sip._tcp.example.com").is_ok());
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct DomainName {
+    name: String,
+    fqdn: bool,
+}
+
+/// Why a domain name is not valid, one variant per rule
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum DomainError {
+    Empty,
+    /// "a..b", or a name starting with a dot
+    EmptyLabel,
+    /// a label longer than 63 bytes
+    LabelTooLong,
+    /// a name longer than 253 bytes, without the trailing dot
+    NameTooLong,
+    LeadingHyphen,
+    TrailingHyphen,
+    /// anything else than letters, digits and hyphens, and underscores in lenient mode
+    InvalidChar(char),
+    /// "example.123", which could be mistaken for an IPv4
+    NumericTopLevelLabel,
+}
+
+impl DomainError {
+    /// A short description, used as the context of parse errors
+    pub fn reason(&self) -> &'static str {
+        match self {
+            DomainError::Empty => "empty domain name",
+            DomainError::EmptyLabel => "empty label in domain name",
+            DomainError::LabelTooLong => "domain label longer than 63 bytes",
+            DomainError::NameTooLong => "domain name longer than 253 bytes",
+            DomainError::LeadingHyphen => "domain label starts with a hyphen",
+            DomainError::TrailingHyphen => "domain label ends with a hyphen",
+            DomainError::InvalidChar(_) => "invalid character in domain name",
+            DomainError::NumericTopLevelLabel => "numeric top-level domain",
+        }
+    }
+}
+
+impl fmt::Display for DomainError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DomainError::InvalidChar(c) => write!(f, "{}: {:?}", self.reason(), c),
+            _ => f.write_str(self.reason()),
+        }
+    }
+}
+
+impl Error for DomainError {}
+
+impl DomainName {
+    /// Checks a domain name with the strict rules: letters, digits and hyphens only
+    pub fn parse(name: &str) -> Result<Self, DomainError> {
+        Self::validate(name).map(|_| Self::new(name))
+    }
+
+    /// Also accepts underscores, as in the "_sip._tcp.example.com" of SRV records
+    pub fn parse_lenient(name: &str) -> Result<Self, DomainError> {
+        Self::validate_lenient(name).map(|_| Self::new(name))
+    }
+
+    /// Checks a domain name like `parse` does, without copying it
+    pub fn validate(name: &str) -> Result<(), DomainError> {
+        check(name, false)
+    }
+
+    /// Checks a domain name like `parse_lenient` does, without copying it
+    pub fn validate_lenient(name: &str) -> Result<(), DomainError> {
+        check(name, true)
+    }
+
+    // Keeps a name that passed the checks
+    fn new(name: &str) -> Self {
+        let (name, fqdn) = match name.strip_suffix('.') {
+            Some(name) => (name, true),
+            None => (name, false),
+        };
+        DomainName {
+            name: name.to_string(),
+            fqdn,
+        }
+    }
+
+    /// The name without the trailing dot
+    pub fn as_str(&self) -> &str {
+        &self.name
+    }
+
+    /// Whether the name was written with a trailing dot, "example.org."
+    pub fn is_fqdn(&self) -> bool {
+        self.fqdn
+    }
+
+    /// "en.wikipedia.org" gives "en", "wikipedia" and "org"
+    pub fn labels(&self) -> impl Iterator<Item = &str> {
+        self.name.split('.')
+    }
+}
+
+// The rules of the DNS, on a name that does not need to be kept
+fn check(name: &str, lenient: bool) -> Result<(), DomainError> {
+    let name = name.strip_suffix('.').unwrap_or(name);
+    if name.is_empty() {
+        return Err(DomainError::Empty);
+    }
+    if name.len() > MAX_NAME_LENGTH {
+        return Err(DomainError::NameTooLong);
+    }
+
+    for label in name.split('.') {
+        if label.is_empty() {
+            return Err(DomainError::EmptyLabel);
+        }
+        if label.len() > MAX_LABEL_LENGTH {
+            return Err(DomainError::LabelTooLong);
+        }
+        if let Some(c) = label
+            .chars()
+            .find(|&c| !(c.is_ascii_alphanumeric() || c == '-' || (lenient && c == '_')))
+        {
+            return Err(DomainError::InvalidChar(c));
+        }
+        if label.starts_with('-') {
+            return Err(DomainError::LeadingHyphen);
+        }
+        if label.ends_with('-') {
+            return Err(DomainError::TrailingHyphen);
+        }
+    }
+
+    let top_level = name.rsplit('.').next().unwrap_or(name);
+    if top_level.chars().all(|c| c.is_ascii_digit()) {
+        return Err(DomainError::NumericTopLevelLabel);
+    }
+    Ok(())
+}
+
+/// Writes the name as it was given, with the trailing dot of a fully qualified name
+impl fmt::Display for DomainName {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.name)?;
+        if self.fqdn {
+            f.write_str(".")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_valid_names() {
+        for name in [
+            "localhost",
+            "example.org",
+            "some-subsite.example.org",
+            "xn--mnchen-3ya.de",
+            "123.example.org",
+            "EXAMPLE.ORG",
+            "a.b2",
+        ] {
+            let domain = DomainName::parse(name).unwrap();
+            assert_eq!(domain.to_string(), name);
+            assert!(!domain.is_fqdn());
+        }
+
+        let domain = DomainName::parse("en.wikipedia.org.").unwrap();
+        assert!(domain.is_fqdn());
+        assert_eq!(domain.as_str(), "en.wikipedia.org");
+        assert_eq!(domain.to_string(), "en.wikipedia.org.");
+        assert_eq!(
+            domain.labels().collect::<Vec<_>>(),
+            vec!["en", "wikipedia", "org"]
+        );
+    }
+
+    #[test]
+    fn test_errors() {
+        assert_eq!(DomainName::parse(""), Err(DomainError::Empty));
+        assert_eq!(DomainName::parse("."), Err(DomainError::Empty));
+        assert_eq!(DomainName::parse("a..b"), Err(DomainError::EmptyLabel));
+        assert_eq!(DomainName::parse(".com"), Err(DomainError::EmptyLabel));
+        assert_eq!(DomainName::parse("-a.com"), Err(DomainError::LeadingHyphen));
+        assert_eq!(
+            DomainName::parse("a-.com"),
+            Err(DomainError::TrailingHyphen)
+        );
+        assert_eq!(
+            DomainName::parse("$$$.com"),
+            Err(DomainError::InvalidChar('$'))
+        );
+        assert_eq!(
+            DomainName::parse("a_b.com"),
+            Err(DomainError::InvalidChar('_'))
+        );
+        assert_eq!(
+            DomainName::parse("example.123"),
+            Err(DomainError::NumericTopLevelLabel)
+        );
+        assert_eq!(
+            DomainName::parse("42"),
+            Err(DomainError::NumericTopLevelLabel)
+        );
+    }
+
+    #[test]
+    fn test_lengths() {
+        let label = "a".repeat(63);
+        assert!(DomainName::parse(&format!("{}.com", label)).is_ok());
+        assert_eq!(
+            DomainName::parse(&format!("a{}.com", label)),
+            Err(DomainError::LabelTooLong)
+        );
+
+        // 4 labels of 63 bytes and 3 dots make 255 bytes
+        let name = [label.as_str(); 4].join(".");
+        assert_eq!(DomainName::parse(&name), Err(DomainError::NameTooLong));
+        let name = &name[..253];
+        assert!(DomainName::parse(name).is_ok());
+        // the trailing dot does not count
+        assert!(DomainName::parse(&format!("{}.", name)).is_ok());
+    }
+
+    #[test]
+    fn test_lenient() {
+        let domain = DomainName::parse_lenient("_sip._tcp.example.com").unwrap();
+        assert_eq!(
+            domain.labels().collect::<Vec<_>>(),
+            vec!["_sip", "_tcp", "example", "com"]
+        );
+        assert_eq!(
+            DomainName::parse_lenient("-sip.example.com"),
+            Err(DomainError::LeadingHyphen)
+        );
+        assert_eq!(
+            DomainName::parse_lenient("sip example.com"),
+            Err(DomainError::InvalidChar(' '))
+        );
+    }
+
+    #[test]
+    fn test_validate() {
+        assert_eq!(DomainName::validate("example.org."), Ok(()));
+        assert_eq!(
+            DomainName::validate("_sip.example.com"),
+            Err(DomainError::InvalidChar('_'))
+        );
+        assert_eq!(DomainName::validate_lenient("_sip.example.com"), Ok(()));
+        assert_eq!(DomainName::validate("a..b"), Err(DomainError::EmptyLabel));
+    }
+}
@@ -0,0 +1,443 @@
+//! The errors of the crate. The parsers are built with nom, and `UriError` is their
+//! error type: the parser that finds what went wrong says it with a `ParseError`,
+//! which gives the byte range of the culprit in the input.
+
+use std::{error::Error, fmt, ops::Range};
+
+use nom::{
+    error::{ContextError, ErrorKind, FromExternalError, ParseError as NomParseError},
+    InputLength,
+};
+
+use crate::uri::ipv4::{parse_whatwg_number, Ipv4Error};
+
+/// Why an input is not a URI, with the byte range of the culprit in the input.
+///
+/// ```
+/// use uri_parser::{uri_parser, ParseError};
+///
+/// let input = "http://192.168.0.256/";
+/// let error = ParseError::from_nom(input, uri_parser(input).unwrap_err());
+/// assert_eq!(error, ParseError::InvalidOctet { value: 256, span: 17..20 });
+///
+/// let input = "http://example.org/a b";
+/// let (rest, _) = uri_parser(input).unwrap();
+/// assert_eq!(ParseError::trailing(input, rest), ParseError::TrailingInput { span: 20..22 });
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum ParseError {
+    /// "1http:", or no ":" after the scheme
+    InvalidScheme { span: Range<usize> },
+    /// "//" is not followed by a userinfo and a host that can be read
+    InvalidAuthority { span: Range<usize> },
+    /// a host that is recognized but not valid, like "-example.org" or "1.2.3"
+    InvalidHost {
+        reason: &'static str,
+        span: Range<usize>,
+    },
+    /// a part of an IPv4 above 255, like the "256" of "192.168.0.256"
+    InvalidOctet { value: u64, span: Range<usize> },
+    /// a port above 65535
+    PortOutOfRange { span: Range<usize> },
+    /// a "%" that is not followed by two hexadecimal digits
+    InvalidPercentEncoding { span: Range<usize> },
+    /// bytes that are not UTF-8, in a URI read from bytes
+    InvalidUtf8 { span: Range<usize> },
+    /// the URI ends before the input does: the " y" of "http://a.com/x y"
+    TrailingInput { span: Range<usize> },
+    /// anything else, with the context of the parser that stopped: "path", "query"...
+    Unexpected {
+        context: &'static str,
+        span: Range<usize>,
+    },
+}
+
+impl ParseError {
+    /// The bytes of the input that are at fault
+    pub fn span(&self) -> Range<usize> {
+        match self {
+            ParseError::InvalidScheme { span }
+            | ParseError::InvalidAuthority { span }
+            | ParseError::InvalidHost { span, .. }
+            | ParseError::InvalidOctet { span, .. }
+            | ParseError::PortOutOfRange { span }
+            | ParseError::InvalidPercentEncoding { span }
+            | ParseError::InvalidUtf8 { span }
+            | ParseError::TrailingInput { span }
+            | ParseError::Unexpected { span, .. } => span.clone(),
+        }
+    }
+
+    /// The same error, at another place
+    pub(crate) fn with_span(mut self, span: Range<usize>) -> Self {
+        match &mut self {
+            ParseError::InvalidScheme { span: s }
+            | ParseError::InvalidAuthority { span: s }
+            | ParseError::InvalidHost { span: s, .. }
+            | ParseError::InvalidOctet { span: s, .. }
+            | ParseError::PortOutOfRange { span: s }
+            | ParseError::InvalidPercentEncoding { span: s }
+            | ParseError::InvalidUtf8 { span: s }
+            | ParseError::TrailingInput { span: s }
+            | ParseError::Unexpected { span: s, .. } => *s = span,
+        }
+        self
+    }
+
+    /// What went wrong, without the position
+    pub fn message(&self) -> String {
+        match self {
+            ParseError::InvalidScheme { .. } => "invalid scheme".to_string(),
+            ParseError::InvalidAuthority { .. } => "invalid authority".to_string(),
+            ParseError::InvalidHost { reason, .. } => format!("invalid host: {}", reason),
+            ParseError::InvalidOctet { value, .. } => {
+                format!("IPv4 address part out of range: {}", value)
+            }
+            ParseError::PortOutOfRange { .. } => "port out of range".to_string(),
+            ParseError::InvalidPercentEncoding { .. } => {
+                "\"%\" is not followed by two hexadecimal digits".to_string()
+            }
+            ParseError::InvalidUtf8 { .. } => "invalid UTF-8".to_string(),
+            ParseError::TrailingInput { .. } => "unexpected input after the URI".to_string(),
+            ParseError::Unexpected { context, .. } => format!("unexpected input in {}", context),
+        }
+    }
+
+    /// Converts the error of a parser that was given `input`, a `&str` or bytes
+    pub fn from_nom<I: InputLength>(input: I, error: nom::Err<UriError<I>>) -> Self {
+        match error {
+            nom::Err::Error(e) | nom::Err::Failure(e) => {
+                let start = input.input_len().saturating_sub(e.input.input_len());
+                let span = e.error.span();
+                e.error.with_span(start + span.start..start + span.end)
+            }
+            nom::Err::Incomplete(_) => {
+                let end = input.input_len();
+                ParseError::Unexpected {
+                    context: "uri",
+                    span: end..end,
+                }
+            }
+        }
+    }
+
+    /// What a parser that was given `input` left unparsed, `rest` being a suffix
+    /// of `input`: the parsers report the errors they find, so it is only trailing.
+    pub fn trailing(input: &str, rest: &str) -> Self {
+        let start = input.len().saturating_sub(rest.len());
+        ParseError::TrailingInput {
+            span: start..input.len(),
+        }
+    }
+}
+
+// The length in bytes of the first characters of the input
+pub(crate) fn char_length(input: &str, chars: usize) -> usize {
+    input.chars().take(chars).map(char::len_utf8).sum()
+}
+
+/// Finds the part of an IPv4 host that is out of range, the first one above 255
+pub(crate) fn invalid_octet(host: &str) -> ParseError {
+    let mut part_start = 0;
+    for part in host.split('.') {
+        let value = parse_whatwg_number(part).unwrap_or(0);
+        if value > 255 {
+            return ParseError::InvalidOctet {
+                value,
+                span: part_start..part_start + part.len(),
+            };
+        }
+        part_start += part.len() + 1;
+    }
+    ParseError::InvalidHost {
+        reason: Ipv4Error::OutOfRange.reason(),
+        span: 0..host.len(),
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let span = self.span();
+        write!(
+            f,
+            "{}, at bytes {}..{}",
+            self.message(),
+            span.start,
+            span.end
+        )
+    }
+}
+
+impl Error for ParseError {}
+
+// The contexts of the parsers of the crate, those of other parsers are not kept
+const CONTEXTS: [&str; 19] = [
+    "uri",
+    "uri reference",
+    "relative reference",
+    "scheme parsing error",
+    "authority",
+    "ip or host",
+    "host",
+    "ip",
+    "ip literal",
+    "ipv6",
+    "zone",
+    "ipvfuture",
+    "port",
+    "path",
+    "rootless path",
+    "relative path",
+    "query",
+    "fragment",
+    "percent encoding",
+];
+
+// "the IPv6 zone, in the IP address within brackets, in the host, in the URI" is 4 deep
+const MAX_CONTEXTS: usize = 8;
+
+/// The error type of the parsers, in place of nom's `VerboseError`: the input from
+/// where the culprit starts, what went wrong with a span counted from there, and
+/// the contexts of the parsers that were reading it. It does not allocate, so the
+/// parsers that fail while another one succeeds cost little.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct UriError<I> {
+    /// the input, from the culprit to its end
+    pub input: I,
+    /// what went wrong, its span being counted from the start of `input`
+    pub error: ParseError,
+    // whether a parser told what went wrong, or only that it did not match
+    explained: bool,
+    // indexes in CONTEXTS, from the innermost, the deepest ones are kept
+    contexts: [u8; MAX_CONTEXTS],
+    depth: u8,
+}
+
+impl<I> UriError<I> {
+    /// The error of a parser that knows what went wrong
+    pub fn new(input: I, error: ParseError) -> Self {
+        UriError {
+            input,
+            error,
+            explained: true,
+            contexts: [0; MAX_CONTEXTS],
+            depth: 0,
+        }
+    }
+
+    /// The contexts of the parsers of the crate that were reading the culprit,
+    /// from the innermost
+    pub fn contexts(&self) -> impl Iterator<Item = &'static str> + '_ {
+        self.contexts[..usize::from(self.depth)]
+            .iter()
+            .map(|&index| CONTEXTS[usize::from(index)])
+    }
+
+    fn push_context(&mut self, context: &str) {
+        let Some(index) = CONTEXTS.iter().position(|&c| c == context) else {
+            return;
+        };
+        let depth = usize::from(self.depth);
+        let index = index as u8;
+        if depth < MAX_CONTEXTS && self.contexts[..depth].last() != Some(&index) {
+            self.contexts[depth] = index;
+            self.depth += 1;
+        }
+    }
+
+    /// The same error in another input, `f` giving the input at the same place
+    pub fn map_input<J>(self, f: impl FnOnce(I) -> J) -> UriError<J> {
+        UriError {
+            input: f(self.input),
+            error: self.error,
+            explained: self.explained,
+            contexts: self.contexts,
+            depth: self.depth,
+        }
+    }
+
+    /// The same error, as given by parsers with these contexts, from the innermost
+    #[cfg(test)]
+    pub(crate) fn with_contexts(mut self, contexts: &[&'static str]) -> Self {
+        for context in contexts {
+            self.push_context(context);
+        }
+        self
+    }
+
+    /// Tells what went wrong instead, keeping the contexts
+    pub(crate) fn explain(mut self, input: I, error: ParseError) -> Self {
+        self.input = input;
+        self.error = error;
+        self.explained = true;
+        self
+    }
+}
+
+impl<'a> NomParseError<&'a str> for UriError<&'a str> {
+    // a parser that does not match, the context above it tells which one
+    fn from_error_kind(input: &'a str, _: ErrorKind) -> Self {
+        let mut error = UriError::new(
+            input,
+            ParseError::Unexpected {
+                context: "uri",
+                span: 0..0,
+            },
+        );
+        error.explained = false;
+        error
+    }
+
+    fn append(_: &'a str, _: ErrorKind, other: Self) -> Self {
+        other
+    }
+}
+
+impl<'a> ContextError<&'a str> for UriError<&'a str> {
+    fn add_context(input: &'a str, context: &'static str, mut other: Self) -> Self {
+        if !other.explained {
+            // the culprit is where the innermost parser with a context started
+            other = other.explain(
+                input,
+                ParseError::Unexpected {
+                    context,
+                    span: 0..char_length(input, 1),
+                },
+            );
+        }
+        other.push_context(context);
+        other
+    }
+}
+
+impl<'a, E> FromExternalError<&'a str, E> for UriError<&'a str> {
+    fn from_external_error(input: &'a str, kind: ErrorKind, _: E) -> Self {
+        UriError::from_error_kind(input, kind)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::uri::{reference::uri_reference_parser, uri_parser, uri_parser_with, ParserOptions};
+
+    fn parse_error(input: &str) -> ParseError {
+        match uri_parser(input) {
+            Ok((rest, _)) => ParseError::trailing(input, rest),
+            Err(e) => ParseError::from_nom(input, e),
+        }
+    }
+
+    #[test]
+    fn test_from_nom() {
+        assert_eq!(
+            parse_error("1http://example.org"),
+            ParseError::InvalidScheme { span: 0..5 }
+        );
+        assert_eq!(
+            parse_error("http//example.org"),
+            ParseError::InvalidScheme { span: 0..4 }
+        );
+        assert_eq!(
+            parse_error("http://10.0.300.1/"),
+            ParseError::InvalidOctet {
+                value: 300,
+                span: 12..15
+            }
+        );
+        assert_eq!(
+            parse_error("http://1.2.3/"),
+            ParseError::InvalidHost {
+                reason: "wrong number of parts in IPv4 address",
+                span: 7..12
+            }
+        );
+        assert_eq!(
+            parse_error("http://-example.org/"),
+            ParseError::InvalidHost {
+                reason: "domain label starts with a hyphen",
+                span: 7..19
+            }
+        );
+
+        // the positions are in the original input, even when it is cleaned
+        let input = "\thttp://0x100.1.1.1/";
+        let error = ParseError::from_nom(
+            input,
+            uri_parser_with(ParserOptions::whatwg())(input).unwrap_err(),
+        );
+        assert_eq!(
+            error,
+            ParseError::InvalidOctet {
+                value: 256,
+                span: 8..13
+            }
+        );
+        assert_eq!(
+            error.to_string(),
+            "IPv4 address part out of range: 256, at bytes 8..13"
+        );
+    }
+
+    #[test]
+    fn test_typed_errors() {
+        // the parsers tell what went wrong, nothing is guessed from what they leave
+        let failure = |input| match uri_parser(input) {
+            Err(nom::Err::Failure(e)) => e,
+            other => panic!("unexpected result: {:?}", other),
+        };
+        let error = failure("http://example.org:65536");
+        assert_eq!(error.contexts().collect::<Vec<_>>(), ["port", "uri"]);
+        assert_eq!(
+            ParseError::from_nom("http://example.org:65536", nom::Err::Failure(error)),
+            ParseError::PortOutOfRange { span: 19..24 }
+        );
+        assert_eq!(
+            parse_error("http://example.org/a%zz"),
+            ParseError::InvalidPercentEncoding { span: 20..23 }
+        );
+        assert_eq!(
+            parse_error("http://example.org/a%1é"),
+            ParseError::InvalidPercentEncoding { span: 20..24 }
+        );
+        let error = failure("http://[::g]/");
+        assert_eq!(
+            error.contexts().collect::<Vec<_>>(),
+            ["ip literal", "ip or host", "uri"]
+        );
+        assert_eq!(
+            parse_error("http://[::g]/"),
+            ParseError::InvalidAuthority { span: 7..12 }
+        );
+        let input = "//[::g]/";
+        assert_eq!(
+            ParseError::from_nom(input, uri_reference_parser(input).unwrap_err()),
+            ParseError::InvalidAuthority { span: 2..7 }
+        );
+
+        // a span over removed characters covers them in the original input
+        let input = "http://-exa\tmple.org/";
+        assert_eq!(
+            ParseError::from_nom(
+                input,
+                uri_parser_with(ParserOptions::whatwg())(input).unwrap_err()
+            ),
+            ParseError::InvalidHost {
+                reason: "domain label starts with a hyphen",
+                span: 7..20
+            }
+        );
+    }
+
+    #[test]
+    fn test_trailing() {
+        assert_eq!(
+            parse_error("http://a.com/x y"),
+            ParseError::TrailingInput { span: 14..16 }
+        );
+        assert_eq!(
+            ParseError::TrailingInput { span: 14..16 }.to_string(),
+            "unexpected input after the URI, at bytes 14..16"
+        );
+    }
+}
@@ -0,0 +1,239 @@
+//! The application/x-www-form-urlencoded format of HTML forms, as described by the
+//! [WHATWG URL standard](https://url.spec.whatwg.org/#application/x-www-form-urlencoded):
+//! a "+" is a space and everything else but alphanumerics and "*-._" is percent-encoded.
+
+use std::{borrow::Cow, fmt};
+
+use crate::uri::{
+    percent::percent_decode,
+    query::{query_pairs, QueryParam},
+    URI,
+};
+
+/// The decoded name/value pairs of a query, in order and with the duplicates.
+///
+/// ```
+/// use uri_parser::{uri_parser, QueryParams};
+///
+/// let (_, uri) = uri_parser("https://example.org/search?q=hello+world%21&lang=fr").unwrap();
+/// let mut params = uri.query_params();
+/// assert_eq!(params.get("q"), Some("hello world!"));
+///
+/// params.append("page", "2");
+/// params.remove("lang");
+/// assert_eq!(params.to_string(), "q=hello+world%21&page=2");
+/// ```
+#[derive(Debug, Default, PartialEq, Eq, Clone)]
+pub struct QueryParams<'a> {
+    pairs: Vec<(Cow<'a, str>, Cow<'a, str>)>,
+}
+
+impl<'a> QueryParams<'a> {
+    pub fn new() -> Self {
+        QueryParams::default()
+    }
+
+    /// Parses a query written without the "?", as `query_params_parser` splits it.
+    /// A pair without "=" gets an empty value, and the bytes that are not valid
+    /// UTF-8 once decoded are replaced with "\u{FFFD}".
+    pub fn parse(query: &'a str) -> Self {
+        query_pairs(query).collect()
+    }
+
+    pub fn len(&self) -> usize {
+        self.pairs.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.pairs.is_empty()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.pairs
+            .iter()
+            .map(|(name, value)| (name.as_ref(), value.as_ref()))
+    }
+
+    /// The value of the first pair with this name
+    pub fn get(&self, name: &str) -> Option<&str> {
+        self.iter()
+            .find(|(pair_name, _)| *pair_name == name)
+            .map(|(_, value)| value)
+    }
+
+    /// The values of all the pairs with this name, in order
+    pub fn get_all(&self, name: &str) -> Vec<&str> {
+        self.iter()
+            .filter(|(pair_name, _)| *pair_name == name)
+            .map(|(_, value)| value)
+            .collect()
+    }
+
+    pub fn contains(&self, name: &str) -> bool {
+        self.iter().any(|(pair_name, _)| pair_name == name)
+    }
+
+    pub fn append(&mut self, name: impl Into<Cow<'a, str>>, value: impl Into<Cow<'a, str>>) {
+        self.pairs.push((name.into(), value.into()));
+    }
+
+    /// Removes all the pairs with this name
+    pub fn remove(&mut self, name: &str) {
+        self.pairs.retain(|(pair_name, _)| pair_name != name);
+    }
+
+    /// Sorts the pairs by name, keeping the order of the pairs that share a name.
+    /// Names are compared by UTF-16 code units, as browsers do.
+    pub fn sort(&mut self) {
+        self.pairs
+            .sort_by(|(a, _), (b, _)| a.encode_utf16().cmp(b.encode_utf16()));
+    }
+}
+
+/// Decodes the pairs of `query_params_parser`
+impl<'a> FromIterator<QueryParam<'a>> for QueryParams<'a> {
+    fn from_iter<T: IntoIterator<Item = QueryParam<'a>>>(params: T) -> Self {
+        let pairs = params
+            .into_iter()
+            .map(|param| (decode(param.key), decode(param.value.unwrap_or_default())))
+            .collect();
+        QueryParams { pairs }
+    }
+}
+
+/// Serializes the pairs: "name=value&other+name=other%21value"
+impl fmt::Display for QueryParams<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, (name, value)) in self.pairs.iter().enumerate() {
+            if i > 0 {
+                f.write_str("&")?;
+            }
+            write_encoded(f, name)?;
+            f.write_str("=")?;
+            write_encoded(f, value)?;
+        }
+        Ok(())
+    }
+}
+
+// "+" is a space, then the "%HH" triplets are decoded
+pub(crate) fn decode(input: Cow<'_, str>) -> Cow<'_, str> {
+    if !input.contains(['+', '%']) {
+        return input;
+    }
+    let spaced = input.replace('+', " ");
+    Cow::Owned(String::from_utf8_lossy(&percent_decode(&spaced)).into_owned())
+}
+
+fn write_encoded(f: &mut fmt::Formatter<'_>, input: &str) -> fmt::Result {
+    for byte in input.bytes() {
+        match byte {
+            b' ' => f.write_str("+")?,
+            b'*' | b'-' | b'.' | b'_' => write!(f, "{}", byte as char)?,
+            _ if byte.is_ascii_alphanumeric() => write!(f, "{}", byte as char)?,
+            _ => write!(f, "%{:02X}", byte)?,
+        }
+    }
+    Ok(())
+}
+
+impl<'a> URI<'a> {
+    /// The query decoded as a form, empty if there is no query
+    pub fn query_params(&self) -> QueryParams<'_> {
+        self.query().map(QueryParams::parse).unwrap_or_default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::uri::query::query_params_parser;
+
+    #[test]
+    fn test_parse() {
+        let params = QueryParams::parse("q=hello+world%21&flag&&a=1&a=2&x=1;y=2&caf%C3%A9=%FF");
+        assert_eq!(
+            params.iter().collect::<Vec<_>>(),
+            vec![
+                ("q", "hello world!"),
+                ("flag", ""),
+                ("a", "1"),
+                ("a", "2"),
+                ("x", "1;y=2"),
+                ("café", "\u{fffd}"),
+            ]
+        );
+        assert_eq!(params.get("a"), Some("1"));
+        assert_eq!(params.get_all("a"), vec!["1", "2"]);
+        assert_eq!(params.get("missing"), None);
+        assert!(params.contains("flag"));
+
+        // "%2B" is a "+", invalid triplets are kept
+        let params = QueryParams::parse("sum=1%2B1&rate=100%");
+        assert_eq!(params.get("sum"), Some("1+1"));
+        assert_eq!(params.get("rate"), Some("100%"));
+
+        assert!(QueryParams::parse("").is_empty());
+
+        // the raw pairs of the query parser are decoded
+        let (_, raw) = query_params_parser("?q=a+b&flag").unwrap();
+        let params: QueryParams = raw.into_iter().collect();
+        assert_eq!(
+            params.iter().collect::<Vec<_>>(),
+            vec![("q", "a b"), ("flag", "")]
+        );
+    }
+
+    #[test]
+    fn test_serialize() {
+        let mut params = QueryParams::new();
+        params.append("name", "Jörg Müller");
+        params.append("math", "1+1=2 & more");
+        params.append("keep", "*-._~");
+        assert_eq!(
+            params.to_string(),
+            "name=J%C3%B6rg+M%C3%BCller&math=1%2B1%3D2+%26+more&keep=*-._%7E"
+        );
+
+        // serializing then parsing gives the same pairs
+        let written = params.to_string();
+        assert_eq!(QueryParams::parse(&written), params);
+    }
+
+    #[test]
+    fn test_remove_and_sort() {
+        let mut params = QueryParams::parse("z=1&b=2&a=3&b=4&\u{1F600}=5&\u{FF61}=6");
+        params.remove("z");
+        params.sort();
+        assert_eq!(
+            params.iter().collect::<Vec<_>>(),
+            vec![
+                ("a", "3"),
+                ("b", "2"),
+                ("b", "4"),
+                // U+1F600 is written with a surrogate pair, below U+FF61 in UTF-16
+                ("\u{1F600}", "5"),
+                ("\u{FF61}", "6"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_uri_query_params() {
+        use crate::uri::uri_parser;
+
+        let (_, uri) = uri_parser("http://example.org/?q=a+b&q=c").unwrap();
+        assert_eq!(uri.query_params().get_all("q"), vec!["a b", "c"]);
+
+        let (_, mut uri) = uri_parser("http://example.org/").unwrap();
+        assert!(uri.query_params().is_empty());
+
+        let mut params = QueryParams::new();
+        params.append("q", "a b");
+        uri.set_query_params(&params);
+        assert_eq!(uri.to_string(), "http://example.org/?q=a+b");
+
+        uri.set_query_params(&QueryParams::new());
+        assert_eq!(uri.query(), None);
+    }
+}
@@ -1,185 +1,510 @@
+use std::{borrow::Cow, fmt, net::Ipv6Addr};
+
 use nom::{
     self,
     branch::alt,
-    bytes::complete::{tag, take},
-    character::complete::{alpha1, one_of},
-    error::{context, ErrorKind},
-    multi::{count, many1, many_m_n},
-    sequence::{terminated, tuple},
-    AsChar,
+    bytes::complete::{tag, tag_no_case, take_while1, take_while_m_n},
+    combinator::{map_res, opt, recognize},
+    error::{context, ErrorKind, ParseError as _},
+    multi::many1_count,
+    sequence::{delimited, pair, preceded, tuple},
 };
 
-use crate::uri::CustomResult;
+use crate::uri::{
+    domain::DomainName,
+    error::{invalid_octet, ParseError, UriError},
+    idna,
+    ipv4::{parse_ipv4, Ipv4Error, Ipv4Mode},
+    options::ParserOptions,
+    percent::{is_sub_delim, is_unreserved, pct_encoded, percent_decode},
+    CustomResult,
+};
 
 /// The difficulty when parsing the host is that it may be two entirely different
-/// things, either "example.com" or "185.42.23.3"
-#[derive(Debug, PartialEq, Eq)]
-pub enum Host {
+/// things, either "example.com" or "185.42.23.3".
+/// Names are borrowed from the input, unless they are converted, as IDNs are.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Host<'a> {
     /// a string, like "example.com"
-    HOST(String),
+    HOST(Cow<'a, str>),
     /// an array of four bytes, parsed from a "185.42.23.3" string
     IP([u8; 4]),
+    /// sixteen bytes, parsed from a "[2001:db8::1]" string, with the optional
+    /// zone identifier of "[fe80::1%25eth0]" (RFC 6874), here "eth0"
+    IPV6 {
+        address: [u8; 16],
+        zone: Option<Cow<'a, str>>,
+    },
+    /// an address of a future IP version, like "[v7.some:thing]", kept as "v7.some:thing"
+    IPVFUTURE(Cow<'a, str>),
 }
 
-// Parse a text host, like "localhost" or "youtube.com"
-pub fn hostname_parser(input: &str) -> CustomResult<&str, Host> {
-    context(
-        "host",
-        // the alt combinator takes two parsers as arguments and tries both until one succeed
-        alt((
-            tuple((
-                many1(
-                    // find many patterns that end with a period ("en.wikipedia.org")
-                    terminated(
-                        alphanumerichyphen1,
-                        tag("."), // bytes::complete::tag
-                    ),
-                ),
-                alpha1, // the last string of letters (the "org" of "en.wikipedia.org")
-            )),
-            // In case there is no period in the domain name (ex: localhost).
-            // Within the alt combinator, all options have to return the same type.
-            // The type here is: (Vec<String>, String), which explains the use of
-            // many_m_n(1, 1, _)
-            tuple((
-                many_m_n(1, 1, alphanumerichyphen1),
-                take(0 as usize), // bytes::complete::take
-            )),
-        )),
-    )(input)
-    .map(|(next_input, mut res)| {
-        if !res.1.is_empty() {
-            res.0.push(res.1);
+impl<'a> Host<'a> {
+    /// The Unicode form of a domain name: "xn--mnchen-3ya.de" gives "münchen.de".
+    /// A name that is not a valid internationalized one is given as it is, an IP gives `None`.
+    pub fn to_unicode(&self) -> Option<String> {
+        match self {
+            Host::HOST(name) => Some(idna::to_unicode(name).unwrap_or_else(|_| name.to_string())),
+            _ => None,
         }
-        // rebuild the hostname
-        // ["en", "wikipedia", "org"] => "en.wikipedia.org"
-        (next_input, Host::HOST(res.0.join(".")))
-    })
+    }
+
+    /// Copies the borrowed name, to outlive the input
+    pub fn into_owned(self) -> Host<'static> {
+        let owned = |text: Cow<'a, str>| Cow::Owned(text.into_owned());
+        match self {
+            Host::HOST(name) => Host::HOST(owned(name)),
+            Host::IP(octets) => Host::IP(octets),
+            Host::IPV6 { address, zone } => Host::IPV6 {
+                address,
+                zone: zone.map(owned),
+            },
+            Host::IPVFUTURE(address) => Host::IPVFUTURE(owned(address)),
+        }
+    }
+}
+
+/// IPv6 addresses are written in their shortest form, within brackets: "[2001:db8::1]"
+impl fmt::Display for Host<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Host::HOST(name) => f.write_str(name),
+            Host::IP([a, b, c, d]) => write!(f, "{}.{}.{}.{}", a, b, c, d),
+            Host::IPV6 { address, zone } => {
+                write!(f, "[{}", Ipv6Addr::from(*address))?;
+                if let Some(zone) = zone {
+                    // the "%" of the zone is percent-encoded in a URI
+                    write!(f, "%25{}", zone)?;
+                }
+                f.write_str("]")
+            }
+            Host::IPVFUTURE(address) => write!(f, "[{}]", address),
+        }
+    }
+}
+
+// Parse a text host, like "localhost" or "youtube.com", checked with the DNS rules
+pub fn hostname_parser(input: &str) -> CustomResult<&str, Host<'_>> {
+    context("host", domain_name_parser(ParserOptions::rfc3986()))(input)
+}
+
+// The same, accepting underscores: "_sip._tcp.example.com"
+pub fn lenient_hostname_parser(input: &str) -> CustomResult<&str, Host<'_>> {
+    let options = ParserOptions {
+        lenient_hostnames: true,
+        ..ParserOptions::rfc3986()
+    };
+    context("host", domain_name_parser(options))(input)
+}
+
+fn domain_name_parser(options: ParserOptions) -> impl FnMut(&str) -> CustomResult<&str, Host<'_>> {
+    move |input| {
+        // underscores are taken in both modes, to give a clear error in the strict one
+        let (next_input, name) =
+            take_while1(|c: char| c.is_ascii_alphanumeric() || c == '-' || c == '.' || c == '_')(
+                input,
+            )?;
+
+        let checked = if options.lenient_hostnames {
+            DomainName::validate_lenient(name)
+        } else {
+            DomainName::validate(name)
+        };
+        if let Err(e) = checked {
+            return Err(invalid_host(input, e.reason(), name.len()));
+        }
+        let name = if options.lowercase_hostnames && name.bytes().any(|b| b.is_ascii_uppercase()) {
+            Cow::Owned(name.to_ascii_lowercase())
+        } else {
+            Cow::Borrowed(name)
+        };
+        Ok((next_input, Host::HOST(name)))
+    }
 }
 
-// This is a bit complex, but it’s basically just a copied version
-// of nom’s alphanumeric1 parser, with the hyphen (-) added.
-fn alphanumerichyphen1<T>(i: T) -> CustomResult<T, T>
-where
-    T: nom::InputTakeAtPosition,
-    <T as nom::InputTakeAtPosition>::Item: AsChar,
-{
-    i.split_at_position1_complete(
-        |item| {
-            let char_item = item.as_char();
-            !(char_item == '-') && !char_item.is_alphanum()
+// A host that is recognized but not valid is a failure: the other host parsers are not tried
+fn invalid_host<'a>(
+    input: &'a str,
+    reason: &'static str,
+    length: usize,
+) -> nom::Err<UriError<&'a str>> {
+    nom::Err::Failure(UriError::new(
+        input,
+        ParseError::InvalidHost {
+            reason,
+            span: 0..length,
         },
-        ErrorKind::AlphaNumeric,
-    )
+    ))
 }
 
-// To get each individual number, we try to find one to three consecutive digits
-// Parse an IPv4
-fn ip_parser(input: &str) -> CustomResult<&str, Host> {
-    context(
-        "ip",
-        tuple(
-            //
-            (
-                count(terminated(ip_number_parser, tag(".")), 3),
-                ip_number_parser,
-            ),
-        ),
-    )(input)
-    .map(|(next_input, res)| {
-        let mut result: [u8; 4] = [0, 0, 0, 0];
-        res.0
-            .into_iter()
-            .enumerate()
-            .for_each(|(i, v)| result[i] = v);
-        result[3] = res.1;
-        (next_input, Host::IP(result))
+// Whether a label of the name is in the ASCII form of an IDN, "xn--mnchen-3ya"
+fn has_ace_label(name: &str) -> bool {
+    name.split('.').any(|label| {
+        label
+            .get(..4)
+            .is_some_and(|prefix| prefix.eq_ignore_ascii_case("xn--"))
     })
 }
 
-// using our n_to_m_digits parser and convert them to a u8.
-fn ip_number_parser(input: &str) -> CustomResult<&str, u8> {
-    context(
-        "ip number",
-        // this function is defined below
-        n_to_m_digits(1, 3),
+// An internationalized domain name, like "münchen.de" or "xn--mnchen-3ya.de",
+// kept in its ASCII form. Plain ASCII names are left to hostname_parser.
+fn idn_parser(lenient: bool) -> impl FnMut(&str) -> CustomResult<&str, Host<'_>> {
+    move |input| {
+        let (next_input, name) = take_while1(|c: char| {
+            !c.is_ascii()
+                || c.is_ascii_alphanumeric()
+                || c == '-'
+                || c == '.'
+                || (lenient && c == '_')
+        })(input)?;
+
+        if name.is_ascii() && !has_ace_label(name) {
+            return Err(nom::Err::Error(UriError::from_error_kind(
+                input,
+                ErrorKind::Verify,
+            )));
+        }
+
+        let invalid = |reason| invalid_host(input, reason, name.len());
+        let (ascii, checked) = if lenient {
+            let ascii = idna::to_ascii_lenient(name).map_err(|e| invalid(e.reason()))?;
+            let checked = DomainName::validate_lenient(&ascii);
+            (ascii, checked)
+        } else {
+            let ascii = idna::to_ascii(name).map_err(|e| invalid(e.reason()))?;
+            let checked = DomainName::validate(&ascii);
+            (ascii, checked)
+        };
+        checked.map_err(|e| invalid(e.reason()))?;
+        Ok((next_input, Host::HOST(Cow::Owned(ascii))))
+    }
+}
+
+// Parse an IPv4, the RFC 3986 way
+fn ip_parser(input: &str) -> CustomResult<&str, Host<'_>> {
+    context("ip", ipv4_parser(Ipv4Mode::Strict))(input)
+}
+
+/// An IPv4 host, "127.0.0.1", or also "0x7f.1" in WHATWG mode.
+/// A host that is not an IPv4 at all is an error, so that a domain name parser
+/// may be tried next, but an invalid IPv4 like "192.168.0.1444" is a failure.
+pub fn ipv4_parser(mode: Ipv4Mode) -> impl FnMut(&str) -> CustomResult<&str, Host<'_>> {
+    move |input| {
+        // the whole host is read: "1.2.3.4a" is a name, not an IPv4 followed by "a"
+        let (next_input, host) = take_while1(|c: char| {
+            c.is_ascii_alphanumeric() || "-._~".contains(c) || !c.is_ascii()
+        })(input)?;
+
+        match parse_ipv4(host, mode) {
+            Ok(octets) => Ok((next_input, Host::IP(octets))),
+            Err(Ipv4Error::NotAnAddress) => Err(nom::Err::Error(UriError::from_error_kind(
+                input,
+                ErrorKind::Verify,
+            ))),
+            Err(Ipv4Error::OutOfRange) => {
+                Err(nom::Err::Failure(UriError::new(input, invalid_octet(host))))
+            }
+            Err(e) => Err(invalid_host(input, e.reason(), host.len())),
+        }
+    }
+}
+
+// The four numbers of an IPv4, for the IPv6 parser since an IPv6
+// may end with an IPv4, as in "::ffff:192.0.2.1"
+fn ipv4_octets_parser(input: &str) -> CustomResult<&str, [u8; 4]> {
+    map_res(
+        take_while1(|c: char| c.is_ascii_digit() || c == '.'),
+        |ip| parse_ipv4(ip, Ipv4Mode::Strict),
     )(input)
-    .and_then(|(next_input, result)| {
-        match result.parse::<u8>() {
-            Ok(n) => Ok((next_input, n)),
-            Err(_) => Err(
-                // putting this comment just to split up the returned error
-                nom::Err::Error(
-                    // I am getting sick of so much encapsulation
-                    nom::error::VerboseError {
-                        // this is better, innit?
-                        errors: vec![],
-                    },
-                ),
-            ),
+}
+
+// h16 = 1*4HEXDIG, one of the eight 16 bits pieces of an IPv6
+fn h16_parser(input: &str) -> CustomResult<&str, u16> {
+    map_res(take_while_m_n(1, 4, |c: char| c.is_ascii_hexdigit()), |h| {
+        u16::from_str_radix(h, 16)
+    })(input)
+}
+
+// The pieces of an IPv6 that were read, at most eight
+struct Pieces {
+    pieces: [u16; 8],
+    count: usize,
+}
+
+impl Pieces {
+    fn as_slice(&self) -> &[u16] {
+        &self.pieces[..self.count]
+    }
+}
+
+// Parses pieces separated by single colons, like "2001:db8" in "2001:db8::1",
+// and stops in front of a "::". An IPv4 counts as two pieces and can only come last.
+// Returns the pieces and whether they ended with an IPv4.
+fn ipv6_pieces_parser(input: &str) -> CustomResult<&str, (Pieces, bool)> {
+    let mut pieces = Pieces {
+        pieces: [0; 8],
+        count: 0,
+    };
+    let mut next_input = input;
+    loop {
+        let too_many = || nom::Err::Error(UriError::from_error_kind(input, ErrorKind::Verify));
+        if let Ok((rest, ip)) = ipv4_octets_parser(next_input) {
+            let slots = pieces.pieces.get_mut(pieces.count..pieces.count + 2);
+            let slots = slots.ok_or_else(too_many)?;
+            slots[0] = u16::from_be_bytes([ip[0], ip[1]]);
+            slots[1] = u16::from_be_bytes([ip[2], ip[3]]);
+            pieces.count += 2;
+            return Ok((rest, (pieces, true)));
+        }
+        match h16_parser(next_input) {
+            Ok((rest, piece)) => {
+                let slot = pieces.pieces.get_mut(pieces.count).ok_or_else(too_many)?;
+                *slot = piece;
+                pieces.count += 1;
+                next_input = rest;
+            }
+            // no piece at all is fine ("::1"), but a colon has to be followed by one ("1:]")
+            Err(_) if pieces.count == 0 => return Ok((next_input, (pieces, false))),
+            Err(e) => return Err(e),
+        }
+        if next_input.starts_with(':') && !next_input.starts_with("::") {
+            next_input = &next_input[1..];
+        } else {
+            return Ok((next_input, (pieces, false)));
         }
+    }
+}
+
+// All the textual forms of RFC 4291: "2001:db8:0:0:0:0:0:1", "2001:db8::1", "::", "::ffff:192.0.2.1"
+fn ipv6_parser(input: &str) -> CustomResult<&str, [u8; 16]> {
+    let invalid = |i| nom::Err::Error(UriError::from_error_kind(i, ErrorKind::Verify));
+
+    let (next_input, (head, head_ends_with_ipv4)) = ipv6_pieces_parser(input)?;
+    let head = head.as_slice();
+
+    let mut pieces = [0u16; 8];
+    let next_input = match next_input.strip_prefix("::") {
+        // "::" stands for at least one piece of zeros
+        Some(rest) if !head_ends_with_ipv4 => {
+            let (rest, (tail, _)) = ipv6_pieces_parser(rest)?;
+            let tail = tail.as_slice();
+            if head.len() + tail.len() > 7 {
+                return Err(invalid(input));
+            }
+            pieces[..head.len()].copy_from_slice(head);
+            pieces[8 - tail.len()..].copy_from_slice(tail);
+            rest
+        }
+        _ if head.len() == 8 => {
+            pieces.copy_from_slice(head);
+            next_input
+        }
+        _ => return Err(invalid(input)),
+    };
+
+    let mut address = [0u8; 16];
+    for (i, piece) in pieces.iter().enumerate() {
+        address[2 * i..2 * i + 2].copy_from_slice(&piece.to_be_bytes());
+    }
+    Ok((next_input, address))
+}
+
+// ZoneID = 1*( unreserved / pct-encoded ), it comes after an encoded "%", that is "%25"
+fn zone_id_parser(input: &str) -> CustomResult<&str, &str> {
+    recognize(many1_count(alt((take_while1(is_unreserved), pct_encoded))))(input)
+}
+
+// An IPv6 with its optional zone, like "fe80::1%25eth0"
+fn ipv6_with_zone_parser(input: &str) -> CustomResult<&str, Host<'_>> {
+    pair(
+        context("ipv6", ipv6_parser),
+        opt(preceded(tag("%25"), context("zone", zone_id_parser))),
+    )(input)
+    .map(|(next_input, (address, zone))| {
+        let zone = zone.map(Cow::Borrowed);
+        (next_input, Host::IPV6 { address, zone })
     })
 }
 
-fn n_to_m_digits<'a>(min: usize, max: usize) -> impl FnMut(&'a str) -> CustomResult<&str, String> {
-    move |input| {
-        many_m_n(min, max, one_of("0123456789"))(input)
-            .map(|(next_input, result)| (next_input, result.into_iter().collect()))
+// IPvFuture = "v" 1*HEXDIG "." 1*( unreserved / sub-delims / ":" )
+fn ipvfuture_parser(input: &str) -> CustomResult<&str, Host<'_>> {
+    context(
+        "ipvfuture",
+        recognize(tuple((
+            tag_no_case("v"),
+            take_while1(|c: char| c.is_ascii_hexdigit()),
+            tag("."),
+            take_while1(|c| is_unreserved(c) || is_sub_delim(c) || c == ':'),
+        ))),
+    )(input)
+    .map(|(next_input, res)| (next_input, Host::IPVFUTURE(Cow::Borrowed(res))))
+}
+
+// IPv6 and future addresses are written within brackets: "http://[::1]:8080"
+fn ip_literal_parser(input: &str) -> CustomResult<&str, Host<'_>> {
+    context(
+        "ip literal",
+        delimited(
+            tag("["),
+            alt((ipvfuture_parser, ipv6_with_zone_parser)),
+            tag("]"),
+        ),
+    )(input)
+}
+
+/// Combine the IP literal, IP and hostname parsers
+pub fn ip_or_hostname_parser(input: &str) -> CustomResult<&str, Host<'_>> {
+    host_parser(ParserOptions::rfc3986())(input)
+}
+
+/// The same, reading IPv4 and host names the way the parsing options say
+pub fn host_parser(options: ParserOptions) -> impl FnMut(&str) -> CustomResult<&str, Host<'_>> {
+    move |input| context("ip or host", |input| dispatch_host(input, options))(input)
+}
+
+// Only tries the parsers that may read the host, from its first character: each parser
+// that fails builds an error, which a plain host name like "localhost" should not pay for
+fn dispatch_host(input: &str, options: ParserOptions) -> CustomResult<&str, Host<'_>> {
+    let first = input.chars().next();
+    if first == Some('[') {
+        return ip_literal_parser(input);
+    }
+    if options.domain_to_ascii {
+        let name = input
+            .find([':', '/', '?', '#'])
+            .map_or(input, |end| &input[..end]);
+        if name.contains('%') || !name.is_ascii() {
+            return mapped_host_parser(input, name.len(), options);
+        }
+    }
+    // a strict IPv4 starts with a digit, a WHATWG one may also end in "0x7f"
+    let ipv4 = match options.ipv4_mode {
+        Ipv4Mode::Strict if first.is_some_and(|c| c.is_ascii_digit()) => Some(ip_parser(input)),
+        Ipv4Mode::Whatwg if first.is_some_and(|c| c.is_ascii()) => {
+            Some(context("ip", ipv4_parser(Ipv4Mode::Whatwg))(input))
+        }
+        _ => None,
+    };
+    match ipv4 {
+        None | Some(Err(nom::Err::Error(_))) => {}
+        Some(result) => return result,
+    }
+
+    // "xn--" labels need the IDN parser as much as Unicode ones do
+    let name = input
+        .find(|c: char| !(c.is_ascii_alphanumeric() || "-._".contains(c) || !c.is_ascii()))
+        .map_or(input, |end| &input[..end]);
+    if !name.is_ascii() || has_ace_label(name) {
+        idn_parser(options.lenient_hostnames)(input)
+    } else {
+        context("host", domain_name_parser(options))(input)
     }
 }
 
-/// Combine both hostname and IP parsers with alt
-pub fn ip_or_hostname_parser(input: &str) -> CustomResult<&str, Host> {
-    context("ip or host", alt((ip_parser, hostname_parser)))(input)
+// A host the way browsers read it: percent-decoded and mapped with UTS #46, then
+// read as an IPv4 when it ends in a number, so that "%31%32%37.0.0.1" and
+// "１２７.0.0.1" are both 127.0.0.1
+fn mapped_host_parser(
+    input: &str,
+    length: usize,
+    options: ParserOptions,
+) -> CustomResult<&str, Host<'_>> {
+    let invalid = |reason| invalid_host(input, reason, length);
+    // bytes that are not UTF-8 become U+FFFD, which the mapping does not allow
+    let decoded = String::from_utf8_lossy(&percent_decode(&input[..length])).into_owned();
+    let ascii = idna::to_ascii_lenient(&decoded).map_err(|e| invalid(e.reason()))?;
+
+    let host = match parse_ipv4(&ascii, options.ipv4_mode) {
+        Ok(octets) => Host::IP(octets),
+        Err(Ipv4Error::NotAnAddress) => {
+            let checked = if options.lenient_hostnames {
+                DomainName::validate_lenient(&ascii)
+            } else {
+                DomainName::validate(&ascii)
+            };
+            checked.map_err(|e| invalid(e.reason()))?;
+            Host::HOST(Cow::Owned(ascii))
+        }
+        // the spans of the mapped host are not those of the input, the error covers it all
+        Err(e) => return Err(invalid(e.reason())),
+    };
+    Ok((&input[length..], host))
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use nom::{
-        error::{ErrorKind, VerboseError, VerboseErrorKind},
-        Err as NomErr,
-    };
+    use nom::Err as NomErr;
+
+    fn host_failure<'a>(
+        input: &'a str,
+        reason: &'static str,
+        length: usize,
+        contexts: &[&'static str],
+    ) -> CustomResult<&'a str, Host<'a>> {
+        let error = ParseError::InvalidHost {
+            reason,
+            span: 0..length,
+        };
+        Err(NomErr::Failure(
+            UriError::new(input, error).with_contexts(contexts),
+        ))
+    }
 
     #[test]
     fn test_hostname_parser() {
         assert_eq!(
             hostname_parser("localhost:8080"),
-            Ok((":8080", Host::HOST("localhost".to_string())))
+            Ok((":8080", Host::HOST("localhost".into())))
         );
         assert_eq!(
             hostname_parser("example.org:8080"),
-            Ok((":8080", Host::HOST("example.org".to_string())))
+            Ok((":8080", Host::HOST("example.org".into())))
         );
         assert_eq!(
             hostname_parser("some-subsite.example.org:8080"),
-            Ok((":8080", Host::HOST("some-subsite.example.org".to_string())))
+            Ok((":8080", Host::HOST("some-subsite.example.org".into())))
         );
         assert_eq!(
-            hostname_parser("example.123"),
-            Ok((".123", Host::HOST("example".to_string())))
+            hostname_parser("example.org.:8080"),
+            Ok((":8080", Host::HOST("example.org.".into())))
         );
         assert_eq!(
             hostname_parser("$$$.com"),
-            Err(NomErr::Error(VerboseError {
-                errors: vec![
-                    ("$$$.com", VerboseErrorKind::Nom(ErrorKind::AlphaNumeric)),
-                    ("$$$.com", VerboseErrorKind::Nom(ErrorKind::ManyMN)),
-                    ("$$$.com", VerboseErrorKind::Nom(ErrorKind::Alt)),
-                    ("$$$.com", VerboseErrorKind::Context("host")),
-                ]
-            }))
+            Err(NomErr::Error(
+                UriError::new(
+                    "$$$.com",
+                    ParseError::Unexpected {
+                        context: "host",
+                        span: 0..1
+                    }
+                )
+                .with_contexts(&["host"])
+            ))
         );
         assert_eq!(
             hostname_parser(".com"),
-            Err(NomErr::Error(VerboseError {
-                errors: vec![
-                    (".com", VerboseErrorKind::Nom(ErrorKind::AlphaNumeric)),
-                    (".com", VerboseErrorKind::Nom(ErrorKind::ManyMN)),
-                    (".com", VerboseErrorKind::Nom(ErrorKind::Alt)),
-                    (".com", VerboseErrorKind::Context("host")),
-                ]
-            }))
+            host_failure(".com", "empty label in domain name", 4, &["host"])
+        );
+        assert_eq!(
+            hostname_parser("example.123"),
+            host_failure("example.123", "numeric top-level domain", 11, &["host"])
+        );
+        assert!(matches!(
+            hostname_parser("-example.org"),
+            Err(NomErr::Failure(_))
+        ));
+        assert!(matches!(
+            hostname_parser(&format!("{}.org", "a".repeat(64))),
+            Err(NomErr::Failure(_))
+        ));
+        assert!(matches!(
+            hostname_parser("_sip._tcp.example.com"),
+            Err(NomErr::Failure(_))
+        ));
+        assert_eq!(
+            lenient_hostname_parser("_sip._tcp.example.com/"),
+            Ok(("/", Host::HOST("_sip._tcp.example.com".into())))
         );
     }
 
@@ -195,55 +520,243 @@ mod tests {
             Ok((":8080", Host::IP([0, 0, 0, 0,])))
         );
 
+        // the part that is out of range is pointed at
+        let out_of_range = |input, value, span| {
+            let error = ParseError::InvalidOctet { value, span };
+            Err(NomErr::Failure(
+                UriError::new(input, error).with_contexts(&["ip"]),
+            ))
+        };
+
         assert_eq!(
             ip_parser("1924.168.0.1:8080"),
-            Err(NomErr::Error(VerboseError {
-                errors: vec![
-                    ("4.168.0.1:8080", VerboseErrorKind::Nom(ErrorKind::Tag)),
-                    ("1924.168.0.1:8080", VerboseErrorKind::Nom(ErrorKind::Count)),
-                    ("1924.168.0.1:8080", VerboseErrorKind::Context("ip")),
-                ]
-            }))
+            out_of_range("1924.168.0.1:8080", 1924, 0..4)
         );
-
         assert_eq!(
             ip_parser("192.168.0000.144:8080"),
-            Err(NomErr::Error(VerboseError {
-                errors: vec![
-                    ("0.144:8080", VerboseErrorKind::Nom(ErrorKind::Tag)),
-                    (
-                        "192.168.0000.144:8080",
-                        VerboseErrorKind::Nom(ErrorKind::Count)
-                    ),
-                    ("192.168.0000.144:8080", VerboseErrorKind::Context("ip")),
-                ]
-            }))
+            host_failure(
+                "192.168.0000.144:8080",
+                "leading zero in IPv4 address",
+                16,
+                &["ip"]
+            )
         );
-
         assert_eq!(
             ip_parser("192.168.0.1444:8080"),
-            Ok(("4:8080", Host::IP([192, 168, 0, 144])))
+            out_of_range("192.168.0.1444:8080", 1444, 10..14)
         );
-
         assert_eq!(
             ip_parser("192.168.0:8080"),
-            Err(NomErr::Error(VerboseError {
-                errors: vec![
-                    (":8080", VerboseErrorKind::Nom(ErrorKind::Tag)),
-                    ("192.168.0:8080", VerboseErrorKind::Nom(ErrorKind::Count)),
-                    ("192.168.0:8080", VerboseErrorKind::Context("ip")),
-                ]
-            }))
+            host_failure(
+                "192.168.0:8080",
+                "wrong number of parts in IPv4 address",
+                9,
+                &["ip"]
+            )
         );
-
         assert_eq!(
             ip_parser("999.168.0.0:8080"),
-            Err(NomErr::Error(VerboseError {
-                errors: vec![
-                    ("999.168.0.0:8080", VerboseErrorKind::Nom(ErrorKind::Count)),
-                    ("999.168.0.0:8080", VerboseErrorKind::Context("ip")),
-                ]
-            }))
+            out_of_range("999.168.0.0:8080", 999, 0..3)
+        );
+
+        // not an IPv4, the other host parsers are tried
+        assert_eq!(
+            ip_parser("1.2.3.4a:8080"),
+            Err(NomErr::Error(
+                UriError::new(
+                    "1.2.3.4a:8080",
+                    ParseError::Unexpected {
+                        context: "ip",
+                        span: 0..1
+                    }
+                )
+                .with_contexts(&["ip"])
+            ))
+        );
+        assert_eq!(
+            ip_or_hostname_parser("1.2.3.4a:8080"),
+            Ok((":8080", Host::HOST("1.2.3.4a".into())))
+        );
+    }
+
+    #[test]
+    fn test_whatwg_ipv4_parser() {
+        let mut whatwg = ipv4_parser(Ipv4Mode::Whatwg);
+        assert_eq!(
+            whatwg("0x7f.1:8080"),
+            Ok((":8080", Host::IP([127, 0, 0, 1])))
+        );
+        assert_eq!(whatwg("2130706433/"), Ok(("/", Host::IP([127, 0, 0, 1]))));
+        assert!(matches!(whatwg("example.com"), Err(NomErr::Error(_))));
+        assert!(matches!(whatwg("0x7g.1"), Err(NomErr::Failure(_))));
+    }
+
+    fn ipv6(pieces: [u16; 8]) -> [u8; 16] {
+        let mut address = [0u8; 16];
+        for (i, piece) in pieces.iter().enumerate() {
+            address[2 * i..2 * i + 2].copy_from_slice(&piece.to_be_bytes());
+        }
+        address
+    }
+
+    #[test]
+    fn test_ipv6_parser() {
+        assert_eq!(
+            ipv6_parser("2001:db8:0:0:1:0:0:1]"),
+            Ok(("]", ipv6([0x2001, 0xdb8, 0, 0, 1, 0, 0, 1])))
+        );
+        assert_eq!(
+            ipv6_parser("2001:DB8::1]"),
+            Ok(("]", ipv6([0x2001, 0xdb8, 0, 0, 0, 0, 0, 1])))
+        );
+        assert_eq!(
+            ipv6_parser("::1]"),
+            Ok(("]", ipv6([0, 0, 0, 0, 0, 0, 0, 1])))
         );
+        assert_eq!(ipv6_parser("::]"), Ok(("]", [0; 16])));
+        assert_eq!(
+            ipv6_parser("fe80::]"),
+            Ok(("]", ipv6([0xfe80, 0, 0, 0, 0, 0, 0, 0])))
+        );
+        assert_eq!(
+            ipv6_parser("1:2:3:4:5:6:7::]"),
+            Ok(("]", ipv6([1, 2, 3, 4, 5, 6, 7, 0])))
+        );
+        assert_eq!(
+            ipv6_parser("::2:3:4:5:6:7:8]"),
+            Ok(("]", ipv6([0, 2, 3, 4, 5, 6, 7, 8])))
+        );
+        assert_eq!(
+            ipv6_parser("::ffff:192.0.2.1]"),
+            Ok(("]", ipv6([0, 0, 0, 0, 0, 0xffff, 0xc000, 0x0201])))
+        );
+        assert_eq!(
+            ipv6_parser("1:2:3:4:5:6:10.0.0.1]"),
+            Ok(("]", ipv6([1, 2, 3, 4, 5, 6, 0x0a00, 0x0001])))
+        );
+        assert_eq!(
+            ipv6_parser("1::2:3.4.5.6]"),
+            Ok(("]", ipv6([1, 0, 0, 0, 0, 2, 0x0304, 0x0506])))
+        );
+
+        // too many, or not enough pieces
+        assert!(ipv6_parser("1:2:3:4:5:6:7:8:9]").is_err());
+        assert!(ipv6_parser("1:2:3:4:5:6:7]").is_err());
+        assert!(ipv6_parser("1:2:3:4::5:6:7:8]").is_err());
+        assert!(ipv6_parser("1:2:3:4:5:6:7:1.2.3.4]").is_err());
+        // an IPv4 can only come last
+        assert!(ipv6_parser("1.2.3.4::]").is_err());
+        // a dangling colon
+        assert!(ipv6_parser("1:]").is_err());
+        assert!(ipv6_parser(":1::]").is_err());
+    }
+
+    #[test]
+    fn test_ip_literal_parser() {
+        assert_eq!(
+            ip_or_hostname_parser("[::1]:8080"),
+            Ok((
+                ":8080",
+                Host::IPV6 {
+                    address: ipv6([0, 0, 0, 0, 0, 0, 0, 1]),
+                    zone: None
+                }
+            ))
+        );
+        assert_eq!(
+            ip_or_hostname_parser("[2001:db8::1%25eth0]/"),
+            Ok((
+                "/",
+                Host::IPV6 {
+                    address: ipv6([0x2001, 0xdb8, 0, 0, 0, 0, 0, 1]),
+                    zone: Some("eth0".into())
+                }
+            ))
+        );
+        assert_eq!(
+            ip_or_hostname_parser("[fe80::1%25en%301]"),
+            Ok((
+                "",
+                Host::IPV6 {
+                    address: ipv6([0xfe80, 0, 0, 0, 0, 0, 0, 1]),
+                    zone: Some("en%301".into())
+                }
+            ))
+        );
+        assert_eq!(
+            ip_or_hostname_parser("[v1.fe80::a+en1]:80"),
+            Ok((":80", Host::IPVFUTURE("v1.fe80::a+en1".into())))
+        );
+
+        // brackets are mandatory on both sides
+        assert!(ip_literal_parser("[::1").is_err());
+        assert!(ip_literal_parser("::1]").is_err());
+        // the zone delimiter is an encoded "%"
+        assert!(ip_literal_parser("[fe80::1%eth0]").is_err());
+        assert!(ip_literal_parser("[fe80::1%25]").is_err());
+        assert!(ip_literal_parser("[12345::1]").is_err());
+        assert!(ip_literal_parser("[v1.]").is_err());
+        assert!(ip_literal_parser("[vz.a]").is_err());
+    }
+
+    #[test]
+    fn test_idn_parser() {
+        assert_eq!(
+            ip_or_hostname_parser("münchen.de/"),
+            Ok(("/", Host::HOST("xn--mnchen-3ya.de".into())))
+        );
+        assert_eq!(
+            ip_or_hostname_parser("例え.jp:8080"),
+            Ok((":8080", Host::HOST("xn--r8jz45g.jp".into())))
+        );
+        assert_eq!(
+            ip_or_hostname_parser("XN--MNCHEN-3YA.de"),
+            Ok(("", Host::HOST("xn--mnchen-3ya.de".into())))
+        );
+        // plain ASCII names are kept as they are
+        assert_eq!(
+            ip_or_hostname_parser("Example.org"),
+            Ok(("", Host::HOST("Example.org".into())))
+        );
+
+        assert_eq!(
+            ip_or_hostname_parser("xn--zz.de"),
+            host_failure(
+                "xn--zz.de",
+                "invalid punycode in domain name",
+                9,
+                &["ip or host"]
+            )
+        );
+        assert!(matches!(
+            ip_or_hostname_parser("mün--chen..de"),
+            Err(NomErr::Failure(_))
+        ));
+
+        let host = Host::HOST("xn--mnchen-3ya.de".into());
+        assert_eq!(host.to_unicode().as_deref(), Some("münchen.de"));
+        assert_eq!(host.to_string(), "xn--mnchen-3ya.de");
+        assert_eq!(Host::IP([127, 0, 0, 1]).to_unicode(), None);
+    }
+
+    #[test]
+    fn test_borrowed_names() {
+        // names are borrowed from the input, unless they are converted
+        let borrowed = |result: CustomResult<&str, Host>| match result {
+            Ok((_, Host::HOST(name) | Host::IPVFUTURE(name))) => {
+                matches!(name, Cow::Borrowed(_))
+            }
+            Ok((
+                _,
+                Host::IPV6 {
+                    zone: Some(zone), ..
+                },
+            )) => matches!(zone, Cow::Borrowed(_)),
+            other => panic!("unexpected result: {:?}", other),
+        };
+        assert!(borrowed(ip_or_hostname_parser("example.org:8080")));
+        assert!(borrowed(ip_or_hostname_parser("[v1.fe80::a+en1]")));
+        assert!(borrowed(ip_or_hostname_parser("[fe80::1%25eth0]")));
+        assert!(!borrowed(ip_or_hostname_parser("münchen.de")));
     }
 }
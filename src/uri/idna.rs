@@ -0,0 +1,561 @@
+//! Internationalized domain names, following the processing of
+//! [UTS #46](https://www.unicode.org/reports/tr46/): "München.de" is mapped to
+//! "münchen.de", checked, and written "xn--mnchen-3ya.de" in its ASCII form.
+//!
+//! The mapping, the normalization to NFC and the validity criteria, the Bidi and
+//! ContextJ rules included, use the Unicode tables of `tables.rs`, which
+//! `make_tables.py` writes from the data files of Unicode. The labels are then
+//! written in their ASCII form by our own Punycode encoder.
+
+use std::{cmp::Ordering, error::Error, fmt};
+
+use crate::uri::punycode;
+
+#[rustfmt::skip]
+mod tables;
+
+use tables::{BidiClass, JoiningType, Status};
+
+const ACE_PREFIX: &str = "xn--";
+
+/// Why a domain name is not a valid internationalized domain name
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum IdnaError {
+    /// a character that can not be part of a domain name, like "_", " " or U+FFFD,
+    /// or not at its place, like a U+200D joiner that does not follow a virama
+    DisallowedChar(char),
+    /// "a..b", only the last label of "example.org." may be empty
+    EmptyLabel,
+    /// a label starting or ending with "-", or with "--" in third and fourth position
+    HyphenPosition,
+    /// a label starting with a combining mark, like U+0301
+    LeadingCombiningMark,
+    /// an "xn--" label that does not decode, or decodes to plain ASCII
+    InvalidPunycode,
+    /// any other validity criterion of UTS #46, like the Bidi rule that keeps
+    /// right-to-left and left-to-right labels apart
+    InvalidLabel,
+}
+
+impl IdnaError {
+    /// A short description, used as the context of parse errors
+    pub fn reason(&self) -> &'static str {
+        match self {
+            IdnaError::DisallowedChar(_) => "disallowed character in domain name",
+            IdnaError::EmptyLabel => "empty label in domain name",
+            IdnaError::HyphenPosition => "misplaced hyphen in domain name",
+            IdnaError::LeadingCombiningMark => "domain label starts with a combining mark",
+            IdnaError::InvalidPunycode => "invalid punycode in domain name",
+            IdnaError::InvalidLabel => "invalid internationalized domain label",
+        }
+    }
+}
+
+impl fmt::Display for IdnaError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            IdnaError::DisallowedChar(c) => write!(f, "{}: {:?}", self.reason(), c),
+            _ => f.write_str(self.reason()),
+        }
+    }
+}
+
+impl Error for IdnaError {}
+
+// The value of the range of a table that holds the code point, if any
+fn lookup<T: Copy>(table: &[(u32, u32, T)], c: char) -> Option<T> {
+    let cp = c as u32;
+    table
+        .binary_search_by(|&(first, last, _)| {
+            if last < cp {
+                Ordering::Less
+            } else if first > cp {
+                Ordering::Greater
+            } else {
+                Ordering::Equal
+            }
+        })
+        .ok()
+        .map(|i| table[i].2)
+}
+
+fn status(c: char) -> (u32, Status) {
+    let cp = c as u32;
+    // the first run starts at U+0000
+    let i = tables::MAPPING.partition_point(|&(start, _)| start <= cp);
+    tables::MAPPING[i - 1]
+}
+
+fn combining_class(c: char) -> u8 {
+    lookup(tables::COMBINING_CLASS, c).unwrap_or(0)
+}
+
+fn is_mark(c: char) -> bool {
+    let cp = c as u32;
+    let i = tables::MARK.partition_point(|&(first, _)| first <= cp);
+    i > 0 && tables::MARK[i - 1].1 >= cp
+}
+
+fn bidi_class(c: char) -> BidiClass {
+    lookup(tables::BIDI_CLASS, c).unwrap_or(BidiClass::L)
+}
+
+fn joining_type(c: char) -> Option<JoiningType> {
+    lookup(tables::JOINING_TYPE, c)
+}
+
+fn mapped_text(offset: u16, length: u8) -> &'static str {
+    let offset = offset as usize;
+    &tables::MAPPED_TEXT[offset..offset + length as usize]
+}
+
+// What browsers refuse in a host once it is mapped, as the WHATWG URL standard says
+fn is_forbidden_in_url(c: char) -> bool {
+    c <= '\u{1F}' || c == '\u{7F}' || " #%/:<>?@[\\]^|".contains(c)
+}
+
+// Maps each character with the UTS #46 table: "MÜNCHEN。de" gives "münchen.de".
+// With the STD3 rules, only letters, digits and hyphens are allowed in ASCII.
+fn map(domain: &str, std3: bool) -> Result<String, IdnaError> {
+    let mut mapped = String::with_capacity(domain.len());
+    for c in domain.chars() {
+        match status(c) {
+            (_, Status::V) => mapped.push(c),
+            (_, Status::I) => {}
+            (_, Status::S) if !std3 => mapped.push(c),
+            (_, Status::M(offset, length)) => mapped.push_str(mapped_text(offset, length)),
+            (_, Status::SM(offset, length)) if !std3 => {
+                mapped.push_str(mapped_text(offset, length))
+            }
+            (start, Status::R(first)) => {
+                mapped.extend(char::from_u32(first + (c as u32 - start)));
+            }
+            (_, Status::D | Status::S | Status::SM(..)) => {
+                return Err(IdnaError::DisallowedChar(c))
+            }
+        }
+    }
+    Ok(mapped)
+}
+
+const HANGUL_S_BASE: u32 = 0xAC00;
+const HANGUL_L_BASE: u32 = 0x1100;
+const HANGUL_V_BASE: u32 = 0x1161;
+const HANGUL_T_BASE: u32 = 0x11A7;
+const HANGUL_L_COUNT: u32 = 19;
+const HANGUL_V_COUNT: u32 = 21;
+const HANGUL_T_COUNT: u32 = 28;
+const HANGUL_S_COUNT: u32 = HANGUL_L_COUNT * HANGUL_V_COUNT * HANGUL_T_COUNT;
+
+// The full canonical decomposition, Hangul syllables with the algorithm of Unicode 3.12
+fn decompose(c: char, decomposed: &mut Vec<char>) {
+    let cp = c as u32;
+    if (HANGUL_S_BASE..HANGUL_S_BASE + HANGUL_S_COUNT).contains(&cp) {
+        let index = cp - HANGUL_S_BASE;
+        let jamos = [
+            HANGUL_L_BASE + index / (HANGUL_V_COUNT * HANGUL_T_COUNT),
+            HANGUL_V_BASE + index % (HANGUL_V_COUNT * HANGUL_T_COUNT) / HANGUL_T_COUNT,
+            HANGUL_T_BASE + index % HANGUL_T_COUNT,
+        ];
+        let count = if jamos[2] == HANGUL_T_BASE { 2 } else { 3 };
+        decomposed.extend(
+            jamos[..count]
+                .iter()
+                .filter_map(|&jamo| char::from_u32(jamo)),
+        );
+        return;
+    }
+    match tables::DECOMPOSITION.binary_search_by_key(&cp, |&(composite, _, _)| composite) {
+        Ok(i) => {
+            let (_, first, second) = tables::DECOMPOSITION[i];
+            for part in [first, second].into_iter().filter(|&part| part != 0) {
+                decompose(char::from_u32(part).unwrap_or(c), decomposed);
+            }
+        }
+        Err(_) => decomposed.push(c),
+    }
+}
+
+fn compose(first: char, second: char) -> Option<char> {
+    let (first, second) = (first as u32, second as u32);
+    let l = first.wrapping_sub(HANGUL_L_BASE);
+    let v = second.wrapping_sub(HANGUL_V_BASE);
+    if l < HANGUL_L_COUNT && v < HANGUL_V_COUNT {
+        return char::from_u32(HANGUL_S_BASE + (l * HANGUL_V_COUNT + v) * HANGUL_T_COUNT);
+    }
+    let s = first.wrapping_sub(HANGUL_S_BASE);
+    let t = second.wrapping_sub(HANGUL_T_BASE);
+    if s < HANGUL_S_COUNT && s % HANGUL_T_COUNT == 0 && t > 0 && t < HANGUL_T_COUNT {
+        return char::from_u32(first + t);
+    }
+    tables::COMPOSITION
+        .binary_search_by_key(&(first, second), |&(a, b, _)| (a, b))
+        .ok()
+        .and_then(|i| char::from_u32(tables::COMPOSITION[i].2))
+}
+
+// Normalization Form C: decomposed, with the marks in canonical order, then composed
+fn nfc(text: &str) -> String {
+    let mut decomposed = Vec::with_capacity(text.len());
+    for c in text.chars() {
+        decompose(c, &mut decomposed);
+    }
+    // a stable sort of each run of marks by their combining class
+    for i in 1..decomposed.len() {
+        let mut j = i;
+        let class = combining_class(decomposed[j]);
+        while class != 0 && j > 0 && combining_class(decomposed[j - 1]) > class {
+            decomposed.swap(j - 1, j);
+            j -= 1;
+        }
+    }
+
+    let mut composed: Vec<char> = Vec::with_capacity(decomposed.len());
+    let mut starter: Option<usize> = None;
+    // the class of the last character after the starter, none if it is right after it
+    let mut last_class: Option<u8> = None;
+    for c in decomposed {
+        let class = combining_class(c);
+        if let Some(starter) = starter {
+            let blocked = last_class.is_some_and(|last| last == 0 || last >= class);
+            if let Some(composite) = compose(composed[starter], c).filter(|_| !blocked) {
+                composed[starter] = composite;
+                continue;
+            }
+        }
+        if class == 0 {
+            starter = Some(composed.len());
+            last_class = None;
+        } else {
+            last_class = Some(class);
+        }
+        composed.push(c);
+    }
+    composed.into_iter().collect()
+}
+
+fn is_ace_label(label: &str) -> bool {
+    label
+        .get(..ACE_PREFIX.len())
+        .is_some_and(|prefix| prefix.eq_ignore_ascii_case(ACE_PREFIX))
+}
+
+// ContextJ, RFC 5892 appendix A: a joiner follows a virama, or a U+200C joiner
+// sits between characters that join, like the letters of Arabic
+fn check_joiners(label: &str) -> Result<(), IdnaError> {
+    const VIRAMA: u8 = 9;
+    for (i, c) in label.char_indices() {
+        if c != '\u{200C}' && c != '\u{200D}' {
+            continue;
+        }
+        let (before, after) = (&label[..i], &label[i + c.len_utf8()..]);
+        if before.chars().next_back().map(combining_class) == Some(VIRAMA) {
+            continue;
+        }
+        let not_transparent = |t: &Option<JoiningType>| *t != Some(JoiningType::T);
+        let joins_left = matches!(
+            before.chars().rev().map(joining_type).find(not_transparent),
+            Some(Some(JoiningType::L | JoiningType::D))
+        );
+        let joins_right = matches!(
+            after.chars().map(joining_type).find(not_transparent),
+            Some(Some(JoiningType::R | JoiningType::D))
+        );
+        if c == '\u{200D}' || !(joins_left && joins_right) {
+            return Err(IdnaError::DisallowedChar(c));
+        }
+    }
+    Ok(())
+}
+
+// The validity criteria of UTS #46 section 4.1 that look at a label alone.
+// The lenient rules are those of the WHATWG URL standard: hyphens may be anywhere.
+fn check_label(label: &str, lenient: bool) -> Result<(), IdnaError> {
+    if !lenient
+        && (label.starts_with('-')
+            || label.ends_with('-')
+            || (label.chars().nth(2) == Some('-') && label.chars().nth(3) == Some('-')))
+    {
+        return Err(IdnaError::HyphenPosition);
+    }
+    if label.chars().next().is_some_and(is_mark) {
+        return Err(IdnaError::LeadingCombiningMark);
+    }
+    check_joiners(label)
+}
+
+// The Bidi rule of RFC 5893 section 2, for each label of a domain
+// with right-to-left characters
+fn satisfies_bidi_rule(label: &str) -> bool {
+    use BidiClass::*;
+    let last = label
+        .chars()
+        .rev()
+        .map(bidi_class)
+        .find(|&class| class != NSM);
+    match label.chars().next().map(bidi_class) {
+        Some(R | AL) => {
+            let mut classes = label.chars().map(bidi_class);
+            let (has_en, has_an) = (
+                classes.clone().any(|class| class == EN),
+                classes.clone().any(|class| class == AN),
+            );
+            classes.all(|class| matches!(class, R | AL | AN | EN | ES | CS | ET | ON | BN | NSM))
+                && matches!(last, Some(R | AL | EN | AN))
+                && !(has_en && has_an)
+        }
+        Some(L) => {
+            label
+                .chars()
+                .map(bidi_class)
+                .all(|class| matches!(class, L | EN | ES | CS | ET | ON | BN | NSM))
+                && matches!(last, Some(L | EN))
+        }
+        _ => false,
+    }
+}
+
+// Maps and checks the domain, then gives each label in its Unicode form.
+// The lenient rules are those of the WHATWG URL standard: hyphens may be
+// anywhere, and only the characters that would end a host are refused.
+fn process(domain: &str, lenient: bool) -> Result<Vec<String>, IdnaError> {
+    let mapped = map(domain, !lenient)?;
+    if let Some(c) = mapped.chars().find(|&c| lenient && is_forbidden_in_url(c)) {
+        return Err(IdnaError::DisallowedChar(c));
+    }
+    let normalized = if mapped.chars().all(|c| c < '\u{300}') {
+        // nothing below U+0300 composes with what precedes it
+        mapped
+    } else {
+        nfc(&mapped)
+    };
+
+    let mut labels = Vec::new();
+    for label in normalized.split('.') {
+        if !is_ace_label(label) {
+            check_label(label, lenient)?;
+            labels.push(label.to_string());
+            continue;
+        }
+        // the ASCII form of a label, which has to decode to a valid label
+        let decoded = label
+            .is_ascii()
+            .then(|| punycode::decode(&label[ACE_PREFIX.len()..]))
+            .flatten()
+            .filter(|decoded| !decoded.is_ascii())
+            .ok_or(IdnaError::InvalidPunycode)?;
+        if let Some(c) = decoded.chars().find(|&c| match status(c).1 {
+            Status::V => false,
+            Status::S => !lenient || is_forbidden_in_url(c),
+            _ => true,
+        }) {
+            return Err(IdnaError::DisallowedChar(c));
+        }
+        if nfc(&decoded) != decoded {
+            return Err(IdnaError::InvalidLabel);
+        }
+        check_label(&decoded, lenient)?;
+        labels.push(decoded);
+    }
+
+    // only the root of "example.org." may be empty
+    let last = labels.len() - 1;
+    if labels
+        .iter()
+        .enumerate()
+        .any(|(i, label)| label.is_empty() && (i != last || i == 0))
+    {
+        return Err(IdnaError::EmptyLabel);
+    }
+
+    let is_bidi_domain = labels.iter().any(|label| {
+        label
+            .chars()
+            .any(|c| matches!(bidi_class(c), BidiClass::R | BidiClass::AL | BidiClass::AN))
+    });
+    if is_bidi_domain
+        && !labels
+            .iter()
+            .all(|label| label.is_empty() || satisfies_bidi_rule(label))
+    {
+        return Err(IdnaError::InvalidLabel);
+    }
+    Ok(labels)
+}
+
+/// The ASCII form of a domain name: "München.de" gives "xn--mnchen-3ya.de"
+pub fn to_ascii(domain: &str) -> Result<String, IdnaError> {
+    encode(process(domain, false)?)
+}
+
+/// The same with the rules of browsers, which accept "-a-.example" and "_sip.example"
+pub fn to_ascii_lenient(domain: &str) -> Result<String, IdnaError> {
+    encode(process(domain, true)?)
+}
+
+// Writes the labels in their ASCII form, with our own Punycode encoder
+fn encode(labels: Vec<String>) -> Result<String, IdnaError> {
+    let labels = labels
+        .into_iter()
+        .map(|label| {
+            if label.is_ascii() {
+                Ok(label)
+            } else {
+                punycode::encode(&label)
+                    .map(|encoded| format!("{}{}", ACE_PREFIX, encoded))
+                    .ok_or(IdnaError::InvalidPunycode)
+            }
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok(labels.join("."))
+}
+
+/// The Unicode form of a domain name: "xn--mnchen-3ya.de" gives "münchen.de"
+pub fn to_unicode(domain: &str) -> Result<String, IdnaError> {
+    Ok(process(domain, false)?.join("."))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_to_ascii() {
+        assert_eq!(to_ascii("münchen.de"), Ok("xn--mnchen-3ya.de".to_string()));
+        assert_eq!(to_ascii("MÜNCHEN.DE"), Ok("xn--mnchen-3ya.de".to_string()));
+        assert_eq!(to_ascii("例え.jp"), Ok("xn--r8jz45g.jp".to_string()));
+        assert_eq!(
+            to_ascii("ｅｘａｍｐｌｅ。ｃｏｍ"),
+            Ok("example.com".to_string())
+        );
+        assert_eq!(
+            to_ascii("bü\u{00AD}cher.de."),
+            Ok("xn--bcher-kva.de.".to_string())
+        );
+        // already in ASCII form
+        assert_eq!(
+            to_ascii("xn--mnchen-3ya.de"),
+            Ok("xn--mnchen-3ya.de".to_string())
+        );
+        assert_eq!(to_ascii("Example.org"), Ok("example.org".to_string()));
+    }
+
+    #[test]
+    fn test_to_unicode() {
+        assert_eq!(
+            to_unicode("xn--mnchen-3ya.de"),
+            Ok("münchen.de".to_string())
+        );
+        assert_eq!(to_unicode("XN--R8JZ45G.jp"), Ok("例え.jp".to_string()));
+        assert_eq!(to_unicode("example.org"), Ok("example.org".to_string()));
+    }
+
+    #[test]
+    fn test_uts46_tables() {
+        // composed and decomposed forms of "é" give the same name
+        assert_eq!(to_ascii("café.fr"), Ok("xn--caf-dma.fr".to_string()));
+        assert_eq!(
+            to_ascii("cafe\u{0301}.fr"),
+            Ok("xn--caf-dma.fr".to_string())
+        );
+        // mappings beyond case folding and full-width forms
+        assert_eq!(to_ascii("ℌ①.de"), Ok("h1.de".to_string()));
+        assert_eq!(to_ascii("faß.de"), Ok("xn--fa-hia.de".to_string()));
+        // a joiner is valid after a virama (ContextJ)
+        assert_eq!(
+            to_ascii("\u{0915}\u{094D}\u{200C}\u{0937}.in"),
+            Ok("xn--11b2ezcs70k.in".to_string())
+        );
+        // a left-to-right label starting with a digit next to a right-to-left one (Bidi)
+        assert_eq!(to_ascii("0à.\u{05D0}"), Err(IdnaError::InvalidLabel));
+        assert!(to_ascii("\u{05D0}\u{05D1}.example").is_ok());
+        // a U+200C joiner between letters that join (ContextJ)
+        assert!(to_ascii("\u{0628}\u{200C}\u{0628}.example").is_ok());
+        assert_eq!(
+            to_ascii("a\u{200C}b.de"),
+            Err(IdnaError::DisallowedChar('\u{200C}'))
+        );
+    }
+
+    #[test]
+    fn test_normalization() {
+        // the marks are put in canonical order before they are composed
+        assert_eq!(to_ascii("a\u{0323}\u{0302}.vn"), to_ascii("\u{1EAD}.vn"));
+        assert_eq!(to_ascii("a\u{0302}\u{0323}.vn"), to_ascii("\u{1EAD}.vn"));
+        // Hangul jamos are composed into syllables
+        assert_eq!(
+            to_ascii("\u{1112}\u{1161}\u{11AB}\u{AD6D}.kr"),
+            to_ascii("\u{D55C}\u{AD6D}.kr")
+        );
+        assert_eq!(
+            to_unicode("xn--3e0b707e.kr"),
+            Ok("\u{D55C}\u{AD6D}.kr".to_string())
+        );
+        // a decoded label has to be in NFC already
+        assert_eq!(to_ascii("xn--cafe-yvc.fr"), Err(IdnaError::InvalidLabel));
+    }
+
+    #[test]
+    fn test_lenient() {
+        assert_eq!(
+            to_ascii_lenient("-Ü-.example"),
+            Ok("xn-----xka.example".to_string())
+        );
+        assert_eq!(to_ascii("-Ü-.example"), Err(IdnaError::HyphenPosition));
+        assert_eq!(
+            to_ascii_lenient("_sip.münchen.de"),
+            Ok("_sip.xn--mnchen-3ya.de".to_string())
+        );
+        assert_eq!(
+            to_ascii_lenient("a b.münchen.de"),
+            Err(IdnaError::DisallowedChar(' '))
+        );
+    }
+
+    #[test]
+    fn test_errors() {
+        assert_eq!(to_ascii("exa mple.de"), Err(IdnaError::DisallowedChar(' ')));
+        assert_eq!(to_ascii("münchen_de"), Err(IdnaError::DisallowedChar('_')));
+        assert_eq!(
+            to_ascii("a\u{00A0}b.de"),
+            Err(IdnaError::DisallowedChar('\u{00A0}'))
+        );
+        assert_eq!(
+            to_ascii("a\u{200D}b.de"),
+            Err(IdnaError::DisallowedChar('\u{200D}'))
+        );
+        assert_eq!(to_ascii("münchen..de"), Err(IdnaError::EmptyLabel));
+        assert_eq!(to_ascii(".de"), Err(IdnaError::EmptyLabel));
+        assert_eq!(to_ascii("-münchen.de"), Err(IdnaError::HyphenPosition));
+        assert_eq!(to_ascii("mü--chen.de"), Err(IdnaError::HyphenPosition));
+        assert_eq!(
+            to_ascii("m-ü-chen.de"),
+            Ok("xn--m--chen-o2a.de".to_string())
+        );
+        assert_eq!(
+            to_ascii("\u{0301}a.de"),
+            Err(IdnaError::LeadingCombiningMark)
+        );
+        assert_eq!(to_ascii("xn--zz.de"), Err(IdnaError::InvalidPunycode));
+        // an "xn--" label is checked once decoded, what is wrong is the other label
+        assert_eq!(
+            to_ascii("xn--mnchen-3ya.a_b"),
+            Err(IdnaError::DisallowedChar('_'))
+        );
+        assert_eq!(
+            to_ascii("xn--mnchen-3ya.-ab"),
+            Err(IdnaError::HyphenPosition)
+        );
+        assert_eq!(
+            to_ascii_lenient("xn--mnchen-3ya.a b"),
+            Err(IdnaError::DisallowedChar(' '))
+        );
+        // "xn--abc-" decodes to the ASCII "abc"
+        assert_eq!(to_ascii("xn--abc-.de"), Err(IdnaError::InvalidPunycode));
+        // the domain is mapped before being decoded
+        assert_eq!(
+            to_ascii("xn--Mnchen-3ya.de"),
+            Ok("xn--mnchen-3ya.de".to_string())
+        );
+    }
+}
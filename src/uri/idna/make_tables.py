@@ -0,0 +1,231 @@
+#!/usr/bin/env python3
+"""Writes tables.rs, the Unicode data of the UTS #46 processing in idna.rs.
+
+    python3 make_tables.py IdnaMappingTable.txt DerivedJoiningType.txt > tables.rs
+
+IdnaMappingTable.txt is published with UTS #46, DerivedJoiningType.txt with the
+Unicode Character Database (extracted/). The canonical decompositions and combining
+classes, the general categories and the Bidi classes come from the unicodedata
+module of the Python that runs this script.
+"""
+
+import sys
+import unicodedata
+
+
+def code_points(field):
+    bounds = field.split("..")
+    return range(int(bounds[0], 16), int(bounds[-1], 16) + 1)
+
+
+def data_lines(path):
+    for line in open(path, encoding="utf-8"):
+        line = line.split("#")[0].strip()
+        if line:
+            yield [field.strip() for field in line.split(";")]
+
+
+def header(path):
+    for line in open(path, encoding="utf-8"):
+        if line.startswith("# Version:"):
+            return "IdnaMappingTable.txt " + line[2:].strip().lower()
+        if line.startswith("# DerivedJoiningType-"):
+            return line[2:].strip()
+    return path
+
+
+def ranges(values):
+    """Groups {code point: value} into (first, last, value) runs."""
+    runs = []
+    for cp in sorted(values):
+        if runs and runs[-1][1] == cp - 1 and runs[-1][2] == values[cp]:
+            runs[-1][1] = cp
+        else:
+            runs.append([cp, cp, values[cp]])
+    return runs
+
+
+def write_rows(name, kind, rows, per_line):
+    print("pub(super) const %s: &[%s] = &[" % (name, kind))
+    for i in range(0, len(rows), per_line):
+        print("    " + " ".join(row + "," for row in rows[i : i + per_line]))
+    print("];")
+    print()
+
+
+def mapping_table(path):
+    text = ""
+    offsets = {}
+
+    def mapped(targets):
+        nonlocal text
+        value = "".join(chr(cp) for cp in targets)
+        if value not in offsets:
+            at = text.find(value)
+            if at < 0:
+                at = len(text.encode("utf-8"))
+                text += value
+            else:
+                at = len(text[:at].encode("utf-8"))
+            offsets[value] = at
+        return offsets[value], len(value.encode("utf-8"))
+
+    entries = []
+    for fields in data_lines(path):
+        status = fields[1]
+        targets = [int(cp, 16) for cp in fields[2].split()] if len(fields) > 2 else []
+        for cp in code_points(fields[0]):
+            # the deviations are valid in nontransitional processing
+            if status in ("valid", "deviation"):
+                entries.append((cp, "V", None))
+            elif status == "ignored":
+                entries.append((cp, "I", None))
+            elif status == "disallowed":
+                entries.append((cp, "D", None))
+            elif status == "disallowed_STD3_valid":
+                entries.append((cp, "S", None))
+            elif status in ("mapped", "disallowed_STD3_mapped"):
+                entries.append((cp, "M" if status == "mapped" else "SM", tuple(targets)))
+            else:
+                raise ValueError(status)
+
+    rows = []
+    last = None
+    for cp, status, targets in entries:
+        # a run of code points mapped to consecutive ones, like "Ａ".."Ｚ" to "a".."z"
+        if (
+            last is not None
+            and status == "M"
+            and (last[1] == "R" or (last[1] == "M" and last[0] == last[3]))
+            and len(targets) == 1
+            and last[3] == cp - 1
+            and last[4] is not None
+            and last[4] + 1 == targets[0]
+        ):
+            if last[1] == "M":
+                last[1] = "R"
+            last[3], last[4] = cp, targets[0]
+            continue
+        if last is not None and status == last[1] and targets == last[2] and last[3] == cp - 1:
+            last[3] = cp
+            continue
+        last = [cp, status, targets, cp, targets[0] if targets and len(targets) == 1 else None]
+        rows.append(last)
+
+    written = []
+    for start, status, targets, _, _ in rows:
+        if status == "R":
+            written.append("(0x%X, R(0x%X))" % (start, targets[0]))
+        elif status in ("M", "SM"):
+            offset, length = mapped(targets)
+            written.append("(0x%X, %s(%d, %d))" % (start, status, offset, length))
+        else:
+            written.append("(0x%X, %s)" % (start, status))
+    return written, text
+
+
+def main(mapping_path, joining_path):
+    mapping, mapped_text = mapping_table(mapping_path)
+
+    combining = {}
+    decompositions = []
+    compositions = []
+    marks = {}
+    bidi = {}
+    for cp in range(0x110000):
+        c = chr(cp)
+        if unicodedata.combining(c):
+            combining[cp] = unicodedata.combining(c)
+        if unicodedata.category(c).startswith("M"):
+            marks[cp] = True
+        klass = unicodedata.bidirectional(c)
+        if klass and klass != "L":
+            bidi[cp] = klass if klass in ("R", "AL", "AN", "EN", "ES", "CS", "ET", "ON", "BN", "NSM") else "Other"
+        # the Hangul syllables are decomposed by the algorithm of idna.rs
+        decomposition = unicodedata.decomposition(c)
+        if decomposition and not decomposition.startswith("<") and not 0xAC00 <= cp <= 0xD7A3:
+            parts = [int(part, 16) for part in decomposition.split()]
+            decompositions.append((cp, parts[0], parts[1] if len(parts) > 1 else 0))
+            # the primary composites, those that NFC gives back
+            if len(parts) == 2 and unicodedata.normalize("NFC", chr(parts[0]) + chr(parts[1])) == c:
+                compositions.append((parts[0], parts[1], cp))
+
+    joining = {}
+    for fields in data_lines(joining_path):
+        if fields[1] in ("D", "L", "R", "T", "C"):
+            for cp in code_points(fields[0]):
+                joining[cp] = fields[1]
+
+    print("// Generated by make_tables.py, do not edit.")
+    print("// %s, %s," % (header(mapping_path), header(joining_path)))
+    print("// and the Unicode %s data of Python's unicodedata." % unicodedata.unidata_version)
+    print()
+    print("use self::BidiClass as B;")
+    print("use self::JoiningType as J;")
+    print("use self::Status::*;")
+    print()
+    print("/// The status of a code point in the UTS #46 mapping table, where the")
+    print("/// deviations are valid, as they are in nontransitional processing")
+    print("#[derive(Debug, Clone, Copy, PartialEq, Eq)]")
+    print("pub(super) enum Status {")
+    print("    /// valid")
+    print("    V,")
+    print("    /// ignored, removed from the domain")
+    print("    I,")
+    print("    /// disallowed")
+    print("    D,")
+    print("    /// valid, but disallowed with the STD3 rules")
+    print("    S,")
+    print("    /// mapped to the text at this offset and length in MAPPED_TEXT")
+    print("    M(u16, u8),")
+    print("    /// mapped, but disallowed with the STD3 rules")
+    print("    SM(u16, u8),")
+    print("    /// each code point mapped to this one plus its distance to the start of the run")
+    print("    R(u32),")
+    print("}")
+    print()
+    print("#[derive(Debug, Clone, Copy, PartialEq, Eq)]")
+    print("pub(super) enum BidiClass {")
+    print("    L, R, AL, AN, EN, ES, CS, ET, ON, BN, NSM,")
+    print("    /// the classes that can not be in a label of a Bidi domain name")
+    print("    Other,")
+    print("}")
+    print()
+    print("/// The joining types of RFC 5892 appendix A.1, those that are not non-joining")
+    print("#[derive(Debug, Clone, Copy, PartialEq, Eq)]")
+    print("pub(super) enum JoiningType {")
+    print("    D, L, R, T, C,")
+    print("}")
+    print()
+    print("/// The runs of code points that share a status, by their first code point")
+    write_rows("MAPPING", "(u32, Status)", mapping, 4)
+    print("pub(super) const MAPPED_TEXT: &str = %s;" % rust_string(mapped_text))
+    print()
+    print("/// The canonical combining classes that are not 0")
+    write_rows("COMBINING_CLASS", "(u32, u32, u8)", ["(0x%X, 0x%X, %d)" % tuple(run) for run in ranges(combining)], 4)
+    print("/// The canonical decompositions in one or two code points, the second one 0 if there is none")
+    write_rows("DECOMPOSITION", "(u32, u32, u32)", ["(0x%X, 0x%X, 0x%X)" % row for row in decompositions], 4)
+    print("/// The pairs that compose into a primary composite, sorted")
+    write_rows("COMPOSITION", "(u32, u32, u32)", ["(0x%X, 0x%X, 0x%X)" % row for row in sorted(compositions)], 4)
+    print("/// The marks, of general category Mn, Mc or Me")
+    write_rows("MARK", "(u32, u32)", ["(0x%X, 0x%X)" % (first, last) for first, last, _ in ranges(marks)], 6)
+    print("/// The Bidi classes that are not L")
+    write_rows("BIDI_CLASS", "(u32, u32, BidiClass)", ["(0x%X, 0x%X, B::%s)" % tuple(run) for run in ranges(bidi)], 4)
+    print("/// The joining types of the code points that join")
+    write_rows("JOINING_TYPE", "(u32, u32, JoiningType)", ["(0x%X, 0x%X, J::%s)" % tuple(run) for run in ranges(joining)], 4)
+
+
+def rust_string(text):
+    escaped = []
+    for c in text:
+        if c in '"\\':
+            escaped.append("\\" + c)
+        elif unicodedata.category(c)[0] in "LNPS" and c.isprintable() and ord(c) < 0x300:
+            escaped.append(c)
+        else:
+            escaped.append("\\u{%X}" % ord(c))
+    return '"' + "".join(escaped) + '"'
+
+
+if __name__ == "__main__":
+    main(sys.argv[1], sys.argv[2])
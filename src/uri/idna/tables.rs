@@ -0,0 +1,2764 @@
+// Generated by make_tables.py, do not edit.
+// IdnaMappingTable.txt version: 13.0.0, DerivedJoiningType-15.0.0.txt,
+// and the Unicode 14.0.0 data of Python's unicodedata.
+
+use self::BidiClass as B;
+use self::JoiningType as J;
+use self::Status::*;
+
+/// The status of a code point in the UTS #46 mapping table, where the
+/// deviations are valid, as they are in nontransitional processing
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum Status {
+    /// valid
+    V,
+    /// ignored, removed from the domain
+    I,
+    /// disallowed
+    D,
+    /// valid, but disallowed with the STD3 rules
+    S,
+    /// mapped to the text at this offset and length in MAPPED_TEXT
+    M(u16, u8),
+    /// mapped, but disallowed with the STD3 rules
+    SM(u16, u8),
+    /// each code point mapped to this one plus its distance to the start of the run
+    R(u32),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum BidiClass {
+    L, R, AL, AN, EN, ES, CS, ET, ON, BN, NSM,
+    /// the classes that can not be in a label of a Bidi domain name
+    Other,
+}
+
+/// The joining types of RFC 5892 appendix A.1, those that are not non-joining
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum JoiningType {
+    D, L, R, T, C,
+}
+
+/// The runs of code points that share a status, by their first code point
+pub(super) const MAPPING: &[(u32, Status)] = &[
+    (0x0, S), (0x2D, V), (0x2F, S), (0x30, V),
+    (0x3A, S), (0x41, R(0x61)), (0x5B, S), (0x61, V),
+    (0x7B, S), (0x80, D), (0xA0, SM(0, 1)), (0xA1, V),
+    (0xA8, SM(1, 3)), (0xA9, V), (0xAA, M(4, 1)), (0xAB, V),
+    (0xAD, I), (0xAE, V), (0xAF, SM(5, 3)), (0xB0, V),
+    (0xB2, R(0x32)), (0xB4, SM(8, 3)), (0xB5, M(11, 2)), (0xB6, V),
+    (0xB8, SM(13, 3)), (0xB9, M(16, 1)), (0xBA, M(17, 1)), (0xBB, V),
+    (0xBC, M(18, 5)), (0xBD, M(23, 5)), (0xBE, M(28, 5)), (0xBF, V),
+    (0xC0, R(0xE0)), (0xD7, V), (0xD8, R(0xF8)), (0xDF, V),
+    (0x100, M(33, 2)), (0x101, V), (0x102, M(35, 2)), (0x103, V),
+    (0x104, M(37, 2)), (0x105, V), (0x106, M(39, 2)), (0x107, V),
+    (0x108, M(41, 2)), (0x109, V), (0x10A, M(43, 2)), (0x10B, V),
+    (0x10C, M(45, 2)), (0x10D, V), (0x10E, M(47, 2)), (0x10F, V),
+    (0x110, M(49, 2)), (0x111, V), (0x112, M(51, 2)), (0x113, V),
+    (0x114, M(53, 2)), (0x115, V), (0x116, M(55, 2)), (0x117, V),
+    (0x118, M(57, 2)), (0x119, V), (0x11A, M(59, 2)), (0x11B, V),
+    (0x11C, M(61, 2)), (0x11D, V), (0x11E, M(63, 2)), (0x11F, V),
+    (0x120, M(65, 2)), (0x121, V), (0x122, M(67, 2)), (0x123, V),
+    (0x124, M(69, 2)), (0x125, V), (0x126, M(71, 2)), (0x127, V),
+    (0x128, M(73, 2)), (0x129, V), (0x12A, M(75, 2)), (0x12B, V),
+    (0x12C, M(77, 2)), (0x12D, V), (0x12E, M(79, 2)), (0x12F, V),
+    (0x130, M(81, 3)), (0x131, V), (0x132, M(84, 2)), (0x134, M(86, 2)),
+    (0x135, V), (0x136, M(88, 2)), (0x137, V), (0x139, M(90, 2)),
+    (0x13A, V), (0x13B, M(92, 2)), (0x13C, V), (0x13D, M(94, 2)),
+    (0x13E, V), (0x13F, M(96, 3)), (0x141, M(99, 2)), (0x142, V),
+    (0x143, M(101, 2)), (0x144, V), (0x145, M(103, 2)), (0x146, V),
+    (0x147, M(105, 2)), (0x148, V), (0x149, M(107, 3)), (0x14A, M(110, 2)),
+    (0x14B, V), (0x14C, M(112, 2)), (0x14D, V), (0x14E, M(114, 2)),
+    (0x14F, V), (0x150, M(116, 2)), (0x151, V), (0x152, M(118, 2)),
+    (0x153, V), (0x154, M(120, 2)), (0x155, V), (0x156, M(122, 2)),
+    (0x157, V), (0x158, M(124, 2)), (0x159, V), (0x15A, M(126, 2)),
+    (0x15B, V), (0x15C, M(128, 2)), (0x15D, V), (0x15E, M(130, 2)),
+    (0x15F, V), (0x160, M(132, 2)), (0x161, V), (0x162, M(134, 2)),
+    (0x163, V), (0x164, M(136, 2)), (0x165, V), (0x166, M(138, 2)),
+    (0x167, V), (0x168, M(140, 2)), (0x169, V), (0x16A, M(142, 2)),
+    (0x16B, V), (0x16C, M(144, 2)), (0x16D, V), (0x16E, M(146, 2)),
+    (0x16F, V), (0x170, M(148, 2)), (0x171, V), (0x172, M(150, 2)),
+    (0x173, V), (0x174, M(152, 2)), (0x175, V), (0x176, M(154, 2)),
+    (0x177, V), (0x178, M(156, 2)), (0x179, M(158, 2)), (0x17A, V),
+    (0x17B, M(160, 2)), (0x17C, V), (0x17D, M(162, 2)), (0x17E, V),
+    (0x17F, M(164, 1)), (0x180, V), (0x181, M(165, 2)), (0x182, M(167, 2)),
+    (0x183, V), (0x184, M(169, 2)), (0x185, V), (0x186, M(171, 2)),
+    (0x187, M(173, 2)), (0x188, V), (0x189, R(0x256)), (0x18B, M(175, 2)),
+    (0x18C, V), (0x18E, M(177, 2)), (0x18F, M(179, 2)), (0x190, M(181, 2)),
+    (0x191, M(183, 2)), (0x192, V), (0x193, M(185, 2)), (0x194, M(187, 2)),
+    (0x195, V), (0x196, M(189, 2)), (0x197, M(191, 2)), (0x198, M(193, 2)),
+    (0x199, V), (0x19C, M(195, 2)), (0x19D, M(197, 2)), (0x19E, V),
+    (0x19F, M(199, 2)), (0x1A0, M(201, 2)), (0x1A1, V), (0x1A2, M(203, 2)),
+    (0x1A3, V), (0x1A4, M(205, 2)), (0x1A5, V), (0x1A6, M(207, 2)),
+    (0x1A7, M(209, 2)), (0x1A8, V), (0x1A9, M(211, 2)), (0x1AA, V),
+    (0x1AC, M(213, 2)), (0x1AD, V), (0x1AE, M(215, 2)), (0x1AF, M(217, 2)),
+    (0x1B0, V), (0x1B1, R(0x28A)), (0x1B3, M(219, 2)), (0x1B4, V),
+    (0x1B5, M(221, 2)), (0x1B6, V), (0x1B7, M(223, 2)), (0x1B8, M(225, 2)),
+    (0x1B9, V), (0x1BC, M(227, 2)), (0x1BD, V), (0x1C4, M(229, 3)),
+    (0x1C7, M(232, 2)), (0x1CA, M(234, 2)), (0x1CD, M(236, 2)), (0x1CE, V),
+    (0x1CF, M(238, 2)), (0x1D0, V), (0x1D1, M(240, 2)), (0x1D2, V),
+    (0x1D3, M(242, 2)), (0x1D4, V), (0x1D5, M(244, 2)), (0x1D6, V),
+    (0x1D7, M(246, 2)), (0x1D8, V), (0x1D9, M(248, 2)), (0x1DA, V),
+    (0x1DB, M(250, 2)), (0x1DC, V), (0x1DE, M(252, 2)), (0x1DF, V),
+    (0x1E0, M(254, 2)), (0x1E1, V), (0x1E2, M(256, 2)), (0x1E3, V),
+    (0x1E4, M(258, 2)), (0x1E5, V), (0x1E6, M(260, 2)), (0x1E7, V),
+    (0x1E8, M(262, 2)), (0x1E9, V), (0x1EA, M(264, 2)), (0x1EB, V),
+    (0x1EC, M(266, 2)), (0x1ED, V), (0x1EE, M(268, 2)), (0x1EF, V),
+    (0x1F1, M(270, 2)), (0x1F4, M(272, 2)), (0x1F5, V), (0x1F6, M(274, 2)),
+    (0x1F7, M(276, 2)), (0x1F8, M(278, 2)), (0x1F9, V), (0x1FA, M(280, 2)),
+    (0x1FB, V), (0x1FC, M(282, 2)), (0x1FD, V), (0x1FE, M(284, 2)),
+    (0x1FF, V), (0x200, M(286, 2)), (0x201, V), (0x202, M(288, 2)),
+    (0x203, V), (0x204, M(290, 2)), (0x205, V), (0x206, M(292, 2)),
+    (0x207, V), (0x208, M(294, 2)), (0x209, V), (0x20A, M(296, 2)),
+    (0x20B, V), (0x20C, M(298, 2)), (0x20D, V), (0x20E, M(300, 2)),
+    (0x20F, V), (0x210, M(302, 2)), (0x211, V), (0x212, M(304, 2)),
+    (0x213, V), (0x214, M(306, 2)), (0x215, V), (0x216, M(308, 2)),
+    (0x217, V), (0x218, M(310, 2)), (0x219, V), (0x21A, M(312, 2)),
+    (0x21B, V), (0x21C, M(314, 2)), (0x21D, V), (0x21E, M(316, 2)),
+    (0x21F, V), (0x220, M(318, 2)), (0x221, V), (0x222, M(320, 2)),
+    (0x223, V), (0x224, M(322, 2)), (0x225, V), (0x226, M(324, 2)),
+    (0x227, V), (0x228, M(326, 2)), (0x229, V), (0x22A, M(328, 2)),
+    (0x22B, V), (0x22C, M(330, 2)), (0x22D, V), (0x22E, M(332, 2)),
+    (0x22F, V), (0x230, M(334, 2)), (0x231, V), (0x232, M(336, 2)),
+    (0x233, V), (0x23A, M(338, 3)), (0x23B, M(341, 2)), (0x23C, V),
+    (0x23D, M(343, 2)), (0x23E, M(345, 3)), (0x23F, V), (0x241, M(348, 2)),
+    (0x242, V), (0x243, M(350, 2)), (0x244, M(352, 2)), (0x245, M(354, 2)),
+    (0x246, M(356, 2)), (0x247, V), (0x248, M(358, 2)), (0x249, V),
+    (0x24A, M(360, 2)), (0x24B, V), (0x24C, M(362, 2)), (0x24D, V),
+    (0x24E, M(364, 2)), (0x24F, V), (0x2B0, M(366, 1)), (0x2B1, M(367, 2)),
+    (0x2B2, M(85, 1)), (0x2B3, M(369, 1)), (0x2B4, M(370, 2)), (0x2B5, M(372, 2)),
+    (0x2B6, M(374, 2)), (0x2B7, M(376, 1)), (0x2B8, M(377, 1)), (0x2B9, V),
+    (0x2D8, SM(378, 3)), (0x2D9, SM(381, 3)), (0x2DA, SM(384, 3)), (0x2DB, SM(387, 3)),
+    (0x2DC, SM(390, 3)), (0x2DD, SM(393, 3)), (0x2DE, V), (0x2E0, M(187, 2)),
+    (0x2E1, M(96, 1)), (0x2E2, M(164, 1)), (0x2E3, M(396, 1)), (0x2E4, M(397, 2)),
+    (0x2E5, V), (0x340, R(0x300)), (0x342, V), (0x343, M(399, 2)),
+    (0x344, M(401, 4)), (0x345, M(405, 2)), (0x346, V), (0x34F, I),
+    (0x350, V), (0x370, M(407, 2)), (0x371, V), (0x372, M(409, 2)),
+    (0x373, V), (0x374, M(411, 2)), (0x375, V), (0x376, M(413, 2)),
+    (0x377, V), (0x378, D), (0x37A, SM(415, 3)), (0x37B, V),
+    (0x37E, SM(418, 1)), (0x37F, M(419, 2)), (0x380, D), (0x384, SM(8, 3)),
+    (0x385, SM(421, 5)), (0x386, M(426, 2)), (0x387, M(97, 2)), (0x388, R(0x3AD)),
+    (0x38B, D), (0x38C, M(428, 2)), (0x38D, D), (0x38E, R(0x3CD)),
+    (0x390, V), (0x391, R(0x3B1)), (0x3A2, D), (0x3A3, R(0x3C3)),
+    (0x3AC, V), (0x3CF, M(430, 2)), (0x3D0, M(432, 2)), (0x3D1, M(434, 2)),
+    (0x3D2, M(436, 2)), (0x3D3, M(438, 2)), (0x3D4, M(440, 2)), (0x3D5, M(442, 2)),
+    (0x3D6, M(444, 2)), (0x3D7, V), (0x3D8, M(446, 2)), (0x3D9, V),
+    (0x3DA, M(448, 2)), (0x3DB, V), (0x3DC, M(450, 2)), (0x3DD, V),
+    (0x3DE, M(452, 2)), (0x3DF, V), (0x3E0, M(454, 2)), (0x3E1, V),
+    (0x3E2, M(456, 2)), (0x3E3, V), (0x3E4, M(458, 2)), (0x3E5, V),
+    (0x3E6, M(460, 2)), (0x3E7, V), (0x3E8, M(462, 2)), (0x3E9, V),
+    (0x3EA, M(464, 2)), (0x3EB, V), (0x3EC, M(466, 2)), (0x3ED, V),
+    (0x3EE, M(468, 2)), (0x3EF, V), (0x3F0, M(470, 2)), (0x3F1, M(472, 2)),
+    (0x3F2, M(474, 2)), (0x3F3, V), (0x3F4, M(434, 2)), (0x3F5, M(476, 2)),
+    (0x3F6, V), (0x3F7, M(478, 2)), (0x3F8, V), (0x3F9, M(474, 2)),
+    (0x3FA, M(480, 2)), (0x3FB, V), (0x3FD, R(0x37B)), (0x400, R(0x450)),
+    (0x410, R(0x430)), (0x430, V), (0x460, M(482, 2)), (0x461, V),
+    (0x462, M(484, 2)), (0x463, V), (0x464, M(486, 2)), (0x465, V),
+    (0x466, M(488, 2)), (0x467, V), (0x468, M(490, 2)), (0x469, V),
+    (0x46A, M(492, 2)), (0x46B, V), (0x46C, M(494, 2)), (0x46D, V),
+    (0x46E, M(496, 2)), (0x46F, V), (0x470, M(498, 2)), (0x471, V),
+    (0x472, M(500, 2)), (0x473, V), (0x474, M(502, 2)), (0x475, V),
+    (0x476, M(504, 2)), (0x477, V), (0x478, M(506, 2)), (0x479, V),
+    (0x47A, M(508, 2)), (0x47B, V), (0x47C, M(510, 2)), (0x47D, V),
+    (0x47E, M(512, 2)), (0x47F, V), (0x480, M(514, 2)), (0x481, V),
+    (0x48A, M(516, 2)), (0x48B, V), (0x48C, M(518, 2)), (0x48D, V),
+    (0x48E, M(520, 2)), (0x48F, V), (0x490, M(522, 2)), (0x491, V),
+    (0x492, M(524, 2)), (0x493, V), (0x494, M(526, 2)), (0x495, V),
+    (0x496, M(528, 2)), (0x497, V), (0x498, M(530, 2)), (0x499, V),
+    (0x49A, M(532, 2)), (0x49B, V), (0x49C, M(534, 2)), (0x49D, V),
+    (0x49E, M(536, 2)), (0x49F, V), (0x4A0, M(538, 2)), (0x4A1, V),
+    (0x4A2, M(540, 2)), (0x4A3, V), (0x4A4, M(542, 2)), (0x4A5, V),
+    (0x4A6, M(544, 2)), (0x4A7, V), (0x4A8, M(546, 2)), (0x4A9, V),
+    (0x4AA, M(548, 2)), (0x4AB, V), (0x4AC, M(550, 2)), (0x4AD, V),
+    (0x4AE, M(552, 2)), (0x4AF, V), (0x4B0, M(554, 2)), (0x4B1, V),
+    (0x4B2, M(556, 2)), (0x4B3, V), (0x4B4, M(558, 2)), (0x4B5, V),
+    (0x4B6, M(560, 2)), (0x4B7, V), (0x4B8, M(562, 2)), (0x4B9, V),
+    (0x4BA, M(564, 2)), (0x4BB, V), (0x4BC, M(566, 2)), (0x4BD, V),
+    (0x4BE, M(568, 2)), (0x4BF, V), (0x4C0, D), (0x4C1, M(570, 2)),
+    (0x4C2, V), (0x4C3, M(572, 2)), (0x4C4, V), (0x4C5, M(574, 2)),
+    (0x4C6, V), (0x4C7, M(576, 2)), (0x4C8, V), (0x4C9, M(578, 2)),
+    (0x4CA, V), (0x4CB, M(580, 2)), (0x4CC, V), (0x4CD, M(582, 2)),
+    (0x4CE, V), (0x4D0, M(584, 2)), (0x4D1, V), (0x4D2, M(586, 2)),
+    (0x4D3, V), (0x4D4, M(588, 2)), (0x4D5, V), (0x4D6, M(590, 2)),
+    (0x4D7, V), (0x4D8, M(592, 2)), (0x4D9, V), (0x4DA, M(594, 2)),
+    (0x4DB, V), (0x4DC, M(596, 2)), (0x4DD, V), (0x4DE, M(598, 2)),
+    (0x4DF, V), (0x4E0, M(600, 2)), (0x4E1, V), (0x4E2, M(602, 2)),
+    (0x4E3, V), (0x4E4, M(604, 2)), (0x4E5, V), (0x4E6, M(606, 2)),
+    (0x4E7, V), (0x4E8, M(608, 2)), (0x4E9, V), (0x4EA, M(610, 2)),
+    (0x4EB, V), (0x4EC, M(612, 2)), (0x4ED, V), (0x4EE, M(614, 2)),
+    (0x4EF, V), (0x4F0, M(616, 2)), (0x4F1, V), (0x4F2, M(618, 2)),
+    (0x4F3, V), (0x4F4, M(620, 2)), (0x4F5, V), (0x4F6, M(622, 2)),
+    (0x4F7, V), (0x4F8, M(624, 2)), (0x4F9, V), (0x4FA, M(626, 2)),
+    (0x4FB, V), (0x4FC, M(628, 2)), (0x4FD, V), (0x4FE, M(630, 2)),
+    (0x4FF, V), (0x500, M(632, 2)), (0x501, V), (0x502, M(634, 2)),
+    (0x503, V), (0x504, M(636, 2)), (0x505, V), (0x506, M(638, 2)),
+    (0x507, V), (0x508, M(640, 2)), (0x509, V), (0x50A, M(642, 2)),
+    (0x50B, V), (0x50C, M(644, 2)), (0x50D, V), (0x50E, M(646, 2)),
+    (0x50F, V), (0x510, M(648, 2)), (0x511, V), (0x512, M(650, 2)),
+    (0x513, V), (0x514, M(652, 2)), (0x515, V), (0x516, M(654, 2)),
+    (0x517, V), (0x518, M(656, 2)), (0x519, V), (0x51A, M(658, 2)),
+    (0x51B, V), (0x51C, M(660, 2)), (0x51D, V), (0x51E, M(662, 2)),
+    (0x51F, V), (0x520, M(664, 2)), (0x521, V), (0x522, M(666, 2)),
+    (0x523, V), (0x524, M(668, 2)), (0x525, V), (0x526, M(670, 2)),
+    (0x527, V), (0x528, M(672, 2)), (0x529, V), (0x52A, M(674, 2)),
+    (0x52B, V), (0x52C, M(676, 2)), (0x52D, V), (0x52E, M(678, 2)),
+    (0x52F, V), (0x530, D), (0x531, R(0x561)), (0x557, D),
+    (0x559, V), (0x587, M(680, 4)), (0x588, V), (0x58B, D),
+    (0x58D, V), (0x590, D), (0x591, V), (0x5C8, D),
+    (0x5D0, V), (0x5EB, D), (0x5EF, V), (0x5F5, D),
+    (0x606, V), (0x61C, D), (0x61E, V), (0x675, M(684, 4)),
+    (0x676, M(688, 4)), (0x677, M(692, 4)), (0x678, M(696, 4)), (0x679, V),
+    (0x6DD, D), (0x6DE, V), (0x70E, D), (0x710, V),
+    (0x74B, D), (0x74D, V), (0x7B2, D), (0x7C0, V),
+    (0x7FB, D), (0x7FD, V), (0x82E, D), (0x830, V),
+    (0x83F, D), (0x840, V), (0x85C, D), (0x85E, V),
+    (0x85F, D), (0x860, V), (0x86B, D), (0x8A0, V),
+    (0x8B5, D), (0x8B6, V), (0x8C8, D), (0x8D3, V),
+    (0x8E2, D), (0x8E3, V), (0x958, M(700, 6)), (0x959, M(706, 6)),
+    (0x95A, M(712, 6)), (0x95B, M(718, 6)), (0x95C, M(724, 6)), (0x95D, M(730, 6)),
+    (0x95E, M(736, 6)), (0x95F, M(742, 6)), (0x960, V), (0x984, D),
+    (0x985, V), (0x98D, D), (0x98F, V), (0x991, D),
+    (0x993, V), (0x9A9, D), (0x9AA, V), (0x9B1, D),
+    (0x9B2, V), (0x9B3, D), (0x9B6, V), (0x9BA, D),
+    (0x9BC, V), (0x9C5, D), (0x9C7, V), (0x9C9, D),
+    (0x9CB, V), (0x9CF, D), (0x9D7, V), (0x9D8, D),
+    (0x9DC, M(748, 6)), (0x9DD, M(754, 6)), (0x9DE, D), (0x9DF, M(760, 6)),
+    (0x9E0, V), (0x9E4, D), (0x9E6, V), (0x9FF, D),
+    (0xA01, V), (0xA04, D), (0xA05, V), (0xA0B, D),
+    (0xA0F, V), (0xA11, D), (0xA13, V), (0xA29, D),
+    (0xA2A, V), (0xA31, D), (0xA32, V), (0xA33, M(766, 6)),
+    (0xA34, D), (0xA35, V), (0xA36, M(772, 6)), (0xA37, D),
+    (0xA38, V), (0xA3A, D), (0xA3C, V), (0xA3D, D),
+    (0xA3E, V), (0xA43, D), (0xA47, V), (0xA49, D),
+    (0xA4B, V), (0xA4E, D), (0xA51, V), (0xA52, D),
+    (0xA59, M(778, 6)), (0xA5A, M(784, 6)), (0xA5B, M(790, 6)), (0xA5C, V),
+    (0xA5D, D), (0xA5E, M(796, 6)), (0xA5F, D), (0xA66, V),
+    (0xA77, D), (0xA81, V), (0xA84, D), (0xA85, V),
+    (0xA8E, D), (0xA8F, V), (0xA92, D), (0xA93, V),
+    (0xAA9, D), (0xAAA, V), (0xAB1, D), (0xAB2, V),
+    (0xAB4, D), (0xAB5, V), (0xABA, D), (0xABC, V),
+    (0xAC6, D), (0xAC7, V), (0xACA, D), (0xACB, V),
+    (0xACE, D), (0xAD0, V), (0xAD1, D), (0xAE0, V),
+    (0xAE4, D), (0xAE6, V), (0xAF2, D), (0xAF9, V),
+    (0xB00, D), (0xB01, V), (0xB04, D), (0xB05, V),
+    (0xB0D, D), (0xB0F, V), (0xB11, D), (0xB13, V),
+    (0xB29, D), (0xB2A, V), (0xB31, D), (0xB32, V),
+    (0xB34, D), (0xB35, V), (0xB3A, D), (0xB3C, V),
+    (0xB45, D), (0xB47, V), (0xB49, D), (0xB4B, V),
+    (0xB4E, D), (0xB55, V), (0xB58, D), (0xB5C, M(802, 6)),
+    (0xB5D, M(808, 6)), (0xB5E, D), (0xB5F, V), (0xB64, D),
+    (0xB66, V), (0xB78, D), (0xB82, V), (0xB84, D),
+    (0xB85, V), (0xB8B, D), (0xB8E, V), (0xB91, D),
+    (0xB92, V), (0xB96, D), (0xB99, V), (0xB9B, D),
+    (0xB9C, V), (0xB9D, D), (0xB9E, V), (0xBA0, D),
+    (0xBA3, V), (0xBA5, D), (0xBA8, V), (0xBAB, D),
+    (0xBAE, V), (0xBBA, D), (0xBBE, V), (0xBC3, D),
+    (0xBC6, V), (0xBC9, D), (0xBCA, V), (0xBCE, D),
+    (0xBD0, V), (0xBD1, D), (0xBD7, V), (0xBD8, D),
+    (0xBE6, V), (0xBFB, D), (0xC00, V), (0xC0D, D),
+    (0xC0E, V), (0xC11, D), (0xC12, V), (0xC29, D),
+    (0xC2A, V), (0xC3A, D), (0xC3D, V), (0xC45, D),
+    (0xC46, V), (0xC49, D), (0xC4A, V), (0xC4E, D),
+    (0xC55, V), (0xC57, D), (0xC58, V), (0xC5B, D),
+    (0xC60, V), (0xC64, D), (0xC66, V), (0xC70, D),
+    (0xC77, V), (0xC8D, D), (0xC8E, V), (0xC91, D),
+    (0xC92, V), (0xCA9, D), (0xCAA, V), (0xCB4, D),
+    (0xCB5, V), (0xCBA, D), (0xCBC, V), (0xCC5, D),
+    (0xCC6, V), (0xCC9, D), (0xCCA, V), (0xCCE, D),
+    (0xCD5, V), (0xCD7, D), (0xCDE, V), (0xCDF, D),
+    (0xCE0, V), (0xCE4, D), (0xCE6, V), (0xCF0, D),
+    (0xCF1, V), (0xCF3, D), (0xD00, V), (0xD0D, D),
+    (0xD0E, V), (0xD11, D), (0xD12, V), (0xD45, D),
+    (0xD46, V), (0xD49, D), (0xD4A, V), (0xD50, D),
+    (0xD54, V), (0xD64, D), (0xD66, V), (0xD80, D),
+    (0xD81, V), (0xD84, D), (0xD85, V), (0xD97, D),
+    (0xD9A, V), (0xDB2, D), (0xDB3, V), (0xDBC, D),
+    (0xDBD, V), (0xDBE, D), (0xDC0, V), (0xDC7, D),
+    (0xDCA, V), (0xDCB, D), (0xDCF, V), (0xDD5, D),
+    (0xDD6, V), (0xDD7, D), (0xDD8, V), (0xDE0, D),
+    (0xDE6, V), (0xDF0, D), (0xDF2, V), (0xDF5, D),
+    (0xE01, V), (0xE33, M(814, 6)), (0xE34, V), (0xE3B, D),
+    (0xE3F, V), (0xE5C, D), (0xE81, V), (0xE83, D),
+    (0xE84, V), (0xE85, D), (0xE86, V), (0xE8B, D),
+    (0xE8C, V), (0xEA4, D), (0xEA5, V), (0xEA6, D),
+    (0xEA7, V), (0xEB3, M(820, 6)), (0xEB4, V), (0xEBE, D),
+    (0xEC0, V), (0xEC5, D), (0xEC6, V), (0xEC7, D),
+    (0xEC8, V), (0xECE, D), (0xED0, V), (0xEDA, D),
+    (0xEDC, M(826, 6)), (0xEDD, M(832, 6)), (0xEDE, V), (0xEE0, D),
+    (0xF00, V), (0xF0C, M(838, 3)), (0xF0D, V), (0xF43, M(841, 6)),
+    (0xF44, V), (0xF48, D), (0xF49, V), (0xF4D, M(847, 6)),
+    (0xF4E, V), (0xF52, M(853, 6)), (0xF53, V), (0xF57, M(859, 6)),
+    (0xF58, V), (0xF5C, M(865, 6)), (0xF5D, V), (0xF69, M(871, 6)),
+    (0xF6A, V), (0xF6D, D), (0xF71, V), (0xF73, M(877, 6)),
+    (0xF74, V), (0xF75, M(883, 6)), (0xF76, M(889, 6)), (0xF77, M(895, 9)),
+    (0xF78, M(904, 6)), (0xF79, M(910, 9)), (0xF7A, V), (0xF81, M(898, 6)),
+    (0xF82, V), (0xF93, M(919, 6)), (0xF94, V), (0xF98, D),
+    (0xF99, V), (0xF9D, M(925, 6)), (0xF9E, V), (0xFA2, M(931, 6)),
+    (0xFA3, V), (0xFA7, M(937, 6)), (0xFA8, V), (0xFAC, M(943, 6)),
+    (0xFAD, V), (0xFB9, M(949, 6)), (0xFBA, V), (0xFBD, D),
+    (0xFBE, V), (0xFCD, D), (0xFCE, V), (0xFDB, D),
+    (0x1000, V), (0x10A0, D), (0x10C7, M(955, 3)), (0x10C8, D),
+    (0x10CD, M(958, 3)), (0x10CE, D), (0x10D0, V), (0x10FC, M(961, 3)),
+    (0x10FD, V), (0x115F, D), (0x1161, V), (0x1249, D),
+    (0x124A, V), (0x124E, D), (0x1250, V), (0x1257, D),
+    (0x1258, V), (0x1259, D), (0x125A, V), (0x125E, D),
+    (0x1260, V), (0x1289, D), (0x128A, V), (0x128E, D),
+    (0x1290, V), (0x12B1, D), (0x12B2, V), (0x12B6, D),
+    (0x12B8, V), (0x12BF, D), (0x12C0, V), (0x12C1, D),
+    (0x12C2, V), (0x12C6, D), (0x12C8, V), (0x12D7, D),
+    (0x12D8, V), (0x1311, D), (0x1312, V), (0x1316, D),
+    (0x1318, V), (0x135B, D), (0x135D, V), (0x137D, D),
+    (0x1380, V), (0x139A, D), (0x13A0, V), (0x13F6, D),
+    (0x13F8, R(0x13F0)), (0x13FE, D), (0x1400, V), (0x1680, D),
+    (0x1681, V), (0x169D, D), (0x16A0, V), (0x16F9, D),
+    (0x1700, V), (0x170D, D), (0x170E, V), (0x1715, D),
+    (0x1720, V), (0x1737, D), (0x1740, V), (0x1754, D),
+    (0x1760, V), (0x176D, D), (0x176E, V), (0x1771, D),
+    (0x1772, V), (0x1774, D), (0x1780, V), (0x17B4, D),
+    (0x17B6, V), (0x17DE, D), (0x17E0, V), (0x17EA, D),
+    (0x17F0, V), (0x17FA, D), (0x1800, V), (0x1806, D),
+    (0x1807, V), (0x180B, I), (0x180E, D), (0x1810, V),
+    (0x181A, D), (0x1820, V), (0x1879, D), (0x1880, V),
+    (0x18AB, D), (0x18B0, V), (0x18F6, D), (0x1900, V),
+    (0x191F, D), (0x1920, V), (0x192C, D), (0x1930, V),
+    (0x193C, D), (0x1940, V), (0x1941, D), (0x1944, V),
+    (0x196E, D), (0x1970, V), (0x1975, D), (0x1980, V),
+    (0x19AC, D), (0x19B0, V), (0x19CA, D), (0x19D0, V),
+    (0x19DB, D), (0x19DE, V), (0x1A1C, D), (0x1A1E, V),
+    (0x1A5F, D), (0x1A60, V), (0x1A7D, D), (0x1A7F, V),
+    (0x1A8A, D), (0x1A90, V), (0x1A9A, D), (0x1AA0, V),
+    (0x1AAE, D), (0x1AB0, V), (0x1AC1, D), (0x1B00, V),
+    (0x1B4C, D), (0x1B50, V), (0x1B7D, D), (0x1B80, V),
+    (0x1BF4, D), (0x1BFC, V), (0x1C38, D), (0x1C3B, V),
+    (0x1C4A, D), (0x1C4D, V), (0x1C80, M(964, 2)), (0x1C81, M(966, 2)),
+    (0x1C82, M(968, 2)), (0x1C83, R(0x441)), (0x1C85, M(970, 2)), (0x1C86, M(972, 2)),
+    (0x1C87, M(484, 2)), (0x1C88, M(974, 3)), (0x1C89, D), (0x1C90, R(0x10D0)),
+    (0x1CBB, D), (0x1CBD, R(0x10FD)), (0x1CC0, V), (0x1CC8, D),
+    (0x1CD0, V), (0x1CFB, D), (0x1D00, V), (0x1D2C, M(4, 1)),
+    (0x1D2D, M(977, 2)), (0x1D2E, M(979, 1)), (0x1D2F, V), (0x1D30, R(0x64)),
+    (0x1D32, M(177, 2)), (0x1D33, R(0x67)), (0x1D3B, V), (0x1D3C, M(17, 1)),
+    (0x1D3D, M(320, 2)), (0x1D3E, M(980, 1)), (0x1D3F, M(369, 1)), (0x1D40, R(0x74)),
+    (0x1D42, M(376, 1)), (0x1D43, M(4, 1)), (0x1D44, R(0x250)), (0x1D46, M(981, 3)),
+    (0x1D47, M(979, 1)), (0x1D48, R(0x64)), (0x1D4A, M(179, 2)), (0x1D4B, R(0x25B)),
+    (0x1D4D, M(984, 1)), (0x1D4E, V), (0x1D4F, M(985, 1)), (0x1D50, M(986, 1)),
+    (0x1D51, M(110, 2)), (0x1D52, M(17, 1)), (0x1D53, M(171, 2)), (0x1D54, R(0x1D16)),
+    (0x1D56, M(980, 1)), (0x1D57, R(0x74)), (0x1D59, M(987, 3)), (0x1D5A, M(195, 2)),
+    (0x1D5B, M(990, 1)), (0x1D5C, M(991, 3)), (0x1D5D, R(0x3B2)), (0x1D60, R(0x3C6)),
+    (0x1D62, M(81, 1)), (0x1D63, M(369, 1)), (0x1D64, R(0x75)), (0x1D66, R(0x3B2)),
+    (0x1D68, M(472, 2)), (0x1D69, R(0x3C6)), (0x1D6B, V), (0x1D78, M(994, 2)),
+    (0x1D79, V), (0x1D9B, M(996, 2)), (0x1D9C, M(998, 1)), (0x1D9D, M(999, 2)),
+    (0x1D9E, M(1001, 2)), (0x1D9F, M(1003, 2)), (0x1DA0, M(1005, 1)), (0x1DA1, M(1006, 2)),
+    (0x1DA2, M(1008, 2)), (0x1DA3, M(1010, 2)), (0x1DA4, R(0x268)), (0x1DA7, M(1012, 3)),
+    (0x1DA8, M(1015, 2)), (0x1DA9, M(1017, 2)), (0x1DAA, M(1019, 3)), (0x1DAB, M(1022, 2)),
+    (0x1DAC, M(1024, 2)), (0x1DAD, M(1026, 2)), (0x1DAE, R(0x272)), (0x1DB2, M(1028, 2)),
+    (0x1DB3, R(0x282)), (0x1DB5, M(1030, 2)), (0x1DB6, R(0x289)), (0x1DB8, M(1032, 3)),
+    (0x1DB9, R(0x28B)), (0x1DBB, M(271, 1)), (0x1DBC, R(0x290)), (0x1DBF, M(434, 2)),
+    (0x1DC0, V), (0x1DFA, D), (0x1DFB, V), (0x1E00, M(1035, 3)),
+    (0x1E01, V), (0x1E02, M(1038, 3)), (0x1E03, V), (0x1E04, M(1041, 3)),
+    (0x1E05, V), (0x1E06, M(1044, 3)), (0x1E07, V), (0x1E08, M(1047, 3)),
+    (0x1E09, V), (0x1E0A, M(1050, 3)), (0x1E0B, V), (0x1E0C, M(1053, 3)),
+    (0x1E0D, V), (0x1E0E, M(1056, 3)), (0x1E0F, V), (0x1E10, M(1059, 3)),
+    (0x1E11, V), (0x1E12, M(1062, 3)), (0x1E13, V), (0x1E14, M(1065, 3)),
+    (0x1E15, V), (0x1E16, M(1068, 3)), (0x1E17, V), (0x1E18, M(1071, 3)),
+    (0x1E19, V), (0x1E1A, M(1074, 3)), (0x1E1B, V), (0x1E1C, M(1077, 3)),
+    (0x1E1D, V), (0x1E1E, M(1080, 3)), (0x1E1F, V), (0x1E20, M(1083, 3)),
+    (0x1E21, V), (0x1E22, M(1086, 3)), (0x1E23, V), (0x1E24, M(1089, 3)),
+    (0x1E25, V), (0x1E26, M(1092, 3)), (0x1E27, V), (0x1E28, M(1095, 3)),
+    (0x1E29, V), (0x1E2A, M(1098, 3)), (0x1E2B, V), (0x1E2C, M(1101, 3)),
+    (0x1E2D, V), (0x1E2E, M(1104, 3)), (0x1E2F, V), (0x1E30, M(1107, 3)),
+    (0x1E31, V), (0x1E32, M(1110, 3)), (0x1E33, V), (0x1E34, M(1113, 3)),
+    (0x1E35, V), (0x1E36, M(1116, 3)), (0x1E37, V), (0x1E38, M(1119, 3)),
+    (0x1E39, V), (0x1E3A, M(1122, 3)), (0x1E3B, V), (0x1E3C, M(1125, 3)),
+    (0x1E3D, V), (0x1E3E, M(1128, 3)), (0x1E3F, V), (0x1E40, M(1131, 3)),
+    (0x1E41, V), (0x1E42, M(1134, 3)), (0x1E43, V), (0x1E44, M(1137, 3)),
+    (0x1E45, V), (0x1E46, M(1140, 3)), (0x1E47, V), (0x1E48, M(1143, 3)),
+    (0x1E49, V), (0x1E4A, M(1146, 3)), (0x1E4B, V), (0x1E4C, M(1149, 3)),
+    (0x1E4D, V), (0x1E4E, M(1152, 3)), (0x1E4F, V), (0x1E50, M(1155, 3)),
+    (0x1E51, V), (0x1E52, M(1158, 3)), (0x1E53, V), (0x1E54, M(1161, 3)),
+    (0x1E55, V), (0x1E56, M(1164, 3)), (0x1E57, V), (0x1E58, M(1167, 3)),
+    (0x1E59, V), (0x1E5A, M(1170, 3)), (0x1E5B, V), (0x1E5C, M(1173, 3)),
+    (0x1E5D, V), (0x1E5E, M(1176, 3)), (0x1E5F, V), (0x1E60, M(1179, 3)),
+    (0x1E61, V), (0x1E62, M(1182, 3)), (0x1E63, V), (0x1E64, M(1185, 3)),
+    (0x1E65, V), (0x1E66, M(1188, 3)), (0x1E67, V), (0x1E68, M(1191, 3)),
+    (0x1E69, V), (0x1E6A, M(1194, 3)), (0x1E6B, V), (0x1E6C, M(1197, 3)),
+    (0x1E6D, V), (0x1E6E, M(1200, 3)), (0x1E6F, V), (0x1E70, M(1203, 3)),
+    (0x1E71, V), (0x1E72, M(1206, 3)), (0x1E73, V), (0x1E74, M(1209, 3)),
+    (0x1E75, V), (0x1E76, M(1212, 3)), (0x1E77, V), (0x1E78, M(1215, 3)),
+    (0x1E79, V), (0x1E7A, M(1218, 3)), (0x1E7B, V), (0x1E7C, M(1221, 3)),
+    (0x1E7D, V), (0x1E7E, M(1224, 3)), (0x1E7F, V), (0x1E80, M(1227, 3)),
+    (0x1E81, V), (0x1E82, M(1230, 3)), (0x1E83, V), (0x1E84, M(1233, 3)),
+    (0x1E85, V), (0x1E86, M(1236, 3)), (0x1E87, V), (0x1E88, M(1239, 3)),
+    (0x1E89, V), (0x1E8A, M(1242, 3)), (0x1E8B, V), (0x1E8C, M(1245, 3)),
+    (0x1E8D, V), (0x1E8E, M(1248, 3)), (0x1E8F, V), (0x1E90, M(1251, 3)),
+    (0x1E91, V), (0x1E92, M(1254, 3)), (0x1E93, V), (0x1E94, M(1257, 3)),
+    (0x1E95, V), (0x1E9A, M(1260, 3)), (0x1E9B, M(1179, 3)), (0x1E9C, V),
+    (0x1E9E, M(1263, 2)), (0x1E9F, V), (0x1EA0, M(1265, 3)), (0x1EA1, V),
+    (0x1EA2, M(1268, 3)), (0x1EA3, V), (0x1EA4, M(1271, 3)), (0x1EA5, V),
+    (0x1EA6, M(1274, 3)), (0x1EA7, V), (0x1EA8, M(1277, 3)), (0x1EA9, V),
+    (0x1EAA, M(1280, 3)), (0x1EAB, V), (0x1EAC, M(1283, 3)), (0x1EAD, V),
+    (0x1EAE, M(1286, 3)), (0x1EAF, V), (0x1EB0, M(1289, 3)), (0x1EB1, V),
+    (0x1EB2, M(1292, 3)), (0x1EB3, V), (0x1EB4, M(1295, 3)), (0x1EB5, V),
+    (0x1EB6, M(1298, 3)), (0x1EB7, V), (0x1EB8, M(1301, 3)), (0x1EB9, V),
+    (0x1EBA, M(1304, 3)), (0x1EBB, V), (0x1EBC, M(1307, 3)), (0x1EBD, V),
+    (0x1EBE, M(1310, 3)), (0x1EBF, V), (0x1EC0, M(1313, 3)), (0x1EC1, V),
+    (0x1EC2, M(1316, 3)), (0x1EC3, V), (0x1EC4, M(1319, 3)), (0x1EC5, V),
+    (0x1EC6, M(1322, 3)), (0x1EC7, V), (0x1EC8, M(1325, 3)), (0x1EC9, V),
+    (0x1ECA, M(1328, 3)), (0x1ECB, V), (0x1ECC, M(1331, 3)), (0x1ECD, V),
+    (0x1ECE, M(1334, 3)), (0x1ECF, V), (0x1ED0, M(1337, 3)), (0x1ED1, V),
+    (0x1ED2, M(1340, 3)), (0x1ED3, V), (0x1ED4, M(1343, 3)), (0x1ED5, V),
+    (0x1ED6, M(1346, 3)), (0x1ED7, V), (0x1ED8, M(1349, 3)), (0x1ED9, V),
+    (0x1EDA, M(1352, 3)), (0x1EDB, V), (0x1EDC, M(1355, 3)), (0x1EDD, V),
+    (0x1EDE, M(1358, 3)), (0x1EDF, V), (0x1EE0, M(1361, 3)), (0x1EE1, V),
+    (0x1EE2, M(1364, 3)), (0x1EE3, V), (0x1EE4, M(1367, 3)), (0x1EE5, V),
+    (0x1EE6, M(1370, 3)), (0x1EE7, V), (0x1EE8, M(1373, 3)), (0x1EE9, V),
+    (0x1EEA, M(1376, 3)), (0x1EEB, V), (0x1EEC, M(1379, 3)), (0x1EED, V),
+    (0x1EEE, M(1382, 3)), (0x1EEF, V), (0x1EF0, M(1385, 3)), (0x1EF1, V),
+    (0x1EF2, M(1388, 3)), (0x1EF3, V), (0x1EF4, M(1391, 3)), (0x1EF5, V),
+    (0x1EF6, M(1394, 3)), (0x1EF7, V), (0x1EF8, M(1397, 3)), (0x1EF9, V),
+    (0x1EFA, M(1400, 3)), (0x1EFB, V), (0x1EFC, M(1403, 3)), (0x1EFD, V),
+    (0x1EFE, M(1406, 3)), (0x1EFF, V), (0x1F08, R(0x1F00)), (0x1F10, V),
+    (0x1F16, D), (0x1F18, R(0x1F10)), (0x1F1E, D), (0x1F20, V),
+    (0x1F28, R(0x1F20)), (0x1F30, V), (0x1F38, R(0x1F30)), (0x1F40, V),
+    (0x1F46, D), (0x1F48, R(0x1F40)), (0x1F4E, D), (0x1F50, V),
+    (0x1F58, D), (0x1F59, M(1409, 3)), (0x1F5A, D), (0x1F5B, M(1412, 3)),
+    (0x1F5C, D), (0x1F5D, M(1415, 3)), (0x1F5E, D), (0x1F5F, M(1418, 3)),
+    (0x1F60, V), (0x1F68, R(0x1F60)), (0x1F70, V), (0x1F71, M(426, 2)),
+    (0x1F72, V), (0x1F73, M(1421, 2)), (0x1F74, V), (0x1F75, M(1423, 2)),
+    (0x1F76, V), (0x1F77, M(1425, 2)), (0x1F78, V), (0x1F79, M(428, 2)),
+    (0x1F7A, V), (0x1F7B, M(438, 2)), (0x1F7C, V), (0x1F7D, M(1427, 2)),
+    (0x1F7E, D), (0x1F80, M(1429, 5)), (0x1F81, M(1434, 5)), (0x1F82, M(1439, 5)),
+    (0x1F83, M(1444, 5)), (0x1F84, M(1449, 5)), (0x1F85, M(1454, 5)), (0x1F86, M(1459, 5)),
+    (0x1F87, M(1464, 5)), (0x1F88, M(1429, 5)), (0x1F89, M(1434, 5)), (0x1F8A, M(1439, 5)),
+    (0x1F8B, M(1444, 5)), (0x1F8C, M(1449, 5)), (0x1F8D, M(1454, 5)), (0x1F8E, M(1459, 5)),
+    (0x1F8F, M(1464, 5)), (0x1F90, M(1469, 5)), (0x1F91, M(1474, 5)), (0x1F92, M(1479, 5)),
+    (0x1F93, M(1484, 5)), (0x1F94, M(1489, 5)), (0x1F95, M(1494, 5)), (0x1F96, M(1499, 5)),
+    (0x1F97, M(1504, 5)), (0x1F98, M(1469, 5)), (0x1F99, M(1474, 5)), (0x1F9A, M(1479, 5)),
+    (0x1F9B, M(1484, 5)), (0x1F9C, M(1489, 5)), (0x1F9D, M(1494, 5)), (0x1F9E, M(1499, 5)),
+    (0x1F9F, M(1504, 5)), (0x1FA0, M(1509, 5)), (0x1FA1, M(1514, 5)), (0x1FA2, M(1519, 5)),
+    (0x1FA3, M(1524, 5)), (0x1FA4, M(1529, 5)), (0x1FA5, M(1534, 5)), (0x1FA6, M(1539, 5)),
+    (0x1FA7, M(1544, 5)), (0x1FA8, M(1509, 5)), (0x1FA9, M(1514, 5)), (0x1FAA, M(1519, 5)),
+    (0x1FAB, M(1524, 5)), (0x1FAC, M(1529, 5)), (0x1FAD, M(1534, 5)), (0x1FAE, M(1539, 5)),
+    (0x1FAF, M(1544, 5)), (0x1FB0, V), (0x1FB2, M(1549, 5)), (0x1FB3, M(1554, 4)),
+    (0x1FB4, M(1558, 4)), (0x1FB5, D), (0x1FB6, V), (0x1FB7, M(1562, 5)),
+    (0x1FB8, R(0x1FB0)), (0x1FBA, M(1549, 3)), (0x1FBB, M(426, 2)), (0x1FBC, M(1554, 4)),
+    (0x1FBD, SM(1567, 3)), (0x1FBE, M(405, 2)), (0x1FBF, SM(1567, 3)), (0x1FC0, SM(1570, 3)),
+    (0x1FC1, SM(1573, 5)), (0x1FC2, M(1578, 5)), (0x1FC3, M(1583, 4)), (0x1FC4, M(1587, 4)),
+    (0x1FC5, D), (0x1FC6, V), (0x1FC7, M(1591, 5)), (0x1FC8, M(1596, 3)),
+    (0x1FC9, M(1421, 2)), (0x1FCA, M(1578, 3)), (0x1FCB, M(1423, 2)), (0x1FCC, M(1583, 4)),
+    (0x1FCD, SM(1599, 5)), (0x1FCE, SM(1604, 5)), (0x1FCF, SM(1609, 5)), (0x1FD0, V),
+    (0x1FD3, M(1614, 2)), (0x1FD4, D), (0x1FD6, V), (0x1FD8, R(0x1FD0)),
+    (0x1FDA, M(1616, 3)), (0x1FDB, M(1425, 2)), (0x1FDC, D), (0x1FDD, SM(1619, 5)),
+    (0x1FDE, SM(1624, 5)), (0x1FDF, SM(1629, 5)), (0x1FE0, V), (0x1FE3, M(1634, 2)),
+    (0x1FE4, V), (0x1FE8, R(0x1FE0)), (0x1FEA, M(1636, 3)), (0x1FEB, M(438, 2)),
+    (0x1FEC, M(1639, 3)), (0x1FED, SM(1642, 5)), (0x1FEE, SM(421, 5)), (0x1FEF, SM(1647, 1)),
+    (0x1FF0, D), (0x1FF2, M(1648, 5)), (0x1FF3, M(1653, 4)), (0x1FF4, M(1657, 4)),
+    (0x1FF5, D), (0x1FF6, V), (0x1FF7, M(1661, 5)), (0x1FF8, M(1666, 3)),
+    (0x1FF9, M(428, 2)), (0x1FFA, M(1648, 3)), (0x1FFB, M(1427, 2)), (0x1FFC, M(1653, 4)),
+    (0x1FFD, SM(8, 3)), (0x1FFE, SM(1619, 3)), (0x1FFF, D), (0x2000, SM(0, 1)),
+    (0x200B, I), (0x200C, V), (0x200E, D), (0x2010, V),
+    (0x2011, M(1669, 3)), (0x2012, V), (0x2017, SM(1672, 3)), (0x2018, V),
+    (0x2024, D), (0x2027, V), (0x2028, D), (0x202F, SM(0, 1)),
+    (0x2030, V), (0x2033, M(1675, 6)), (0x2034, M(1681, 9)), (0x2035, V),
+    (0x2036, M(1690, 6)), (0x2037, M(1696, 9)), (0x2038, V), (0x203C, SM(1705, 2)),
+    (0x203D, V), (0x203E, SM(1707, 3)), (0x203F, V), (0x2047, SM(1710, 2)),
+    (0x2048, SM(1712, 2)), (0x2049, SM(1714, 2)), (0x204A, V), (0x2057, M(1675, 12)),
+    (0x2058, V), (0x205F, SM(0, 1)), (0x2060, I), (0x2061, D),
+    (0x2064, I), (0x2065, D), (0x2070, M(1716, 1)), (0x2071, M(81, 1)),
+    (0x2072, D), (0x2074, R(0x34)), (0x207A, SM(1717, 1)), (0x207B, M(1718, 3)),
+    (0x207C, SM(1721, 1)), (0x207D, SM(1722, 1)), (0x207E, SM(1723, 1)), (0x207F, M(109, 1)),
+    (0x2080, R(0x30)), (0x208A, SM(1717, 1)), (0x208B, M(1718, 3)), (0x208C, SM(1721, 1)),
+    (0x208D, SM(1722, 1)), (0x208E, SM(1723, 1)), (0x208F, D), (0x2090, M(4, 1)),
+    (0x2091, M(1724, 1)), (0x2092, M(17, 1)), (0x2093, M(396, 1)), (0x2094, M(179, 2)),
+    (0x2095, M(366, 1)), (0x2096, R(0x6B)), (0x209A, M(980, 1)), (0x209B, R(0x73)),
+    (0x209D, D), (0x20A0, V), (0x20A8, M(1725, 2)), (0x20A9, V),
+    (0x20C0, D), (0x20D0, V), (0x20F1, D), (0x2100, SM(1727, 3)),
+    (0x2101, SM(1730, 3)), (0x2102, M(998, 1)), (0x2103, M(1733, 3)), (0x2104, V),
+    (0x2105, SM(1736, 3)), (0x2106, SM(1739, 3)), (0x2107, M(181, 2)), (0x2108, V),
+    (0x2109, M(1742, 3)), (0x210A, R(0x67)), (0x210C, M(366, 1)), (0x210F, M(71, 2)),
+    (0x2110, M(81, 1)), (0x2112, M(96, 1)), (0x2114, V), (0x2115, M(109, 1)),
+    (0x2116, M(1745, 2)), (0x2117, V), (0x2119, R(0x70)), (0x211C, M(369, 1)),
+    (0x211E, V), (0x2120, M(1747, 2)), (0x2121, M(1749, 3)), (0x2122, M(1752, 2)),
+    (0x2123, V), (0x2124, M(271, 1)), (0x2125, V), (0x2126, M(1653, 2)),
+    (0x2127, V), (0x2128, M(271, 1)), (0x2129, V), (0x212A, M(985, 1)),
+    (0x212B, M(1754, 2)), (0x212C, R(0x62)), (0x212E, V), (0x212F, M(1724, 1)),
+    (0x2131, M(1005, 1)), (0x2132, D), (0x2133, M(986, 1)), (0x2134, M(17, 1)),
+    (0x2135, R(0x5D0)), (0x2139, M(81, 1)), (0x213A, V), (0x213B, M(1756, 3)),
+    (0x213C, M(444, 2)), (0x213D, M(1759, 2)), (0x213F, M(444, 2)), (0x2140, M(1761, 3)),
+    (0x2141, V), (0x2145, M(229, 1)), (0x2147, M(1724, 1)), (0x2148, R(0x69)),
+    (0x214A, V), (0x2150, M(1764, 5)), (0x2151, M(1769, 5)), (0x2152, M(1774, 6)),
+    (0x2153, M(1780, 5)), (0x2154, M(1785, 5)), (0x2155, M(1790, 5)), (0x2156, M(1795, 5)),
+    (0x2157, M(1800, 5)), (0x2158, M(1805, 5)), (0x2159, M(1810, 5)), (0x215A, M(1815, 5)),
+    (0x215B, M(1820, 5)), (0x215C, M(1825, 5)), (0x215D, M(1830, 5)), (0x215E, M(1835, 5)),
+    (0x215F, M(18, 4)), (0x2160, M(81, 1)), (0x2161, M(1840, 2)), (0x2162, M(1842, 3)),
+    (0x2163, M(1845, 2)), (0x2164, M(990, 1)), (0x2165, M(1847, 2)), (0x2166, M(1849, 3)),
+    (0x2167, M(1852, 4)), (0x2168, M(1856, 2)), (0x2169, M(396, 1)), (0x216A, M(1858, 2)),
+    (0x216B, M(1860, 3)), (0x216C, M(96, 1)), (0x216D, R(0x63)), (0x216F, M(986, 1)),
+    (0x2170, M(81, 1)), (0x2171, M(1840, 2)), (0x2172, M(1842, 3)), (0x2173, M(1845, 2)),
+    (0x2174, M(990, 1)), (0x2175, M(1847, 2)), (0x2176, M(1849, 3)), (0x2177, M(1852, 4)),
+    (0x2178, M(1856, 2)), (0x2179, M(396, 1)), (0x217A, M(1858, 2)), (0x217B, M(1860, 3)),
+    (0x217C, M(96, 1)), (0x217D, R(0x63)), (0x217F, M(986, 1)), (0x2180, V),
+    (0x2183, D), (0x2184, V), (0x2189, M(1863, 5)), (0x218A, V),
+    (0x218C, D), (0x2190, V), (0x222C, M(1868, 6)), (0x222D, M(1874, 9)),
+    (0x222E, V), (0x222F, M(1883, 6)), (0x2230, M(1889, 9)), (0x2231, V),
+    (0x2260, S), (0x2261, V), (0x226E, S), (0x2270, V),
+    (0x2329, R(0x3008)), (0x232B, V), (0x2427, D), (0x2440, V),
+    (0x244B, D), (0x2460, R(0x31)), (0x2469, M(1778, 2)), (0x246A, M(1898, 2)),
+    (0x246B, M(1900, 2)), (0x246C, M(1902, 2)), (0x246D, M(1904, 2)), (0x246E, M(1906, 2)),
+    (0x246F, M(1908, 2)), (0x2470, M(1910, 2)), (0x2471, M(1912, 2)), (0x2472, M(1914, 2)),
+    (0x2473, M(1916, 2)), (0x2474, SM(1918, 3)), (0x2475, SM(1921, 3)), (0x2476, SM(1924, 3)),
+    (0x2477, SM(1927, 3)), (0x2478, SM(1930, 3)), (0x2479, SM(1933, 3)), (0x247A, SM(1936, 3)),
+    (0x247B, SM(1939, 3)), (0x247C, SM(1942, 3)), (0x247D, SM(1945, 4)), (0x247E, SM(1949, 4)),
+    (0x247F, SM(1953, 4)), (0x2480, SM(1957, 4)), (0x2481, SM(1961, 4)), (0x2482, SM(1965, 4)),
+    (0x2483, SM(1969, 4)), (0x2484, SM(1973, 4)), (0x2485, SM(1977, 4)), (0x2486, SM(1981, 4)),
+    (0x2487, SM(1985, 4)), (0x2488, D), (0x249C, SM(1989, 3)), (0x249D, SM(1992, 3)),
+    (0x249E, SM(1995, 3)), (0x249F, SM(1998, 3)), (0x24A0, SM(2001, 3)), (0x24A1, SM(2004, 3)),
+    (0x24A2, SM(2007, 3)), (0x24A3, SM(2010, 3)), (0x24A4, SM(2013, 3)), (0x24A5, SM(2016, 3)),
+    (0x24A6, SM(2019, 3)), (0x24A7, SM(2022, 3)), (0x24A8, SM(2025, 3)), (0x24A9, SM(2028, 3)),
+    (0x24AA, SM(2031, 3)), (0x24AB, SM(2034, 3)), (0x24AC, SM(2037, 3)), (0x24AD, SM(2040, 3)),
+    (0x24AE, SM(2043, 3)), (0x24AF, SM(2046, 3)), (0x24B0, SM(2049, 3)), (0x24B1, SM(2052, 3)),
+    (0x24B2, SM(2055, 3)), (0x24B3, SM(2058, 3)), (0x24B4, SM(2061, 3)), (0x24B5, SM(2064, 3)),
+    (0x24B6, R(0x61)), (0x24D0, R(0x61)), (0x24EA, M(1716, 1)), (0x24EB, V),
+    (0x2A0C, M(1868, 12)), (0x2A0D, V), (0x2A74, SM(2067, 3)), (0x2A75, SM(2070, 2)),
+    (0x2A76, SM(2069, 3)), (0x2A77, V), (0x2ADC, M(2072, 5)), (0x2ADD, V),
+    (0x2B74, D), (0x2B76, V), (0x2B96, D), (0x2B97, V),
+    (0x2C00, R(0x2C30)), (0x2C2F, D), (0x2C30, V), (0x2C5F, D),
+    (0x2C60, M(2077, 3)), (0x2C61, V), (0x2C62, M(2080, 2)), (0x2C63, M(2082, 3)),
+    (0x2C64, M(2085, 2)), (0x2C65, V), (0x2C67, M(2087, 3)), (0x2C68, V),
+    (0x2C69, M(2090, 3)), (0x2C6A, V), (0x2C6B, M(2093, 3)), (0x2C6C, V),
+    (0x2C6D, M(2096, 2)), (0x2C6E, M(1024, 2)), (0x2C6F, M(2098, 2)), (0x2C70, M(996, 2)),
+    (0x2C71, V), (0x2C72, M(2100, 3)), (0x2C73, V), (0x2C75, M(2103, 3)),
+    (0x2C76, V), (0x2C7C, M(85, 1)), (0x2C7D, M(990, 1)), (0x2C7E, R(0x23F)),
+    (0x2C80, M(2106, 3)), (0x2C81, V), (0x2C82, M(2109, 3)), (0x2C83, V),
+    (0x2C84, M(2112, 3)), (0x2C85, V), (0x2C86, M(2115, 3)), (0x2C87, V),
+    (0x2C88, M(2118, 3)), (0x2C89, V), (0x2C8A, M(2121, 3)), (0x2C8B, V),
+    (0x2C8C, M(2124, 3)), (0x2C8D, V), (0x2C8E, M(2127, 3)), (0x2C8F, V),
+    (0x2C90, M(2130, 3)), (0x2C91, V), (0x2C92, M(2133, 3)), (0x2C93, V),
+    (0x2C94, M(2136, 3)), (0x2C95, V), (0x2C96, M(2139, 3)), (0x2C97, V),
+    (0x2C98, M(2142, 3)), (0x2C99, V), (0x2C9A, M(2145, 3)), (0x2C9B, V),
+    (0x2C9C, M(2148, 3)), (0x2C9D, V), (0x2C9E, M(2151, 3)), (0x2C9F, V),
+    (0x2CA0, M(2154, 3)), (0x2CA1, V), (0x2CA2, M(2157, 3)), (0x2CA3, V),
+    (0x2CA4, M(2160, 3)), (0x2CA5, V), (0x2CA6, M(2163, 3)), (0x2CA7, V),
+    (0x2CA8, M(2166, 3)), (0x2CA9, V), (0x2CAA, M(2169, 3)), (0x2CAB, V),
+    (0x2CAC, M(2172, 3)), (0x2CAD, V), (0x2CAE, M(2175, 3)), (0x2CAF, V),
+    (0x2CB0, M(2178, 3)), (0x2CB1, V), (0x2CB2, M(2181, 3)), (0x2CB3, V),
+    (0x2CB4, M(2184, 3)), (0x2CB5, V), (0x2CB6, M(2187, 3)), (0x2CB7, V),
+    (0x2CB8, M(2190, 3)), (0x2CB9, V), (0x2CBA, M(2193, 3)), (0x2CBB, V),
+    (0x2CBC, M(2196, 3)), (0x2CBD, V), (0x2CBE, M(2199, 3)), (0x2CBF, V),
+    (0x2CC0, M(2202, 3)), (0x2CC1, V), (0x2CC2, M(2205, 3)), (0x2CC3, V),
+    (0x2CC4, M(2208, 3)), (0x2CC5, V), (0x2CC6, M(2211, 3)), (0x2CC7, V),
+    (0x2CC8, M(2214, 3)), (0x2CC9, V), (0x2CCA, M(2217, 3)), (0x2CCB, V),
+    (0x2CCC, M(2220, 3)), (0x2CCD, V), (0x2CCE, M(2223, 3)), (0x2CCF, V),
+    (0x2CD0, M(2226, 3)), (0x2CD1, V), (0x2CD2, M(2229, 3)), (0x2CD3, V),
+    (0x2CD4, M(2232, 3)), (0x2CD5, V), (0x2CD6, M(2235, 3)), (0x2CD7, V),
+    (0x2CD8, M(2238, 3)), (0x2CD9, V), (0x2CDA, M(2241, 3)), (0x2CDB, V),
+    (0x2CDC, M(2244, 3)), (0x2CDD, V), (0x2CDE, M(2247, 3)), (0x2CDF, V),
+    (0x2CE0, M(2250, 3)), (0x2CE1, V), (0x2CE2, M(2253, 3)), (0x2CE3, V),
+    (0x2CEB, M(2256, 3)), (0x2CEC, V), (0x2CED, M(2259, 3)), (0x2CEE, V),
+    (0x2CF2, M(2262, 3)), (0x2CF3, V), (0x2CF4, D), (0x2CF9, V),
+    (0x2D26, D), (0x2D27, V), (0x2D28, D), (0x2D2D, V),
+    (0x2D2E, D), (0x2D30, V), (0x2D68, D), (0x2D6F, M(2265, 3)),
+    (0x2D70, V), (0x2D71, D), (0x2D7F, V), (0x2D97, D),
+    (0x2DA0, V), (0x2DA7, D), (0x2DA8, V), (0x2DAF, D),
+    (0x2DB0, V), (0x2DB7, D), (0x2DB8, V), (0x2DBF, D),
+    (0x2DC0, V), (0x2DC7, D), (0x2DC8, V), (0x2DCF, D),
+    (0x2DD0, V), (0x2DD7, D), (0x2DD8, V), (0x2DDF, D),
+    (0x2DE0, V), (0x2E53, D), (0x2E80, V), (0x2E9A, D),
+    (0x2E9B, V), (0x2E9F, M(2268, 3)), (0x2EA0, V), (0x2EF3, M(2271, 3)),
+    (0x2EF4, D), (0x2F00, M(2274, 3)), (0x2F01, M(2277, 3)), (0x2F02, M(2280, 3)),
+    (0x2F03, M(2283, 3)), (0x2F04, M(2286, 3)), (0x2F05, M(2289, 3)), (0x2F06, M(2292, 3)),
+    (0x2F07, M(2295, 3)), (0x2F08, M(2298, 3)), (0x2F09, M(2301, 3)), (0x2F0A, M(2304, 3)),
+    (0x2F0B, M(2307, 3)), (0x2F0C, M(2310, 3)), (0x2F0D, M(2313, 3)), (0x2F0E, M(2316, 3)),
+    (0x2F0F, M(2319, 3)), (0x2F10, M(2322, 3)), (0x2F11, M(2325, 3)), (0x2F12, M(2328, 3)),
+    (0x2F13, M(2331, 3)), (0x2F14, M(2334, 3)), (0x2F15, M(2337, 3)), (0x2F16, M(2340, 3)),
+    (0x2F17, M(2343, 3)), (0x2F18, M(2346, 3)), (0x2F19, M(2349, 3)), (0x2F1A, M(2352, 3)),
+    (0x2F1B, M(2355, 3)), (0x2F1C, M(2358, 3)), (0x2F1D, M(2361, 3)), (0x2F1E, M(2364, 3)),
+    (0x2F1F, M(2367, 3)), (0x2F20, M(2370, 3)), (0x2F21, M(2373, 3)), (0x2F22, M(2376, 3)),
+    (0x2F23, M(2379, 3)), (0x2F24, M(2382, 3)), (0x2F25, M(2385, 3)), (0x2F26, M(2388, 3)),
+    (0x2F27, M(2391, 3)), (0x2F28, M(2394, 3)), (0x2F29, M(2397, 3)), (0x2F2A, M(2400, 3)),
+    (0x2F2B, M(2403, 3)), (0x2F2C, M(2406, 3)), (0x2F2D, M(2409, 3)), (0x2F2E, M(2412, 3)),
+    (0x2F2F, M(2415, 3)), (0x2F30, M(2418, 3)), (0x2F31, M(2421, 3)), (0x2F32, M(2424, 3)),
+    (0x2F33, M(2427, 3)), (0x2F34, M(2430, 3)), (0x2F35, M(2433, 3)), (0x2F36, M(2436, 3)),
+    (0x2F37, M(2439, 3)), (0x2F38, M(2442, 3)), (0x2F39, M(2445, 3)), (0x2F3A, M(2448, 3)),
+    (0x2F3B, M(2451, 3)), (0x2F3C, M(2454, 3)), (0x2F3D, M(2457, 3)), (0x2F3E, M(2460, 3)),
+    (0x2F3F, M(2463, 3)), (0x2F40, M(2466, 3)), (0x2F41, M(2469, 3)), (0x2F42, M(2472, 3)),
+    (0x2F43, M(2475, 3)), (0x2F44, M(2478, 3)), (0x2F45, M(2481, 3)), (0x2F46, M(2484, 3)),
+    (0x2F47, M(2487, 3)), (0x2F48, M(2490, 3)), (0x2F49, M(2493, 3)), (0x2F4A, M(2496, 3)),
+    (0x2F4B, M(2499, 3)), (0x2F4C, M(2502, 3)), (0x2F4D, M(2505, 3)), (0x2F4E, M(2508, 3)),
+    (0x2F4F, M(2511, 3)), (0x2F50, M(2514, 3)), (0x2F51, M(2517, 3)), (0x2F52, M(2520, 3)),
+    (0x2F53, M(2523, 3)), (0x2F54, M(2526, 3)), (0x2F55, M(2529, 3)), (0x2F56, M(2532, 3)),
+    (0x2F57, M(2535, 3)), (0x2F58, M(2538, 3)), (0x2F59, M(2541, 3)), (0x2F5A, M(2544, 3)),
+    (0x2F5B, M(2547, 3)), (0x2F5C, M(2550, 3)), (0x2F5D, M(2553, 3)), (0x2F5E, M(2556, 3)),
+    (0x2F5F, M(2559, 3)), (0x2F60, M(2562, 3)), (0x2F61, M(2565, 3)), (0x2F62, M(2568, 3)),
+    (0x2F63, M(2571, 3)), (0x2F64, M(2574, 3)), (0x2F65, M(2577, 3)), (0x2F66, M(2580, 3)),
+    (0x2F67, M(2583, 3)), (0x2F68, M(2586, 3)), (0x2F69, M(2589, 3)), (0x2F6A, M(2592, 3)),
+    (0x2F6B, M(2595, 3)), (0x2F6C, M(2598, 3)), (0x2F6D, M(2601, 3)), (0x2F6E, M(2604, 3)),
+    (0x2F6F, M(2607, 3)), (0x2F70, M(2610, 3)), (0x2F71, M(2613, 3)), (0x2F72, M(2616, 3)),
+    (0x2F73, M(2619, 3)), (0x2F74, M(2622, 3)), (0x2F75, M(2625, 3)), (0x2F76, M(2628, 3)),
+    (0x2F77, M(2631, 3)), (0x2F78, M(2634, 3)), (0x2F79, M(2637, 3)), (0x2F7A, M(2640, 3)),
+    (0x2F7B, M(2643, 3)), (0x2F7C, M(2646, 3)), (0x2F7D, M(2649, 3)), (0x2F7E, M(2652, 3)),
+    (0x2F7F, M(2655, 3)), (0x2F80, M(2658, 3)), (0x2F81, M(2661, 3)), (0x2F82, M(2664, 3)),
+    (0x2F83, M(2667, 3)), (0x2F84, M(2670, 3)), (0x2F85, M(2673, 3)), (0x2F86, M(2676, 3)),
+    (0x2F87, M(2679, 3)), (0x2F88, M(2682, 3)), (0x2F89, M(2685, 3)), (0x2F8A, M(2688, 3)),
+    (0x2F8B, M(2691, 3)), (0x2F8C, M(2694, 3)), (0x2F8D, M(2697, 3)), (0x2F8E, M(2700, 3)),
+    (0x2F8F, M(2703, 3)), (0x2F90, M(2706, 3)), (0x2F91, M(2709, 3)), (0x2F92, M(2712, 3)),
+    (0x2F93, M(2715, 3)), (0x2F94, M(2718, 3)), (0x2F95, M(2721, 3)), (0x2F96, M(2724, 3)),
+    (0x2F97, M(2727, 3)), (0x2F98, M(2730, 3)), (0x2F99, M(2733, 3)), (0x2F9A, M(2736, 3)),
+    (0x2F9B, M(2739, 3)), (0x2F9C, M(2742, 3)), (0x2F9D, M(2745, 3)), (0x2F9E, M(2748, 3)),
+    (0x2F9F, M(2751, 3)), (0x2FA0, M(2754, 3)), (0x2FA1, M(2757, 3)), (0x2FA2, M(2760, 3)),
+    (0x2FA3, M(2763, 3)), (0x2FA4, M(2766, 3)), (0x2FA5, M(2769, 3)), (0x2FA6, M(2772, 3)),
+    (0x2FA7, M(2775, 3)), (0x2FA8, M(2778, 3)), (0x2FA9, M(2781, 3)), (0x2FAA, M(2784, 3)),
+    (0x2FAB, M(2787, 3)), (0x2FAC, M(2790, 3)), (0x2FAD, M(2793, 3)), (0x2FAE, M(2796, 3)),
+    (0x2FAF, M(2799, 3)), (0x2FB0, M(2802, 3)), (0x2FB1, M(2805, 3)), (0x2FB2, M(2808, 3)),
+    (0x2FB3, M(2811, 3)), (0x2FB4, M(2814, 3)), (0x2FB5, M(2817, 3)), (0x2FB6, M(2820, 3)),
+    (0x2FB7, M(2823, 3)), (0x2FB8, M(2826, 3)), (0x2FB9, M(2829, 3)), (0x2FBA, M(2832, 3)),
+    (0x2FBB, M(2835, 3)), (0x2FBC, M(2838, 3)), (0x2FBD, M(2841, 3)), (0x2FBE, M(2844, 3)),
+    (0x2FBF, M(2847, 3)), (0x2FC0, M(2850, 3)), (0x2FC1, M(2853, 3)), (0x2FC2, M(2856, 3)),
+    (0x2FC3, M(2859, 3)), (0x2FC4, M(2862, 3)), (0x2FC5, M(2865, 3)), (0x2FC6, M(2868, 3)),
+    (0x2FC7, M(2871, 3)), (0x2FC8, M(2874, 3)), (0x2FC9, M(2877, 3)), (0x2FCA, M(2880, 3)),
+    (0x2FCB, M(2883, 3)), (0x2FCC, M(2886, 3)), (0x2FCD, M(2889, 3)), (0x2FCE, M(2892, 3)),
+    (0x2FCF, M(2895, 3)), (0x2FD0, M(2898, 3)), (0x2FD1, M(2901, 3)), (0x2FD2, M(2904, 3)),
+    (0x2FD3, M(2907, 3)), (0x2FD4, M(2910, 3)), (0x2FD5, M(2913, 3)), (0x2FD6, D),
+    (0x3000, SM(0, 1)), (0x3001, V), (0x3002, M(2916, 1)), (0x3003, V),
+    (0x3036, M(2917, 3)), (0x3037, V), (0x3038, M(2343, 3)), (0x3039, R(0x5344)),
+    (0x303B, V), (0x3040, D), (0x3041, V), (0x3097, D),
+    (0x3099, V), (0x309B, SM(2920, 4)), (0x309C, SM(2924, 4)), (0x309D, V),
+    (0x309F, M(2928, 6)), (0x30A0, V), (0x30FF, M(2934, 6)), (0x3100, D),
+    (0x3105, V), (0x3130, D), (0x3131, R(0x1100)), (0x3133, M(2940, 3)),
+    (0x3134, M(2943, 3)), (0x3135, R(0x11AC)), (0x3137, R(0x1103)), (0x313A, R(0x11B0)),
+    (0x3140, M(2946, 3)), (0x3141, R(0x1106)), (0x3144, M(2949, 3)), (0x3145, R(0x1109)),
+    (0x314F, R(0x1161)), (0x3164, D), (0x3165, R(0x1114)), (0x3167, R(0x11C7)),
+    (0x3169, M(2952, 3)), (0x316A, M(2955, 3)), (0x316B, M(2958, 3)), (0x316C, M(2961, 3)),
+    (0x316D, M(2964, 3)), (0x316E, M(2967, 3)), (0x316F, M(2970, 3)), (0x3170, M(2973, 3)),
+    (0x3171, R(0x111D)), (0x3173, M(2976, 3)), (0x3174, R(0x1122)), (0x3176, M(2979, 3)),
+    (0x3177, M(2982, 3)), (0x3178, R(0x112B)), (0x317D, M(2985, 3)), (0x317E, M(2988, 3)),
+    (0x317F, M(2991, 3)), (0x3180, M(2994, 3)), (0x3181, M(2997, 3)), (0x3182, R(0x11F1)),
+    (0x3184, R(0x1157)), (0x3187, R(0x1184)), (0x3189, M(3000, 3)), (0x318A, R(0x1191)),
+    (0x318C, M(3003, 3)), (0x318D, M(3006, 3)), (0x318E, M(3009, 3)), (0x318F, D),
+    (0x3190, V), (0x3192, M(2274, 3)), (0x3193, M(2292, 3)), (0x3194, M(3012, 3)),
+    (0x3195, M(3015, 3)), (0x3196, M(3018, 3)), (0x3197, M(3021, 3)), (0x3198, M(3024, 3)),
+    (0x3199, M(3027, 3)), (0x319A, M(2286, 3)), (0x319B, M(3030, 3)), (0x319C, M(3033, 3)),
+    (0x319D, M(3036, 3)), (0x319E, M(3039, 3)), (0x319F, M(2298, 3)), (0x31A0, V),
+    (0x31E4, D), (0x31F0, V), (0x3200, SM(3042, 5)), (0x3201, SM(3047, 5)),
+    (0x3202, SM(3052, 5)), (0x3203, SM(3057, 5)), (0x3204, SM(3062, 5)), (0x3205, SM(3067, 5)),
+    (0x3206, SM(3072, 5)), (0x3207, SM(3077, 5)), (0x3208, SM(3082, 5)), (0x3209, SM(3087, 5)),
+    (0x320A, SM(3092, 5)), (0x320B, SM(3097, 5)), (0x320C, SM(3102, 5)), (0x320D, SM(3107, 5)),
+    (0x320E, SM(3112, 5)), (0x320F, SM(3117, 5)), (0x3210, SM(3122, 5)), (0x3211, SM(3127, 5)),
+    (0x3212, SM(3132, 5)), (0x3213, SM(3137, 5)), (0x3214, SM(3142, 5)), (0x3215, SM(3147, 5)),
+    (0x3216, SM(3152, 5)), (0x3217, SM(3157, 5)), (0x3218, SM(3162, 5)), (0x3219, SM(3167, 5)),
+    (0x321A, SM(3172, 5)), (0x321B, SM(3177, 5)), (0x321C, SM(3182, 5)), (0x321D, SM(3187, 8)),
+    (0x321E, SM(3195, 8)), (0x321F, D), (0x3220, SM(3203, 5)), (0x3221, SM(3208, 5)),
+    (0x3222, SM(3213, 5)), (0x3223, SM(3218, 5)), (0x3224, SM(3223, 5)), (0x3225, SM(3228, 5)),
+    (0x3226, SM(3233, 5)), (0x3227, SM(3238, 5)), (0x3228, SM(3243, 5)), (0x3229, SM(3248, 5)),
+    (0x322A, SM(3253, 5)), (0x322B, SM(3258, 5)), (0x322C, SM(3263, 5)), (0x322D, SM(3268, 5)),
+    (0x322E, SM(3273, 5)), (0x322F, SM(3278, 5)), (0x3230, SM(3283, 5)), (0x3231, SM(3288, 5)),
+    (0x3232, SM(3293, 5)), (0x3233, SM(3298, 5)), (0x3234, SM(3303, 5)), (0x3235, SM(3308, 5)),
+    (0x3236, SM(3313, 5)), (0x3237, SM(3318, 5)), (0x3238, SM(3323, 5)), (0x3239, SM(3328, 5)),
+    (0x323A, SM(3333, 5)), (0x323B, SM(3338, 5)), (0x323C, SM(3343, 5)), (0x323D, SM(3348, 5)),
+    (0x323E, SM(3353, 5)), (0x323F, SM(3358, 5)), (0x3240, SM(3363, 5)), (0x3241, SM(3368, 5)),
+    (0x3242, SM(3373, 5)), (0x3243, SM(3378, 5)), (0x3244, M(3383, 3)), (0x3245, M(3386, 3)),
+    (0x3246, M(2472, 3)), (0x3247, M(3389, 3)), (0x3248, V), (0x3250, M(3392, 3)),
+    (0x3251, M(1901, 2)), (0x3252, M(3395, 2)), (0x3253, M(27, 2)), (0x3254, M(3397, 2)),
+    (0x3255, M(3399, 2)), (0x3256, M(3401, 2)), (0x3257, M(3403, 2)), (0x3258, M(3405, 2)),
+    (0x3259, M(3407, 2)), (0x325A, M(3409, 2)), (0x325B, M(1789, 2)), (0x325C, M(1784, 2)),
+    (0x325D, M(3411, 2)), (0x325E, M(3413, 2)), (0x325F, M(3415, 2)), (0x3260, M(3043, 3)),
+    (0x3261, R(0x1102)), (0x3263, R(0x1105)), (0x3266, M(3073, 3)), (0x3267, R(0x110B)),
+    (0x3269, R(0x110E)), (0x326E, M(3113, 3)), (0x326F, M(3118, 3)), (0x3270, M(3123, 3)),
+    (0x3271, M(3128, 3)), (0x3272, M(3133, 3)), (0x3273, M(3138, 3)), (0x3274, M(3143, 3)),
+    (0x3275, M(3148, 3)), (0x3276, M(3153, 3)), (0x3277, M(3158, 3)), (0x3278, M(3163, 3)),
+    (0x3279, M(3168, 3)), (0x327A, M(3173, 3)), (0x327B, M(3178, 3)), (0x327C, M(3417, 6)),
+    (0x327D, M(3423, 6)), (0x327E, M(3429, 3)), (0x327F, V), (0x3280, M(2274, 3)),
+    (0x3281, M(2292, 3)), (0x3282, M(3012, 3)), (0x3283, M(3015, 3)), (0x3284, M(3224, 3)),
+    (0x3285, M(3229, 3)), (0x3286, M(3234, 3)), (0x3287, M(2307, 3)), (0x3288, M(3244, 3)),
+    (0x3289, M(2343, 3)), (0x328A, M(2493, 3)), (0x328B, M(2529, 3)), (0x328C, M(2526, 3)),
+    (0x328D, M(2496, 3)), (0x328E, M(2772, 3)), (0x328F, M(2367, 3)), (0x3290, M(2487, 3)),
+    (0x3291, M(3289, 3)), (0x3292, M(3294, 3)), (0x3293, M(3299, 3)), (0x3294, M(3304, 3)),
+    (0x3295, M(3309, 3)), (0x3296, M(3314, 3)), (0x3297, M(3319, 3)), (0x3298, M(3324, 3)),
+    (0x3299, M(3432, 3)), (0x329A, M(3435, 3)), (0x329B, M(2385, 3)), (0x329C, M(3438, 3)),
+    (0x329D, M(3441, 3)), (0x329E, M(3444, 3)), (0x329F, M(3447, 3)), (0x32A0, M(3450, 3)),
+    (0x32A1, M(3369, 3)), (0x32A2, M(3453, 3)), (0x32A3, M(3456, 3)), (0x32A4, M(3018, 3)),
+    (0x32A5, M(3021, 3)), (0x32A6, M(3024, 3)), (0x32A7, M(3459, 3)), (0x32A8, M(3462, 3)),
+    (0x32A9, M(3465, 3)), (0x32AA, M(3468, 3)), (0x32AB, M(3339, 3)), (0x32AC, M(3344, 3)),
+    (0x32AD, M(3349, 3)), (0x32AE, M(3354, 3)), (0x32AF, M(3359, 3)), (0x32B0, M(3471, 3)),
+    (0x32B1, M(3474, 2)), (0x32B2, M(3476, 2)), (0x32B3, M(3478, 2)), (0x32B4, M(3480, 2)),
+    (0x32B5, M(3482, 2)), (0x32B6, M(22, 2)), (0x32B7, M(3398, 2)), (0x32B8, M(3414, 2)),
+    (0x32B9, M(3484, 2)), (0x32BA, M(3486, 2)), (0x32BB, M(3488, 2)), (0x32BC, M(3490, 2)),
+    (0x32BD, M(3492, 2)), (0x32BE, M(3494, 2)), (0x32BF, M(3496, 2)), (0x32C0, M(3498, 4)),
+    (0x32C1, M(3502, 4)), (0x32C2, M(3506, 4)), (0x32C3, M(3510, 4)), (0x32C4, M(3514, 4)),
+    (0x32C5, M(3518, 4)), (0x32C6, M(3522, 4)), (0x32C7, M(3526, 4)), (0x32C8, M(3530, 4)),
+    (0x32C9, M(3534, 5)), (0x32CA, M(3539, 5)), (0x32CB, M(3544, 5)), (0x32CC, M(3549, 2)),
+    (0x32CD, M(3551, 3)), (0x32CE, M(3554, 2)), (0x32CF, M(3556, 3)), (0x32D0, M(3559, 3)),
+    (0x32D1, M(3562, 3)), (0x32D2, M(3565, 3)), (0x32D3, M(3568, 3)), (0x32D4, R(0x30AA)),
+    (0x32D6, M(3571, 3)), (0x32D7, M(3574, 3)), (0x32D8, M(3577, 3)), (0x32D9, M(2934, 3)),
+    (0x32DA, M(3580, 3)), (0x32DB, M(3583, 3)), (0x32DC, M(3586, 3)), (0x32DD, M(3589, 3)),
+    (0x32DE, M(3592, 3)), (0x32DF, M(3595, 3)), (0x32E0, M(3598, 3)), (0x32E1, M(3601, 3)),
+    (0x32E2, M(3604, 3)), (0x32E3, M(2937, 3)), (0x32E4, R(0x30CA)), (0x32EA, M(3607, 3)),
+    (0x32EB, M(3610, 3)), (0x32EC, M(3613, 3)), (0x32ED, M(3616, 3)), (0x32EE, R(0x30DE)),
+    (0x32F3, M(3619, 3)), (0x32F4, M(3622, 3)), (0x32F5, R(0x30E8)), (0x32FB, R(0x30EF)),
+    (0x32FF, M(3625, 6)), (0x3300, M(3631, 12)), (0x3301, M(3643, 12)), (0x3302, M(3655, 12)),
+    (0x3303, M(3667, 9)), (0x3304, M(3676, 12)), (0x3305, M(3688, 9)), (0x3306, M(3697, 9)),
+    (0x3307, M(3706, 15)), (0x3308, M(3721, 12)), (0x3309, M(3733, 9)), (0x330A, M(3742, 9)),
+    (0x330B, M(3751, 9)), (0x330C, M(3760, 12)), (0x330D, M(3772, 12)), (0x330E, M(3784, 9)),
+    (0x330F, M(3793, 9)), (0x3310, M(3802, 6)), (0x3311, M(3808, 9)), (0x3312, M(3817, 12)),
+    (0x3313, M(3829, 12)), (0x3314, M(3841, 6)), (0x3315, M(3847, 15)), (0x3316, M(3862, 18)),
+    (0x3317, M(3880, 15)), (0x3318, M(3853, 9)), (0x3319, M(3895, 15)), (0x331A, M(3910, 15)),
+    (0x331B, M(3925, 12)), (0x331C, M(3937, 9)), (0x331D, M(3946, 9)), (0x331E, M(3955, 9)),
+    (0x331F, M(3964, 12)), (0x3320, M(3976, 15)), (0x3321, M(3991, 12)), (0x3322, M(4003, 9)),
+    (0x3323, M(4012, 9)), (0x3324, M(4021, 9)), (0x3325, M(4030, 6)), (0x3326, M(4036, 6)),
+    (0x3327, M(3904, 6)), (0x3328, M(4042, 6)), (0x3329, M(4048, 9)), (0x332A, M(4057, 9)),
+    (0x332B, M(4066, 15)), (0x332C, M(4081, 9)), (0x332D, M(4090, 12)), (0x332E, M(4102, 15)),
+    (0x332F, M(4117, 9)), (0x3330, M(4126, 6)), (0x3331, M(4132, 6)), (0x3332, M(4138, 15)),
+    (0x3333, M(4153, 12)), (0x3334, M(4165, 15)), (0x3335, M(4180, 9)), (0x3336, M(4189, 15)),
+    (0x3337, M(4204, 6)), (0x3338, M(4210, 9)), (0x3339, M(4219, 9)), (0x333A, M(4228, 9)),
+    (0x333B, M(4237, 9)), (0x333C, M(4246, 9)), (0x333D, M(4255, 12)), (0x333E, M(4267, 9)),
+    (0x333F, M(4276, 6)), (0x3340, M(4282, 9)), (0x3341, M(4291, 9)), (0x3342, M(4300, 9)),
+    (0x3343, M(4309, 12)), (0x3344, M(4321, 9)), (0x3345, M(4330, 9)), (0x3346, M(4339, 9)),
+    (0x3347, M(4348, 15)), (0x3348, M(4363, 12)), (0x3349, M(4375, 6)), (0x334A, M(4381, 15)),
+    (0x334B, M(4396, 6)), (0x334C, M(4402, 12)), (0x334D, M(3868, 12)), (0x334E, M(4414, 9)),
+    (0x334F, M(4423, 9)), (0x3350, M(4432, 9)), (0x3351, M(4441, 12)), (0x3352, M(4453, 6)),
+    (0x3353, M(4459, 9)), (0x3354, M(4468, 12)), (0x3355, M(4480, 6)), (0x3356, M(4486, 15)),
+    (0x3357, M(3886, 9)), (0x3358, M(4501, 4)), (0x3359, M(4505, 4)), (0x335A, M(4509, 4)),
+    (0x335B, M(4513, 4)), (0x335C, M(4517, 4)), (0x335D, M(4521, 4)), (0x335E, M(4525, 4)),
+    (0x335F, M(4529, 4)), (0x3360, M(4533, 4)), (0x3361, M(4537, 4)), (0x3362, M(4541, 5)),
+    (0x3363, M(4546, 5)), (0x3364, M(4551, 5)), (0x3365, M(4556, 5)), (0x3366, M(4561, 5)),
+    (0x3367, M(4566, 5)), (0x3368, M(4571, 5)), (0x3369, M(4576, 5)), (0x336A, M(4581, 5)),
+    (0x336B, M(4586, 5)), (0x336C, M(4591, 5)), (0x336D, M(4596, 5)), (0x336E, M(4601, 5)),
+    (0x336F, M(4606, 5)), (0x3370, M(4611, 5)), (0x3371, M(4616, 3)), (0x3372, M(4619, 2)),
+    (0x3373, M(4621, 2)), (0x3374, M(4623, 3)), (0x3375, M(4626, 2)), (0x3376, M(4628, 2)),
+    (0x3377, M(4630, 2)), (0x3378, M(4632, 3)), (0x3379, M(4635, 3)), (0x337A, M(4638, 2)),
+    (0x337B, M(4640, 6)), (0x337C, M(4646, 6)), (0x337D, M(4652, 6)), (0x337E, M(4658, 6)),
+    (0x337F, M(4664, 12)), (0x3380, M(4617, 2)), (0x3381, M(4676, 2)), (0x3382, M(4678, 3)),
+    (0x3383, M(4681, 2)), (0x3384, M(4683, 2)), (0x3385, M(4685, 2)), (0x3386, M(4687, 2)),
+    (0x3387, M(4689, 2)), (0x3388, M(4691, 3)), (0x3389, M(4694, 4)), (0x338A, M(4698, 2)),
+    (0x338B, M(4700, 2)), (0x338C, M(4702, 3)), (0x338D, M(4705, 3)), (0x338E, M(4708, 2)),
+    (0x338F, M(4710, 2)), (0x3390, M(4712, 2)), (0x3391, M(4714, 3)), (0x3392, M(4717, 3)),
+    (0x3393, M(4711, 3)), (0x3394, M(4720, 3)), (0x3395, M(4723, 3)), (0x3396, M(4726, 2)),
+    (0x3397, M(4728, 2)), (0x3398, M(4730, 2)), (0x3399, M(4732, 2)), (0x339A, M(4734, 2)),
+    (0x339B, M(4736, 3)), (0x339C, M(4739, 2)), (0x339D, M(4741, 2)), (0x339E, M(985, 2)),
+    (0x339F, M(4743, 3)), (0x33A0, M(4746, 3)), (0x33A1, M(4633, 2)), (0x33A2, M(4749, 3)),
+    (0x33A3, M(4752, 3)), (0x33A4, M(4755, 3)), (0x33A5, M(4636, 2)), (0x33A6, M(4758, 3)),
+    (0x33A7, M(4761, 5)), (0x33A8, M(4766, 6)), (0x33A9, M(4617, 2)), (0x33AA, M(4772, 3)),
+    (0x33AB, M(4775, 3)), (0x33AC, M(4778, 3)), (0x33AD, M(4781, 3)), (0x33AE, M(4784, 7)),
+    (0x33AF, M(4791, 8)), (0x33B0, M(4799, 2)), (0x33B1, M(4801, 2)), (0x33B2, M(4803, 3)),
+    (0x33B3, M(4806, 2)), (0x33B4, M(4808, 2)), (0x33B5, M(4810, 2)), (0x33B6, M(4812, 3)),
+    (0x33B7, M(4815, 2)), (0x33B8, M(4817, 2)), (0x33B9, M(4815, 2)), (0x33BA, M(4819, 2)),
+    (0x33BB, M(4821, 2)), (0x33BC, M(4823, 3)), (0x33BD, M(4826, 2)), (0x33BE, M(4828, 2)),
+    (0x33BF, M(4826, 2)), (0x33C0, M(4830, 3)), (0x33C1, M(4833, 3)), (0x33C2, D),
+    (0x33C3, M(4836, 2)), (0x33C4, M(1735, 2)), (0x33C5, M(4629, 2)), (0x33C6, M(4838, 6)),
+    (0x33C7, D), (0x33C8, M(4844, 2)), (0x33C9, M(4846, 2)), (0x33CA, M(4848, 2)),
+    (0x33CB, M(4616, 2)), (0x33CC, M(4850, 2)), (0x33CD, M(4852, 2)), (0x33CE, M(985, 2)),
+    (0x33CF, M(4854, 2)), (0x33D0, M(4725, 2)), (0x33D1, M(4856, 2)), (0x33D2, M(4858, 3)),
+    (0x33D3, M(4861, 2)), (0x33D4, M(4687, 2)), (0x33D5, M(4863, 3)), (0x33D6, M(4866, 3)),
+    (0x33D7, M(4869, 2)), (0x33D8, D), (0x33D9, M(4871, 3)), (0x33DA, M(4874, 2)),
+    (0x33DB, M(4790, 2)), (0x33DC, M(4876, 2)), (0x33DD, M(4878, 2)), (0x33DE, M(4880, 5)),
+    (0x33DF, M(4885, 5)), (0x33E0, M(4890, 4)), (0x33E1, M(4894, 4)), (0x33E2, M(4898, 4)),
+    (0x33E3, M(4902, 4)), (0x33E4, M(4906, 4)), (0x33E5, M(4910, 4)), (0x33E6, M(4914, 4)),
+    (0x33E7, M(4918, 4)), (0x33E8, M(4922, 4)), (0x33E9, M(4926, 5)), (0x33EA, M(4931, 5)),
+    (0x33EB, M(4936, 5)), (0x33EC, M(4941, 5)), (0x33ED, M(4946, 5)), (0x33EE, M(4951, 5)),
+    (0x33EF, M(4956, 5)), (0x33F0, M(4961, 5)), (0x33F1, M(4966, 5)), (0x33F2, M(4971, 5)),
+    (0x33F3, M(4976, 5)), (0x33F4, M(4981, 5)), (0x33F5, M(4986, 5)), (0x33F6, M(4991, 5)),
+    (0x33F7, M(4996, 5)), (0x33F8, M(5001, 5)), (0x33F9, M(5006, 5)), (0x33FA, M(5011, 5)),
+    (0x33FB, M(5016, 5)), (0x33FC, M(5021, 5)), (0x33FD, M(5026, 5)), (0x33FE, M(5031, 5)),
+    (0x33FF, M(5036, 3)), (0x3400, V), (0x9FFD, D), (0xA000, V),
+    (0xA48D, D), (0xA490, V), (0xA4C7, D), (0xA4D0, V),
+    (0xA62C, D), (0xA640, M(5039, 3)), (0xA641, V), (0xA642, M(5042, 3)),
+    (0xA643, V), (0xA644, M(5045, 3)), (0xA645, V), (0xA646, M(5048, 3)),
+    (0xA647, V), (0xA648, M(5051, 3)), (0xA649, V), (0xA64A, M(974, 3)),
+    (0xA64B, V), (0xA64C, M(5054, 3)), (0xA64D, V), (0xA64E, M(5057, 3)),
+    (0xA64F, V), (0xA650, M(5060, 3)), (0xA651, V), (0xA652, M(5063, 3)),
+    (0xA653, V), (0xA654, M(5066, 3)), (0xA655, V), (0xA656, M(5069, 3)),
+    (0xA657, V), (0xA658, M(5072, 3)), (0xA659, V), (0xA65A, M(5075, 3)),
+    (0xA65B, V), (0xA65C, M(5078, 3)), (0xA65D, V), (0xA65E, M(5081, 3)),
+    (0xA65F, V), (0xA660, M(5084, 3)), (0xA661, V), (0xA662, M(5087, 3)),
+    (0xA663, V), (0xA664, M(5090, 3)), (0xA665, V), (0xA666, M(5093, 3)),
+    (0xA667, V), (0xA668, M(5096, 3)), (0xA669, V), (0xA66A, M(5099, 3)),
+    (0xA66B, V), (0xA66C, M(5102, 3)), (0xA66D, V), (0xA680, M(5105, 3)),
+    (0xA681, V), (0xA682, M(5108, 3)), (0xA683, V), (0xA684, M(5111, 3)),
+    (0xA685, V), (0xA686, M(5114, 3)), (0xA687, V), (0xA688, M(5117, 3)),
+    (0xA689, V), (0xA68A, M(5120, 3)), (0xA68B, V), (0xA68C, M(5123, 3)),
+    (0xA68D, V), (0xA68E, M(5126, 3)), (0xA68F, V), (0xA690, M(5129, 3)),
+    (0xA691, V), (0xA692, M(5132, 3)), (0xA693, V), (0xA694, M(5135, 3)),
+    (0xA695, V), (0xA696, M(5138, 3)), (0xA697, V), (0xA698, M(5141, 3)),
+    (0xA699, V), (0xA69A, M(5144, 3)), (0xA69B, V), (0xA69C, M(972, 2)),
+    (0xA69D, M(5147, 2)), (0xA69E, V), (0xA6F8, D), (0xA700, V),
+    (0xA722, M(5149, 3)), (0xA723, V), (0xA724, M(5152, 3)), (0xA725, V),
+    (0xA726, M(5155, 3)), (0xA727, V), (0xA728, M(5158, 3)), (0xA729, V),
+    (0xA72A, M(5161, 3)), (0xA72B, V), (0xA72C, M(5164, 3)), (0xA72D, V),
+    (0xA72E, M(5167, 3)), (0xA72F, V), (0xA732, M(5170, 3)), (0xA733, V),
+    (0xA734, M(5173, 3)), (0xA735, V), (0xA736, M(5176, 3)), (0xA737, V),
+    (0xA738, M(5179, 3)), (0xA739, V), (0xA73A, M(5182, 3)), (0xA73B, V),
+    (0xA73C, M(5185, 3)), (0xA73D, V), (0xA73E, M(5188, 3)), (0xA73F, V),
+    (0xA740, M(5191, 3)), (0xA741, V), (0xA742, M(5194, 3)), (0xA743, V),
+    (0xA744, M(5197, 3)), (0xA745, V), (0xA746, M(5200, 3)), (0xA747, V),
+    (0xA748, M(5203, 3)), (0xA749, V), (0xA74A, M(5206, 3)), (0xA74B, V),
+    (0xA74C, M(5209, 3)), (0xA74D, V), (0xA74E, M(5212, 3)), (0xA74F, V),
+    (0xA750, M(5215, 3)), (0xA751, V), (0xA752, M(5218, 3)), (0xA753, V),
+    (0xA754, M(5221, 3)), (0xA755, V), (0xA756, M(5224, 3)), (0xA757, V),
+    (0xA758, M(5227, 3)), (0xA759, V), (0xA75A, M(5230, 3)), (0xA75B, V),
+    (0xA75C, M(5233, 3)), (0xA75D, V), (0xA75E, M(5236, 3)), (0xA75F, V),
+    (0xA760, M(5239, 3)), (0xA761, V), (0xA762, M(5242, 3)), (0xA763, V),
+    (0xA764, M(5245, 3)), (0xA765, V), (0xA766, M(5248, 3)), (0xA767, V),
+    (0xA768, M(5251, 3)), (0xA769, V), (0xA76A, M(5254, 3)), (0xA76B, V),
+    (0xA76C, M(5257, 3)), (0xA76D, V), (0xA76E, M(5260, 3)), (0xA76F, V),
+    (0xA770, M(5260, 3)), (0xA771, V), (0xA779, M(5263, 3)), (0xA77A, V),
+    (0xA77B, M(5266, 3)), (0xA77C, V), (0xA77D, M(5269, 3)), (0xA77E, M(5272, 3)),
+    (0xA77F, V), (0xA780, M(5275, 3)), (0xA781, V), (0xA782, M(5278, 3)),
+    (0xA783, V), (0xA784, M(5281, 3)), (0xA785, V), (0xA786, M(5284, 3)),
+    (0xA787, V), (0xA78B, M(5287, 3)), (0xA78C, V), (0xA78D, M(1010, 2)),
+    (0xA78E, V), (0xA790, M(5290, 3)), (0xA791, V), (0xA792, M(5293, 3)),
+    (0xA793, V), (0xA796, M(5296, 3)), (0xA797, V), (0xA798, M(5299, 3)),
+    (0xA799, V), (0xA79A, M(5302, 3)), (0xA79B, V), (0xA79C, M(5305, 3)),
+    (0xA79D, V), (0xA79E, M(5308, 3)), (0xA79F, V), (0xA7A0, M(5311, 3)),
+    (0xA7A1, V), (0xA7A2, M(5314, 3)), (0xA7A3, V), (0xA7A4, M(5317, 3)),
+    (0xA7A5, V), (0xA7A6, M(5320, 3)), (0xA7A7, V), (0xA7A8, M(5323, 3)),
+    (0xA7A9, V), (0xA7AA, M(367, 2)), (0xA7AB, M(1003, 2)), (0xA7AC, M(1008, 2)),
+    (0xA7AD, M(5326, 2)), (0xA7AE, M(5328, 2)), (0xA7AF, V), (0xA7B0, M(5330, 2)),
+    (0xA7B1, M(5332, 2)), (0xA7B2, M(1015, 2)), (0xA7B3, M(5334, 3)), (0xA7B4, M(5337, 3)),
+    (0xA7B5, V), (0xA7B6, M(5340, 3)), (0xA7B7, V), (0xA7B8, M(5343, 3)),
+    (0xA7B9, V), (0xA7BA, M(5346, 3)), (0xA7BB, V), (0xA7BC, M(5349, 3)),
+    (0xA7BD, V), (0xA7BE, M(5352, 3)), (0xA7BF, V), (0xA7C0, D),
+    (0xA7C2, M(5355, 3)), (0xA7C3, V), (0xA7C4, M(5358, 3)), (0xA7C5, M(5361, 2)),
+    (0xA7C6, M(5363, 3)), (0xA7C7, M(5366, 3)), (0xA7C8, V), (0xA7C9, M(5369, 3)),
+    (0xA7CA, V), (0xA7CB, D), (0xA7F5, M(5372, 3)), (0xA7F6, V),
+    (0xA7F8, M(71, 2)), (0xA7F9, M(118, 2)), (0xA7FA, V), (0xA82D, D),
+    (0xA830, V), (0xA83A, D), (0xA840, V), (0xA878, D),
+    (0xA880, V), (0xA8C6, D), (0xA8CE, V), (0xA8DA, D),
+    (0xA8E0, V), (0xA954, D), (0xA95F, V), (0xA97D, D),
+    (0xA980, V), (0xA9CE, D), (0xA9CF, V), (0xA9DA, D),
+    (0xA9DE, V), (0xA9FF, D), (0xAA00, V), (0xAA37, D),
+    (0xAA40, V), (0xAA4E, D), (0xAA50, V), (0xAA5A, D),
+    (0xAA5C, V), (0xAAC3, D), (0xAADB, V), (0xAAF7, D),
+    (0xAB01, V), (0xAB07, D), (0xAB09, V), (0xAB0F, D),
+    (0xAB11, V), (0xAB17, D), (0xAB20, V), (0xAB27, D),
+    (0xAB28, V), (0xAB2F, D), (0xAB30, V), (0xAB5C, M(5155, 3)),
+    (0xAB5D, M(5375, 3)), (0xAB5E, M(2080, 2)), (0xAB5F, M(5378, 3)), (0xAB60, V),
+    (0xAB69, M(5381, 2)), (0xAB6A, V), (0xAB6C, D), (0xAB70, R(0x13A0)),
+    (0xABC0, V), (0xABEE, D), (0xABF0, V), (0xABFA, D),
+    (0xAC00, V), (0xD7A4, D), (0xD7B0, V), (0xD7C7, D),
+    (0xD7CB, V), (0xD7FC, D), (0xF900, M(5383, 3)), (0xF901, M(5386, 3)),
+    (0xF902, M(2748, 3)), (0xF903, M(5389, 3)), (0xF904, M(5392, 3)), (0xF905, M(5395, 3)),
+    (0xF906, M(5398, 3)), (0xF907, M(2910, 3)), (0xF909, M(5401, 3)), (0xF90A, M(2772, 3)),
+    (0xF90B, M(5404, 3)), (0xF90C, M(5407, 3)), (0xF90D, M(5410, 3)), (0xF90E, M(5413, 3)),
+    (0xF90F, M(5416, 3)), (0xF910, M(5419, 3)), (0xF911, M(5422, 3)), (0xF912, M(5425, 3)),
+    (0xF913, M(5428, 3)), (0xF914, M(5431, 3)), (0xF915, M(5434, 3)), (0xF916, M(5437, 3)),
+    (0xF917, M(5440, 3)), (0xF918, M(5443, 3)), (0xF919, M(5446, 3)), (0xF91A, M(5449, 3)),
+    (0xF91B, M(5452, 3)), (0xF91C, M(5455, 3)), (0xF91D, M(5458, 3)), (0xF91E, M(5461, 3)),
+    (0xF91F, M(5464, 3)), (0xF920, M(5467, 3)), (0xF921, M(5470, 3)), (0xF922, M(5473, 3)),
+    (0xF923, M(5476, 3)), (0xF924, M(5479, 3)), (0xF925, M(5482, 3)), (0xF926, M(5485, 3)),
+    (0xF927, M(5488, 3)), (0xF928, M(5491, 3)), (0xF929, M(5494, 3)), (0xF92A, M(5497, 3)),
+    (0xF92B, M(5500, 3)), (0xF92C, M(5503, 3)), (0xF92D, M(5506, 3)), (0xF92E, M(5509, 3)),
+    (0xF92F, M(5512, 3)), (0xF930, M(5515, 3)), (0xF931, M(5518, 3)), (0xF932, M(5521, 3)),
+    (0xF933, M(5524, 3)), (0xF934, M(2646, 3)), (0xF935, M(5527, 3)), (0xF936, M(5530, 3)),
+    (0xF937, M(5533, 3)), (0xF938, M(5536, 3)), (0xF939, M(5539, 3)), (0xF93A, M(5542, 3)),
+    (0xF93B, M(5545, 3)), (0xF93C, M(5548, 3)), (0xF93D, M(5551, 3)), (0xF93E, M(5554, 3)),
+    (0xF93F, M(5557, 3)), (0xF940, M(2865, 3)), (0xF941, M(5560, 3)), (0xF942, M(5563, 3)),
+    (0xF943, M(5566, 3)), (0xF944, M(5569, 3)), (0xF945, M(5572, 3)), (0xF946, M(5575, 3)),
+    (0xF947, M(5578, 3)), (0xF948, M(5581, 3)), (0xF949, M(5584, 3)), (0xF94A, M(5587, 3)),
+    (0xF94B, M(5590, 3)), (0xF94C, M(5593, 3)), (0xF94D, M(5596, 3)), (0xF94E, M(5599, 3)),
+    (0xF94F, M(5602, 3)), (0xF950, M(5605, 3)), (0xF951, M(5608, 3)), (0xF952, M(5611, 3)),
+    (0xF953, M(5614, 3)), (0xF954, M(5617, 3)), (0xF955, M(5620, 3)), (0xF956, M(5623, 3)),
+    (0xF957, M(5626, 3)), (0xF958, M(5629, 3)), (0xF959, M(5632, 3)), (0xF95A, M(5635, 3)),
+    (0xF95B, M(5638, 3)), (0xF95C, M(5431, 3)), (0xF95D, M(5641, 3)), (0xF95E, M(5644, 3)),
+    (0xF95F, M(5647, 3)), (0xF960, M(5650, 3)), (0xF961, M(5653, 3)), (0xF962, M(5656, 3)),
+    (0xF963, M(5659, 3)), (0xF964, M(5662, 3)), (0xF965, M(5665, 3)), (0xF966, M(5668, 3)),
+    (0xF967, M(5671, 3)), (0xF968, M(5674, 3)), (0xF969, M(5677, 3)), (0xF96A, M(5680, 3)),
+    (0xF96B, M(5683, 3)), (0xF96C, M(5686, 3)), (0xF96D, M(5689, 3)), (0xF96E, M(5692, 3)),
+    (0xF96F, M(5695, 3)), (0xF970, M(5698, 3)), (0xF971, M(2754, 3)), (0xF972, M(5701, 3)),
+    (0xF973, M(5704, 3)), (0xF974, M(5707, 3)), (0xF975, M(5710, 3)), (0xF976, M(5713, 3)),
+    (0xF977, M(5716, 3)), (0xF978, M(5719, 3)), (0xF979, M(5722, 3)), (0xF97A, M(5725, 3)),
+    (0xF97B, M(5728, 3)), (0xF97C, M(5731, 3)), (0xF97D, M(5734, 3)), (0xF97E, M(5737, 3)),
+    (0xF97F, M(5740, 3)), (0xF980, M(5743, 3)), (0xF981, M(2385, 3)), (0xF982, M(5746, 3)),
+    (0xF983, M(5749, 3)), (0xF984, M(5752, 3)), (0xF985, M(5755, 3)), (0xF986, M(5758, 3)),
+    (0xF987, M(5761, 3)), (0xF988, M(5764, 3)), (0xF989, M(5767, 3)), (0xF98A, M(2328, 3)),
+    (0xF98B, M(5770, 3)), (0xF98C, M(5773, 3)), (0xF98D, M(5776, 3)), (0xF98E, M(5779, 3)),
+    (0xF98F, M(5782, 3)), (0xF990, M(5785, 3)), (0xF991, M(5788, 3)), (0xF992, M(5791, 3)),
+    (0xF993, M(5794, 3)), (0xF994, M(5797, 3)), (0xF995, M(5800, 3)), (0xF996, M(5803, 3)),
+    (0xF997, M(5806, 3)), (0xF998, M(5809, 3)), (0xF999, M(5812, 3)), (0xF99A, M(5815, 3)),
+    (0xF99B, M(5818, 3)), (0xF99C, M(5821, 3)), (0xF99D, M(5824, 3)), (0xF99E, M(5827, 3)),
+    (0xF99F, M(5830, 3)), (0xF9A0, M(5833, 3)), (0xF9A1, M(5695, 3)), (0xF9A2, M(5836, 3)),
+    (0xF9A3, M(5839, 3)), (0xF9A4, M(5842, 3)), (0xF9A5, M(5845, 3)), (0xF9A6, M(5848, 3)),
+    (0xF9A7, M(5851, 3)), (0xF9A8, M(3625, 3)), (0xF9A9, M(5854, 3)), (0xF9AA, M(5647, 3)),
+    (0xF9AB, M(5857, 3)), (0xF9AC, M(5860, 3)), (0xF9AD, M(5863, 3)), (0xF9AE, M(5866, 3)),
+    (0xF9AF, M(5869, 3)), (0xF9B0, M(5872, 3)), (0xF9B1, M(5875, 3)), (0xF9B2, M(5878, 3)),
+    (0xF9B3, M(5881, 3)), (0xF9B4, M(5884, 3)), (0xF9B5, M(5887, 3)), (0xF9B6, M(5890, 3)),
+    (0xF9B7, M(5893, 3)), (0xF9B8, M(5896, 3)), (0xF9B9, M(5899, 3)), (0xF9BA, M(5902, 3)),
+    (0xF9BB, M(5905, 3)), (0xF9BC, M(5908, 3)), (0xF9BD, M(5911, 3)), (0xF9BE, M(5914, 3)),
+    (0xF9BF, M(5431, 3)), (0xF9C0, M(5917, 3)), (0xF9C1, M(5920, 3)), (0xF9C2, M(5923, 3)),
+    (0xF9C3, M(5926, 3)), (0xF9C4, M(2907, 3)), (0xF9C5, M(5929, 3)), (0xF9C6, M(5932, 3)),
+    (0xF9C7, M(5935, 3)), (0xF9C8, M(5938, 3)), (0xF9C9, M(5941, 3)), (0xF9CA, M(5944, 3)),
+    (0xF9CB, M(5947, 3)), (0xF9CC, M(5950, 3)), (0xF9CD, M(5953, 3)), (0xF9CE, M(5956, 3)),
+    (0xF9CF, M(5959, 3)), (0xF9D0, M(5962, 3)), (0xF9D1, M(3229, 3)), (0xF9D2, M(5965, 3)),
+    (0xF9D3, M(5968, 3)), (0xF9D4, M(5971, 3)), (0xF9D5, M(5974, 3)), (0xF9D6, M(5977, 3)),
+    (0xF9D7, M(5980, 3)), (0xF9D8, M(5983, 3)), (0xF9D9, M(5986, 3)), (0xF9DA, M(5989, 3)),
+    (0xF9DB, M(5653, 3)), (0xF9DC, M(5992, 3)), (0xF9DD, M(5995, 3)), (0xF9DE, M(5998, 3)),
+    (0xF9DF, M(6001, 3)), (0xF9E0, M(6004, 3)), (0xF9E1, M(6007, 3)), (0xF9E2, M(6010, 3)),
+    (0xF9E3, M(6013, 3)), (0xF9E4, M(6016, 3)), (0xF9E5, M(6019, 3)), (0xF9E6, M(6022, 3)),
+    (0xF9E7, M(6025, 3)), (0xF9E8, M(6028, 3)), (0xF9E9, M(2769, 3)), (0xF9EA, M(6031, 3)),
+    (0xF9EB, M(6034, 3)), (0xF9EC, M(6037, 3)), (0xF9ED, M(6040, 3)), (0xF9EE, M(6043, 3)),
+    (0xF9EF, M(6046, 3)), (0xF9F0, M(6049, 3)), (0xF9F1, M(6052, 3)), (0xF9F2, M(6055, 3)),
+    (0xF9F3, M(6058, 3)), (0xF9F4, M(6061, 3)), (0xF9F5, M(6064, 3)), (0xF9F6, M(6067, 3)),
+    (0xF9F7, M(2622, 3)), (0xF9F8, M(6070, 3)), (0xF9F9, M(6073, 3)), (0xF9FA, M(6076, 3)),
+    (0xF9FB, M(6079, 3)), (0xF9FC, M(6082, 3)), (0xF9FD, M(6085, 3)), (0xF9FE, M(6088, 3)),
+    (0xF9FF, M(6091, 3)), (0xFA00, M(6094, 3)), (0xFA01, M(6097, 3)), (0xFA02, M(6100, 3)),
+    (0xFA03, M(6103, 3)), (0xFA04, M(6106, 3)), (0xFA05, M(6109, 3)), (0xFA06, M(6112, 3)),
+    (0xFA07, M(6115, 3)), (0xFA08, M(2703, 3)), (0xFA09, M(6118, 3)), (0xFA0A, M(2712, 3)),
+    (0xFA0B, M(6121, 3)), (0xFA0C, M(6124, 3)), (0xFA0D, M(6127, 3)), (0xFA0E, V),
+    (0xFA10, M(6130, 3)), (0xFA11, V), (0xFA12, M(6133, 3)), (0xFA13, V),
+    (0xFA15, M(6136, 3)), (0xFA16, M(6139, 3)), (0xFA17, M(6142, 3)), (0xFA18, M(6145, 3)),
+    (0xFA19, M(6148, 3)), (0xFA1A, M(6151, 3)), (0xFA1B, M(6154, 3)), (0xFA1C, M(6157, 3)),
+    (0xFA1D, M(6160, 3)), (0xFA1E, M(2643, 3)), (0xFA1F, V), (0xFA20, M(6163, 3)),
+    (0xFA21, V), (0xFA22, M(6166, 3)), (0xFA23, V), (0xFA25, M(6169, 3)),
+    (0xFA26, M(6172, 3)), (0xFA27, V), (0xFA2A, M(6175, 3)), (0xFA2B, M(6178, 3)),
+    (0xFA2C, M(6181, 3)), (0xFA2D, M(6184, 3)), (0xFA2E, M(6187, 3)), (0xFA2F, M(6190, 3)),
+    (0xFA30, M(6193, 3)), (0xFA31, M(6196, 3)), (0xFA32, M(6199, 3)), (0xFA33, M(6202, 3)),
+    (0xFA34, M(6205, 3)), (0xFA35, M(6208, 3)), (0xFA36, M(6211, 3)), (0xFA37, M(6214, 3)),
+    (0xFA38, M(6217, 3)), (0xFA39, M(6220, 3)), (0xFA3A, M(6223, 3)), (0xFA3B, M(6226, 3)),
+    (0xFA3C, M(2406, 3)), (0xFA3D, M(6229, 3)), (0xFA3E, M(6232, 3)), (0xFA3F, M(6235, 3)),
+    (0xFA40, M(6238, 3)), (0xFA41, M(6241, 3)), (0xFA42, M(6244, 3)), (0xFA43, M(6247, 3)),
+    (0xFA44, M(6250, 3)), (0xFA45, M(6253, 3)), (0xFA46, M(6256, 3)), (0xFA47, M(6259, 3)),
+    (0xFA48, M(6262, 3)), (0xFA49, M(6265, 3)), (0xFA4A, M(6268, 3)), (0xFA4B, M(6271, 3)),
+    (0xFA4C, M(3299, 3)), (0xFA4D, M(6274, 3)), (0xFA4E, M(6277, 3)), (0xFA4F, M(6280, 3)),
+    (0xFA50, M(6283, 3)), (0xFA51, M(3319, 3)), (0xFA52, R(0x798D)), (0xFA54, M(6286, 3)),
+    (0xFA55, M(6289, 3)), (0xFA56, M(6292, 3)), (0xFA57, M(5803, 3)), (0xFA58, M(6295, 3)),
+    (0xFA59, M(6298, 3)), (0xFA5A, M(6301, 3)), (0xFA5B, M(6304, 3)), (0xFA5C, M(6307, 3)),
+    (0xFA5D, M(6310, 3)), (0xFA5F, M(6313, 3)), (0xFA60, M(6316, 3)), (0xFA61, M(6319, 3)),
+    (0xFA62, M(6322, 3)), (0xFA63, M(6325, 3)), (0xFA64, M(6328, 3)), (0xFA65, M(6331, 3)),
+    (0xFA66, M(6334, 3)), (0xFA67, M(6169, 3)), (0xFA68, M(6337, 3)), (0xFA69, M(6340, 3)),
+    (0xFA6A, M(6343, 3)), (0xFA6B, M(6346, 3)), (0xFA6C, M(6349, 4)), (0xFA6D, M(6353, 3)),
+    (0xFA6E, D), (0xFA70, M(6356, 3)), (0xFA71, M(6359, 3)), (0xFA72, M(6362, 3)),
+    (0xFA73, M(6365, 3)), (0xFA74, M(6368, 3)), (0xFA75, M(6371, 3)), (0xFA76, M(6374, 3)),
+    (0xFA77, M(6377, 3)), (0xFA78, M(6211, 3)), (0xFA79, M(6380, 3)), (0xFA7A, M(6383, 3)),
+    (0xFA7B, M(6386, 3)), (0xFA7C, M(6130, 3)), (0xFA7D, M(6389, 3)), (0xFA7E, M(6392, 3)),
+    (0xFA7F, M(6395, 3)), (0xFA80, M(6398, 3)), (0xFA81, M(6401, 3)), (0xFA82, M(6404, 3)),
+    (0xFA83, M(6407, 3)), (0xFA84, M(6410, 3)), (0xFA85, M(6413, 3)), (0xFA86, M(6416, 3)),
+    (0xFA87, M(6419, 3)), (0xFA88, M(6422, 3)), (0xFA89, M(6235, 3)), (0xFA8A, M(6425, 3)),
+    (0xFA8B, M(6238, 3)), (0xFA8C, M(6428, 3)), (0xFA8D, M(6431, 3)), (0xFA8E, M(6434, 3)),
+    (0xFA8F, M(6437, 3)), (0xFA90, M(6440, 3)), (0xFA91, M(6133, 3)), (0xFA92, M(5494, 3)),
+    (0xFA93, M(6443, 3)), (0xFA94, M(6446, 3)), (0xFA95, M(2505, 3)), (0xFA96, M(5698, 3)),
+    (0xFA97, M(5944, 3)), (0xFA98, M(6449, 3)), (0xFA99, M(6452, 3)), (0xFA9A, M(6259, 3)),
+    (0xFA9B, M(6455, 3)), (0xFA9C, M(6262, 3)), (0xFA9D, M(6458, 3)), (0xFA9E, M(6461, 3)),
+    (0xFA9F, M(6464, 3)), (0xFAA0, M(6139, 3)), (0xFAA1, M(6467, 3)), (0xFAA2, M(6470, 3)),
+    (0xFAA3, M(6473, 3)), (0xFAA4, M(6476, 3)), (0xFAA5, M(6479, 3)), (0xFAA6, M(6142, 3)),
+    (0xFAA7, M(6482, 3)), (0xFAA8, M(6485, 3)), (0xFAA9, M(6488, 3)), (0xFAAA, M(6491, 3)),
+    (0xFAAB, M(6494, 3)), (0xFAAC, M(6497, 3)), (0xFAAD, M(6292, 3)), (0xFAAE, M(6500, 3)),
+    (0xFAAF, M(6503, 3)), (0xFAB0, M(5803, 3)), (0xFAB1, M(6506, 3)), (0xFAB2, M(6304, 3)),
+    (0xFAB3, M(6509, 3)), (0xFAB4, M(6512, 3)), (0xFAB5, M(6515, 3)), (0xFAB6, M(6518, 3)),
+    (0xFAB7, M(6521, 3)), (0xFAB8, M(6319, 3)), (0xFAB9, M(6524, 3)), (0xFABA, M(6166, 3)),
+    (0xFABB, M(6527, 3)), (0xFABC, M(6322, 3)), (0xFABD, M(5641, 3)), (0xFABE, M(6530, 3)),
+    (0xFABF, M(6325, 3)), (0xFAC0, M(6533, 3)), (0xFAC1, M(6331, 3)), (0xFAC2, M(6536, 3)),
+    (0xFAC3, M(6539, 3)), (0xFAC4, M(6542, 3)), (0xFAC5, M(6545, 3)), (0xFAC6, M(6548, 3)),
+    (0xFAC7, M(6337, 3)), (0xFAC8, M(6157, 3)), (0xFAC9, M(6551, 3)), (0xFACA, M(6340, 3)),
+    (0xFACB, M(6554, 3)), (0xFACC, M(6343, 3)), (0xFACD, M(6557, 3)), (0xFACE, M(2910, 3)),
+    (0xFACF, M(6560, 4)), (0xFAD0, M(6564, 4)), (0xFAD1, M(6568, 4)), (0xFAD2, M(6572, 3)),
+    (0xFAD3, M(6575, 3)), (0xFAD4, M(6578, 3)), (0xFAD5, M(6581, 4)), (0xFAD6, M(6585, 4)),
+    (0xFAD7, M(6589, 4)), (0xFAD8, M(6593, 3)), (0xFAD9, M(6596, 3)), (0xFADA, D),
+    (0xFB00, M(6599, 2)), (0xFB01, M(6601, 2)), (0xFB02, M(6603, 2)), (0xFB03, M(6600, 3)),
+    (0xFB04, M(6605, 3)), (0xFB05, M(6608, 2)), (0xFB07, D), (0xFB13, M(6610, 4)),
+    (0xFB14, M(6614, 4)), (0xFB15, M(6618, 4)), (0xFB16, M(6622, 4)), (0xFB17, M(6626, 4)),
+    (0xFB18, D), (0xFB1D, M(6630, 4)), (0xFB1E, V), (0xFB1F, M(6634, 4)),
+    (0xFB20, M(6638, 2)), (0xFB21, M(6640, 2)), (0xFB22, R(0x5D3)), (0xFB24, R(0x5DB)),
+    (0xFB27, M(6642, 2)), (0xFB28, M(6644, 2)), (0xFB29, SM(1717, 1)), (0xFB2A, M(6646, 4)),
+    (0xFB2B, M(6650, 4)), (0xFB2C, M(6654, 6)), (0xFB2D, M(6660, 6)), (0xFB2E, M(6666, 4)),
+    (0xFB2F, M(6670, 4)), (0xFB30, M(6674, 4)), (0xFB31, M(6678, 4)), (0xFB32, M(6682, 4)),
+    (0xFB33, M(6686, 4)), (0xFB34, M(6690, 4)), (0xFB35, M(6694, 4)), (0xFB36, M(6698, 4)),
+    (0xFB37, D), (0xFB38, M(6702, 4)), (0xFB39, M(6706, 4)), (0xFB3A, M(6710, 4)),
+    (0xFB3B, M(6714, 4)), (0xFB3C, M(6718, 4)), (0xFB3D, D), (0xFB3E, M(6722, 4)),
+    (0xFB3F, D), (0xFB40, M(6726, 4)), (0xFB41, M(6730, 4)), (0xFB42, D),
+    (0xFB43, M(6734, 4)), (0xFB44, M(6738, 4)), (0xFB45, D), (0xFB46, M(6742, 4)),
+    (0xFB47, M(6746, 4)), (0xFB48, M(6750, 4)), (0xFB49, M(6654, 4)), (0xFB4A, M(6754, 4)),
+    (0xFB4B, M(6758, 4)), (0xFB4C, M(6762, 4)), (0xFB4D, M(6766, 4)), (0xFB4E, M(6770, 4)),
+    (0xFB4F, M(6774, 4)), (0xFB50, M(6778, 2)), (0xFB52, M(6780, 2)), (0xFB56, M(6782, 2)),
+    (0xFB5A, M(6784, 2)), (0xFB5E, M(6786, 2)), (0xFB62, M(6788, 2)), (0xFB66, M(6790, 2)),
+    (0xFB6A, M(6792, 2)), (0xFB6E, M(6794, 2)), (0xFB72, M(6796, 2)), (0xFB76, M(6798, 2)),
+    (0xFB7A, M(6800, 2)), (0xFB7E, M(6802, 2)), (0xFB82, M(6804, 2)), (0xFB84, M(6806, 2)),
+    (0xFB86, M(6808, 2)), (0xFB88, M(6810, 2)), (0xFB8A, M(6812, 2)), (0xFB8C, M(6814, 2)),
+    (0xFB8E, M(6816, 2)), (0xFB92, M(6818, 2)), (0xFB96, M(6820, 2)), (0xFB9A, M(6822, 2)),
+    (0xFB9E, M(6824, 2)), (0xFBA0, M(6826, 2)), (0xFBA4, M(6828, 2)), (0xFBA6, M(6830, 2)),
+    (0xFBAA, M(6832, 2)), (0xFBAE, M(6834, 2)), (0xFBB0, M(6836, 2)), (0xFBB2, V),
+    (0xFBC2, D), (0xFBD3, M(6838, 2)), (0xFBD7, M(692, 2)), (0xFBD9, M(6840, 2)),
+    (0xFBDB, M(6842, 2)), (0xFBDD, M(692, 4)), (0xFBDE, M(6844, 2)), (0xFBE0, M(6846, 2)),
+    (0xFBE2, M(6848, 2)), (0xFBE4, M(6850, 2)), (0xFBE8, M(6852, 2)), (0xFBEA, M(6854, 4)),
+    (0xFBEC, M(6858, 4)), (0xFBEE, M(6862, 4)), (0xFBF0, M(6866, 4)), (0xFBF2, M(6870, 4)),
+    (0xFBF4, M(6874, 4)), (0xFBF6, M(6878, 4)), (0xFBF9, M(6882, 4)), (0xFBFC, M(6886, 2)),
+    (0xFC00, M(6888, 4)), (0xFC01, M(6892, 4)), (0xFC02, M(6896, 4)), (0xFC03, M(6882, 4)),
+    (0xFC04, M(6900, 4)), (0xFC05, M(6904, 4)), (0xFC06, M(6908, 4)), (0xFC07, M(6912, 4)),
+    (0xFC08, M(6916, 4)), (0xFC09, M(6920, 4)), (0xFC0A, M(6924, 4)), (0xFC0B, M(6928, 4)),
+    (0xFC0C, M(6932, 4)), (0xFC0D, M(6936, 4)), (0xFC0E, M(6940, 4)), (0xFC0F, M(6944, 4)),
+    (0xFC10, M(6948, 4)), (0xFC11, M(6952, 4)), (0xFC12, M(6956, 4)), (0xFC13, M(6960, 4)),
+    (0xFC14, M(6964, 4)), (0xFC15, M(6968, 4)), (0xFC16, M(6972, 4)), (0xFC17, M(6970, 4)),
+    (0xFC18, M(6976, 4)), (0xFC19, M(6980, 4)), (0xFC1A, M(6984, 4)), (0xFC1B, M(6988, 4)),
+    (0xFC1C, M(6992, 4)), (0xFC1D, M(6996, 4)), (0xFC1E, M(7000, 4)), (0xFC1F, M(7004, 4)),
+    (0xFC20, M(7008, 4)), (0xFC21, M(7012, 4)), (0xFC22, M(7016, 4)), (0xFC23, M(7020, 4)),
+    (0xFC24, M(7024, 4)), (0xFC25, M(7028, 4)), (0xFC26, M(7032, 4)), (0xFC27, M(7036, 4)),
+    (0xFC28, M(7040, 4)), (0xFC29, M(7044, 4)), (0xFC2A, M(7048, 4)), (0xFC2B, M(7052, 4)),
+    (0xFC2C, M(7056, 4)), (0xFC2D, M(7060, 4)), (0xFC2E, M(7064, 4)), (0xFC2F, M(7068, 4)),
+    (0xFC30, M(7072, 4)), (0xFC31, M(7076, 4)), (0xFC32, M(7080, 4)), (0xFC33, M(7084, 4)),
+    (0xFC34, M(7088, 4)), (0xFC35, M(7092, 4)), (0xFC36, M(7096, 4)), (0xFC37, M(7100, 4)),
+    (0xFC38, M(7104, 4)), (0xFC39, M(7108, 4)), (0xFC3A, M(7112, 4)), (0xFC3B, M(7116, 4)),
+    (0xFC3C, M(7120, 4)), (0xFC3D, M(7124, 4)), (0xFC3E, M(7128, 4)), (0xFC3F, M(7132, 4)),
+    (0xFC40, M(7136, 4)), (0xFC41, M(7140, 4)), (0xFC42, M(7144, 4)), (0xFC43, M(7148, 4)),
+    (0xFC44, M(7152, 4)), (0xFC45, M(7156, 4)), (0xFC46, M(6974, 4)), (0xFC47, M(6978, 4)),
+    (0xFC48, M(7160, 4)), (0xFC49, M(7164, 4)), (0xFC4A, M(7168, 4)), (0xFC4B, M(7172, 4)),
+    (0xFC4C, M(7176, 4)), (0xFC4D, M(7180, 4)), (0xFC4E, M(7184, 4)), (0xFC4F, M(7188, 4)),
+    (0xFC50, M(7192, 4)), (0xFC51, M(7196, 4)), (0xFC52, M(7200, 4)), (0xFC53, M(7204, 4)),
+    (0xFC54, M(7208, 4)), (0xFC55, M(6966, 4)), (0xFC56, M(7212, 4)), (0xFC57, M(7216, 4)),
+    (0xFC58, M(7154, 4)), (0xFC59, M(7220, 4)), (0xFC5A, M(7210, 4)), (0xFC5B, M(7224, 4)),
+    (0xFC5C, M(7228, 4)), (0xFC5D, M(7232, 4)), (0xFC5E, SM(7236, 5)), (0xFC5F, SM(7241, 5)),
+    (0xFC60, SM(7246, 5)), (0xFC61, SM(7251, 5)), (0xFC62, SM(7256, 5)), (0xFC63, SM(7261, 5)),
+    (0xFC64, M(7266, 4)), (0xFC65, M(7270, 4)), (0xFC66, M(6896, 4)), (0xFC67, M(7274, 4)),
+    (0xFC68, M(6882, 4)), (0xFC69, M(6900, 4)), (0xFC6A, M(7278, 4)), (0xFC6B, M(7282, 4)),
+    (0xFC6C, M(6916, 4)), (0xFC6D, M(7286, 4)), (0xFC6E, M(6920, 4)), (0xFC6F, M(6924, 4)),
+    (0xFC70, M(7290, 4)), (0xFC71, M(7294, 4)), (0xFC72, M(6940, 4)), (0xFC73, M(7298, 4)),
+    (0xFC74, M(6944, 4)), (0xFC75, M(6948, 4)), (0xFC76, M(7302, 4)), (0xFC77, M(7306, 4)),
+    (0xFC78, M(6956, 4)), (0xFC79, M(7310, 4)), (0xFC7A, M(6960, 4)), (0xFC7B, M(6964, 4)),
+    (0xFC7C, M(7076, 4)), (0xFC7D, M(7080, 4)), (0xFC7E, M(7092, 4)), (0xFC7F, M(7096, 4)),
+    (0xFC80, M(7100, 4)), (0xFC81, M(7116, 4)), (0xFC82, M(7120, 4)), (0xFC83, M(7124, 4)),
+    (0xFC84, M(7128, 4)), (0xFC85, M(7144, 4)), (0xFC86, M(7148, 4)), (0xFC87, M(7152, 4)),
+    (0xFC88, M(7314, 4)), (0xFC89, M(7160, 4)), (0xFC8A, M(7318, 4)), (0xFC8B, M(7322, 4)),
+    (0xFC8C, M(7184, 4)), (0xFC8D, M(7326, 4)), (0xFC8E, M(7188, 4)), (0xFC8F, M(7192, 4)),
+    (0xFC90, M(7232, 4)), (0xFC91, M(7330, 4)), (0xFC92, M(7334, 4)), (0xFC93, M(7154, 4)),
+    (0xFC94, M(7170, 4)), (0xFC95, M(7220, 4)), (0xFC96, M(7210, 4)), (0xFC97, M(6888, 4)),
+    (0xFC98, M(6892, 4)), (0xFC99, M(7338, 4)), (0xFC9A, M(6896, 4)), (0xFC9B, M(7342, 4)),
+    (0xFC9C, M(6904, 4)), (0xFC9D, M(6908, 4)), (0xFC9E, M(6912, 4)), (0xFC9F, M(6916, 4)),
+    (0xFCA0, M(7346, 4)), (0xFCA1, M(6928, 4)), (0xFCA2, M(6932, 4)), (0xFCA3, M(6936, 4)),
+    (0xFCA4, M(6940, 4)), (0xFCA5, M(7350, 4)), (0xFCA6, M(6956, 4)), (0xFCA7, M(6968, 4)),
+    (0xFCA8, M(6972, 4)), (0xFCA9, M(6970, 4)), (0xFCAA, M(6976, 4)), (0xFCAB, M(6980, 4)),
+    (0xFCAC, M(6988, 4)), (0xFCAD, M(6992, 4)), (0xFCAE, M(6996, 4)), (0xFCAF, M(7000, 4)),
+    (0xFCB0, M(7004, 4)), (0xFCB1, M(7008, 4)), (0xFCB2, M(7354, 4)), (0xFCB3, M(7012, 4)),
+    (0xFCB4, M(7016, 4)), (0xFCB5, M(7020, 4)), (0xFCB6, M(7024, 4)), (0xFCB7, M(7028, 4)),
+    (0xFCB8, M(7032, 4)), (0xFCB9, M(7040, 4)), (0xFCBA, M(7044, 4)), (0xFCBB, M(7048, 4)),
+    (0xFCBC, M(7052, 4)), (0xFCBD, M(7056, 4)), (0xFCBE, M(7060, 4)), (0xFCBF, M(7064, 4)),
+    (0xFCC0, M(7068, 4)), (0xFCC1, M(7072, 4)), (0xFCC2, M(7084, 4)), (0xFCC3, M(7088, 4)),
+    (0xFCC4, M(7104, 4)), (0xFCC5, M(7108, 4)), (0xFCC6, M(7112, 4)), (0xFCC7, M(7116, 4)),
+    (0xFCC8, M(7120, 4)), (0xFCC9, M(7132, 4)), (0xFCCA, M(7136, 4)), (0xFCCB, M(7140, 4)),
+    (0xFCCC, M(7144, 4)), (0xFCCD, M(7358, 4)), (0xFCCE, M(7156, 4)), (0xFCCF, M(6974, 4)),
+    (0xFCD0, M(6978, 4)), (0xFCD1, M(7160, 4)), (0xFCD2, M(7172, 4)), (0xFCD3, M(7176, 4)),
+    (0xFCD4, M(7180, 4)), (0xFCD5, M(7184, 4)), (0xFCD6, M(7362, 4)), (0xFCD7, M(7196, 4)),
+    (0xFCD8, M(7200, 4)), (0xFCD9, M(7366, 4)), (0xFCDA, M(6966, 4)), (0xFCDB, M(7212, 4)),
+    (0xFCDC, M(7216, 4)), (0xFCDD, M(7154, 4)), (0xFCDE, M(7194, 4)), (0xFCDF, M(6896, 4)),
+    (0xFCE0, M(7342, 4)), (0xFCE1, M(6916, 4)), (0xFCE2, M(7346, 4)), (0xFCE3, M(6940, 4)),
+    (0xFCE4, M(7350, 4)), (0xFCE5, M(6956, 4)), (0xFCE6, M(7370, 4)), (0xFCE7, M(7004, 4)),
+    (0xFCE8, M(7374, 4)), (0xFCE9, M(7378, 4)), (0xFCEA, M(7382, 4)), (0xFCEB, M(7116, 4)),
+    (0xFCEC, M(7120, 4)), (0xFCED, M(7144, 4)), (0xFCEE, M(7184, 4)), (0xFCEF, M(7362, 4)),
+    (0xFCF0, M(7154, 4)), (0xFCF1, M(7194, 4)), (0xFCF2, M(7386, 6)), (0xFCF3, M(7392, 6)),
+    (0xFCF4, M(7398, 6)), (0xFCF5, M(7404, 4)), (0xFCF6, M(7408, 4)), (0xFCF7, M(7412, 4)),
+    (0xFCF8, M(7416, 4)), (0xFCF9, M(7420, 4)), (0xFCFA, M(7424, 4)), (0xFCFB, M(7428, 4)),
+    (0xFCFC, M(7432, 4)), (0xFCFD, M(7436, 4)), (0xFCFE, M(7440, 4)), (0xFCFF, M(7444, 4)),
+    (0xFD00, M(7214, 4)), (0xFD01, M(7448, 4)), (0xFD02, M(7452, 4)), (0xFD03, M(7456, 4)),
+    (0xFD04, M(7218, 4)), (0xFD05, M(7460, 4)), (0xFD06, M(7464, 4)), (0xFD07, M(7468, 4)),
+    (0xFD08, M(7472, 4)), (0xFD09, M(7476, 4)), (0xFD0A, M(7480, 4)), (0xFD0B, M(7484, 4)),
+    (0xFD0C, M(7378, 4)), (0xFD0D, M(7488, 4)), (0xFD0E, M(7492, 4)), (0xFD0F, M(7496, 4)),
+    (0xFD10, M(7500, 4)), (0xFD11, M(7404, 4)), (0xFD12, M(7408, 4)), (0xFD13, M(7412, 4)),
+    (0xFD14, M(7416, 4)), (0xFD15, M(7420, 4)), (0xFD16, M(7424, 4)), (0xFD17, M(7428, 4)),
+    (0xFD18, M(7432, 4)), (0xFD19, M(7436, 4)), (0xFD1A, M(7440, 4)), (0xFD1B, M(7444, 4)),
+    (0xFD1C, M(7214, 4)), (0xFD1D, M(7448, 4)), (0xFD1E, M(7452, 4)), (0xFD1F, M(7456, 4)),
+    (0xFD20, M(7218, 4)), (0xFD21, M(7460, 4)), (0xFD22, M(7464, 4)), (0xFD23, M(7468, 4)),
+    (0xFD24, M(7472, 4)), (0xFD25, M(7476, 4)), (0xFD26, M(7480, 4)), (0xFD27, M(7484, 4)),
+    (0xFD28, M(7378, 4)), (0xFD29, M(7488, 4)), (0xFD2A, M(7492, 4)), (0xFD2B, M(7496, 4)),
+    (0xFD2C, M(7500, 4)), (0xFD2D, M(7476, 4)), (0xFD2E, M(7480, 4)), (0xFD2F, M(7484, 4)),
+    (0xFD30, M(7378, 4)), (0xFD31, M(7374, 4)), (0xFD32, M(7382, 4)), (0xFD33, M(7036, 4)),
+    (0xFD34, M(6992, 4)), (0xFD35, M(6996, 4)), (0xFD36, M(7000, 4)), (0xFD37, M(7476, 4)),
+    (0xFD38, M(7480, 4)), (0xFD39, M(7484, 4)), (0xFD3A, M(7036, 4)), (0xFD3B, M(7040, 4)),
+    (0xFD3C, M(7504, 4)), (0xFD3E, V), (0xFD40, D), (0xFD50, M(7508, 6)),
+    (0xFD51, M(7514, 6)), (0xFD53, M(7520, 6)), (0xFD54, M(7526, 6)), (0xFD55, M(7532, 6)),
+    (0xFD56, M(7538, 6)), (0xFD57, M(7544, 6)), (0xFD58, M(6972, 6)), (0xFD5A, M(7550, 6)),
+    (0xFD5B, M(7556, 6)), (0xFD5C, M(7562, 6)), (0xFD5D, M(7568, 6)), (0xFD5E, M(7574, 6)),
+    (0xFD5F, M(7580, 6)), (0xFD61, M(7586, 6)), (0xFD62, M(7592, 6)), (0xFD64, M(7598, 6)),
+    (0xFD66, M(7604, 6)), (0xFD67, M(7610, 6)), (0xFD69, M(7616, 6)), (0xFD6A, M(7622, 6)),
+    (0xFD6C, M(7628, 6)), (0xFD6E, M(7634, 6)), (0xFD6F, M(7640, 6)), (0xFD71, M(7646, 6)),
+    (0xFD73, M(7652, 6)), (0xFD74, M(7658, 6)), (0xFD75, M(7664, 6)), (0xFD76, M(7670, 6)),
+    (0xFD78, M(7676, 6)), (0xFD79, M(7682, 6)), (0xFD7A, M(7688, 6)), (0xFD7B, M(7694, 6)),
+    (0xFD7C, M(7700, 6)), (0xFD7E, M(7706, 6)), (0xFD7F, M(7712, 6)), (0xFD80, M(7718, 6)),
+    (0xFD81, M(7724, 6)), (0xFD82, M(7730, 6)), (0xFD83, M(7736, 6)), (0xFD85, M(7742, 6)),
+    (0xFD87, M(7748, 6)), (0xFD89, M(7754, 6)), (0xFD8A, M(6974, 6)), (0xFD8B, M(7760, 6)),
+    (0xFD8C, M(7766, 6)), (0xFD8D, M(7156, 6)), (0xFD8E, M(6978, 6)), (0xFD8F, M(7772, 6)),
+    (0xFD90, D), (0xFD92, M(7778, 6)), (0xFD93, M(7784, 6)), (0xFD94, M(7790, 6)),
+    (0xFD95, M(7796, 6)), (0xFD96, M(7802, 6)), (0xFD97, M(7808, 6)), (0xFD99, M(7814, 6)),
+    (0xFD9A, M(7820, 6)), (0xFD9B, M(7826, 6)), (0xFD9C, M(7832, 6)), (0xFD9E, M(7838, 6)),
+    (0xFD9F, M(7844, 6)), (0xFDA0, M(7850, 6)), (0xFDA1, M(7856, 6)), (0xFDA2, M(7862, 6)),
+    (0xFDA3, M(7868, 6)), (0xFDA4, M(7874, 6)), (0xFDA5, M(7880, 6)), (0xFDA6, M(7886, 6)),
+    (0xFDA7, M(7892, 6)), (0xFDA8, M(7898, 6)), (0xFDA9, M(7904, 6)), (0xFDAA, M(7910, 6)),
+    (0xFDAB, M(7916, 6)), (0xFDAC, M(7922, 6)), (0xFDAD, M(7928, 6)), (0xFDAE, M(7212, 6)),
+    (0xFDAF, M(7934, 6)), (0xFDB0, M(7940, 6)), (0xFDB1, M(7946, 6)), (0xFDB2, M(7952, 6)),
+    (0xFDB3, M(7958, 6)), (0xFDB4, M(7706, 6)), (0xFDB5, M(7718, 6)), (0xFDB6, M(7964, 6)),
+    (0xFDB7, M(7970, 6)), (0xFDB8, M(7976, 6)), (0xFDB9, M(7982, 6)), (0xFDBA, M(7988, 6)),
+    (0xFDBB, M(7994, 6)), (0xFDBC, M(7988, 6)), (0xFDBD, M(7976, 6)), (0xFDBE, M(8000, 6)),
+    (0xFDBF, M(8006, 6)), (0xFDC0, M(8012, 6)), (0xFDC1, M(8018, 6)), (0xFDC2, M(8024, 6)),
+    (0xFDC3, M(7994, 6)), (0xFDC4, M(7664, 6)), (0xFDC5, M(7604, 6)), (0xFDC6, M(8030, 6)),
+    (0xFDC7, M(8036, 6)), (0xFDC8, D), (0xFDF0, M(8042, 6)), (0xFDF1, M(8048, 6)),
+    (0xFDF2, M(8054, 8)), (0xFDF3, M(8062, 8)), (0xFDF4, M(8070, 8)), (0xFDF5, M(8078, 8)),
+    (0xFDF6, M(8086, 8)), (0xFDF7, M(8094, 8)), (0xFDF8, M(8102, 8)), (0xFDF9, M(8110, 6)),
+    (0xFDFA, SM(8116, 33)), (0xFDFB, SM(8149, 15)), (0xFDFC, M(8164, 8)), (0xFDFD, V),
+    (0xFDFE, D), (0xFE00, I), (0xFE10, SM(8172, 1)), (0xFE11, M(8173, 3)),
+    (0xFE12, D), (0xFE13, SM(2067, 1)), (0xFE14, SM(418, 1)), (0xFE15, SM(1705, 1)),
+    (0xFE16, SM(1710, 1)), (0xFE17, R(0x3016)), (0xFE19, D), (0xFE20, V),
+    (0xFE30, D), (0xFE31, M(8176, 3)), (0xFE32, M(8179, 3)), (0xFE33, SM(8182, 1)),
+    (0xFE35, SM(1722, 1)), (0xFE36, SM(1723, 1)), (0xFE37, SM(8183, 1)), (0xFE38, SM(8184, 1)),
+    (0xFE39, R(0x3014)), (0xFE3B, R(0x3010)), (0xFE3D, R(0x300A)), (0xFE3F, R(0x3008)),
+    (0xFE41, R(0x300C)), (0xFE45, V), (0xFE47, SM(8185, 1)), (0xFE48, SM(8186, 1)),
+    (0xFE49, SM(1707, 3)), (0xFE4D, SM(8182, 1)), (0xFE50, SM(8172, 1)), (0xFE51, M(8173, 3)),
+    (0xFE52, D), (0xFE54, SM(418, 1)), (0xFE55, SM(2067, 1)), (0xFE56, SM(1710, 1)),
+    (0xFE57, SM(1705, 1)), (0xFE58, M(8176, 3)), (0xFE59, SM(1722, 1)), (0xFE5A, SM(1723, 1)),
+    (0xFE5B, SM(8183, 1)), (0xFE5C, SM(8184, 1)), (0xFE5D, R(0x3014)), (0xFE5F, SM(8187, 1)),
+    (0xFE60, SM(8188, 1)), (0xFE61, SM(8189, 1)), (0xFE62, SM(1717, 1)), (0xFE63, M(8190, 1)),
+    (0xFE64, SM(8191, 1)), (0xFE65, SM(8192, 1)), (0xFE66, SM(1721, 1)), (0xFE67, D),
+    (0xFE68, SM(8193, 1)), (0xFE69, SM(8194, 1)), (0xFE6A, SM(8195, 1)), (0xFE6B, SM(8196, 1)),
+    (0xFE6C, D), (0xFE70, SM(8197, 3)), (0xFE71, M(8200, 4)), (0xFE72, SM(7236, 3)),
+    (0xFE73, V), (0xFE74, SM(7241, 3)), (0xFE75, D), (0xFE76, SM(7246, 3)),
+    (0xFE77, M(7386, 4)), (0xFE78, SM(7251, 3)), (0xFE79, M(7392, 4)), (0xFE7A, SM(7256, 3)),
+    (0xFE7B, M(7398, 4)), (0xFE7C, SM(7261, 3)), (0xFE7D, M(8204, 4)), (0xFE7E, SM(8208, 3)),
+    (0xFE7F, M(8211, 4)), (0xFE80, R(0x621)), (0xFE82, R(0x622)), (0xFE84, R(0x623)),
+    (0xFE86, R(0x624)), (0xFE88, R(0x625)), (0xFE8A, M(6854, 2)), (0xFE8D, M(684, 2)),
+    (0xFE8F, M(6904, 2)), (0xFE93, M(8215, 2)), (0xFE95, M(6928, 2)), (0xFE99, M(6952, 2)),
+    (0xFE9D, M(6890, 2)), (0xFEA1, M(6894, 2)), (0xFEA5, M(6914, 2)), (0xFEA9, M(8076, 2)),
+    (0xFEAB, M(7224, 2)), (0xFEAD, M(7228, 2)), (0xFEAF, M(7272, 2)), (0xFEB1, M(6992, 2)),
+    (0xFEB5, M(7378, 2)), (0xFEB9, M(7008, 2)), (0xFEBD, M(7016, 2)), (0xFEC1, M(7032, 2)),
+    (0xFEC5, M(7040, 2)), (0xFEC9, M(7044, 2)), (0xFECD, M(7052, 2)), (0xFED1, M(7060, 2)),
+    (0xFED5, M(7084, 2)), (0xFED9, M(7100, 2)), (0xFEDD, M(7118, 2)), (0xFEE1, M(6898, 2)),
+    (0xFEE5, M(7172, 2)), (0xFEE9, M(7196, 2)), (0xFEED, M(688, 2)), (0xFEEF, M(6852, 2)),
+    (0xFEF1, M(696, 2)), (0xFEF5, M(8217, 4)), (0xFEF7, M(8221, 4)), (0xFEF9, M(8225, 4)),
+    (0xFEFB, M(8156, 4)), (0xFEFD, D), (0xFEFF, I), (0xFF00, D),
+    (0xFF01, SM(1705, 1)), (0xFF02, SM(8229, 1)), (0xFF03, SM(8187, 1)), (0xFF04, SM(8194, 1)),
+    (0xFF05, SM(8195, 1)), (0xFF06, SM(8188, 1)), (0xFF07, SM(8230, 1)), (0xFF08, SM(1722, 1)),
+    (0xFF09, SM(1723, 1)), (0xFF0A, SM(8189, 1)), (0xFF0B, SM(1717, 1)), (0xFF0C, SM(8172, 1)),
+    (0xFF0D, R(0x2D)), (0xFF0F, SM(1728, 1)), (0xFF10, R(0x30)), (0xFF1A, SM(2067, 1)),
+    (0xFF1B, SM(418, 1)), (0xFF1C, SM(8191, 1)), (0xFF1D, SM(1721, 1)), (0xFF1E, SM(8192, 1)),
+    (0xFF1F, SM(1710, 1)), (0xFF20, SM(8196, 1)), (0xFF21, R(0x61)), (0xFF3B, SM(8185, 1)),
+    (0xFF3C, SM(8193, 1)), (0xFF3D, SM(8186, 1)), (0xFF3E, SM(8231, 1)), (0xFF3F, SM(8182, 1)),
+    (0xFF40, SM(1647, 1)), (0xFF41, R(0x61)), (0xFF5B, SM(8183, 1)), (0xFF5C, SM(8232, 1)),
+    (0xFF5D, SM(8184, 1)), (0xFF5E, SM(8233, 1)), (0xFF5F, R(0x2985)), (0xFF61, M(2916, 1)),
+    (0xFF62, R(0x300C)), (0xFF64, M(8173, 3)), (0xFF65, M(8234, 3)), (0xFF66, M(8237, 3)),
+    (0xFF67, M(3652, 3)), (0xFF68, M(4156, 3)), (0xFF69, M(8240, 3)), (0xFF6A, M(4174, 3)),
+    (0xFF6B, M(3700, 3)), (0xFF6C, M(8243, 3)), (0xFF6D, M(3820, 3)), (0xFF6E, M(4357, 3)),
+    (0xFF6F, M(3766, 3)), (0xFF70, M(3637, 3)), (0xFF71, M(3559, 3)), (0xFF72, M(3562, 3)),
+    (0xFF73, M(3565, 3)), (0xFF74, M(3568, 3)), (0xFF75, R(0x30AA)), (0xFF77, M(3571, 3)),
+    (0xFF78, M(3574, 3)), (0xFF79, M(3577, 3)), (0xFF7A, M(2934, 3)), (0xFF7B, M(3580, 3)),
+    (0xFF7C, M(3583, 3)), (0xFF7D, M(3586, 3)), (0xFF7E, M(3589, 3)), (0xFF7F, M(3592, 3)),
+    (0xFF80, M(3595, 3)), (0xFF81, M(3598, 3)), (0xFF82, M(3601, 3)), (0xFF83, M(3604, 3)),
+    (0xFF84, M(2937, 3)), (0xFF85, R(0x30CA)), (0xFF8B, M(3607, 3)), (0xFF8C, M(3610, 3)),
+    (0xFF8D, M(3613, 3)), (0xFF8E, M(3616, 3)), (0xFF8F, R(0x30DE)), (0xFF94, M(3619, 3)),
+    (0xFF95, M(3622, 3)), (0xFF96, R(0x30E8)), (0xFF9C, M(3886, 3)), (0xFF9D, M(3658, 3)),
+    (0xFF9E, R(0x3099)), (0xFFA0, D), (0xFFA1, R(0x1100)), (0xFFA3, M(2940, 3)),
+    (0xFFA4, M(2943, 3)), (0xFFA5, R(0x11AC)), (0xFFA7, R(0x1103)), (0xFFAA, R(0x11B0)),
+    (0xFFB0, M(2946, 3)), (0xFFB1, R(0x1106)), (0xFFB4, M(2949, 3)), (0xFFB5, R(0x1109)),
+    (0xFFBF, D), (0xFFC2, R(0x1161)), (0xFFC8, D), (0xFFCA, R(0x1167)),
+    (0xFFD0, D), (0xFFD2, R(0x116D)), (0xFFD8, D), (0xFFDA, R(0x1173)),
+    (0xFFDD, D), (0xFFE0, R(0xA2)), (0xFFE2, M(8246, 2)), (0xFFE3, SM(5, 3)),
+    (0xFFE4, M(8248, 2)), (0xFFE5, M(8250, 2)), (0xFFE6, M(8252, 3)), (0xFFE7, D),
+    (0xFFE8, M(8255, 3)), (0xFFE9, R(0x2190)), (0xFFED, M(8258, 3)), (0xFFEE, M(8261, 3)),
+    (0xFFEF, D), (0x10000, V), (0x1000C, D), (0x1000D, V),
+    (0x10027, D), (0x10028, V), (0x1003B, D), (0x1003C, V),
+    (0x1003E, D), (0x1003F, V), (0x1004E, D), (0x10050, V),
+    (0x1005E, D), (0x10080, V), (0x100FB, D), (0x10100, V),
+    (0x10103, D), (0x10107, V), (0x10134, D), (0x10137, V),
+    (0x1018F, D), (0x10190, V), (0x1019D, D), (0x101A0, V),
+    (0x101A1, D), (0x101D0, V), (0x101FE, D), (0x10280, V),
+    (0x1029D, D), (0x102A0, V), (0x102D1, D), (0x102E0, V),
+    (0x102FC, D), (0x10300, V), (0x10324, D), (0x1032D, V),
+    (0x1034B, D), (0x10350, V), (0x1037B, D), (0x10380, V),
+    (0x1039E, D), (0x1039F, V), (0x103C4, D), (0x103C8, V),
+    (0x103D6, D), (0x10400, R(0x10428)), (0x10428, V), (0x1049E, D),
+    (0x104A0, V), (0x104AA, D), (0x104B0, R(0x104D8)), (0x104D4, D),
+    (0x104D8, V), (0x104FC, D), (0x10500, V), (0x10528, D),
+    (0x10530, V), (0x10564, D), (0x1056F, V), (0x10570, D),
+    (0x10600, V), (0x10737, D), (0x10740, V), (0x10756, D),
+    (0x10760, V), (0x10768, D), (0x10800, V), (0x10806, D),
+    (0x10808, V), (0x10809, D), (0x1080A, V), (0x10836, D),
+    (0x10837, V), (0x10839, D), (0x1083C, V), (0x1083D, D),
+    (0x1083F, V), (0x10856, D), (0x10857, V), (0x1089F, D),
+    (0x108A7, V), (0x108B0, D), (0x108E0, V), (0x108F3, D),
+    (0x108F4, V), (0x108F6, D), (0x108FB, V), (0x1091C, D),
+    (0x1091F, V), (0x1093A, D), (0x1093F, V), (0x10940, D),
+    (0x10980, V), (0x109B8, D), (0x109BC, V), (0x109D0, D),
+    (0x109D2, V), (0x10A04, D), (0x10A05, V), (0x10A07, D),
+    (0x10A0C, V), (0x10A14, D), (0x10A15, V), (0x10A18, D),
+    (0x10A19, V), (0x10A36, D), (0x10A38, V), (0x10A3B, D),
+    (0x10A3F, V), (0x10A49, D), (0x10A50, V), (0x10A59, D),
+    (0x10A60, V), (0x10AA0, D), (0x10AC0, V), (0x10AE7, D),
+    (0x10AEB, V), (0x10AF7, D), (0x10B00, V), (0x10B36, D),
+    (0x10B39, V), (0x10B56, D), (0x10B58, V), (0x10B73, D),
+    (0x10B78, V), (0x10B92, D), (0x10B99, V), (0x10B9D, D),
+    (0x10BA9, V), (0x10BB0, D), (0x10C00, V), (0x10C49, D),
+    (0x10C80, R(0x10CC0)), (0x10CB3, D), (0x10CC0, V), (0x10CF3, D),
+    (0x10CFA, V), (0x10D28, D), (0x10D30, V), (0x10D3A, D),
+    (0x10E60, V), (0x10E7F, D), (0x10E80, V), (0x10EAA, D),
+    (0x10EAB, V), (0x10EAE, D), (0x10EB0, V), (0x10EB2, D),
+    (0x10F00, V), (0x10F28, D), (0x10F30, V), (0x10F5A, D),
+    (0x10FB0, V), (0x10FCC, D), (0x10FE0, V), (0x10FF7, D),
+    (0x11000, V), (0x1104E, D), (0x11052, V), (0x11070, D),
+    (0x1107F, V), (0x110BD, D), (0x110BE, V), (0x110C2, D),
+    (0x110D0, V), (0x110E9, D), (0x110F0, V), (0x110FA, D),
+    (0x11100, V), (0x11135, D), (0x11136, V), (0x11148, D),
+    (0x11150, V), (0x11177, D), (0x11180, V), (0x111E0, D),
+    (0x111E1, V), (0x111F5, D), (0x11200, V), (0x11212, D),
+    (0x11213, V), (0x1123F, D), (0x11280, V), (0x11287, D),
+    (0x11288, V), (0x11289, D), (0x1128A, V), (0x1128E, D),
+    (0x1128F, V), (0x1129E, D), (0x1129F, V), (0x112AA, D),
+    (0x112B0, V), (0x112EB, D), (0x112F0, V), (0x112FA, D),
+    (0x11300, V), (0x11304, D), (0x11305, V), (0x1130D, D),
+    (0x1130F, V), (0x11311, D), (0x11313, V), (0x11329, D),
+    (0x1132A, V), (0x11331, D), (0x11332, V), (0x11334, D),
+    (0x11335, V), (0x1133A, D), (0x1133B, V), (0x11345, D),
+    (0x11347, V), (0x11349, D), (0x1134B, V), (0x1134E, D),
+    (0x11350, V), (0x11351, D), (0x11357, V), (0x11358, D),
+    (0x1135D, V), (0x11364, D), (0x11366, V), (0x1136D, D),
+    (0x11370, V), (0x11375, D), (0x11400, V), (0x1145C, D),
+    (0x1145D, V), (0x11462, D), (0x11480, V), (0x114C8, D),
+    (0x114D0, V), (0x114DA, D), (0x11580, V), (0x115B6, D),
+    (0x115B8, V), (0x115DE, D), (0x11600, V), (0x11645, D),
+    (0x11650, V), (0x1165A, D), (0x11660, V), (0x1166D, D),
+    (0x11680, V), (0x116B9, D), (0x116C0, V), (0x116CA, D),
+    (0x11700, V), (0x1171B, D), (0x1171D, V), (0x1172C, D),
+    (0x11730, V), (0x11740, D), (0x11800, V), (0x1183C, D),
+    (0x118A0, R(0x118C0)), (0x118C0, V), (0x118F3, D), (0x118FF, V),
+    (0x11907, D), (0x11909, V), (0x1190A, D), (0x1190C, V),
+    (0x11914, D), (0x11915, V), (0x11917, D), (0x11918, V),
+    (0x11936, D), (0x11937, V), (0x11939, D), (0x1193B, V),
+    (0x11947, D), (0x11950, V), (0x1195A, D), (0x119A0, V),
+    (0x119A8, D), (0x119AA, V), (0x119D8, D), (0x119DA, V),
+    (0x119E5, D), (0x11A00, V), (0x11A48, D), (0x11A50, V),
+    (0x11AA3, D), (0x11AC0, V), (0x11AF9, D), (0x11C00, V),
+    (0x11C09, D), (0x11C0A, V), (0x11C37, D), (0x11C38, V),
+    (0x11C46, D), (0x11C50, V), (0x11C6D, D), (0x11C70, V),
+    (0x11C90, D), (0x11C92, V), (0x11CA8, D), (0x11CA9, V),
+    (0x11CB7, D), (0x11D00, V), (0x11D07, D), (0x11D08, V),
+    (0x11D0A, D), (0x11D0B, V), (0x11D37, D), (0x11D3A, V),
+    (0x11D3B, D), (0x11D3C, V), (0x11D3E, D), (0x11D3F, V),
+    (0x11D48, D), (0x11D50, V), (0x11D5A, D), (0x11D60, V),
+    (0x11D66, D), (0x11D67, V), (0x11D69, D), (0x11D6A, V),
+    (0x11D8F, D), (0x11D90, V), (0x11D92, D), (0x11D93, V),
+    (0x11D99, D), (0x11DA0, V), (0x11DAA, D), (0x11EE0, V),
+    (0x11EF9, D), (0x11FB0, V), (0x11FB1, D), (0x11FC0, V),
+    (0x11FF2, D), (0x11FFF, V), (0x1239A, D), (0x12400, V),
+    (0x1246F, D), (0x12470, V), (0x12475, D), (0x12480, V),
+    (0x12544, D), (0x13000, V), (0x1342F, D), (0x14400, V),
+    (0x14647, D), (0x16800, V), (0x16A39, D), (0x16A40, V),
+    (0x16A5F, D), (0x16A60, V), (0x16A6A, D), (0x16A6E, V),
+    (0x16A70, D), (0x16AD0, V), (0x16AEE, D), (0x16AF0, V),
+    (0x16AF6, D), (0x16B00, V), (0x16B46, D), (0x16B50, V),
+    (0x16B5A, D), (0x16B5B, V), (0x16B62, D), (0x16B63, V),
+    (0x16B78, D), (0x16B7D, V), (0x16B90, D), (0x16E40, R(0x16E60)),
+    (0x16E60, V), (0x16E9B, D), (0x16F00, V), (0x16F4B, D),
+    (0x16F4F, V), (0x16F88, D), (0x16F8F, V), (0x16FA0, D),
+    (0x16FE0, V), (0x16FE5, D), (0x16FF0, V), (0x16FF2, D),
+    (0x17000, V), (0x187F8, D), (0x18800, V), (0x18CD6, D),
+    (0x18D00, V), (0x18D09, D), (0x1B000, V), (0x1B11F, D),
+    (0x1B150, V), (0x1B153, D), (0x1B164, V), (0x1B168, D),
+    (0x1B170, V), (0x1B2FC, D), (0x1BC00, V), (0x1BC6B, D),
+    (0x1BC70, V), (0x1BC7D, D), (0x1BC80, V), (0x1BC89, D),
+    (0x1BC90, V), (0x1BC9A, D), (0x1BC9C, V), (0x1BCA0, I),
+    (0x1BCA4, D), (0x1D000, V), (0x1D0F6, D), (0x1D100, V),
+    (0x1D127, D), (0x1D129, V), (0x1D15E, M(8264, 8)), (0x1D15F, M(8272, 8)),
+    (0x1D160, M(8280, 12)), (0x1D161, M(8292, 12)), (0x1D162, M(8304, 12)), (0x1D163, M(8316, 12)),
+    (0x1D164, M(8328, 12)), (0x1D165, V), (0x1D173, D), (0x1D17B, V),
+    (0x1D1BB, M(8340, 8)), (0x1D1BC, M(8348, 8)), (0x1D1BD, M(8356, 12)), (0x1D1BE, M(8368, 12)),
+    (0x1D1BF, M(8380, 12)), (0x1D1C0, M(8392, 12)), (0x1D1C1, V), (0x1D1E9, D),
+    (0x1D200, V), (0x1D246, D), (0x1D2E0, V), (0x1D2F4, D),
+    (0x1D300, V), (0x1D357, D), (0x1D360, V), (0x1D379, D),
+    (0x1D400, R(0x61)), (0x1D41A, R(0x61)), (0x1D434, R(0x61)), (0x1D44E, R(0x61)),
+    (0x1D455, D), (0x1D456, R(0x69)), (0x1D468, R(0x61)), (0x1D482, R(0x61)),
+    (0x1D49C, M(4, 1)), (0x1D49D, D), (0x1D49E, R(0x63)), (0x1D4A0, D),
+    (0x1D4A2, M(984, 1)), (0x1D4A3, D), (0x1D4A5, R(0x6A)), (0x1D4A7, D),
+    (0x1D4A9, R(0x6E)), (0x1D4AD, D), (0x1D4AE, R(0x73)), (0x1D4B6, R(0x61)),
+    (0x1D4BA, D), (0x1D4BB, M(1005, 1)), (0x1D4BC, D), (0x1D4BD, R(0x68)),
+    (0x1D4C4, D), (0x1D4C5, R(0x70)), (0x1D4D0, R(0x61)), (0x1D4EA, R(0x61)),
+    (0x1D504, R(0x61)), (0x1D506, D), (0x1D507, R(0x64)), (0x1D50B, D),
+    (0x1D50D, R(0x6A)), (0x1D515, D), (0x1D516, R(0x73)), (0x1D51D, D),
+    (0x1D51E, R(0x61)), (0x1D538, R(0x61)), (0x1D53A, D), (0x1D53B, R(0x64)),
+    (0x1D53F, D), (0x1D540, R(0x69)), (0x1D545, D), (0x1D546, M(17, 1)),
+    (0x1D547, D), (0x1D54A, R(0x73)), (0x1D551, D), (0x1D552, R(0x61)),
+    (0x1D56C, R(0x61)), (0x1D586, R(0x61)), (0x1D5A0, R(0x61)), (0x1D5BA, R(0x61)),
+    (0x1D5D4, R(0x61)), (0x1D5EE, R(0x61)), (0x1D608, R(0x61)), (0x1D622, R(0x61)),
+    (0x1D63C, R(0x61)), (0x1D656, R(0x61)), (0x1D670, R(0x61)), (0x1D68A, R(0x61)),
+    (0x1D6A4, M(8404, 2)), (0x1D6A5, M(8406, 2)), (0x1D6A6, D), (0x1D6A8, R(0x3B1)),
+    (0x1D6B9, M(434, 2)), (0x1D6BA, R(0x3C3)), (0x1D6C1, M(8408, 3)), (0x1D6C2, R(0x3B1)),
+    (0x1D6D3, M(474, 2)), (0x1D6D5, R(0x3C4)), (0x1D6DB, M(8411, 3)), (0x1D6DC, M(476, 2)),
+    (0x1D6DD, M(434, 2)), (0x1D6DE, M(470, 2)), (0x1D6DF, M(442, 2)), (0x1D6E0, M(472, 2)),
+    (0x1D6E1, M(444, 2)), (0x1D6E2, R(0x3B1)), (0x1D6F3, M(434, 2)), (0x1D6F4, R(0x3C3)),
+    (0x1D6FB, M(8408, 3)), (0x1D6FC, R(0x3B1)), (0x1D70D, M(474, 2)), (0x1D70F, R(0x3C4)),
+    (0x1D715, M(8411, 3)), (0x1D716, M(476, 2)), (0x1D717, M(434, 2)), (0x1D718, M(470, 2)),
+    (0x1D719, M(442, 2)), (0x1D71A, M(472, 2)), (0x1D71B, M(444, 2)), (0x1D71C, R(0x3B1)),
+    (0x1D72D, M(434, 2)), (0x1D72E, R(0x3C3)), (0x1D735, M(8408, 3)), (0x1D736, R(0x3B1)),
+    (0x1D747, M(474, 2)), (0x1D749, R(0x3C4)), (0x1D74F, M(8411, 3)), (0x1D750, M(476, 2)),
+    (0x1D751, M(434, 2)), (0x1D752, M(470, 2)), (0x1D753, M(442, 2)), (0x1D754, M(472, 2)),
+    (0x1D755, M(444, 2)), (0x1D756, R(0x3B1)), (0x1D767, M(434, 2)), (0x1D768, R(0x3C3)),
+    (0x1D76F, M(8408, 3)), (0x1D770, R(0x3B1)), (0x1D781, M(474, 2)), (0x1D783, R(0x3C4)),
+    (0x1D789, M(8411, 3)), (0x1D78A, M(476, 2)), (0x1D78B, M(434, 2)), (0x1D78C, M(470, 2)),
+    (0x1D78D, M(442, 2)), (0x1D78E, M(472, 2)), (0x1D78F, M(444, 2)), (0x1D790, R(0x3B1)),
+    (0x1D7A1, M(434, 2)), (0x1D7A2, R(0x3C3)), (0x1D7A9, M(8408, 3)), (0x1D7AA, R(0x3B1)),
+    (0x1D7BB, M(474, 2)), (0x1D7BD, R(0x3C4)), (0x1D7C3, M(8411, 3)), (0x1D7C4, M(476, 2)),
+    (0x1D7C5, M(434, 2)), (0x1D7C6, M(470, 2)), (0x1D7C7, M(442, 2)), (0x1D7C8, M(472, 2)),
+    (0x1D7C9, M(444, 2)), (0x1D7CA, M(450, 2)), (0x1D7CC, D), (0x1D7CE, R(0x30)),
+    (0x1D7D8, R(0x30)), (0x1D7E2, R(0x30)), (0x1D7EC, R(0x30)), (0x1D7F6, R(0x30)),
+    (0x1D800, V), (0x1DA8C, D), (0x1DA9B, V), (0x1DAA0, D),
+    (0x1DAA1, V), (0x1DAB0, D), (0x1E000, V), (0x1E007, D),
+    (0x1E008, V), (0x1E019, D), (0x1E01B, V), (0x1E022, D),
+    (0x1E023, V), (0x1E025, D), (0x1E026, V), (0x1E02B, D),
+    (0x1E100, V), (0x1E12D, D), (0x1E130, V), (0x1E13E, D),
+    (0x1E140, V), (0x1E14A, D), (0x1E14E, V), (0x1E150, D),
+    (0x1E2C0, V), (0x1E2FA, D), (0x1E2FF, V), (0x1E300, D),
+    (0x1E800, V), (0x1E8C5, D), (0x1E8C7, V), (0x1E8D7, D),
+    (0x1E900, R(0x1E922)), (0x1E922, V), (0x1E94C, D), (0x1E950, V),
+    (0x1E95A, D), (0x1E95E, V), (0x1E960, D), (0x1EC71, V),
+    (0x1ECB5, D), (0x1ED01, V), (0x1ED3E, D), (0x1EE00, R(0x627)),
+    (0x1EE02, M(6890, 2)), (0x1EE03, M(8076, 2)), (0x1EE04, D), (0x1EE05, M(688, 2)),
+    (0x1EE06, M(7272, 2)), (0x1EE07, M(6894, 2)), (0x1EE08, M(7032, 2)), (0x1EE09, M(696, 2)),
+    (0x1EE0A, R(0x643)), (0x1EE0E, M(6992, 2)), (0x1EE0F, M(7044, 2)), (0x1EE10, M(7060, 2)),
+    (0x1EE11, M(7008, 2)), (0x1EE12, M(7084, 2)), (0x1EE13, M(7228, 2)), (0x1EE14, M(7378, 2)),
+    (0x1EE15, R(0x62A)), (0x1EE17, M(6914, 2)), (0x1EE18, M(7224, 2)), (0x1EE19, M(7016, 2)),
+    (0x1EE1A, M(7040, 2)), (0x1EE1B, M(7052, 2)), (0x1EE1C, M(8414, 2)), (0x1EE1D, M(6824, 2)),
+    (0x1EE1E, M(8416, 2)), (0x1EE1F, M(8418, 2)), (0x1EE20, D), (0x1EE21, M(6904, 2)),
+    (0x1EE22, M(6890, 2)), (0x1EE23, D), (0x1EE24, M(7196, 2)), (0x1EE25, D),
+    (0x1EE27, M(6894, 2)), (0x1EE28, D), (0x1EE29, M(696, 2)), (0x1EE2A, R(0x643)),
+    (0x1EE2E, M(6992, 2)), (0x1EE2F, M(7044, 2)), (0x1EE30, M(7060, 2)), (0x1EE31, M(7008, 2)),
+    (0x1EE32, M(7084, 2)), (0x1EE33, D), (0x1EE34, M(7378, 2)), (0x1EE35, R(0x62A)),
+    (0x1EE37, M(6914, 2)), (0x1EE38, D), (0x1EE39, M(7016, 2)), (0x1EE3A, D),
+    (0x1EE3B, M(7052, 2)), (0x1EE3C, D), (0x1EE42, M(6890, 2)), (0x1EE43, D),
+    (0x1EE47, M(6894, 2)), (0x1EE48, D), (0x1EE49, M(696, 2)), (0x1EE4A, D),
+    (0x1EE4B, M(7118, 2)), (0x1EE4C, D), (0x1EE4D, M(7172, 2)), (0x1EE4E, M(6992, 2)),
+    (0x1EE4F, M(7044, 2)), (0x1EE50, D), (0x1EE51, M(7008, 2)), (0x1EE52, M(7084, 2)),
+    (0x1EE53, D), (0x1EE54, M(7378, 2)), (0x1EE55, D), (0x1EE57, M(6914, 2)),
+    (0x1EE58, D), (0x1EE59, M(7016, 2)), (0x1EE5A, D), (0x1EE5B, M(7052, 2)),
+    (0x1EE5C, D), (0x1EE5D, M(6824, 2)), (0x1EE5E, D), (0x1EE5F, M(8418, 2)),
+    (0x1EE60, D), (0x1EE61, M(6904, 2)), (0x1EE62, M(6890, 2)), (0x1EE63, D),
+    (0x1EE64, M(7196, 2)), (0x1EE65, D), (0x1EE67, M(6894, 2)), (0x1EE68, M(7032, 2)),
+    (0x1EE69, M(696, 2)), (0x1EE6A, M(7100, 2)), (0x1EE6B, D), (0x1EE6C, R(0x645)),
+    (0x1EE6E, M(6992, 2)), (0x1EE6F, M(7044, 2)), (0x1EE70, M(7060, 2)), (0x1EE71, M(7008, 2)),
+    (0x1EE72, M(7084, 2)), (0x1EE73, D), (0x1EE74, M(7378, 2)), (0x1EE75, R(0x62A)),
+    (0x1EE77, M(6914, 2)), (0x1EE78, D), (0x1EE79, M(7016, 2)), (0x1EE7A, M(7040, 2)),
+    (0x1EE7B, M(7052, 2)), (0x1EE7C, M(8414, 2)), (0x1EE7D, D), (0x1EE7E, M(8416, 2)),
+    (0x1EE7F, D), (0x1EE80, R(0x627)), (0x1EE82, M(6890, 2)), (0x1EE83, M(8076, 2)),
+    (0x1EE84, R(0x647)), (0x1EE86, M(7272, 2)), (0x1EE87, M(6894, 2)), (0x1EE88, M(7032, 2)),
+    (0x1EE89, M(696, 2)), (0x1EE8A, D), (0x1EE8B, R(0x644)), (0x1EE8E, M(6992, 2)),
+    (0x1EE8F, M(7044, 2)), (0x1EE90, M(7060, 2)), (0x1EE91, M(7008, 2)), (0x1EE92, M(7084, 2)),
+    (0x1EE93, M(7228, 2)), (0x1EE94, M(7378, 2)), (0x1EE95, R(0x62A)), (0x1EE97, M(6914, 2)),
+    (0x1EE98, M(7224, 2)), (0x1EE99, M(7016, 2)), (0x1EE9A, M(7040, 2)), (0x1EE9B, M(7052, 2)),
+    (0x1EE9C, D), (0x1EEA1, M(6904, 2)), (0x1EEA2, M(6890, 2)), (0x1EEA3, M(8076, 2)),
+    (0x1EEA4, D), (0x1EEA5, M(688, 2)), (0x1EEA6, M(7272, 2)), (0x1EEA7, M(6894, 2)),
+    (0x1EEA8, M(7032, 2)), (0x1EEA9, M(696, 2)), (0x1EEAA, D), (0x1EEAB, R(0x644)),
+    (0x1EEAE, M(6992, 2)), (0x1EEAF, M(7044, 2)), (0x1EEB0, M(7060, 2)), (0x1EEB1, M(7008, 2)),
+    (0x1EEB2, M(7084, 2)), (0x1EEB3, M(7228, 2)), (0x1EEB4, M(7378, 2)), (0x1EEB5, R(0x62A)),
+    (0x1EEB7, M(6914, 2)), (0x1EEB8, M(7224, 2)), (0x1EEB9, M(7016, 2)), (0x1EEBA, M(7040, 2)),
+    (0x1EEBB, M(7052, 2)), (0x1EEBC, D), (0x1EEF0, V), (0x1EEF2, D),
+    (0x1F000, V), (0x1F02C, D), (0x1F030, V), (0x1F094, D),
+    (0x1F0A0, V), (0x1F0AF, D), (0x1F0B1, V), (0x1F0C0, D),
+    (0x1F0C1, V), (0x1F0D0, D), (0x1F0D1, V), (0x1F0F6, D),
+    (0x1F101, SM(8420, 2)), (0x1F102, SM(8422, 2)), (0x1F103, SM(8424, 2)), (0x1F104, SM(8426, 2)),
+    (0x1F105, SM(8428, 2)), (0x1F106, SM(8430, 2)), (0x1F107, SM(8432, 2)), (0x1F108, SM(8434, 2)),
+    (0x1F109, SM(8436, 2)), (0x1F10A, SM(8438, 2)), (0x1F10B, V), (0x1F110, SM(1989, 3)),
+    (0x1F111, SM(1992, 3)), (0x1F112, SM(1995, 3)), (0x1F113, SM(1998, 3)), (0x1F114, SM(2001, 3)),
+    (0x1F115, SM(2004, 3)), (0x1F116, SM(2007, 3)), (0x1F117, SM(2010, 3)), (0x1F118, SM(2013, 3)),
+    (0x1F119, SM(2016, 3)), (0x1F11A, SM(2019, 3)), (0x1F11B, SM(2022, 3)), (0x1F11C, SM(2025, 3)),
+    (0x1F11D, SM(2028, 3)), (0x1F11E, SM(2031, 3)), (0x1F11F, SM(2034, 3)), (0x1F120, SM(2037, 3)),
+    (0x1F121, SM(2040, 3)), (0x1F122, SM(2043, 3)), (0x1F123, SM(2046, 3)), (0x1F124, SM(2049, 3)),
+    (0x1F125, SM(2052, 3)), (0x1F126, SM(2055, 3)), (0x1F127, SM(2058, 3)), (0x1F128, SM(2061, 3)),
+    (0x1F129, SM(2064, 3)), (0x1F12A, M(8440, 7)), (0x1F12B, M(998, 1)), (0x1F12C, M(369, 1)),
+    (0x1F12D, M(4629, 2)), (0x1F12E, M(8447, 2)), (0x1F12F, V), (0x1F130, R(0x61)),
+    (0x1F14A, M(8449, 2)), (0x1F14B, M(4815, 2)), (0x1F14C, M(8451, 2)), (0x1F14D, M(1263, 2)),
+    (0x1F14E, M(8453, 3)), (0x1F14F, M(8456, 2)), (0x1F150, V), (0x1F16A, M(4740, 2)),
+    (0x1F16B, M(4631, 2)), (0x1F16C, M(8458, 2)), (0x1F16D, V), (0x1F190, M(8460, 2)),
+    (0x1F191, V), (0x1F1AE, D), (0x1F1E6, V), (0x1F200, M(8462, 6)),
+    (0x1F201, M(8468, 6)), (0x1F202, M(3580, 3)), (0x1F203, D), (0x1F210, M(2463, 3)),
+    (0x1F211, M(8474, 3)), (0x1F212, M(8477, 3)), (0x1F213, M(4030, 3)), (0x1F214, M(2292, 3)),
+    (0x1F215, M(8480, 3)), (0x1F216, M(8483, 3)), (0x1F217, M(3036, 3)), (0x1F218, M(8486, 3)),
+    (0x1F219, M(8489, 3)), (0x1F21A, M(8492, 3)), (0x1F21B, M(5914, 3)), (0x1F21C, M(8495, 3)),
+    (0x1F21D, M(8498, 3)), (0x1F21E, M(8501, 3)), (0x1F21F, M(8504, 3)), (0x1F220, M(8507, 3)),
+    (0x1F221, M(8510, 3)), (0x1F222, M(2571, 3)), (0x1F223, M(8513, 3)), (0x1F224, M(8516, 3)),
+    (0x1F225, M(8519, 3)), (0x1F226, M(8522, 3)), (0x1F227, M(8525, 3)), (0x1F228, M(8528, 3)),
+    (0x1F229, M(2274, 3)), (0x1F22A, M(3012, 3)), (0x1F22B, M(8531, 3)), (0x1F22C, M(3459, 3)),
+    (0x1F22D, M(3021, 3)), (0x1F22E, M(3462, 3)), (0x1F22F, M(8534, 3)), (0x1F230, M(2739, 3)),
+    (0x1F231, M(8537, 3)), (0x1F232, M(8540, 3)), (0x1F233, M(8543, 3)), (0x1F234, M(8546, 3)),
+    (0x1F235, M(8549, 3)), (0x1F236, M(3294, 3)), (0x1F237, M(2493, 3)), (0x1F238, M(8552, 3)),
+    (0x1F239, M(8555, 3)), (0x1F23A, M(8558, 3)), (0x1F23B, M(8561, 3)), (0x1F23C, D),
+    (0x1F240, M(8564, 9)), (0x1F241, M(8573, 9)), (0x1F242, M(8582, 9)), (0x1F243, M(8591, 9)),
+    (0x1F244, M(8600, 9)), (0x1F245, M(8609, 9)), (0x1F246, M(8618, 9)), (0x1F247, M(8627, 9)),
+    (0x1F248, M(8636, 9)), (0x1F249, D), (0x1F250, M(8645, 3)), (0x1F251, M(8648, 3)),
+    (0x1F252, D), (0x1F260, V), (0x1F266, D), (0x1F300, V),
+    (0x1F6D8, D), (0x1F6E0, V), (0x1F6ED, D), (0x1F6F0, V),
+    (0x1F6FD, D), (0x1F700, V), (0x1F774, D), (0x1F780, V),
+    (0x1F7D9, D), (0x1F7E0, V), (0x1F7EC, D), (0x1F800, V),
+    (0x1F80C, D), (0x1F810, V), (0x1F848, D), (0x1F850, V),
+    (0x1F85A, D), (0x1F860, V), (0x1F888, D), (0x1F890, V),
+    (0x1F8AE, D), (0x1F8B0, V), (0x1F8B2, D), (0x1F900, V),
+    (0x1F979, D), (0x1F97A, V), (0x1F9CC, D), (0x1F9CD, V),
+    (0x1FA54, D), (0x1FA60, V), (0x1FA6E, D), (0x1FA70, V),
+    (0x1FA75, D), (0x1FA78, V), (0x1FA7B, D), (0x1FA80, V),
+    (0x1FA87, D), (0x1FA90, V), (0x1FAA9, D), (0x1FAB0, V),
+    (0x1FAB7, D), (0x1FAC0, V), (0x1FAC3, D), (0x1FAD0, V),
+    (0x1FAD7, D), (0x1FB00, V), (0x1FB93, D), (0x1FB94, V),
+    (0x1FBCB, D), (0x1FBF0, R(0x30)), (0x1FBFA, D), (0x20000, V),
+    (0x2A6DE, D), (0x2A700, V), (0x2B735, D), (0x2B740, V),
+    (0x2B81E, D), (0x2B820, V), (0x2CEA2, D), (0x2CEB0, V),
+    (0x2EBE1, D), (0x2F800, M(8651, 3)), (0x2F801, M(8654, 3)), (0x2F802, M(8657, 3)),
+    (0x2F803, M(8660, 4)), (0x2F804, M(8664, 3)), (0x2F805, M(6193, 3)), (0x2F806, M(8667, 3)),
+    (0x2F807, M(8670, 3)), (0x2F808, M(8673, 3)), (0x2F809, M(8676, 3)), (0x2F80A, M(6196, 3)),
+    (0x2F80B, M(8679, 3)), (0x2F80C, M(8682, 3)), (0x2F80D, M(8685, 4)), (0x2F80E, M(6199, 3)),
+    (0x2F80F, M(8689, 3)), (0x2F810, M(8692, 3)), (0x2F811, M(8695, 3)), (0x2F812, M(8698, 4)),
+    (0x2F813, M(8702, 3)), (0x2F814, M(8705, 3)), (0x2F815, M(8501, 3)), (0x2F816, M(8708, 4)),
+    (0x2F817, M(8712, 3)), (0x2F818, M(8715, 3)), (0x2F819, M(8718, 3)), (0x2F81A, M(8721, 3)),
+    (0x2F81B, M(6359, 3)), (0x2F81C, M(8724, 4)), (0x2F81D, M(2322, 3)), (0x2F81E, M(8728, 3)),
+    (0x2F81F, M(8731, 3)), (0x2F820, M(8734, 3)), (0x2F821, M(8737, 3)), (0x2F822, M(8555, 3)),
+    (0x2F823, M(8740, 3)), (0x2F824, M(8743, 3)), (0x2F825, M(6374, 3)), (0x2F826, M(6202, 3)),
+    (0x2F827, M(6205, 3)), (0x2F828, M(6377, 3)), (0x2F829, R(0x5305)), (0x2F82B, M(5659, 3)),
+    (0x2F82C, M(8746, 3)), (0x2F82D, M(6208, 3)), (0x2F82E, M(8749, 3)), (0x2F82F, M(8752, 3)),
+    (0x2F830, M(8755, 3)), (0x2F831, M(8758, 3)), (0x2F834, M(8761, 4)), (0x2F835, M(8765, 3)),
+    (0x2F836, M(8768, 3)), (0x2F837, M(8771, 3)), (0x2F838, M(8774, 4)), (0x2F839, M(8778, 3)),
+    (0x2F83A, M(8781, 3)), (0x2F83B, M(8784, 3)), (0x2F83C, M(8787, 3)), (0x2F83D, M(8790, 3)),
+    (0x2F83E, M(8793, 3)), (0x2F83F, M(8796, 3)), (0x2F840, M(8799, 3)), (0x2F841, M(8802, 3)),
+    (0x2F842, M(8805, 3)), (0x2F843, M(8808, 3)), (0x2F844, M(8811, 3)), (0x2F845, M(8814, 3)),
+    (0x2F847, M(6383, 3)), (0x2F848, M(8817, 3)), (0x2F849, M(8820, 3)), (0x2F84A, M(8823, 3)),
+    (0x2F84B, M(8826, 3)), (0x2F84C, M(6214, 3)), (0x2F84D, M(8829, 3)), (0x2F84E, M(8832, 3)),
+    (0x2F84F, M(8835, 3)), (0x2F850, M(6094, 3)), (0x2F851, M(8838, 3)), (0x2F852, M(8841, 3)),
+    (0x2F853, M(8844, 3)), (0x2F854, M(8847, 3)), (0x2F855, M(8850, 3)), (0x2F856, M(8853, 3)),
+    (0x2F857, M(8856, 3)), (0x2F858, M(8859, 3)), (0x2F859, M(8862, 4)), (0x2F85A, M(8866, 3)),
+    (0x2F85B, M(8869, 3)), (0x2F85C, M(8872, 3)), (0x2F85D, M(8480, 3)), (0x2F85E, M(8875, 3)),
+    (0x2F85F, M(8878, 3)), (0x2F860, M(8881, 4)), (0x2F861, M(8885, 4)), (0x2F862, M(8889, 3)),
+    (0x2F863, M(8892, 3)), (0x2F864, M(8895, 3)), (0x2F865, M(8898, 3)), (0x2F866, M(8901, 3)),
+    (0x2F867, M(8904, 3)), (0x2F868, D), (0x2F869, M(8907, 3)), (0x2F86A, M(8910, 3)),
+    (0x2F86C, M(8913, 4)), (0x2F86D, M(8917, 3)), (0x2F86E, M(8920, 3)), (0x2F86F, M(5647, 3)),
+    (0x2F870, M(8923, 3)), (0x2F871, M(8926, 4)), (0x2F872, M(8930, 3)), (0x2F873, M(8933, 3)),
+    (0x2F874, D), (0x2F875, M(2400, 3)), (0x2F876, M(8936, 3)), (0x2F877, M(8939, 3)),
+    (0x2F878, M(2406, 3)), (0x2F879, M(8942, 3)), (0x2F87A, M(8945, 3)), (0x2F87B, M(8948, 4)),
+    (0x2F87C, M(8952, 3)), (0x2F87D, M(8955, 4)), (0x2F87E, M(8959, 3)), (0x2F87F, M(8962, 3)),
+    (0x2F880, M(8965, 3)), (0x2F881, R(0x5DE1)), (0x2F883, M(8968, 3)), (0x2F884, M(8971, 3)),
+    (0x2F885, M(8974, 3)), (0x2F886, M(8977, 3)), (0x2F887, M(8980, 3)), (0x2F888, M(8983, 3)),
+    (0x2F889, M(8986, 4)), (0x2F88A, M(8990, 3)), (0x2F88B, M(8993, 3)), (0x2F88C, M(8996, 3)),
+    (0x2F88D, M(8999, 3)), (0x2F88E, M(5491, 3)), (0x2F88F, M(9002, 4)), (0x2F890, M(2436, 3)),
+    (0x2F891, M(9006, 4)), (0x2F893, M(9010, 3)), (0x2F894, M(9013, 3)), (0x2F896, M(9016, 3)),
+    (0x2F897, M(9019, 4)), (0x2F898, M(9023, 4)), (0x2F899, M(9027, 3)), (0x2F89A, M(9030, 3)),
+    (0x2F89B, M(9033, 3)), (0x2F89C, M(9036, 3)), (0x2F89D, M(9039, 3)), (0x2F89E, M(9042, 3)),
+    (0x2F89F, M(9045, 3)), (0x2F8A0, M(9048, 3)), (0x2F8A1, M(9051, 3)), (0x2F8A2, M(9054, 3)),
+    (0x2F8A3, M(6229, 3)), (0x2F8A4, M(9057, 4)), (0x2F8A5, M(9061, 3)), (0x2F8A6, M(9064, 3)),
+    (0x2F8A7, M(9067, 3)), (0x2F8A8, M(6419, 3)), (0x2F8A9, M(9067, 3)), (0x2F8AA, M(9070, 3)),
+    (0x2F8AB, M(6235, 3)), (0x2F8AC, M(9073, 3)), (0x2F8AD, M(9076, 3)), (0x2F8AE, M(9079, 3)),
+    (0x2F8AF, M(9082, 3)), (0x2F8B0, M(6238, 3)), (0x2F8B1, M(5410, 3)), (0x2F8B2, M(4643, 3)),
+    (0x2F8B3, M(9085, 3)), (0x2F8B4, M(9088, 3)), (0x2F8B5, M(9091, 3)), (0x2F8B6, M(9094, 3)),
+    (0x2F8B7, M(9097, 3)), (0x2F8B8, M(9100, 4)), (0x2F8B9, M(9104, 3)), (0x2F8BA, M(9107, 3)),
+    (0x2F8BB, M(9110, 3)), (0x2F8BC, M(9113, 3)), (0x2F8BD, M(9116, 3)), (0x2F8BE, M(9119, 4)),
+    (0x2F8BF, M(9123, 3)), (0x2F8C0, M(9126, 3)), (0x2F8C1, M(9129, 3)), (0x2F8C2, M(9132, 3)),
+    (0x2F8C3, M(9135, 3)), (0x2F8C4, M(9138, 3)), (0x2F8C5, M(9141, 3)), (0x2F8C6, M(9144, 3)),
+    (0x2F8C7, M(9147, 3)), (0x2F8C8, M(6241, 3)), (0x2F8C9, M(9150, 3)), (0x2F8CA, M(9153, 4)),
+    (0x2F8CB, M(9157, 3)), (0x2F8CC, M(9160, 3)), (0x2F8CD, M(9163, 3)), (0x2F8CE, M(9166, 3)),
+    (0x2F8CF, M(6247, 3)), (0x2F8D0, M(9169, 3)), (0x2F8D1, M(9172, 3)), (0x2F8D2, M(9175, 3)),
+    (0x2F8D3, M(9178, 3)), (0x2F8D4, M(9181, 3)), (0x2F8D5, M(9184, 3)), (0x2F8D6, M(9187, 3)),
+    (0x2F8D7, M(9190, 3)), (0x2F8D8, M(5494, 3)), (0x2F8D9, M(6443, 3)), (0x2F8DA, M(9193, 3)),
+    (0x2F8DB, M(9196, 3)), (0x2F8DC, M(9199, 3)), (0x2F8DD, M(9202, 4)), (0x2F8DE, M(9206, 3)),
+    (0x2F8DF, M(9209, 3)), (0x2F8E0, M(9212, 3)), (0x2F8E1, M(9215, 3)), (0x2F8E2, M(6250, 3)),
+    (0x2F8E3, M(9218, 4)), (0x2F8E4, M(9222, 3)), (0x2F8E5, M(9225, 3)), (0x2F8E6, M(9228, 3)),
+    (0x2F8E7, M(6572, 3)), (0x2F8E8, M(9231, 3)), (0x2F8E9, M(9234, 3)), (0x2F8EA, M(9237, 3)),
+    (0x2F8EB, M(9240, 3)), (0x2F8EC, M(9243, 4)), (0x2F8ED, M(9247, 3)), (0x2F8EE, M(9250, 3)),
+    (0x2F8EF, M(9253, 3)), (0x2F8F0, M(9256, 4)), (0x2F8F1, M(9260, 3)), (0x2F8F2, M(9263, 3)),
+    (0x2F8F3, M(9266, 3)), (0x2F8F4, M(9269, 3)), (0x2F8F5, R(0x6BBA)), (0x2F8F7, M(9272, 4)),
+    (0x2F8F8, M(9276, 4)), (0x2F8F9, M(9280, 4)), (0x2F8FA, M(9284, 3)), (0x2F8FB, M(9287, 4)),
+    (0x2F8FC, M(9291, 3)), (0x2F8FD, M(9294, 3)), (0x2F8FE, M(9297, 3)), (0x2F8FF, M(9300, 3)),
+    (0x2F900, M(9303, 3)), (0x2F901, M(6253, 3)), (0x2F902, M(5944, 3)), (0x2F903, M(9306, 3)),
+    (0x2F904, M(9309, 3)), (0x2F905, M(9312, 3)), (0x2F906, M(9315, 4)), (0x2F907, M(9319, 3)),
+    (0x2F908, M(9322, 3)), (0x2F909, M(9325, 3)), (0x2F90A, M(9328, 3)), (0x2F90B, M(6452, 3)),
+    (0x2F90C, M(9331, 3)), (0x2F90D, M(9334, 4)), (0x2F90E, M(9338, 3)), (0x2F90F, M(9341, 3)),
+    (0x2F910, M(9344, 4)), (0x2F911, M(9348, 4)), (0x2F912, M(9352, 3)), (0x2F913, M(9355, 3)),
+    (0x2F914, M(6455, 3)), (0x2F915, M(9358, 3)), (0x2F916, M(9361, 3)), (0x2F917, M(9364, 3)),
+    (0x2F918, M(9367, 3)), (0x2F919, M(9370, 3)), (0x2F91A, M(9373, 3)), (0x2F91B, M(9376, 4)),
+    (0x2F91C, M(9380, 3)), (0x2F91D, M(9383, 4)), (0x2F91E, M(9387, 3)), (0x2F91F, D),
+    (0x2F920, M(9390, 3)), (0x2F921, M(6461, 3)), (0x2F922, M(9393, 3)), (0x2F923, M(9396, 4)),
+    (0x2F924, M(9400, 3)), (0x2F925, M(9403, 3)), (0x2F926, M(9406, 4)), (0x2F927, M(9410, 4)),
+    (0x2F928, M(9414, 3)), (0x2F929, M(9417, 3)), (0x2F92A, M(9420, 3)), (0x2F92B, M(9423, 3)),
+    (0x2F92C, M(9426, 3)), (0x2F92E, M(9429, 3)), (0x2F92F, M(9432, 3)), (0x2F930, M(6467, 3)),
+    (0x2F931, M(9435, 3)), (0x2F932, M(9438, 3)), (0x2F933, M(9441, 3)), (0x2F934, M(9444, 3)),
+    (0x2F935, M(9447, 4)), (0x2F936, M(9451, 3)), (0x2F937, M(9454, 4)), (0x2F938, M(5656, 3)),
+    (0x2F939, M(9458, 4)), (0x2F93A, M(9462, 3)), (0x2F93B, M(9465, 4)), (0x2F93C, M(9469, 4)),
+    (0x2F93D, M(9473, 4)), (0x2F93E, M(9477, 3)), (0x2F93F, M(9480, 3)), (0x2F940, M(6485, 3)),
+    (0x2F941, M(9483, 4)), (0x2F942, M(9487, 4)), (0x2F943, M(9491, 4)), (0x2F944, M(9495, 4)),
+    (0x2F945, R(0x771E)), (0x2F947, M(9499, 3)), (0x2F948, M(6488, 3)), (0x2F949, M(6578, 3)),
+    (0x2F94A, M(9502, 3)), (0x2F94B, M(9505, 3)), (0x2F94C, M(9508, 3)), (0x2F94D, M(9511, 4)),
+    (0x2F94E, M(9515, 3)), (0x2F94F, M(5545, 3)), (0x2F950, M(6494, 3)), (0x2F951, M(9518, 3)),
+    (0x2F952, M(9521, 4)), (0x2F953, M(6283, 3)), (0x2F954, M(9525, 4)), (0x2F955, M(9529, 4)),
+    (0x2F956, M(6154, 3)), (0x2F957, M(9533, 3)), (0x2F958, M(9536, 3)), (0x2F959, M(6286, 3)),
+    (0x2F95A, M(9539, 3)), (0x2F95B, M(9542, 3)), (0x2F95C, M(9545, 4)), (0x2F95D, M(9549, 4)),
+    (0x2F95F, D), (0x2F960, M(9553, 3)), (0x2F961, M(9556, 4)), (0x2F962, M(9560, 3)),
+    (0x2F963, M(9563, 3)), (0x2F964, M(9566, 3)), (0x2F965, M(9569, 4)), (0x2F966, M(9573, 3)),
+    (0x2F967, M(9576, 3)), (0x2F968, M(9579, 3)), (0x2F969, M(9582, 3)), (0x2F96A, M(9585, 3)),
+    (0x2F96B, M(9588, 4)), (0x2F96C, M(9592, 3)), (0x2F96D, M(9595, 3)), (0x2F96E, M(9598, 3)),
+    (0x2F96F, M(9601, 3)), (0x2F970, M(9604, 3)), (0x2F971, M(9607, 3)), (0x2F972, M(9610, 4)),
+    (0x2F973, M(9614, 4)), (0x2F974, M(9618, 3)), (0x2F975, M(9621, 4)), (0x2F976, M(9625, 3)),
+    (0x2F977, M(9628, 4)), (0x2F978, M(9632, 3)), (0x2F979, M(9635, 3)), (0x2F97A, M(6304, 3)),
+    (0x2F97B, M(9638, 4)), (0x2F97C, M(9642, 4)), (0x2F97D, M(9646, 3)), (0x2F97E, M(9649, 4)),
+    (0x2F97F, M(9653, 3)), (0x2F980, M(9656, 4)), (0x2F981, M(9660, 3)), (0x2F982, M(9663, 3)),
+    (0x2F983, M(9666, 3)), (0x2F984, M(9669, 3)), (0x2F985, M(9672, 3)), (0x2F986, M(9675, 3)),
+    (0x2F987, M(9678, 4)), (0x2F988, M(9682, 4)), (0x2F989, M(9686, 4)), (0x2F98A, M(9690, 4)),
+    (0x2F98B, M(9010, 3)), (0x2F98C, M(9694, 3)), (0x2F98D, M(9697, 3)), (0x2F98E, M(9700, 3)),
+    (0x2F98F, M(9703, 3)), (0x2F990, M(9706, 3)), (0x2F991, M(9709, 3)), (0x2F992, M(9712, 3)),
+    (0x2F993, M(9715, 3)), (0x2F994, M(9718, 3)), (0x2F995, M(9721, 3)), (0x2F996, M(9724, 3)),
+    (0x2F997, M(9727, 4)), (0x2F998, M(5707, 3)), (0x2F999, M(9731, 3)), (0x2F99A, M(9734, 3)),
+    (0x2F99B, M(9737, 3)), (0x2F99C, M(9740, 3)), (0x2F99D, M(9743, 3)), (0x2F99E, M(9746, 3)),
+    (0x2F99F, M(6313, 3)), (0x2F9A0, M(9749, 3)), (0x2F9A1, M(9752, 3)), (0x2F9A2, M(9755, 3)),
+    (0x2F9A3, M(9758, 3)), (0x2F9A4, M(9761, 4)), (0x2F9A5, M(9765, 4)), (0x2F9A6, M(9769, 4)),
+    (0x2F9A7, M(9773, 3)), (0x2F9A8, M(9776, 3)), (0x2F9A9, M(9779, 3)), (0x2F9AA, M(9782, 3)),
+    (0x2F9AB, M(9785, 4)), (0x2F9AC, M(9789, 3)), (0x2F9AD, M(9792, 4)), (0x2F9AE, M(9796, 3)),
+    (0x2F9AF, M(9799, 3)), (0x2F9B0, M(9802, 4)), (0x2F9B1, M(9806, 4)), (0x2F9B2, M(9810, 3)),
+    (0x2F9B3, M(9813, 3)), (0x2F9B4, M(5530, 3)), (0x2F9B5, M(9816, 3)), (0x2F9B6, M(9819, 3)),
+    (0x2F9B7, M(9822, 3)), (0x2F9B8, M(9825, 3)), (0x2F9B9, M(9828, 3)), (0x2F9BA, M(9831, 3)),
+    (0x2F9BB, M(6515, 3)), (0x2F9BC, M(9834, 3)), (0x2F9BD, M(9837, 3)), (0x2F9BE, M(9840, 3)),
+    (0x2F9BF, D), (0x2F9C0, M(9843, 3)), (0x2F9C1, M(9846, 3)), (0x2F9C2, M(9849, 3)),
+    (0x2F9C3, M(9852, 3)), (0x2F9C4, M(2706, 3)), (0x2F9C5, M(9855, 4)), (0x2F9C6, M(9859, 3)),
+    (0x2F9C7, M(9862, 3)), (0x2F9C8, M(9865, 3)), (0x2F9C9, M(9868, 3)), (0x2F9CA, M(9871, 3)),
+    (0x2F9CB, M(9874, 4)), (0x2F9CC, M(9878, 4)), (0x2F9CD, M(9882, 3)), (0x2F9CE, M(9885, 3)),
+    (0x2F9CF, M(9888, 3)), (0x2F9D0, M(6530, 3)), (0x2F9D1, M(6533, 3)), (0x2F9D2, M(2727, 3)),
+    (0x2F9D3, M(9891, 4)), (0x2F9D4, M(9895, 3)), (0x2F9D5, M(9898, 3)), (0x2F9D6, M(9901, 3)),
+    (0x2F9D7, M(9904, 3)), (0x2F9D8, M(9907, 4)), (0x2F9D9, M(9911, 4)), (0x2F9DA, M(9915, 3)),
+    (0x2F9DB, M(9918, 3)), (0x2F9DC, M(9921, 3)), (0x2F9DD, M(9924, 4)), (0x2F9DE, M(9928, 3)),
+    (0x2F9DF, M(6536, 3)), (0x2F9E0, M(9931, 4)), (0x2F9E1, M(9935, 4)), (0x2F9E2, M(9939, 3)),
+    (0x2F9E3, M(9942, 3)), (0x2F9E4, M(9945, 3)), (0x2F9E5, M(9948, 4)), (0x2F9E6, M(9952, 3)),
+    (0x2F9E7, M(9955, 3)), (0x2F9E8, R(0x92D7)), (0x2F9EA, M(9958, 3)), (0x2F9EB, M(9961, 3)),
+    (0x2F9EC, M(9964, 3)), (0x2F9ED, M(9967, 4)), (0x2F9EE, M(9971, 3)), (0x2F9EF, M(9974, 3)),
+    (0x2F9F0, M(9977, 3)), (0x2F9F1, M(9980, 4)), (0x2F9F2, M(9984, 3)), (0x2F9F3, M(9987, 3)),
+    (0x2F9F4, M(9990, 3)), (0x2F9F5, M(9993, 3)), (0x2F9F6, M(9996, 4)), (0x2F9F7, M(10000, 4)),
+    (0x2F9F8, M(10004, 3)), (0x2F9F9, M(10007, 3)), (0x2F9FA, M(10010, 3)), (0x2F9FB, M(10013, 4)),
+    (0x2F9FC, M(10017, 3)), (0x2F9FD, M(10020, 4)), (0x2F9FE, M(6554, 3)), (0x2FA00, M(10024, 3)),
+    (0x2FA01, M(10027, 4)), (0x2FA02, M(10031, 3)), (0x2FA03, M(10034, 3)), (0x2FA04, M(10037, 3)),
+    (0x2FA05, M(10040, 3)), (0x2FA06, M(10043, 3)), (0x2FA07, M(10046, 3)), (0x2FA08, M(10049, 3)),
+    (0x2FA09, M(10052, 4)), (0x2FA0A, M(6557, 3)), (0x2FA0B, M(10056, 3)), (0x2FA0C, M(10059, 3)),
+    (0x2FA0D, M(10062, 3)), (0x2FA0E, M(10065, 3)), (0x2FA0F, M(10068, 3)), (0x2FA10, M(10071, 4)),
+    (0x2FA11, M(10075, 3)), (0x2FA12, M(10078, 4)), (0x2FA13, M(10082, 4)), (0x2FA14, M(10086, 4)),
+    (0x2FA15, M(2871, 3)), (0x2FA16, M(10090, 3)), (0x2FA17, M(2883, 3)), (0x2FA18, M(10093, 3)),
+    (0x2FA19, M(10096, 3)), (0x2FA1A, M(10099, 3)), (0x2FA1B, M(10102, 3)), (0x2FA1C, M(2898, 3)),
+    (0x2FA1D, M(10105, 4)), (0x2FA1E, D), (0x30000, V), (0x3134B, D),
+    (0xE0100, I), (0xE01F0, D),
+];
+
+pub(super) const MAPPED_TEXT: &str = "\u{20}\u{20}\u{308}a\u{20}\u{304}\u{20}\u{301}\u{3BC}\u{20}\u{327}1o1\u{2044}41\u{2044}23\u{2044}4āăąćĉċčďđēĕėęěĝğġģĥħĩīĭįi\u{307}ijĵķĺļľl·łńņňʼnŋōŏőœŕŗřśŝşšţťŧũūŭůűųŵŷÿźżžsɓƃƅɔƈƌǝəɛƒɠɣɩɨƙɯɲɵơƣƥʀƨʃƭʈưƴƶʒƹƽdžljnjǎǐǒǔǖǘǚǜǟǡǣǥǧǩǫǭǯdzǵƕƿǹǻǽǿȁȃȅȇȉȋȍȏȑȓȕȗșțȝȟƞȣȥȧȩȫȭȯȱȳ\u{2C65}ȼƚ\u{2C66}ɂƀʉʌɇɉɋɍɏhɦrɹɻʁwy\u{20}\u{306}\u{20}\u{307}\u{20}\u{30A}\u{20}\u{328}\u{20}\u{303}\u{20}\u{30B}xʕ\u{313}\u{308}\u{301}\u{3B9}\u{371}\u{373}ʹ\u{377}\u{20}\u{3B9};\u{3F3}\u{20}\u{308}\u{301}\u{3AC}\u{3CC}\u{3D7}\u{3B2}\u{3B8}\u{3C5}\u{3CD}\u{3CB}\u{3C6}\u{3C0}\u{3D9}\u{3DB}\u{3DD}\u{3DF}\u{3E1}\u{3E3}\u{3E5}\u{3E7}\u{3E9}\u{3EB}\u{3ED}\u{3EF}\u{3BA}\u{3C1}\u{3C3}\u{3B5}\u{3F8}\u{3FB}\u{461}\u{463}\u{465}\u{467}\u{469}\u{46B}\u{46D}\u{46F}\u{471}\u{473}\u{475}\u{477}\u{479}\u{47B}\u{47D}\u{47F}\u{481}\u{48B}\u{48D}\u{48F}\u{491}\u{493}\u{495}\u{497}\u{499}\u{49B}\u{49D}\u{49F}\u{4A1}\u{4A3}\u{4A5}\u{4A7}\u{4A9}\u{4AB}\u{4AD}\u{4AF}\u{4B1}\u{4B3}\u{4B5}\u{4B7}\u{4B9}\u{4BB}\u{4BD}\u{4BF}\u{4C2}\u{4C4}\u{4C6}\u{4C8}\u{4CA}\u{4CC}\u{4CE}\u{4D1}\u{4D3}\u{4D5}\u{4D7}\u{4D9}\u{4DB}\u{4DD}\u{4DF}\u{4E1}\u{4E3}\u{4E5}\u{4E7}\u{4E9}\u{4EB}\u{4ED}\u{4EF}\u{4F1}\u{4F3}\u{4F5}\u{4F7}\u{4F9}\u{4FB}\u{4FD}\u{4FF}\u{501}\u{503}\u{505}\u{507}\u{509}\u{50B}\u{50D}\u{50F}\u{511}\u{513}\u{515}\u{517}\u{519}\u{51B}\u{51D}\u{51F}\u{521}\u{523}\u{525}\u{527}\u{529}\u{52B}\u{52D}\u{52F}\u{565}\u{582}\u{627}\u{674}\u{648}\u{674}\u{6C7}\u{674}\u{64A}\u{674}\u{915}\u{93C}\u{916}\u{93C}\u{917}\u{93C}\u{91C}\u{93C}\u{921}\u{93C}\u{922}\u{93C}\u{92B}\u{93C}\u{92F}\u{93C}\u{9A1}\u{9BC}\u{9A2}\u{9BC}\u{9AF}\u{9BC}\u{A32}\u{A3C}\u{A38}\u{A3C}\u{A16}\u{A3C}\u{A17}\u{A3C}\u{A1C}\u{A3C}\u{A2B}\u{A3C}\u{B21}\u{B3C}\u{B22}\u{B3C}\u{E4D}\u{E32}\u{ECD}\u{EB2}\u{EAB}\u{E99}\u{EAB}\u{EA1}\u{F0B}\u{F42}\u{FB7}\u{F4C}\u{FB7}\u{F51}\u{FB7}\u{F56}\u{FB7}\u{F5B}\u{FB7}\u{F40}\u{FB5}\u{F71}\u{F72}\u{F71}\u{F74}\u{FB2}\u{F80}\u{FB2}\u{F71}\u{F80}\u{FB3}\u{F80}\u{FB3}\u{F71}\u{F80}\u{F92}\u{FB7}\u{F9C}\u{FB7}\u{FA1}\u{FB7}\u{FA6}\u{FB7}\u{FAB}\u{FB7}\u{F90}\u{FB5}\u{2D27}\u{2D2D}\u{10DC}\u{432}\u{434}\u{43E}\u{442}\u{44A}\u{A64B}æbp\u{1D02}gkm\u{1D1D}v\u{1D25}\u{43D}ɒcɕðɜfɟɡɥ\u{1D7B}ʝɭ\u{1D85}ʟɱɰɸƫ\u{1D1C}\u{1E01}\u{1E03}\u{1E05}\u{1E07}\u{1E09}\u{1E0B}\u{1E0D}\u{1E0F}\u{1E11}\u{1E13}\u{1E15}\u{1E17}\u{1E19}\u{1E1B}\u{1E1D}\u{1E1F}\u{1E21}\u{1E23}\u{1E25}\u{1E27}\u{1E29}\u{1E2B}\u{1E2D}\u{1E2F}\u{1E31}\u{1E33}\u{1E35}\u{1E37}\u{1E39}\u{1E3B}\u{1E3D}\u{1E3F}\u{1E41}\u{1E43}\u{1E45}\u{1E47}\u{1E49}\u{1E4B}\u{1E4D}\u{1E4F}\u{1E51}\u{1E53}\u{1E55}\u{1E57}\u{1E59}\u{1E5B}\u{1E5D}\u{1E5F}\u{1E61}\u{1E63}\u{1E65}\u{1E67}\u{1E69}\u{1E6B}\u{1E6D}\u{1E6F}\u{1E71}\u{1E73}\u{1E75}\u{1E77}\u{1E79}\u{1E7B}\u{1E7D}\u{1E7F}\u{1E81}\u{1E83}\u{1E85}\u{1E87}\u{1E89}\u{1E8B}\u{1E8D}\u{1E8F}\u{1E91}\u{1E93}\u{1E95}aʾss\u{1EA1}\u{1EA3}\u{1EA5}\u{1EA7}\u{1EA9}\u{1EAB}\u{1EAD}\u{1EAF}\u{1EB1}\u{1EB3}\u{1EB5}\u{1EB7}\u{1EB9}\u{1EBB}\u{1EBD}\u{1EBF}\u{1EC1}\u{1EC3}\u{1EC5}\u{1EC7}\u{1EC9}\u{1ECB}\u{1ECD}\u{1ECF}\u{1ED1}\u{1ED3}\u{1ED5}\u{1ED7}\u{1ED9}\u{1EDB}\u{1EDD}\u{1EDF}\u{1EE1}\u{1EE3}\u{1EE5}\u{1EE7}\u{1EE9}\u{1EEB}\u{1EED}\u{1EEF}\u{1EF1}\u{1EF3}\u{1EF5}\u{1EF7}\u{1EF9}\u{1EFB}\u{1EFD}\u{1EFF}\u{1F51}\u{1F53}\u{1F55}\u{1F57}\u{3AD}\u{3AE}\u{3AF}\u{3CE}\u{1F00}\u{3B9}\u{1F01}\u{3B9}\u{1F02}\u{3B9}\u{1F03}\u{3B9}\u{1F04}\u{3B9}\u{1F05}\u{3B9}\u{1F06}\u{3B9}\u{1F07}\u{3B9}\u{1F20}\u{3B9}\u{1F21}\u{3B9}\u{1F22}\u{3B9}\u{1F23}\u{3B9}\u{1F24}\u{3B9}\u{1F25}\u{3B9}\u{1F26}\u{3B9}\u{1F27}\u{3B9}\u{1F60}\u{3B9}\u{1F61}\u{3B9}\u{1F62}\u{3B9}\u{1F63}\u{3B9}\u{1F64}\u{3B9}\u{1F65}\u{3B9}\u{1F66}\u{3B9}\u{1F67}\u{3B9}\u{1F70}\u{3B9}\u{3B1}\u{3B9}\u{3AC}\u{3B9}\u{1FB6}\u{3B9}\u{20}\u{313}\u{20}\u{342}\u{20}\u{308}\u{342}\u{1F74}\u{3B9}\u{3B7}\u{3B9}\u{3AE}\u{3B9}\u{1FC6}\u{3B9}\u{1F72}\u{20}\u{313}\u{300}\u{20}\u{313}\u{301}\u{20}\u{313}\u{342}\u{390}\u{1F76}\u{20}\u{314}\u{300}\u{20}\u{314}\u{301}\u{20}\u{314}\u{342}\u{3B0}\u{1F7A}\u{1FE5}\u{20}\u{308}\u{300}`\u{1F7C}\u{3B9}\u{3C9}\u{3B9}\u{3CE}\u{3B9}\u{1FF6}\u{3B9}\u{1F78}\u{2010}\u{20}\u{333}\u{2032}\u{2032}\u{2032}\u{2032}\u{2032}\u{2035}\u{2035}\u{2035}\u{2035}\u{2035}!!\u{20}\u{305}???!!?0+\u{2212}=()ersa/ca/s°cc/oc/u°fnosmteltmåfax\u{3B3}\u{2211}1\u{2044}71\u{2044}91\u{2044}101\u{2044}32\u{2044}31\u{2044}52\u{2044}53\u{2044}54\u{2044}51\u{2044}65\u{2044}61\u{2044}83\u{2044}85\u{2044}87\u{2044}8iiiiiivviviiviiiixxixii0\u{2044}3\u{222B}\u{222B}\u{222B}\u{222B}\u{222B}\u{222E}\u{222E}\u{222E}\u{222E}\u{222E}11121314151617181920(1)(2)(3)(4)(5)(6)(7)(8)(9)(10)(11)(12)(13)(14)(15)(16)(17)(18)(19)(20)(a)(b)(c)(d)(e)(f)(g)(h)(i)(j)(k)(l)(m)(n)(o)(p)(q)(r)(s)(t)(u)(v)(w)(x)(y)(z)::===\u{2ADD}\u{338}\u{2C61}ɫ\u{1D7D}ɽ\u{2C68}\u{2C6A}\u{2C6C}ɑɐ\u{2C73}\u{2C76}\u{2C81}\u{2C83}\u{2C85}\u{2C87}\u{2C89}\u{2C8B}\u{2C8D}\u{2C8F}\u{2C91}\u{2C93}\u{2C95}\u{2C97}\u{2C99}\u{2C9B}\u{2C9D}\u{2C9F}\u{2CA1}\u{2CA3}\u{2CA5}\u{2CA7}\u{2CA9}\u{2CAB}\u{2CAD}\u{2CAF}\u{2CB1}\u{2CB3}\u{2CB5}\u{2CB7}\u{2CB9}\u{2CBB}\u{2CBD}\u{2CBF}\u{2CC1}\u{2CC3}\u{2CC5}\u{2CC7}\u{2CC9}\u{2CCB}\u{2CCD}\u{2CCF}\u{2CD1}\u{2CD3}\u{2CD5}\u{2CD7}\u{2CD9}\u{2CDB}\u{2CDD}\u{2CDF}\u{2CE1}\u{2CE3}\u{2CEC}\u{2CEE}\u{2CF3}\u{2D61}\u{6BCD}\u{9F9F}\u{4E00}\u{4E28}\u{4E36}\u{4E3F}\u{4E59}\u{4E85}\u{4E8C}\u{4EA0}\u{4EBA}\u{513F}\u{5165}\u{516B}\u{5182}\u{5196}\u{51AB}\u{51E0}\u{51F5}\u{5200}\u{529B}\u{52F9}\u{5315}\u{531A}\u{5338}\u{5341}\u{535C}\u{5369}\u{5382}\u{53B6}\u{53C8}\u{53E3}\u{56D7}\u{571F}\u{58EB}\u{5902}\u{590A}\u{5915}\u{5927}\u{5973}\u{5B50}\u{5B80}\u{5BF8}\u{5C0F}\u{5C22}\u{5C38}\u{5C6E}\u{5C71}\u{5DDB}\u{5DE5}\u{5DF1}\u{5DFE}\u{5E72}\u{5E7A}\u{5E7F}\u{5EF4}\u{5EFE}\u{5F0B}\u{5F13}\u{5F50}\u{5F61}\u{5F73}\u{5FC3}\u{6208}\u{6236}\u{624B}\u{652F}\u{6534}\u{6587}\u{6597}\u{65A4}\u{65B9}\u{65E0}\u{65E5}\u{66F0}\u{6708}\u{6728}\u{6B20}\u{6B62}\u{6B79}\u{6BB3}\u{6BCB}\u{6BD4}\u{6BDB}\u{6C0F}\u{6C14}\u{6C34}\u{706B}\u{722A}\u{7236}\u{723B}\u{723F}\u{7247}\u{7259}\u{725B}\u{72AC}\u{7384}\u{7389}\u{74DC}\u{74E6}\u{7518}\u{751F}\u{7528}\u{7530}\u{758B}\u{7592}\u{7676}\u{767D}\u{76AE}\u{76BF}\u{76EE}\u{77DB}\u{77E2}\u{77F3}\u{793A}\u{79B8}\u{79BE}\u{7A74}\u{7ACB}\u{7AF9}\u{7C73}\u{7CF8}\u{7F36}\u{7F51}\u{7F8A}\u{7FBD}\u{8001}\u{800C}\u{8012}\u{8033}\u{807F}\u{8089}\u{81E3}\u{81EA}\u{81F3}\u{81FC}\u{820C}\u{821B}\u{821F}\u{826E}\u{8272}\u{8278}\u{864D}\u{866B}\u{8840}\u{884C}\u{8863}\u{897E}\u{898B}\u{89D2}\u{8A00}\u{8C37}\u{8C46}\u{8C55}\u{8C78}\u{8C9D}\u{8D64}\u{8D70}\u{8DB3}\u{8EAB}\u{8ECA}\u{8F9B}\u{8FB0}\u{8FB5}\u{9091}\u{9149}\u{91C6}\u{91CC}\u{91D1}\u{9577}\u{9580}\u{961C}\u{96B6}\u{96B9}\u{96E8}\u{9751}\u{975E}\u{9762}\u{9769}\u{97CB}\u{97ED}\u{97F3}\u{9801}\u{98A8}\u{98DB}\u{98DF}\u{9996}\u{9999}\u{99AC}\u{9AA8}\u{9AD8}\u{9ADF}\u{9B25}\u{9B2F}\u{9B32}\u{9B3C}\u{9B5A}\u{9CE5}\u{9E75}\u{9E7F}\u{9EA5}\u{9EBB}\u{9EC3}\u{9ECD}\u{9ED1}\u{9EF9}\u{9EFD}\u{9F0E}\u{9F13}\u{9F20}\u{9F3B}\u{9F4A}\u{9F52}\u{9F8D}\u{9F9C}\u{9FA0}.\u{3012}\u{20}\u{3099}\u{20}\u{309A}\u{3088}\u{308A}\u{30B3}\u{30C8}\u{11AA}\u{1102}\u{111A}\u{1121}\u{11CC}\u{11CE}\u{11D3}\u{11D7}\u{11D9}\u{111C}\u{11DD}\u{11DF}\u{1120}\u{1127}\u{1129}\u{1132}\u{1136}\u{1140}\u{1147}\u{114C}\u{1188}\u{1194}\u{119E}\u{11A1}\u{4E09}\u{56DB}\u{4E0A}\u{4E2D}\u{4E0B}\u{7532}\u{4E19}\u{4E01}\u{5929}\u{5730}(\u{1100})(\u{1102})(\u{1103})(\u{1105})(\u{1106})(\u{1107})(\u{1109})(\u{110B})(\u{110C})(\u{110E})(\u{110F})(\u{1110})(\u{1111})(\u{1112})(\u{AC00})(\u{B098})(\u{B2E4})(\u{B77C})(\u{B9C8})(\u{BC14})(\u{C0AC})(\u{C544})(\u{C790})(\u{CC28})(\u{CE74})(\u{D0C0})(\u{D30C})(\u{D558})(\u{C8FC})(\u{C624}\u{C804})(\u{C624}\u{D6C4})(\u{4E00})(\u{4E8C})(\u{4E09})(\u{56DB})(\u{4E94})(\u{516D})(\u{4E03})(\u{516B})(\u{4E5D})(\u{5341})(\u{6708})(\u{706B})(\u{6C34})(\u{6728})(\u{91D1})(\u{571F})(\u{65E5})(\u{682A})(\u{6709})(\u{793E})(\u{540D})(\u{7279})(\u{8CA1})(\u{795D})(\u{52B4})(\u{4EE3})(\u{547C})(\u{5B66})(\u{76E3})(\u{4F01})(\u{8CC7})(\u{5354})(\u{796D})(\u{4F11})(\u{81EA})(\u{81F3})\u{554F}\u{5E7C}\u{7B8F}pte2224252627282930333435\u{CC38}\u{ACE0}\u{C8FC}\u{C758}\u{C6B0}\u{79D8}\u{7537}\u{9069}\u{512A}\u{5370}\u{6CE8}\u{9805}\u{5199}\u{6B63}\u{5DE6}\u{53F3}\u{533B}\u{5B97}\u{591C}3637383940444546474849501\u{6708}2\u{6708}3\u{6708}4\u{6708}5\u{6708}6\u{6708}7\u{6708}8\u{6708}9\u{6708}10\u{6708}11\u{6708}12\u{6708}hgergevltd\u{30A2}\u{30A4}\u{30A6}\u{30A8}\u{30AD}\u{30AF}\u{30B1}\u{30B5}\u{30B7}\u{30B9}\u{30BB}\u{30BD}\u{30BF}\u{30C1}\u{30C4}\u{30C6}\u{30D2}\u{30D5}\u{30D8}\u{30DB}\u{30E4}\u{30E6}\u{4EE4}\u{548C}\u{30A2}\u{30D1}\u{30FC}\u{30C8}\u{30A2}\u{30EB}\u{30D5}\u{30A1}\u{30A2}\u{30F3}\u{30DA}\u{30A2}\u{30A2}\u{30FC}\u{30EB}\u{30A4}\u{30CB}\u{30F3}\u{30B0}\u{30A4}\u{30F3}\u{30C1}\u{30A6}\u{30A9}\u{30F3}\u{30A8}\u{30B9}\u{30AF}\u{30FC}\u{30C9}\u{30A8}\u{30FC}\u{30AB}\u{30FC}\u{30AA}\u{30F3}\u{30B9}\u{30AA}\u{30FC}\u{30E0}\u{30AB}\u{30A4}\u{30EA}\u{30AB}\u{30E9}\u{30C3}\u{30C8}\u{30AB}\u{30ED}\u{30EA}\u{30FC}\u{30AC}\u{30ED}\u{30F3}\u{30AC}\u{30F3}\u{30DE}\u{30AE}\u{30AC}\u{30AE}\u{30CB}\u{30FC}\u{30AD}\u{30E5}\u{30EA}\u{30FC}\u{30AE}\u{30EB}\u{30C0}\u{30FC}\u{30AD}\u{30ED}\u{30AD}\u{30ED}\u{30B0}\u{30E9}\u{30E0}\u{30AD}\u{30ED}\u{30E1}\u{30FC}\u{30C8}\u{30EB}\u{30AD}\u{30ED}\u{30EF}\u{30C3}\u{30C8}\u{30B0}\u{30E9}\u{30E0}\u{30C8}\u{30F3}\u{30AF}\u{30EB}\u{30BC}\u{30A4}\u{30ED}\u{30AF}\u{30ED}\u{30FC}\u{30CD}\u{30B1}\u{30FC}\u{30B9}\u{30B3}\u{30EB}\u{30CA}\u{30B3}\u{30FC}\u{30DD}\u{30B5}\u{30A4}\u{30AF}\u{30EB}\u{30B5}\u{30F3}\u{30C1}\u{30FC}\u{30E0}\u{30B7}\u{30EA}\u{30F3}\u{30B0}\u{30BB}\u{30F3}\u{30C1}\u{30BB}\u{30F3}\u{30C8}\u{30C0}\u{30FC}\u{30B9}\u{30C7}\u{30B7}\u{30C9}\u{30EB}\u{30CA}\u{30CE}\u{30CE}\u{30C3}\u{30C8}\u{30CF}\u{30A4}\u{30C4}\u{30D1}\u{30FC}\u{30BB}\u{30F3}\u{30C8}\u{30D1}\u{30FC}\u{30C4}\u{30D0}\u{30FC}\u{30EC}\u{30EB}\u{30D4}\u{30A2}\u{30B9}\u{30C8}\u{30EB}\u{30D4}\u{30AF}\u{30EB}\u{30D4}\u{30B3}\u{30D3}\u{30EB}\u{30D5}\u{30A1}\u{30E9}\u{30C3}\u{30C9}\u{30D5}\u{30A3}\u{30FC}\u{30C8}\u{30D6}\u{30C3}\u{30B7}\u{30A7}\u{30EB}\u{30D5}\u{30E9}\u{30F3}\u{30D8}\u{30AF}\u{30BF}\u{30FC}\u{30EB}\u{30DA}\u{30BD}\u{30DA}\u{30CB}\u{30D2}\u{30D8}\u{30EB}\u{30C4}\u{30DA}\u{30F3}\u{30B9}\u{30DA}\u{30FC}\u{30B8}\u{30D9}\u{30FC}\u{30BF}\u{30DD}\u{30A4}\u{30F3}\u{30C8}\u{30DC}\u{30EB}\u{30C8}\u{30DB}\u{30F3}\u{30DD}\u{30F3}\u{30C9}\u{30DB}\u{30FC}\u{30EB}\u{30DB}\u{30FC}\u{30F3}\u{30DE}\u{30A4}\u{30AF}\u{30ED}\u{30DE}\u{30A4}\u{30EB}\u{30DE}\u{30C3}\u{30CF}\u{30DE}\u{30EB}\u{30AF}\u{30DE}\u{30F3}\u{30B7}\u{30E7}\u{30F3}\u{30DF}\u{30AF}\u{30ED}\u{30F3}\u{30DF}\u{30EA}\u{30DF}\u{30EA}\u{30D0}\u{30FC}\u{30EB}\u{30E1}\u{30AC}\u{30E1}\u{30AC}\u{30C8}\u{30F3}\u{30E4}\u{30FC}\u{30C9}\u{30E4}\u{30FC}\u{30EB}\u{30E6}\u{30A2}\u{30F3}\u{30EA}\u{30C3}\u{30C8}\u{30EB}\u{30EA}\u{30E9}\u{30EB}\u{30D4}\u{30FC}\u{30EB}\u{30FC}\u{30D6}\u{30EB}\u{30EC}\u{30E0}\u{30EC}\u{30F3}\u{30C8}\u{30B2}\u{30F3}0\u{70B9}1\u{70B9}2\u{70B9}3\u{70B9}4\u{70B9}5\u{70B9}6\u{70B9}7\u{70B9}8\u{70B9}9\u{70B9}10\u{70B9}11\u{70B9}12\u{70B9}13\u{70B9}14\u{70B9}15\u{70B9}16\u{70B9}17\u{70B9}18\u{70B9}19\u{70B9}20\u{70B9}21\u{70B9}22\u{70B9}23\u{70B9}24\u{70B9}hpadaaubarovpcdmdm2dm3iu\u{5E73}\u{6210}\u{662D}\u{548C}\u{5927}\u{6B63}\u{660E}\u{6CBB}\u{682A}\u{5F0F}\u{4F1A}\u{793E}na\u{3BC}amakakbmbgbcalkcalpfnf\u{3BC}f\u{3BC}gmgkghzkhzmhzthz\u{3BC}lmldlklfmnm\u{3BC}mmmcmmm2cm2km2mm3cm3km3m\u{2215}sm\u{2215}s2kpampagparadrad\u{2215}srad\u{2215}s2psns\u{3BC}smspvnv\u{3BC}vmvkvpwnw\u{3BC}wmwkwk\u{3C9}m\u{3C9}bqc\u{2215}kgdbgyhainkkktlnloglxmilmolphppmprsvwbv\u{2215}ma\u{2215}m1\u{65E5}2\u{65E5}3\u{65E5}4\u{65E5}5\u{65E5}6\u{65E5}7\u{65E5}8\u{65E5}9\u{65E5}10\u{65E5}11\u{65E5}12\u{65E5}13\u{65E5}14\u{65E5}15\u{65E5}16\u{65E5}17\u{65E5}18\u{65E5}19\u{65E5}20\u{65E5}21\u{65E5}22\u{65E5}23\u{65E5}24\u{65E5}25\u{65E5}26\u{65E5}27\u{65E5}28\u{65E5}29\u{65E5}30\u{65E5}31\u{65E5}gal\u{A641}\u{A643}\u{A645}\u{A647}\u{A649}\u{A64D}\u{A64F}\u{A651}\u{A653}\u{A655}\u{A657}\u{A659}\u{A65B}\u{A65D}\u{A65F}\u{A661}\u{A663}\u{A665}\u{A667}\u{A669}\u{A66B}\u{A66D}\u{A681}\u{A683}\u{A685}\u{A687}\u{A689}\u{A68B}\u{A68D}\u{A68F}\u{A691}\u{A693}\u{A695}\u{A697}\u{A699}\u{A69B}\u{44C}\u{A723}\u{A725}\u{A727}\u{A729}\u{A72B}\u{A72D}\u{A72F}\u{A733}\u{A735}\u{A737}\u{A739}\u{A73B}\u{A73D}\u{A73F}\u{A741}\u{A743}\u{A745}\u{A747}\u{A749}\u{A74B}\u{A74D}\u{A74F}\u{A751}\u{A753}\u{A755}\u{A757}\u{A759}\u{A75B}\u{A75D}\u{A75F}\u{A761}\u{A763}\u{A765}\u{A767}\u{A769}\u{A76B}\u{A76D}\u{A76F}\u{A77A}\u{A77C}\u{1D79}\u{A77F}\u{A781}\u{A783}\u{A785}\u{A787}\u{A78C}\u{A791}\u{A793}\u{A797}\u{A799}\u{A79B}\u{A79D}\u{A79F}\u{A7A1}\u{A7A3}\u{A7A5}\u{A7A7}\u{A7A9}ɬɪʞʇ\u{AB53}\u{A7B5}\u{A7B7}\u{A7B9}\u{A7BB}\u{A7BD}\u{A7BF}\u{A7C3}\u{A794}ʂ\u{1D8E}\u{A7C8}\u{A7CA}\u{A7F6}\u{AB37}\u{AB52}ʍ\u{8C48}\u{66F4}\u{8CC8}\u{6ED1}\u{4E32}\u{53E5}\u{5951}\u{5587}\u{5948}\u{61F6}\u{7669}\u{7F85}\u{863F}\u{87BA}\u{88F8}\u{908F}\u{6A02}\u{6D1B}\u{70D9}\u{73DE}\u{843D}\u{916A}\u{99F1}\u{4E82}\u{5375}\u{6B04}\u{721B}\u{862D}\u{9E1E}\u{5D50}\u{6FEB}\u{85CD}\u{8964}\u{62C9}\u{81D8}\u{881F}\u{5ECA}\u{6717}\u{6D6A}\u{72FC}\u{90CE}\u{4F86}\u{51B7}\u{52DE}\u{64C4}\u{6AD3}\u{7210}\u{76E7}\u{8606}\u{865C}\u{8DEF}\u{9732}\u{9B6F}\u{9DFA}\u{788C}\u{797F}\u{7DA0}\u{83C9}\u{9304}\u{8AD6}\u{58DF}\u{5F04}\u{7C60}\u{807E}\u{7262}\u{78CA}\u{8CC2}\u{96F7}\u{58D8}\u{5C62}\u{6A13}\u{6DDA}\u{6F0F}\u{7D2F}\u{7E37}\u{964B}\u{52D2}\u{808B}\u{51DC}\u{51CC}\u{7A1C}\u{7DBE}\u{83F1}\u{9675}\u{8B80}\u{62CF}\u{8AFE}\u{4E39}\u{5BE7}\u{6012}\u{7387}\u{7570}\u{5317}\u{78FB}\u{4FBF}\u{5FA9}\u{4E0D}\u{6CCC}\u{6578}\u{7D22}\u{53C3}\u{585E}\u{7701}\u{8449}\u{8AAA}\u{6BBA}\u{6C88}\u{62FE}\u{82E5}\u{63A0}\u{7565}\u{4EAE}\u{5169}\u{51C9}\u{6881}\u{7CE7}\u{826F}\u{8AD2}\u{91CF}\u{52F5}\u{5442}\u{5EEC}\u{65C5}\u{6FFE}\u{792A}\u{95AD}\u{9A6A}\u{9E97}\u{9ECE}\u{66C6}\u{6B77}\u{8F62}\u{5E74}\u{6190}\u{6200}\u{649A}\u{6F23}\u{7149}\u{7489}\u{79CA}\u{7DF4}\u{806F}\u{8F26}\u{84EE}\u{9023}\u{934A}\u{5217}\u{52A3}\u{54BD}\u{70C8}\u{88C2}\u{5EC9}\u{5FF5}\u{637B}\u{6BAE}\u{7C3E}\u{7375}\u{56F9}\u{5DBA}\u{601C}\u{73B2}\u{7469}\u{7F9A}\u{8046}\u{9234}\u{96F6}\u{9748}\u{9818}\u{4F8B}\u{79AE}\u{91B4}\u{96B8}\u{60E1}\u{4E86}\u{50DA}\u{5BEE}\u{5C3F}\u{6599}\u{71CE}\u{7642}\u{84FC}\u{907C}\u{6688}\u{962E}\u{5289}\u{677B}\u{67F3}\u{6D41}\u{6E9C}\u{7409}\u{7559}\u{786B}\u{7D10}\u{985E}\u{622E}\u{9678}\u{502B}\u{5D19}\u{6DEA}\u{8F2A}\u{5F8B}\u{6144}\u{6817}\u{9686}\u{5229}\u{540F}\u{5C65}\u{6613}\u{674E}\u{68A8}\u{6CE5}\u{7406}\u{75E2}\u{7F79}\u{88CF}\u{88E1}\u{96E2}\u{533F}\u{6EBA}\u{541D}\u{71D0}\u{7498}\u{85FA}\u{96A3}\u{9C57}\u{9E9F}\u{6797}\u{6DCB}\u{81E8}\u{7B20}\u{7C92}\u{72C0}\u{7099}\u{8B58}\u{4EC0}\u{8336}\u{523A}\u{5207}\u{5EA6}\u{62D3}\u{7CD6}\u{5B85}\u{6D1E}\u{66B4}\u{8F3B}\u{964D}\u{5ED3}\u{5140}\u{55C0}\u{585A}\u{6674}\u{51DE}\u{732A}\u{76CA}\u{793C}\u{795E}\u{7965}\u{798F}\u{9756}\u{7CBE}\u{8612}\u{8AF8}\u{9038}\u{90FD}\u{98EF}\u{98FC}\u{9928}\u{9DB4}\u{90DE}\u{96B7}\u{4FAE}\u{50E7}\u{514D}\u{52C9}\u{52E4}\u{5351}\u{559D}\u{5606}\u{5668}\u{5840}\u{58A8}\u{5C64}\u{6094}\u{6168}\u{618E}\u{61F2}\u{654F}\u{65E2}\u{6691}\u{6885}\u{6D77}\u{6E1A}\u{6F22}\u{716E}\u{722B}\u{7422}\u{7891}\u{7949}\u{7948}\u{7950}\u{7956}\u{7A40}\u{7A81}\u{7BC0}\u{7E09}\u{7E41}\u{7F72}\u{8005}\u{81ED}\u{8279}\u{8457}\u{8910}\u{8996}\u{8B01}\u{8B39}\u{8CD3}\u{8D08}\u{8FB6}\u{96E3}\u{97FF}\u{983B}\u{6075}\u{242EE}\u{8218}\u{4E26}\u{51B5}\u{5168}\u{4F80}\u{5145}\u{5180}\u{52C7}\u{52FA}\u{5555}\u{5599}\u{55E2}\u{58B3}\u{5944}\u{5954}\u{5A62}\u{5B28}\u{5ED2}\u{5ED9}\u{5F69}\u{5FAD}\u{60D8}\u{614E}\u{6108}\u{6160}\u{6234}\u{63C4}\u{641C}\u{6452}\u{6556}\u{671B}\u{6756}\u{6EDB}\u{6ECB}\u{701E}\u{77A7}\u{7235}\u{72AF}\u{7471}\u{7506}\u{753B}\u{761D}\u{761F}\u{76DB}\u{76F4}\u{774A}\u{7740}\u{78CC}\u{7AB1}\u{7C7B}\u{7D5B}\u{7F3E}\u{8352}\u{83EF}\u{8779}\u{8941}\u{8986}\u{8ABF}\u{8ACB}\u{8AED}\u{8B8A}\u{8F38}\u{9072}\u{9199}\u{9276}\u{967C}\u{97DB}\u{980B}\u{9B12}\u{2284A}\u{22844}\u{233D5}\u{3B9D}\u{4018}\u{4039}\u{25249}\u{25CD0}\u{27ED3}\u{9F43}\u{9F8E}fffiflfflst\u{574}\u{576}\u{574}\u{565}\u{574}\u{56B}\u{57E}\u{576}\u{574}\u{56D}\u{5D9}\u{5B4}\u{5F2}\u{5B7}\u{5E2}\u{5D0}\u{5E8}\u{5EA}\u{5E9}\u{5C1}\u{5E9}\u{5C2}\u{5E9}\u{5BC}\u{5C1}\u{5E9}\u{5BC}\u{5C2}\u{5D0}\u{5B7}\u{5D0}\u{5B8}\u{5D0}\u{5BC}\u{5D1}\u{5BC}\u{5D2}\u{5BC}\u{5D3}\u{5BC}\u{5D4}\u{5BC}\u{5D5}\u{5BC}\u{5D6}\u{5BC}\u{5D8}\u{5BC}\u{5D9}\u{5BC}\u{5DA}\u{5BC}\u{5DB}\u{5BC}\u{5DC}\u{5BC}\u{5DE}\u{5BC}\u{5E0}\u{5BC}\u{5E1}\u{5BC}\u{5E3}\u{5BC}\u{5E4}\u{5BC}\u{5E6}\u{5BC}\u{5E7}\u{5BC}\u{5E8}\u{5BC}\u{5EA}\u{5BC}\u{5D5}\u{5B9}\u{5D1}\u{5BF}\u{5DB}\u{5BF}\u{5E4}\u{5BF}\u{5D0}\u{5DC}\u{671}\u{67B}\u{67E}\u{680}\u{67A}\u{67F}\u{679}\u{6A4}\u{6A6}\u{684}\u{683}\u{686}\u{687}\u{68D}\u{68C}\u{68E}\u{688}\u{698}\u{691}\u{6A9}\u{6AF}\u{6B3}\u{6B1}\u{6BA}\u{6BB}\u{6C0}\u{6C1}\u{6BE}\u{6D2}\u{6D3}\u{6AD}\u{6C6}\u{6C8}\u{6CB}\u{6C5}\u{6C9}\u{6D0}\u{649}\u{626}\u{627}\u{626}\u{6D5}\u{626}\u{648}\u{626}\u{6C7}\u{626}\u{6C6}\u{626}\u{6C8}\u{626}\u{6D0}\u{626}\u{649}\u{6CC}\u{626}\u{62C}\u{626}\u{62D}\u{626}\u{645}\u{626}\u{64A}\u{628}\u{62C}\u{628}\u{62D}\u{628}\u{62E}\u{628}\u{645}\u{628}\u{649}\u{628}\u{64A}\u{62A}\u{62C}\u{62A}\u{62D}\u{62A}\u{62E}\u{62A}\u{645}\u{62A}\u{649}\u{62A}\u{64A}\u{62B}\u{62C}\u{62B}\u{645}\u{62B}\u{649}\u{62B}\u{64A}\u{62C}\u{62D}\u{62C}\u{645}\u{62D}\u{645}\u{62E}\u{62C}\u{62E}\u{62D}\u{62E}\u{645}\u{633}\u{62C}\u{633}\u{62D}\u{633}\u{62E}\u{633}\u{645}\u{635}\u{62D}\u{635}\u{645}\u{636}\u{62C}\u{636}\u{62D}\u{636}\u{62E}\u{636}\u{645}\u{637}\u{62D}\u{637}\u{645}\u{638}\u{645}\u{639}\u{62C}\u{639}\u{645}\u{63A}\u{62C}\u{63A}\u{645}\u{641}\u{62C}\u{641}\u{62D}\u{641}\u{62E}\u{641}\u{645}\u{641}\u{649}\u{641}\u{64A}\u{642}\u{62D}\u{642}\u{645}\u{642}\u{649}\u{642}\u{64A}\u{643}\u{627}\u{643}\u{62C}\u{643}\u{62D}\u{643}\u{62E}\u{643}\u{644}\u{643}\u{645}\u{643}\u{649}\u{643}\u{64A}\u{644}\u{62C}\u{644}\u{62D}\u{644}\u{62E}\u{644}\u{645}\u{644}\u{649}\u{644}\u{64A}\u{645}\u{62C}\u{645}\u{645}\u{645}\u{649}\u{645}\u{64A}\u{646}\u{62C}\u{646}\u{62D}\u{646}\u{62E}\u{646}\u{645}\u{646}\u{649}\u{646}\u{64A}\u{647}\u{62C}\u{647}\u{645}\u{647}\u{649}\u{647}\u{64A}\u{64A}\u{62D}\u{64A}\u{62E}\u{64A}\u{649}\u{630}\u{670}\u{631}\u{670}\u{649}\u{670}\u{20}\u{64C}\u{651}\u{20}\u{64D}\u{651}\u{20}\u{64E}\u{651}\u{20}\u{64F}\u{651}\u{20}\u{650}\u{651}\u{20}\u{651}\u{670}\u{626}\u{631}\u{626}\u{632}\u{626}\u{646}\u{628}\u{631}\u{628}\u{632}\u{628}\u{646}\u{62A}\u{631}\u{62A}\u{632}\u{62A}\u{646}\u{62B}\u{631}\u{62B}\u{632}\u{62B}\u{646}\u{645}\u{627}\u{646}\u{631}\u{646}\u{632}\u{646}\u{646}\u{64A}\u{631}\u{64A}\u{632}\u{626}\u{62E}\u{626}\u{647}\u{628}\u{647}\u{62A}\u{647}\u{635}\u{62E}\u{644}\u{647}\u{646}\u{647}\u{647}\u{670}\u{62B}\u{647}\u{633}\u{647}\u{634}\u{645}\u{634}\u{647}\u{640}\u{64E}\u{651}\u{640}\u{64F}\u{651}\u{640}\u{650}\u{651}\u{637}\u{649}\u{637}\u{64A}\u{639}\u{649}\u{639}\u{64A}\u{63A}\u{649}\u{63A}\u{64A}\u{633}\u{649}\u{633}\u{64A}\u{634}\u{649}\u{634}\u{64A}\u{62D}\u{649}\u{62C}\u{649}\u{62C}\u{64A}\u{62E}\u{649}\u{635}\u{649}\u{635}\u{64A}\u{636}\u{649}\u{636}\u{64A}\u{634}\u{62C}\u{634}\u{62D}\u{634}\u{62E}\u{634}\u{631}\u{633}\u{631}\u{635}\u{631}\u{636}\u{631}\u{627}\u{64B}\u{62A}\u{62C}\u{645}\u{62A}\u{62D}\u{62C}\u{62A}\u{62D}\u{645}\u{62A}\u{62E}\u{645}\u{62A}\u{645}\u{62C}\u{62A}\u{645}\u{62D}\u{62A}\u{645}\u{62E}\u{62D}\u{645}\u{64A}\u{62D}\u{645}\u{649}\u{633}\u{62D}\u{62C}\u{633}\u{62C}\u{62D}\u{633}\u{62C}\u{649}\u{633}\u{645}\u{62D}\u{633}\u{645}\u{62C}\u{633}\u{645}\u{645}\u{635}\u{62D}\u{62D}\u{635}\u{645}\u{645}\u{634}\u{62D}\u{645}\u{634}\u{62C}\u{64A}\u{634}\u{645}\u{62E}\u{634}\u{645}\u{645}\u{636}\u{62D}\u{649}\u{636}\u{62E}\u{645}\u{637}\u{645}\u{62D}\u{637}\u{645}\u{645}\u{637}\u{645}\u{64A}\u{639}\u{62C}\u{645}\u{639}\u{645}\u{645}\u{639}\u{645}\u{649}\u{63A}\u{645}\u{645}\u{63A}\u{645}\u{64A}\u{63A}\u{645}\u{649}\u{641}\u{62E}\u{645}\u{642}\u{645}\u{62D}\u{642}\u{645}\u{645}\u{644}\u{62D}\u{645}\u{644}\u{62D}\u{64A}\u{644}\u{62D}\u{649}\u{644}\u{62C}\u{62C}\u{644}\u{62E}\u{645}\u{644}\u{645}\u{62D}\u{645}\u{62D}\u{62C}\u{645}\u{62D}\u{64A}\u{645}\u{62C}\u{62D}\u{645}\u{62E}\u{645}\u{645}\u{62C}\u{62E}\u{647}\u{645}\u{62C}\u{647}\u{645}\u{645}\u{646}\u{62D}\u{645}\u{646}\u{62D}\u{649}\u{646}\u{62C}\u{645}\u{646}\u{62C}\u{649}\u{646}\u{645}\u{64A}\u{646}\u{645}\u{649}\u{64A}\u{645}\u{645}\u{628}\u{62E}\u{64A}\u{62A}\u{62C}\u{64A}\u{62A}\u{62C}\u{649}\u{62A}\u{62E}\u{64A}\u{62A}\u{62E}\u{649}\u{62A}\u{645}\u{64A}\u{62A}\u{645}\u{649}\u{62C}\u{645}\u{64A}\u{62C}\u{62D}\u{649}\u{62C}\u{645}\u{649}\u{633}\u{62E}\u{649}\u{635}\u{62D}\u{64A}\u{634}\u{62D}\u{64A}\u{636}\u{62D}\u{64A}\u{644}\u{62C}\u{64A}\u{644}\u{645}\u{64A}\u{64A}\u{62C}\u{64A}\u{64A}\u{645}\u{64A}\u{645}\u{645}\u{64A}\u{642}\u{645}\u{64A}\u{646}\u{62D}\u{64A}\u{639}\u{645}\u{64A}\u{643}\u{645}\u{64A}\u{646}\u{62C}\u{62D}\u{645}\u{62E}\u{64A}\u{644}\u{62C}\u{645}\u{643}\u{645}\u{645}\u{62C}\u{62D}\u{64A}\u{62D}\u{62C}\u{64A}\u{645}\u{62C}\u{64A}\u{641}\u{645}\u{64A}\u{628}\u{62D}\u{64A}\u{633}\u{62E}\u{64A}\u{646}\u{62C}\u{64A}\u{635}\u{644}\u{6D2}\u{642}\u{644}\u{6D2}\u{627}\u{644}\u{644}\u{647}\u{627}\u{643}\u{628}\u{631}\u{645}\u{62D}\u{645}\u{62F}\u{635}\u{644}\u{639}\u{645}\u{631}\u{633}\u{648}\u{644}\u{639}\u{644}\u{64A}\u{647}\u{648}\u{633}\u{644}\u{645}\u{635}\u{644}\u{649}\u{635}\u{644}\u{649}\u{20}\u{627}\u{644}\u{644}\u{647}\u{20}\u{639}\u{644}\u{64A}\u{647}\u{20}\u{648}\u{633}\u{644}\u{645}\u{62C}\u{644}\u{20}\u{62C}\u{644}\u{627}\u{644}\u{647}\u{631}\u{6CC}\u{627}\u{644},\u{3001}\u{2014}\u{2013}_{}[]#&*-<>\\$%@\u{20}\u{64B}\u{640}\u{64B}\u{640}\u{651}\u{20}\u{652}\u{640}\u{652}\u{629}\u{644}\u{622}\u{644}\u{623}\u{644}\u{625}\"'^|~\u{30FB}\u{30F2}\u{30A5}\u{30E3}¬¦¥\u{20A9}\u{2502}\u{25A0}\u{25CB}\u{1D157}\u{1D165}\u{1D158}\u{1D165}\u{1D158}\u{1D165}\u{1D16E}\u{1D158}\u{1D165}\u{1D16F}\u{1D158}\u{1D165}\u{1D170}\u{1D158}\u{1D165}\u{1D171}\u{1D158}\u{1D165}\u{1D172}\u{1D1B9}\u{1D165}\u{1D1BA}\u{1D165}\u{1D1B9}\u{1D165}\u{1D16E}\u{1D1BA}\u{1D165}\u{1D16E}\u{1D1B9}\u{1D165}\u{1D16F}\u{1D1BA}\u{1D165}\u{1D16F}ıȷ\u{2207}\u{2202}\u{66E}\u{6A1}\u{66F}0,1,2,3,4,5,6,7,8,9,\u{3014}s\u{3015}wzhvsdppvwcmrdj\u{307B}\u{304B}\u{30B3}\u{30B3}\u{5B57}\u{53CC}\u{591A}\u{89E3}\u{4EA4}\u{6620}\u{7121}\u{524D}\u{5F8C}\u{518D}\u{65B0}\u{521D}\u{7D42}\u{8CA9}\u{58F0}\u{5439}\u{6F14}\u{6295}\u{6355}\u{904A}\u{6307}\u{6253}\u{7981}\u{7A7A}\u{5408}\u{6E80}\u{7533}\u{5272}\u{55B6}\u{914D}\u{3014}\u{672C}\u{3015}\u{3014}\u{4E09}\u{3015}\u{3014}\u{4E8C}\u{3015}\u{3014}\u{5B89}\u{3015}\u{3014}\u{70B9}\u{3015}\u{3014}\u{6253}\u{3015}\u{3014}\u{76D7}\u{3015}\u{3014}\u{52DD}\u{3015}\u{3014}\u{6557}\u{3015}\u{5F97}\u{53EF}\u{4E3D}\u{4E38}\u{4E41}\u{20122}\u{4F60}\u{4FBB}\u{5002}\u{507A}\u{5099}\u{50CF}\u{349E}\u{2063A}\u{5154}\u{5164}\u{5177}\u{2051C}\u{34B9}\u{5167}\u{2054B}\u{5197}\u{51A4}\u{4ECC}\u{51AC}\u{291DF}\u{5203}\u{34DF}\u{523B}\u{5246}\u{5277}\u{3515}\u{5349}\u{535A}\u{5373}\u{537D}\u{537F}\u{20A2C}\u{7070}\u{53CA}\u{53DF}\u{20B63}\u{53EB}\u{53F1}\u{5406}\u{549E}\u{5438}\u{5448}\u{5468}\u{54A2}\u{54F6}\u{5510}\u{5553}\u{5563}\u{5584}\u{55AB}\u{55B3}\u{55C2}\u{5716}\u{5717}\u{5651}\u{5674}\u{58EE}\u{57CE}\u{57F4}\u{580D}\u{578B}\u{5832}\u{5831}\u{58AC}\u{214E4}\u{58F2}\u{58F7}\u{5906}\u{5922}\u{5962}\u{216A8}\u{216EA}\u{59EC}\u{5A1B}\u{5A27}\u{59D8}\u{5A66}\u{36EE}\u{5B08}\u{5B3E}\u{219C8}\u{5BC3}\u{5BD8}\u{5BF3}\u{21B18}\u{5BFF}\u{5C06}\u{3781}\u{5C60}\u{5CC0}\u{5C8D}\u{21DE4}\u{5D43}\u{21DE6}\u{5D6E}\u{5D6B}\u{5D7C}\u{382F}\u{5DFD}\u{5E28}\u{5E3D}\u{5E69}\u{3862}\u{22183}\u{387C}\u{5EB0}\u{5EB3}\u{5EB6}\u{2A392}\u{22331}\u{8201}\u{5F22}\u{38C7}\u{232B8}\u{261DA}\u{5F62}\u{5F6B}\u{38E3}\u{5F9A}\u{5FCD}\u{5FD7}\u{5FF9}\u{6081}\u{393A}\u{391C}\u{226D4}\u{60C7}\u{6148}\u{614C}\u{617A}\u{61B2}\u{61A4}\u{61AF}\u{61DE}\u{621B}\u{625D}\u{62B1}\u{62D4}\u{6350}\u{22B0C}\u{633D}\u{62FC}\u{6368}\u{6383}\u{63E4}\u{22BF1}\u{6422}\u{63C5}\u{63A9}\u{3A2E}\u{6469}\u{647E}\u{649D}\u{6477}\u{3A6C}\u{656C}\u{2300A}\u{65E3}\u{66F8}\u{6649}\u{3B19}\u{3B08}\u{3AE4}\u{5192}\u{5195}\u{6700}\u{669C}\u{80AD}\u{43D9}\u{6721}\u{675E}\u{6753}\u{233C3}\u{3B49}\u{67FA}\u{6785}\u{6852}\u{2346D}\u{688E}\u{681F}\u{6914}\u{6942}\u{69A3}\u{69EA}\u{6AA8}\u{236A3}\u{6ADB}\u{3C18}\u{6B21}\u{238A7}\u{6B54}\u{3C4E}\u{6B72}\u{6B9F}\u{23A8D}\u{21D0B}\u{23AFA}\u{6C4E}\u{23CBC}\u{6CBF}\u{6CCD}\u{6C67}\u{6D16}\u{6D3E}\u{6D69}\u{6D78}\u{6D85}\u{23D1E}\u{6D34}\u{6E2F}\u{6E6E}\u{3D33}\u{6EC7}\u{23ED1}\u{6DF9}\u{6F6E}\u{23F5E}\u{23F8E}\u{6FC6}\u{7039}\u{701B}\u{3D96}\u{704A}\u{707D}\u{7077}\u{70AD}\u{20525}\u{7145}\u{24263}\u{719C}\u{7228}\u{7250}\u{24608}\u{7280}\u{7295}\u{24735}\u{24814}\u{737A}\u{738B}\u{3EAC}\u{73A5}\u{3EB8}\u{7447}\u{745C}\u{7485}\u{74CA}\u{3F1B}\u{7524}\u{24C36}\u{753E}\u{24C92}\u{2219F}\u{7610}\u{24FA1}\u{24FB8}\u{25044}\u{3FFC}\u{4008}\u{250F3}\u{250F2}\u{25119}\u{25133}\u{771F}\u{778B}\u{4046}\u{4096}\u{2541D}\u{784E}\u{40E3}\u{25626}\u{2569A}\u{256C5}\u{79EB}\u{412F}\u{7A4A}\u{7A4F}\u{2597C}\u{25AA7}\u{4202}\u{25BAB}\u{7BC6}\u{7BC9}\u{4227}\u{25C80}\u{7CD2}\u{42A0}\u{7CE8}\u{7CE3}\u{7D00}\u{25F86}\u{7D63}\u{4301}\u{7DC7}\u{7E02}\u{7E45}\u{4334}\u{26228}\u{26247}\u{4359}\u{262D9}\u{7F7A}\u{2633E}\u{7F95}\u{7FFA}\u{264DA}\u{26523}\u{8060}\u{265A8}\u{8070}\u{2335F}\u{43D5}\u{80B2}\u{8103}\u{440B}\u{813E}\u{5AB5}\u{267A7}\u{267B5}\u{23393}\u{2339C}\u{8204}\u{8F9E}\u{446B}\u{8291}\u{828B}\u{829D}\u{52B3}\u{82B1}\u{82B3}\u{82BD}\u{82E6}\u{26B3C}\u{831D}\u{8363}\u{83AD}\u{8323}\u{83BD}\u{83E7}\u{8353}\u{83CA}\u{83CC}\u{83DC}\u{26C36}\u{26D6B}\u{26CD5}\u{452B}\u{84F1}\u{84F3}\u{8516}\u{273CA}\u{8564}\u{26F2C}\u{455D}\u{4561}\u{26FB1}\u{270D2}\u{456B}\u{8650}\u{8667}\u{8669}\u{86A9}\u{8688}\u{870E}\u{86E2}\u{8728}\u{876B}\u{8786}\u{87E1}\u{8801}\u{45F9}\u{8860}\u{27667}\u{88D7}\u{88DE}\u{4635}\u{88FA}\u{34BB}\u{278AE}\u{27966}\u{46BE}\u{46C7}\u{8AA0}\u{27CA8}\u{8CAB}\u{8CC1}\u{8D1B}\u{8D77}\u{27F2F}\u{20804}\u{8DCB}\u{8DBC}\u{8DF0}\u{208DE}\u{8ED4}\u{285D2}\u{285ED}\u{9094}\u{90F1}\u{9111}\u{2872E}\u{911B}\u{9238}\u{927C}\u{93F9}\u{9415}\u{28BFA}\u{958B}\u{4995}\u{95B7}\u{28D77}\u{49E6}\u{96C3}\u{5DB2}\u{9723}\u{29145}\u{2921A}\u{4A6E}\u{4A76}\u{97E0}\u{2940A}\u{4AB2}\u{29496}\u{9829}\u{295B6}\u{98E2}\u{4B33}\u{9929}\u{99A7}\u{99C2}\u{99FE}\u{4BCE}\u{29B30}\u{9C40}\u{9CFD}\u{4CCE}\u{4CED}\u{9D67}\u{2A0CE}\u{4CF8}\u{2A105}\u{2A20E}\u{2A291}\u{4D56}\u{9EFE}\u{9F05}\u{9F0F}\u{9F16}\u{2A600}";
+
+/// The canonical combining classes that are not 0
+pub(super) const COMBINING_CLASS: &[(u32, u32, u8)] = &[
+    (0x300, 0x314, 230), (0x315, 0x315, 232), (0x316, 0x319, 220), (0x31A, 0x31A, 232),
+    (0x31B, 0x31B, 216), (0x31C, 0x320, 220), (0x321, 0x322, 202), (0x323, 0x326, 220),
+    (0x327, 0x328, 202), (0x329, 0x333, 220), (0x334, 0x338, 1), (0x339, 0x33C, 220),
+    (0x33D, 0x344, 230), (0x345, 0x345, 240), (0x346, 0x346, 230), (0x347, 0x349, 220),
+    (0x34A, 0x34C, 230), (0x34D, 0x34E, 220), (0x350, 0x352, 230), (0x353, 0x356, 220),
+    (0x357, 0x357, 230), (0x358, 0x358, 232), (0x359, 0x35A, 220), (0x35B, 0x35B, 230),
+    (0x35C, 0x35C, 233), (0x35D, 0x35E, 234), (0x35F, 0x35F, 233), (0x360, 0x361, 234),
+    (0x362, 0x362, 233), (0x363, 0x36F, 230), (0x483, 0x487, 230), (0x591, 0x591, 220),
+    (0x592, 0x595, 230), (0x596, 0x596, 220), (0x597, 0x599, 230), (0x59A, 0x59A, 222),
+    (0x59B, 0x59B, 220), (0x59C, 0x5A1, 230), (0x5A2, 0x5A7, 220), (0x5A8, 0x5A9, 230),
+    (0x5AA, 0x5AA, 220), (0x5AB, 0x5AC, 230), (0x5AD, 0x5AD, 222), (0x5AE, 0x5AE, 228),
+    (0x5AF, 0x5AF, 230), (0x5B0, 0x5B0, 10), (0x5B1, 0x5B1, 11), (0x5B2, 0x5B2, 12),
+    (0x5B3, 0x5B3, 13), (0x5B4, 0x5B4, 14), (0x5B5, 0x5B5, 15), (0x5B6, 0x5B6, 16),
+    (0x5B7, 0x5B7, 17), (0x5B8, 0x5B8, 18), (0x5B9, 0x5BA, 19), (0x5BB, 0x5BB, 20),
+    (0x5BC, 0x5BC, 21), (0x5BD, 0x5BD, 22), (0x5BF, 0x5BF, 23), (0x5C1, 0x5C1, 24),
+    (0x5C2, 0x5C2, 25), (0x5C4, 0x5C4, 230), (0x5C5, 0x5C5, 220), (0x5C7, 0x5C7, 18),
+    (0x610, 0x617, 230), (0x618, 0x618, 30), (0x619, 0x619, 31), (0x61A, 0x61A, 32),
+    (0x64B, 0x64B, 27), (0x64C, 0x64C, 28), (0x64D, 0x64D, 29), (0x64E, 0x64E, 30),
+    (0x64F, 0x64F, 31), (0x650, 0x650, 32), (0x651, 0x651, 33), (0x652, 0x652, 34),
+    (0x653, 0x654, 230), (0x655, 0x656, 220), (0x657, 0x65B, 230), (0x65C, 0x65C, 220),
+    (0x65D, 0x65E, 230), (0x65F, 0x65F, 220), (0x670, 0x670, 35), (0x6D6, 0x6DC, 230),
+    (0x6DF, 0x6E2, 230), (0x6E3, 0x6E3, 220), (0x6E4, 0x6E4, 230), (0x6E7, 0x6E8, 230),
+    (0x6EA, 0x6EA, 220), (0x6EB, 0x6EC, 230), (0x6ED, 0x6ED, 220), (0x711, 0x711, 36),
+    (0x730, 0x730, 230), (0x731, 0x731, 220), (0x732, 0x733, 230), (0x734, 0x734, 220),
+    (0x735, 0x736, 230), (0x737, 0x739, 220), (0x73A, 0x73A, 230), (0x73B, 0x73C, 220),
+    (0x73D, 0x73D, 230), (0x73E, 0x73E, 220), (0x73F, 0x741, 230), (0x742, 0x742, 220),
+    (0x743, 0x743, 230), (0x744, 0x744, 220), (0x745, 0x745, 230), (0x746, 0x746, 220),
+    (0x747, 0x747, 230), (0x748, 0x748, 220), (0x749, 0x74A, 230), (0x7EB, 0x7F1, 230),
+    (0x7F2, 0x7F2, 220), (0x7F3, 0x7F3, 230), (0x7FD, 0x7FD, 220), (0x816, 0x819, 230),
+    (0x81B, 0x823, 230), (0x825, 0x827, 230), (0x829, 0x82D, 230), (0x859, 0x85B, 220),
+    (0x898, 0x898, 230), (0x899, 0x89B, 220), (0x89C, 0x89F, 230), (0x8CA, 0x8CE, 230),
+    (0x8CF, 0x8D3, 220), (0x8D4, 0x8E1, 230), (0x8E3, 0x8E3, 220), (0x8E4, 0x8E5, 230),
+    (0x8E6, 0x8E6, 220), (0x8E7, 0x8E8, 230), (0x8E9, 0x8E9, 220), (0x8EA, 0x8EC, 230),
+    (0x8ED, 0x8EF, 220), (0x8F0, 0x8F0, 27), (0x8F1, 0x8F1, 28), (0x8F2, 0x8F2, 29),
+    (0x8F3, 0x8F5, 230), (0x8F6, 0x8F6, 220), (0x8F7, 0x8F8, 230), (0x8F9, 0x8FA, 220),
+    (0x8FB, 0x8FF, 230), (0x93C, 0x93C, 7), (0x94D, 0x94D, 9), (0x951, 0x951, 230),
+    (0x952, 0x952, 220), (0x953, 0x954, 230), (0x9BC, 0x9BC, 7), (0x9CD, 0x9CD, 9),
+    (0x9FE, 0x9FE, 230), (0xA3C, 0xA3C, 7), (0xA4D, 0xA4D, 9), (0xABC, 0xABC, 7),
+    (0xACD, 0xACD, 9), (0xB3C, 0xB3C, 7), (0xB4D, 0xB4D, 9), (0xBCD, 0xBCD, 9),
+    (0xC3C, 0xC3C, 7), (0xC4D, 0xC4D, 9), (0xC55, 0xC55, 84), (0xC56, 0xC56, 91),
+    (0xCBC, 0xCBC, 7), (0xCCD, 0xCCD, 9), (0xD3B, 0xD3C, 9), (0xD4D, 0xD4D, 9),
+    (0xDCA, 0xDCA, 9), (0xE38, 0xE39, 103), (0xE3A, 0xE3A, 9), (0xE48, 0xE4B, 107),
+    (0xEB8, 0xEB9, 118), (0xEBA, 0xEBA, 9), (0xEC8, 0xECB, 122), (0xF18, 0xF19, 220),
+    (0xF35, 0xF35, 220), (0xF37, 0xF37, 220), (0xF39, 0xF39, 216), (0xF71, 0xF71, 129),
+    (0xF72, 0xF72, 130), (0xF74, 0xF74, 132), (0xF7A, 0xF7D, 130), (0xF80, 0xF80, 130),
+    (0xF82, 0xF83, 230), (0xF84, 0xF84, 9), (0xF86, 0xF87, 230), (0xFC6, 0xFC6, 220),
+    (0x1037, 0x1037, 7), (0x1039, 0x103A, 9), (0x108D, 0x108D, 220), (0x135D, 0x135F, 230),
+    (0x1714, 0x1715, 9), (0x1734, 0x1734, 9), (0x17D2, 0x17D2, 9), (0x17DD, 0x17DD, 230),
+    (0x18A9, 0x18A9, 228), (0x1939, 0x1939, 222), (0x193A, 0x193A, 230), (0x193B, 0x193B, 220),
+    (0x1A17, 0x1A17, 230), (0x1A18, 0x1A18, 220), (0x1A60, 0x1A60, 9), (0x1A75, 0x1A7C, 230),
+    (0x1A7F, 0x1A7F, 220), (0x1AB0, 0x1AB4, 230), (0x1AB5, 0x1ABA, 220), (0x1ABB, 0x1ABC, 230),
+    (0x1ABD, 0x1ABD, 220), (0x1ABF, 0x1AC0, 220), (0x1AC1, 0x1AC2, 230), (0x1AC3, 0x1AC4, 220),
+    (0x1AC5, 0x1AC9, 230), (0x1ACA, 0x1ACA, 220), (0x1ACB, 0x1ACE, 230), (0x1B34, 0x1B34, 7),
+    (0x1B44, 0x1B44, 9), (0x1B6B, 0x1B6B, 230), (0x1B6C, 0x1B6C, 220), (0x1B6D, 0x1B73, 230),
+    (0x1BAA, 0x1BAB, 9), (0x1BE6, 0x1BE6, 7), (0x1BF2, 0x1BF3, 9), (0x1C37, 0x1C37, 7),
+    (0x1CD0, 0x1CD2, 230), (0x1CD4, 0x1CD4, 1), (0x1CD5, 0x1CD9, 220), (0x1CDA, 0x1CDB, 230),
+    (0x1CDC, 0x1CDF, 220), (0x1CE0, 0x1CE0, 230), (0x1CE2, 0x1CE8, 1), (0x1CED, 0x1CED, 220),
+    (0x1CF4, 0x1CF4, 230), (0x1CF8, 0x1CF9, 230), (0x1DC0, 0x1DC1, 230), (0x1DC2, 0x1DC2, 220),
+    (0x1DC3, 0x1DC9, 230), (0x1DCA, 0x1DCA, 220), (0x1DCB, 0x1DCC, 230), (0x1DCD, 0x1DCD, 234),
+    (0x1DCE, 0x1DCE, 214), (0x1DCF, 0x1DCF, 220), (0x1DD0, 0x1DD0, 202), (0x1DD1, 0x1DF5, 230),
+    (0x1DF6, 0x1DF6, 232), (0x1DF7, 0x1DF8, 228), (0x1DF9, 0x1DF9, 220), (0x1DFA, 0x1DFA, 218),
+    (0x1DFB, 0x1DFB, 230), (0x1DFC, 0x1DFC, 233), (0x1DFD, 0x1DFD, 220), (0x1DFE, 0x1DFE, 230),
+    (0x1DFF, 0x1DFF, 220), (0x20D0, 0x20D1, 230), (0x20D2, 0x20D3, 1), (0x20D4, 0x20D7, 230),
+    (0x20D8, 0x20DA, 1), (0x20DB, 0x20DC, 230), (0x20E1, 0x20E1, 230), (0x20E5, 0x20E6, 1),
+    (0x20E7, 0x20E7, 230), (0x20E8, 0x20E8, 220), (0x20E9, 0x20E9, 230), (0x20EA, 0x20EB, 1),
+    (0x20EC, 0x20EF, 220), (0x20F0, 0x20F0, 230), (0x2CEF, 0x2CF1, 230), (0x2D7F, 0x2D7F, 9),
+    (0x2DE0, 0x2DFF, 230), (0x302A, 0x302A, 218), (0x302B, 0x302B, 228), (0x302C, 0x302C, 232),
+    (0x302D, 0x302D, 222), (0x302E, 0x302F, 224), (0x3099, 0x309A, 8), (0xA66F, 0xA66F, 230),
+    (0xA674, 0xA67D, 230), (0xA69E, 0xA69F, 230), (0xA6F0, 0xA6F1, 230), (0xA806, 0xA806, 9),
+    (0xA82C, 0xA82C, 9), (0xA8C4, 0xA8C4, 9), (0xA8E0, 0xA8F1, 230), (0xA92B, 0xA92D, 220),
+    (0xA953, 0xA953, 9), (0xA9B3, 0xA9B3, 7), (0xA9C0, 0xA9C0, 9), (0xAAB0, 0xAAB0, 230),
+    (0xAAB2, 0xAAB3, 230), (0xAAB4, 0xAAB4, 220), (0xAAB7, 0xAAB8, 230), (0xAABE, 0xAABF, 230),
+    (0xAAC1, 0xAAC1, 230), (0xAAF6, 0xAAF6, 9), (0xABED, 0xABED, 9), (0xFB1E, 0xFB1E, 26),
+    (0xFE20, 0xFE26, 230), (0xFE27, 0xFE2D, 220), (0xFE2E, 0xFE2F, 230), (0x101FD, 0x101FD, 220),
+    (0x102E0, 0x102E0, 220), (0x10376, 0x1037A, 230), (0x10A0D, 0x10A0D, 220), (0x10A0F, 0x10A0F, 230),
+    (0x10A38, 0x10A38, 230), (0x10A39, 0x10A39, 1), (0x10A3A, 0x10A3A, 220), (0x10A3F, 0x10A3F, 9),
+    (0x10AE5, 0x10AE5, 230), (0x10AE6, 0x10AE6, 220), (0x10D24, 0x10D27, 230), (0x10EAB, 0x10EAC, 230),
+    (0x10F46, 0x10F47, 220), (0x10F48, 0x10F4A, 230), (0x10F4B, 0x10F4B, 220), (0x10F4C, 0x10F4C, 230),
+    (0x10F4D, 0x10F50, 220), (0x10F82, 0x10F82, 230), (0x10F83, 0x10F83, 220), (0x10F84, 0x10F84, 230),
+    (0x10F85, 0x10F85, 220), (0x11046, 0x11046, 9), (0x11070, 0x11070, 9), (0x1107F, 0x1107F, 9),
+    (0x110B9, 0x110B9, 9), (0x110BA, 0x110BA, 7), (0x11100, 0x11102, 230), (0x11133, 0x11134, 9),
+    (0x11173, 0x11173, 7), (0x111C0, 0x111C0, 9), (0x111CA, 0x111CA, 7), (0x11235, 0x11235, 9),
+    (0x11236, 0x11236, 7), (0x112E9, 0x112E9, 7), (0x112EA, 0x112EA, 9), (0x1133B, 0x1133C, 7),
+    (0x1134D, 0x1134D, 9), (0x11366, 0x1136C, 230), (0x11370, 0x11374, 230), (0x11442, 0x11442, 9),
+    (0x11446, 0x11446, 7), (0x1145E, 0x1145E, 230), (0x114C2, 0x114C2, 9), (0x114C3, 0x114C3, 7),
+    (0x115BF, 0x115BF, 9), (0x115C0, 0x115C0, 7), (0x1163F, 0x1163F, 9), (0x116B6, 0x116B6, 9),
+    (0x116B7, 0x116B7, 7), (0x1172B, 0x1172B, 9), (0x11839, 0x11839, 9), (0x1183A, 0x1183A, 7),
+    (0x1193D, 0x1193E, 9), (0x11943, 0x11943, 7), (0x119E0, 0x119E0, 9), (0x11A34, 0x11A34, 9),
+    (0x11A47, 0x11A47, 9), (0x11A99, 0x11A99, 9), (0x11C3F, 0x11C3F, 9), (0x11D42, 0x11D42, 7),
+    (0x11D44, 0x11D45, 9), (0x11D97, 0x11D97, 9), (0x16AF0, 0x16AF4, 1), (0x16B30, 0x16B36, 230),
+    (0x16FF0, 0x16FF1, 6), (0x1BC9E, 0x1BC9E, 1), (0x1D165, 0x1D166, 216), (0x1D167, 0x1D169, 1),
+    (0x1D16D, 0x1D16D, 226), (0x1D16E, 0x1D172, 216), (0x1D17B, 0x1D182, 220), (0x1D185, 0x1D189, 230),
+    (0x1D18A, 0x1D18B, 220), (0x1D1AA, 0x1D1AD, 230), (0x1D242, 0x1D244, 230), (0x1E000, 0x1E006, 230),
+    (0x1E008, 0x1E018, 230), (0x1E01B, 0x1E021, 230), (0x1E023, 0x1E024, 230), (0x1E026, 0x1E02A, 230),
+    (0x1E130, 0x1E136, 230), (0x1E2AE, 0x1E2AE, 230), (0x1E2EC, 0x1E2EF, 230), (0x1E8D0, 0x1E8D6, 220),
+    (0x1E944, 0x1E949, 230), (0x1E94A, 0x1E94A, 7),
+];
+
+/// The canonical decompositions in one or two code points, the second one 0 if there is none
+pub(super) const DECOMPOSITION: &[(u32, u32, u32)] = &[
+    (0xC0, 0x41, 0x300), (0xC1, 0x41, 0x301), (0xC2, 0x41, 0x302), (0xC3, 0x41, 0x303),
+    (0xC4, 0x41, 0x308), (0xC5, 0x41, 0x30A), (0xC7, 0x43, 0x327), (0xC8, 0x45, 0x300),
+    (0xC9, 0x45, 0x301), (0xCA, 0x45, 0x302), (0xCB, 0x45, 0x308), (0xCC, 0x49, 0x300),
+    (0xCD, 0x49, 0x301), (0xCE, 0x49, 0x302), (0xCF, 0x49, 0x308), (0xD1, 0x4E, 0x303),
+    (0xD2, 0x4F, 0x300), (0xD3, 0x4F, 0x301), (0xD4, 0x4F, 0x302), (0xD5, 0x4F, 0x303),
+    (0xD6, 0x4F, 0x308), (0xD9, 0x55, 0x300), (0xDA, 0x55, 0x301), (0xDB, 0x55, 0x302),
+    (0xDC, 0x55, 0x308), (0xDD, 0x59, 0x301), (0xE0, 0x61, 0x300), (0xE1, 0x61, 0x301),
+    (0xE2, 0x61, 0x302), (0xE3, 0x61, 0x303), (0xE4, 0x61, 0x308), (0xE5, 0x61, 0x30A),
+    (0xE7, 0x63, 0x327), (0xE8, 0x65, 0x300), (0xE9, 0x65, 0x301), (0xEA, 0x65, 0x302),
+    (0xEB, 0x65, 0x308), (0xEC, 0x69, 0x300), (0xED, 0x69, 0x301), (0xEE, 0x69, 0x302),
+    (0xEF, 0x69, 0x308), (0xF1, 0x6E, 0x303), (0xF2, 0x6F, 0x300), (0xF3, 0x6F, 0x301),
+    (0xF4, 0x6F, 0x302), (0xF5, 0x6F, 0x303), (0xF6, 0x6F, 0x308), (0xF9, 0x75, 0x300),
+    (0xFA, 0x75, 0x301), (0xFB, 0x75, 0x302), (0xFC, 0x75, 0x308), (0xFD, 0x79, 0x301),
+    (0xFF, 0x79, 0x308), (0x100, 0x41, 0x304), (0x101, 0x61, 0x304), (0x102, 0x41, 0x306),
+    (0x103, 0x61, 0x306), (0x104, 0x41, 0x328), (0x105, 0x61, 0x328), (0x106, 0x43, 0x301),
+    (0x107, 0x63, 0x301), (0x108, 0x43, 0x302), (0x109, 0x63, 0x302), (0x10A, 0x43, 0x307),
+    (0x10B, 0x63, 0x307), (0x10C, 0x43, 0x30C), (0x10D, 0x63, 0x30C), (0x10E, 0x44, 0x30C),
+    (0x10F, 0x64, 0x30C), (0x112, 0x45, 0x304), (0x113, 0x65, 0x304), (0x114, 0x45, 0x306),
+    (0x115, 0x65, 0x306), (0x116, 0x45, 0x307), (0x117, 0x65, 0x307), (0x118, 0x45, 0x328),
+    (0x119, 0x65, 0x328), (0x11A, 0x45, 0x30C), (0x11B, 0x65, 0x30C), (0x11C, 0x47, 0x302),
+    (0x11D, 0x67, 0x302), (0x11E, 0x47, 0x306), (0x11F, 0x67, 0x306), (0x120, 0x47, 0x307),
+    (0x121, 0x67, 0x307), (0x122, 0x47, 0x327), (0x123, 0x67, 0x327), (0x124, 0x48, 0x302),
+    (0x125, 0x68, 0x302), (0x128, 0x49, 0x303), (0x129, 0x69, 0x303), (0x12A, 0x49, 0x304),
+    (0x12B, 0x69, 0x304), (0x12C, 0x49, 0x306), (0x12D, 0x69, 0x306), (0x12E, 0x49, 0x328),
+    (0x12F, 0x69, 0x328), (0x130, 0x49, 0x307), (0x134, 0x4A, 0x302), (0x135, 0x6A, 0x302),
+    (0x136, 0x4B, 0x327), (0x137, 0x6B, 0x327), (0x139, 0x4C, 0x301), (0x13A, 0x6C, 0x301),
+    (0x13B, 0x4C, 0x327), (0x13C, 0x6C, 0x327), (0x13D, 0x4C, 0x30C), (0x13E, 0x6C, 0x30C),
+    (0x143, 0x4E, 0x301), (0x144, 0x6E, 0x301), (0x145, 0x4E, 0x327), (0x146, 0x6E, 0x327),
+    (0x147, 0x4E, 0x30C), (0x148, 0x6E, 0x30C), (0x14C, 0x4F, 0x304), (0x14D, 0x6F, 0x304),
+    (0x14E, 0x4F, 0x306), (0x14F, 0x6F, 0x306), (0x150, 0x4F, 0x30B), (0x151, 0x6F, 0x30B),
+    (0x154, 0x52, 0x301), (0x155, 0x72, 0x301), (0x156, 0x52, 0x327), (0x157, 0x72, 0x327),
+    (0x158, 0x52, 0x30C), (0x159, 0x72, 0x30C), (0x15A, 0x53, 0x301), (0x15B, 0x73, 0x301),
+    (0x15C, 0x53, 0x302), (0x15D, 0x73, 0x302), (0x15E, 0x53, 0x327), (0x15F, 0x73, 0x327),
+    (0x160, 0x53, 0x30C), (0x161, 0x73, 0x30C), (0x162, 0x54, 0x327), (0x163, 0x74, 0x327),
+    (0x164, 0x54, 0x30C), (0x165, 0x74, 0x30C), (0x168, 0x55, 0x303), (0x169, 0x75, 0x303),
+    (0x16A, 0x55, 0x304), (0x16B, 0x75, 0x304), (0x16C, 0x55, 0x306), (0x16D, 0x75, 0x306),
+    (0x16E, 0x55, 0x30A), (0x16F, 0x75, 0x30A), (0x170, 0x55, 0x30B), (0x171, 0x75, 0x30B),
+    (0x172, 0x55, 0x328), (0x173, 0x75, 0x328), (0x174, 0x57, 0x302), (0x175, 0x77, 0x302),
+    (0x176, 0x59, 0x302), (0x177, 0x79, 0x302), (0x178, 0x59, 0x308), (0x179, 0x5A, 0x301),
+    (0x17A, 0x7A, 0x301), (0x17B, 0x5A, 0x307), (0x17C, 0x7A, 0x307), (0x17D, 0x5A, 0x30C),
+    (0x17E, 0x7A, 0x30C), (0x1A0, 0x4F, 0x31B), (0x1A1, 0x6F, 0x31B), (0x1AF, 0x55, 0x31B),
+    (0x1B0, 0x75, 0x31B), (0x1CD, 0x41, 0x30C), (0x1CE, 0x61, 0x30C), (0x1CF, 0x49, 0x30C),
+    (0x1D0, 0x69, 0x30C), (0x1D1, 0x4F, 0x30C), (0x1D2, 0x6F, 0x30C), (0x1D3, 0x55, 0x30C),
+    (0x1D4, 0x75, 0x30C), (0x1D5, 0xDC, 0x304), (0x1D6, 0xFC, 0x304), (0x1D7, 0xDC, 0x301),
+    (0x1D8, 0xFC, 0x301), (0x1D9, 0xDC, 0x30C), (0x1DA, 0xFC, 0x30C), (0x1DB, 0xDC, 0x300),
+    (0x1DC, 0xFC, 0x300), (0x1DE, 0xC4, 0x304), (0x1DF, 0xE4, 0x304), (0x1E0, 0x226, 0x304),
+    (0x1E1, 0x227, 0x304), (0x1E2, 0xC6, 0x304), (0x1E3, 0xE6, 0x304), (0x1E6, 0x47, 0x30C),
+    (0x1E7, 0x67, 0x30C), (0x1E8, 0x4B, 0x30C), (0x1E9, 0x6B, 0x30C), (0x1EA, 0x4F, 0x328),
+    (0x1EB, 0x6F, 0x328), (0x1EC, 0x1EA, 0x304), (0x1ED, 0x1EB, 0x304), (0x1EE, 0x1B7, 0x30C),
+    (0x1EF, 0x292, 0x30C), (0x1F0, 0x6A, 0x30C), (0x1F4, 0x47, 0x301), (0x1F5, 0x67, 0x301),
+    (0x1F8, 0x4E, 0x300), (0x1F9, 0x6E, 0x300), (0x1FA, 0xC5, 0x301), (0x1FB, 0xE5, 0x301),
+    (0x1FC, 0xC6, 0x301), (0x1FD, 0xE6, 0x301), (0x1FE, 0xD8, 0x301), (0x1FF, 0xF8, 0x301),
+    (0x200, 0x41, 0x30F), (0x201, 0x61, 0x30F), (0x202, 0x41, 0x311), (0x203, 0x61, 0x311),
+    (0x204, 0x45, 0x30F), (0x205, 0x65, 0x30F), (0x206, 0x45, 0x311), (0x207, 0x65, 0x311),
+    (0x208, 0x49, 0x30F), (0x209, 0x69, 0x30F), (0x20A, 0x49, 0x311), (0x20B, 0x69, 0x311),
+    (0x20C, 0x4F, 0x30F), (0x20D, 0x6F, 0x30F), (0x20E, 0x4F, 0x311), (0x20F, 0x6F, 0x311),
+    (0x210, 0x52, 0x30F), (0x211, 0x72, 0x30F), (0x212, 0x52, 0x311), (0x213, 0x72, 0x311),
+    (0x214, 0x55, 0x30F), (0x215, 0x75, 0x30F), (0x216, 0x55, 0x311), (0x217, 0x75, 0x311),
+    (0x218, 0x53, 0x326), (0x219, 0x73, 0x326), (0x21A, 0x54, 0x326), (0x21B, 0x74, 0x326),
+    (0x21E, 0x48, 0x30C), (0x21F, 0x68, 0x30C), (0x226, 0x41, 0x307), (0x227, 0x61, 0x307),
+    (0x228, 0x45, 0x327), (0x229, 0x65, 0x327), (0x22A, 0xD6, 0x304), (0x22B, 0xF6, 0x304),
+    (0x22C, 0xD5, 0x304), (0x22D, 0xF5, 0x304), (0x22E, 0x4F, 0x307), (0x22F, 0x6F, 0x307),
+    (0x230, 0x22E, 0x304), (0x231, 0x22F, 0x304), (0x232, 0x59, 0x304), (0x233, 0x79, 0x304),
+    (0x340, 0x300, 0x0), (0x341, 0x301, 0x0), (0x343, 0x313, 0x0), (0x344, 0x308, 0x301),
+    (0x374, 0x2B9, 0x0), (0x37E, 0x3B, 0x0), (0x385, 0xA8, 0x301), (0x386, 0x391, 0x301),
+    (0x387, 0xB7, 0x0), (0x388, 0x395, 0x301), (0x389, 0x397, 0x301), (0x38A, 0x399, 0x301),
+    (0x38C, 0x39F, 0x301), (0x38E, 0x3A5, 0x301), (0x38F, 0x3A9, 0x301), (0x390, 0x3CA, 0x301),
+    (0x3AA, 0x399, 0x308), (0x3AB, 0x3A5, 0x308), (0x3AC, 0x3B1, 0x301), (0x3AD, 0x3B5, 0x301),
+    (0x3AE, 0x3B7, 0x301), (0x3AF, 0x3B9, 0x301), (0x3B0, 0x3CB, 0x301), (0x3CA, 0x3B9, 0x308),
+    (0x3CB, 0x3C5, 0x308), (0x3CC, 0x3BF, 0x301), (0x3CD, 0x3C5, 0x301), (0x3CE, 0x3C9, 0x301),
+    (0x3D3, 0x3D2, 0x301), (0x3D4, 0x3D2, 0x308), (0x400, 0x415, 0x300), (0x401, 0x415, 0x308),
+    (0x403, 0x413, 0x301), (0x407, 0x406, 0x308), (0x40C, 0x41A, 0x301), (0x40D, 0x418, 0x300),
+    (0x40E, 0x423, 0x306), (0x419, 0x418, 0x306), (0x439, 0x438, 0x306), (0x450, 0x435, 0x300),
+    (0x451, 0x435, 0x308), (0x453, 0x433, 0x301), (0x457, 0x456, 0x308), (0x45C, 0x43A, 0x301),
+    (0x45D, 0x438, 0x300), (0x45E, 0x443, 0x306), (0x476, 0x474, 0x30F), (0x477, 0x475, 0x30F),
+    (0x4C1, 0x416, 0x306), (0x4C2, 0x436, 0x306), (0x4D0, 0x410, 0x306), (0x4D1, 0x430, 0x306),
+    (0x4D2, 0x410, 0x308), (0x4D3, 0x430, 0x308), (0x4D6, 0x415, 0x306), (0x4D7, 0x435, 0x306),
+    (0x4DA, 0x4D8, 0x308), (0x4DB, 0x4D9, 0x308), (0x4DC, 0x416, 0x308), (0x4DD, 0x436, 0x308),
+    (0x4DE, 0x417, 0x308), (0x4DF, 0x437, 0x308), (0x4E2, 0x418, 0x304), (0x4E3, 0x438, 0x304),
+    (0x4E4, 0x418, 0x308), (0x4E5, 0x438, 0x308), (0x4E6, 0x41E, 0x308), (0x4E7, 0x43E, 0x308),
+    (0x4EA, 0x4E8, 0x308), (0x4EB, 0x4E9, 0x308), (0x4EC, 0x42D, 0x308), (0x4ED, 0x44D, 0x308),
+    (0x4EE, 0x423, 0x304), (0x4EF, 0x443, 0x304), (0x4F0, 0x423, 0x308), (0x4F1, 0x443, 0x308),
+    (0x4F2, 0x423, 0x30B), (0x4F3, 0x443, 0x30B), (0x4F4, 0x427, 0x308), (0x4F5, 0x447, 0x308),
+    (0x4F8, 0x42B, 0x308), (0x4F9, 0x44B, 0x308), (0x622, 0x627, 0x653), (0x623, 0x627, 0x654),
+    (0x624, 0x648, 0x654), (0x625, 0x627, 0x655), (0x626, 0x64A, 0x654), (0x6C0, 0x6D5, 0x654),
+    (0x6C2, 0x6C1, 0x654), (0x6D3, 0x6D2, 0x654), (0x929, 0x928, 0x93C), (0x931, 0x930, 0x93C),
+    (0x934, 0x933, 0x93C), (0x958, 0x915, 0x93C), (0x959, 0x916, 0x93C), (0x95A, 0x917, 0x93C),
+    (0x95B, 0x91C, 0x93C), (0x95C, 0x921, 0x93C), (0x95D, 0x922, 0x93C), (0x95E, 0x92B, 0x93C),
+    (0x95F, 0x92F, 0x93C), (0x9CB, 0x9C7, 0x9BE), (0x9CC, 0x9C7, 0x9D7), (0x9DC, 0x9A1, 0x9BC),
+    (0x9DD, 0x9A2, 0x9BC), (0x9DF, 0x9AF, 0x9BC), (0xA33, 0xA32, 0xA3C), (0xA36, 0xA38, 0xA3C),
+    (0xA59, 0xA16, 0xA3C), (0xA5A, 0xA17, 0xA3C), (0xA5B, 0xA1C, 0xA3C), (0xA5E, 0xA2B, 0xA3C),
+    (0xB48, 0xB47, 0xB56), (0xB4B, 0xB47, 0xB3E), (0xB4C, 0xB47, 0xB57), (0xB5C, 0xB21, 0xB3C),
+    (0xB5D, 0xB22, 0xB3C), (0xB94, 0xB92, 0xBD7), (0xBCA, 0xBC6, 0xBBE), (0xBCB, 0xBC7, 0xBBE),
+    (0xBCC, 0xBC6, 0xBD7), (0xC48, 0xC46, 0xC56), (0xCC0, 0xCBF, 0xCD5), (0xCC7, 0xCC6, 0xCD5),
+    (0xCC8, 0xCC6, 0xCD6), (0xCCA, 0xCC6, 0xCC2), (0xCCB, 0xCCA, 0xCD5), (0xD4A, 0xD46, 0xD3E),
+    (0xD4B, 0xD47, 0xD3E), (0xD4C, 0xD46, 0xD57), (0xDDA, 0xDD9, 0xDCA), (0xDDC, 0xDD9, 0xDCF),
+    (0xDDD, 0xDDC, 0xDCA), (0xDDE, 0xDD9, 0xDDF), (0xF43, 0xF42, 0xFB7), (0xF4D, 0xF4C, 0xFB7),
+    (0xF52, 0xF51, 0xFB7), (0xF57, 0xF56, 0xFB7), (0xF5C, 0xF5B, 0xFB7), (0xF69, 0xF40, 0xFB5),
+    (0xF73, 0xF71, 0xF72), (0xF75, 0xF71, 0xF74), (0xF76, 0xFB2, 0xF80), (0xF78, 0xFB3, 0xF80),
+    (0xF81, 0xF71, 0xF80), (0xF93, 0xF92, 0xFB7), (0xF9D, 0xF9C, 0xFB7), (0xFA2, 0xFA1, 0xFB7),
+    (0xFA7, 0xFA6, 0xFB7), (0xFAC, 0xFAB, 0xFB7), (0xFB9, 0xF90, 0xFB5), (0x1026, 0x1025, 0x102E),
+    (0x1B06, 0x1B05, 0x1B35), (0x1B08, 0x1B07, 0x1B35), (0x1B0A, 0x1B09, 0x1B35), (0x1B0C, 0x1B0B, 0x1B35),
+    (0x1B0E, 0x1B0D, 0x1B35), (0x1B12, 0x1B11, 0x1B35), (0x1B3B, 0x1B3A, 0x1B35), (0x1B3D, 0x1B3C, 0x1B35),
+    (0x1B40, 0x1B3E, 0x1B35), (0x1B41, 0x1B3F, 0x1B35), (0x1B43, 0x1B42, 0x1B35), (0x1E00, 0x41, 0x325),
+    (0x1E01, 0x61, 0x325), (0x1E02, 0x42, 0x307), (0x1E03, 0x62, 0x307), (0x1E04, 0x42, 0x323),
+    (0x1E05, 0x62, 0x323), (0x1E06, 0x42, 0x331), (0x1E07, 0x62, 0x331), (0x1E08, 0xC7, 0x301),
+    (0x1E09, 0xE7, 0x301), (0x1E0A, 0x44, 0x307), (0x1E0B, 0x64, 0x307), (0x1E0C, 0x44, 0x323),
+    (0x1E0D, 0x64, 0x323), (0x1E0E, 0x44, 0x331), (0x1E0F, 0x64, 0x331), (0x1E10, 0x44, 0x327),
+    (0x1E11, 0x64, 0x327), (0x1E12, 0x44, 0x32D), (0x1E13, 0x64, 0x32D), (0x1E14, 0x112, 0x300),
+    (0x1E15, 0x113, 0x300), (0x1E16, 0x112, 0x301), (0x1E17, 0x113, 0x301), (0x1E18, 0x45, 0x32D),
+    (0x1E19, 0x65, 0x32D), (0x1E1A, 0x45, 0x330), (0x1E1B, 0x65, 0x330), (0x1E1C, 0x228, 0x306),
+    (0x1E1D, 0x229, 0x306), (0x1E1E, 0x46, 0x307), (0x1E1F, 0x66, 0x307), (0x1E20, 0x47, 0x304),
+    (0x1E21, 0x67, 0x304), (0x1E22, 0x48, 0x307), (0x1E23, 0x68, 0x307), (0x1E24, 0x48, 0x323),
+    (0x1E25, 0x68, 0x323), (0x1E26, 0x48, 0x308), (0x1E27, 0x68, 0x308), (0x1E28, 0x48, 0x327),
+    (0x1E29, 0x68, 0x327), (0x1E2A, 0x48, 0x32E), (0x1E2B, 0x68, 0x32E), (0x1E2C, 0x49, 0x330),
+    (0x1E2D, 0x69, 0x330), (0x1E2E, 0xCF, 0x301), (0x1E2F, 0xEF, 0x301), (0x1E30, 0x4B, 0x301),
+    (0x1E31, 0x6B, 0x301), (0x1E32, 0x4B, 0x323), (0x1E33, 0x6B, 0x323), (0x1E34, 0x4B, 0x331),
+    (0x1E35, 0x6B, 0x331), (0x1E36, 0x4C, 0x323), (0x1E37, 0x6C, 0x323), (0x1E38, 0x1E36, 0x304),
+    (0x1E39, 0x1E37, 0x304), (0x1E3A, 0x4C, 0x331), (0x1E3B, 0x6C, 0x331), (0x1E3C, 0x4C, 0x32D),
+    (0x1E3D, 0x6C, 0x32D), (0x1E3E, 0x4D, 0x301), (0x1E3F, 0x6D, 0x301), (0x1E40, 0x4D, 0x307),
+    (0x1E41, 0x6D, 0x307), (0x1E42, 0x4D, 0x323), (0x1E43, 0x6D, 0x323), (0x1E44, 0x4E, 0x307),
+    (0x1E45, 0x6E, 0x307), (0x1E46, 0x4E, 0x323), (0x1E47, 0x6E, 0x323), (0x1E48, 0x4E, 0x331),
+    (0x1E49, 0x6E, 0x331), (0x1E4A, 0x4E, 0x32D), (0x1E4B, 0x6E, 0x32D), (0x1E4C, 0xD5, 0x301),
+    (0x1E4D, 0xF5, 0x301), (0x1E4E, 0xD5, 0x308), (0x1E4F, 0xF5, 0x308), (0x1E50, 0x14C, 0x300),
+    (0x1E51, 0x14D, 0x300), (0x1E52, 0x14C, 0x301), (0x1E53, 0x14D, 0x301), (0x1E54, 0x50, 0x301),
+    (0x1E55, 0x70, 0x301), (0x1E56, 0x50, 0x307), (0x1E57, 0x70, 0x307), (0x1E58, 0x52, 0x307),
+    (0x1E59, 0x72, 0x307), (0x1E5A, 0x52, 0x323), (0x1E5B, 0x72, 0x323), (0x1E5C, 0x1E5A, 0x304),
+    (0x1E5D, 0x1E5B, 0x304), (0x1E5E, 0x52, 0x331), (0x1E5F, 0x72, 0x331), (0x1E60, 0x53, 0x307),
+    (0x1E61, 0x73, 0x307), (0x1E62, 0x53, 0x323), (0x1E63, 0x73, 0x323), (0x1E64, 0x15A, 0x307),
+    (0x1E65, 0x15B, 0x307), (0x1E66, 0x160, 0x307), (0x1E67, 0x161, 0x307), (0x1E68, 0x1E62, 0x307),
+    (0x1E69, 0x1E63, 0x307), (0x1E6A, 0x54, 0x307), (0x1E6B, 0x74, 0x307), (0x1E6C, 0x54, 0x323),
+    (0x1E6D, 0x74, 0x323), (0x1E6E, 0x54, 0x331), (0x1E6F, 0x74, 0x331), (0x1E70, 0x54, 0x32D),
+    (0x1E71, 0x74, 0x32D), (0x1E72, 0x55, 0x324), (0x1E73, 0x75, 0x324), (0x1E74, 0x55, 0x330),
+    (0x1E75, 0x75, 0x330), (0x1E76, 0x55, 0x32D), (0x1E77, 0x75, 0x32D), (0x1E78, 0x168, 0x301),
+    (0x1E79, 0x169, 0x301), (0x1E7A, 0x16A, 0x308), (0x1E7B, 0x16B, 0x308), (0x1E7C, 0x56, 0x303),
+    (0x1E7D, 0x76, 0x303), (0x1E7E, 0x56, 0x323), (0x1E7F, 0x76, 0x323), (0x1E80, 0x57, 0x300),
+    (0x1E81, 0x77, 0x300), (0x1E82, 0x57, 0x301), (0x1E83, 0x77, 0x301), (0x1E84, 0x57, 0x308),
+    (0x1E85, 0x77, 0x308), (0x1E86, 0x57, 0x307), (0x1E87, 0x77, 0x307), (0x1E88, 0x57, 0x323),
+    (0x1E89, 0x77, 0x323), (0x1E8A, 0x58, 0x307), (0x1E8B, 0x78, 0x307), (0x1E8C, 0x58, 0x308),
+    (0x1E8D, 0x78, 0x308), (0x1E8E, 0x59, 0x307), (0x1E8F, 0x79, 0x307), (0x1E90, 0x5A, 0x302),
+    (0x1E91, 0x7A, 0x302), (0x1E92, 0x5A, 0x323), (0x1E93, 0x7A, 0x323), (0x1E94, 0x5A, 0x331),
+    (0x1E95, 0x7A, 0x331), (0x1E96, 0x68, 0x331), (0x1E97, 0x74, 0x308), (0x1E98, 0x77, 0x30A),
+    (0x1E99, 0x79, 0x30A), (0x1E9B, 0x17F, 0x307), (0x1EA0, 0x41, 0x323), (0x1EA1, 0x61, 0x323),
+    (0x1EA2, 0x41, 0x309), (0x1EA3, 0x61, 0x309), (0x1EA4, 0xC2, 0x301), (0x1EA5, 0xE2, 0x301),
+    (0x1EA6, 0xC2, 0x300), (0x1EA7, 0xE2, 0x300), (0x1EA8, 0xC2, 0x309), (0x1EA9, 0xE2, 0x309),
+    (0x1EAA, 0xC2, 0x303), (0x1EAB, 0xE2, 0x303), (0x1EAC, 0x1EA0, 0x302), (0x1EAD, 0x1EA1, 0x302),
+    (0x1EAE, 0x102, 0x301), (0x1EAF, 0x103, 0x301), (0x1EB0, 0x102, 0x300), (0x1EB1, 0x103, 0x300),
+    (0x1EB2, 0x102, 0x309), (0x1EB3, 0x103, 0x309), (0x1EB4, 0x102, 0x303), (0x1EB5, 0x103, 0x303),
+    (0x1EB6, 0x1EA0, 0x306), (0x1EB7, 0x1EA1, 0x306), (0x1EB8, 0x45, 0x323), (0x1EB9, 0x65, 0x323),
+    (0x1EBA, 0x45, 0x309), (0x1EBB, 0x65, 0x309), (0x1EBC, 0x45, 0x303), (0x1EBD, 0x65, 0x303),
+    (0x1EBE, 0xCA, 0x301), (0x1EBF, 0xEA, 0x301), (0x1EC0, 0xCA, 0x300), (0x1EC1, 0xEA, 0x300),
+    (0x1EC2, 0xCA, 0x309), (0x1EC3, 0xEA, 0x309), (0x1EC4, 0xCA, 0x303), (0x1EC5, 0xEA, 0x303),
+    (0x1EC6, 0x1EB8, 0x302), (0x1EC7, 0x1EB9, 0x302), (0x1EC8, 0x49, 0x309), (0x1EC9, 0x69, 0x309),
+    (0x1ECA, 0x49, 0x323), (0x1ECB, 0x69, 0x323), (0x1ECC, 0x4F, 0x323), (0x1ECD, 0x6F, 0x323),
+    (0x1ECE, 0x4F, 0x309), (0x1ECF, 0x6F, 0x309), (0x1ED0, 0xD4, 0x301), (0x1ED1, 0xF4, 0x301),
+    (0x1ED2, 0xD4, 0x300), (0x1ED3, 0xF4, 0x300), (0x1ED4, 0xD4, 0x309), (0x1ED5, 0xF4, 0x309),
+    (0x1ED6, 0xD4, 0x303), (0x1ED7, 0xF4, 0x303), (0x1ED8, 0x1ECC, 0x302), (0x1ED9, 0x1ECD, 0x302),
+    (0x1EDA, 0x1A0, 0x301), (0x1EDB, 0x1A1, 0x301), (0x1EDC, 0x1A0, 0x300), (0x1EDD, 0x1A1, 0x300),
+    (0x1EDE, 0x1A0, 0x309), (0x1EDF, 0x1A1, 0x309), (0x1EE0, 0x1A0, 0x303), (0x1EE1, 0x1A1, 0x303),
+    (0x1EE2, 0x1A0, 0x323), (0x1EE3, 0x1A1, 0x323), (0x1EE4, 0x55, 0x323), (0x1EE5, 0x75, 0x323),
+    (0x1EE6, 0x55, 0x309), (0x1EE7, 0x75, 0x309), (0x1EE8, 0x1AF, 0x301), (0x1EE9, 0x1B0, 0x301),
+    (0x1EEA, 0x1AF, 0x300), (0x1EEB, 0x1B0, 0x300), (0x1EEC, 0x1AF, 0x309), (0x1EED, 0x1B0, 0x309),
+    (0x1EEE, 0x1AF, 0x303), (0x1EEF, 0x1B0, 0x303), (0x1EF0, 0x1AF, 0x323), (0x1EF1, 0x1B0, 0x323),
+    (0x1EF2, 0x59, 0x300), (0x1EF3, 0x79, 0x300), (0x1EF4, 0x59, 0x323), (0x1EF5, 0x79, 0x323),
+    (0x1EF6, 0x59, 0x309), (0x1EF7, 0x79, 0x309), (0x1EF8, 0x59, 0x303), (0x1EF9, 0x79, 0x303),
+    (0x1F00, 0x3B1, 0x313), (0x1F01, 0x3B1, 0x314), (0x1F02, 0x1F00, 0x300), (0x1F03, 0x1F01, 0x300),
+    (0x1F04, 0x1F00, 0x301), (0x1F05, 0x1F01, 0x301), (0x1F06, 0x1F00, 0x342), (0x1F07, 0x1F01, 0x342),
+    (0x1F08, 0x391, 0x313), (0x1F09, 0x391, 0x314), (0x1F0A, 0x1F08, 0x300), (0x1F0B, 0x1F09, 0x300),
+    (0x1F0C, 0x1F08, 0x301), (0x1F0D, 0x1F09, 0x301), (0x1F0E, 0x1F08, 0x342), (0x1F0F, 0x1F09, 0x342),
+    (0x1F10, 0x3B5, 0x313), (0x1F11, 0x3B5, 0x314), (0x1F12, 0x1F10, 0x300), (0x1F13, 0x1F11, 0x300),
+    (0x1F14, 0x1F10, 0x301), (0x1F15, 0x1F11, 0x301), (0x1F18, 0x395, 0x313), (0x1F19, 0x395, 0x314),
+    (0x1F1A, 0x1F18, 0x300), (0x1F1B, 0x1F19, 0x300), (0x1F1C, 0x1F18, 0x301), (0x1F1D, 0x1F19, 0x301),
+    (0x1F20, 0x3B7, 0x313), (0x1F21, 0x3B7, 0x314), (0x1F22, 0x1F20, 0x300), (0x1F23, 0x1F21, 0x300),
+    (0x1F24, 0x1F20, 0x301), (0x1F25, 0x1F21, 0x301), (0x1F26, 0x1F20, 0x342), (0x1F27, 0x1F21, 0x342),
+    (0x1F28, 0x397, 0x313), (0x1F29, 0x397, 0x314), (0x1F2A, 0x1F28, 0x300), (0x1F2B, 0x1F29, 0x300),
+    (0x1F2C, 0x1F28, 0x301), (0x1F2D, 0x1F29, 0x301), (0x1F2E, 0x1F28, 0x342), (0x1F2F, 0x1F29, 0x342),
+    (0x1F30, 0x3B9, 0x313), (0x1F31, 0x3B9, 0x314), (0x1F32, 0x1F30, 0x300), (0x1F33, 0x1F31, 0x300),
+    (0x1F34, 0x1F30, 0x301), (0x1F35, 0x1F31, 0x301), (0x1F36, 0x1F30, 0x342), (0x1F37, 0x1F31, 0x342),
+    (0x1F38, 0x399, 0x313), (0x1F39, 0x399, 0x314), (0x1F3A, 0x1F38, 0x300), (0x1F3B, 0x1F39, 0x300),
+    (0x1F3C, 0x1F38, 0x301), (0x1F3D, 0x1F39, 0x301), (0x1F3E, 0x1F38, 0x342), (0x1F3F, 0x1F39, 0x342),
+    (0x1F40, 0x3BF, 0x313), (0x1F41, 0x3BF, 0x314), (0x1F42, 0x1F40, 0x300), (0x1F43, 0x1F41, 0x300),
+    (0x1F44, 0x1F40, 0x301), (0x1F45, 0x1F41, 0x301), (0x1F48, 0x39F, 0x313), (0x1F49, 0x39F, 0x314),
+    (0x1F4A, 0x1F48, 0x300), (0x1F4B, 0x1F49, 0x300), (0x1F4C, 0x1F48, 0x301), (0x1F4D, 0x1F49, 0x301),
+    (0x1F50, 0x3C5, 0x313), (0x1F51, 0x3C5, 0x314), (0x1F52, 0x1F50, 0x300), (0x1F53, 0x1F51, 0x300),
+    (0x1F54, 0x1F50, 0x301), (0x1F55, 0x1F51, 0x301), (0x1F56, 0x1F50, 0x342), (0x1F57, 0x1F51, 0x342),
+    (0x1F59, 0x3A5, 0x314), (0x1F5B, 0x1F59, 0x300), (0x1F5D, 0x1F59, 0x301), (0x1F5F, 0x1F59, 0x342),
+    (0x1F60, 0x3C9, 0x313), (0x1F61, 0x3C9, 0x314), (0x1F62, 0x1F60, 0x300), (0x1F63, 0x1F61, 0x300),
+    (0x1F64, 0x1F60, 0x301), (0x1F65, 0x1F61, 0x301), (0x1F66, 0x1F60, 0x342), (0x1F67, 0x1F61, 0x342),
+    (0x1F68, 0x3A9, 0x313), (0x1F69, 0x3A9, 0x314), (0x1F6A, 0x1F68, 0x300), (0x1F6B, 0x1F69, 0x300),
+    (0x1F6C, 0x1F68, 0x301), (0x1F6D, 0x1F69, 0x301), (0x1F6E, 0x1F68, 0x342), (0x1F6F, 0x1F69, 0x342),
+    (0x1F70, 0x3B1, 0x300), (0x1F71, 0x3AC, 0x0), (0x1F72, 0x3B5, 0x300), (0x1F73, 0x3AD, 0x0),
+    (0x1F74, 0x3B7, 0x300), (0x1F75, 0x3AE, 0x0), (0x1F76, 0x3B9, 0x300), (0x1F77, 0x3AF, 0x0),
+    (0x1F78, 0x3BF, 0x300), (0x1F79, 0x3CC, 0x0), (0x1F7A, 0x3C5, 0x300), (0x1F7B, 0x3CD, 0x0),
+    (0x1F7C, 0x3C9, 0x300), (0x1F7D, 0x3CE, 0x0), (0x1F80, 0x1F00, 0x345), (0x1F81, 0x1F01, 0x345),
+    (0x1F82, 0x1F02, 0x345), (0x1F83, 0x1F03, 0x345), (0x1F84, 0x1F04, 0x345), (0x1F85, 0x1F05, 0x345),
+    (0x1F86, 0x1F06, 0x345), (0x1F87, 0x1F07, 0x345), (0x1F88, 0x1F08, 0x345), (0x1F89, 0x1F09, 0x345),
+    (0x1F8A, 0x1F0A, 0x345), (0x1F8B, 0x1F0B, 0x345), (0x1F8C, 0x1F0C, 0x345), (0x1F8D, 0x1F0D, 0x345),
+    (0x1F8E, 0x1F0E, 0x345), (0x1F8F, 0x1F0F, 0x345), (0x1F90, 0x1F20, 0x345), (0x1F91, 0x1F21, 0x345),
+    (0x1F92, 0x1F22, 0x345), (0x1F93, 0x1F23, 0x345), (0x1F94, 0x1F24, 0x345), (0x1F95, 0x1F25, 0x345),
+    (0x1F96, 0x1F26, 0x345), (0x1F97, 0x1F27, 0x345), (0x1F98, 0x1F28, 0x345), (0x1F99, 0x1F29, 0x345),
+    (0x1F9A, 0x1F2A, 0x345), (0x1F9B, 0x1F2B, 0x345), (0x1F9C, 0x1F2C, 0x345), (0x1F9D, 0x1F2D, 0x345),
+    (0x1F9E, 0x1F2E, 0x345), (0x1F9F, 0x1F2F, 0x345), (0x1FA0, 0x1F60, 0x345), (0x1FA1, 0x1F61, 0x345),
+    (0x1FA2, 0x1F62, 0x345), (0x1FA3, 0x1F63, 0x345), (0x1FA4, 0x1F64, 0x345), (0x1FA5, 0x1F65, 0x345),
+    (0x1FA6, 0x1F66, 0x345), (0x1FA7, 0x1F67, 0x345), (0x1FA8, 0x1F68, 0x345), (0x1FA9, 0x1F69, 0x345),
+    (0x1FAA, 0x1F6A, 0x345), (0x1FAB, 0x1F6B, 0x345), (0x1FAC, 0x1F6C, 0x345), (0x1FAD, 0x1F6D, 0x345),
+    (0x1FAE, 0x1F6E, 0x345), (0x1FAF, 0x1F6F, 0x345), (0x1FB0, 0x3B1, 0x306), (0x1FB1, 0x3B1, 0x304),
+    (0x1FB2, 0x1F70, 0x345), (0x1FB3, 0x3B1, 0x345), (0x1FB4, 0x3AC, 0x345), (0x1FB6, 0x3B1, 0x342),
+    (0x1FB7, 0x1FB6, 0x345), (0x1FB8, 0x391, 0x306), (0x1FB9, 0x391, 0x304), (0x1FBA, 0x391, 0x300),
+    (0x1FBB, 0x386, 0x0), (0x1FBC, 0x391, 0x345), (0x1FBE, 0x3B9, 0x0), (0x1FC1, 0xA8, 0x342),
+    (0x1FC2, 0x1F74, 0x345), (0x1FC3, 0x3B7, 0x345), (0x1FC4, 0x3AE, 0x345), (0x1FC6, 0x3B7, 0x342),
+    (0x1FC7, 0x1FC6, 0x345), (0x1FC8, 0x395, 0x300), (0x1FC9, 0x388, 0x0), (0x1FCA, 0x397, 0x300),
+    (0x1FCB, 0x389, 0x0), (0x1FCC, 0x397, 0x345), (0x1FCD, 0x1FBF, 0x300), (0x1FCE, 0x1FBF, 0x301),
+    (0x1FCF, 0x1FBF, 0x342), (0x1FD0, 0x3B9, 0x306), (0x1FD1, 0x3B9, 0x304), (0x1FD2, 0x3CA, 0x300),
+    (0x1FD3, 0x390, 0x0), (0x1FD6, 0x3B9, 0x342), (0x1FD7, 0x3CA, 0x342), (0x1FD8, 0x399, 0x306),
+    (0x1FD9, 0x399, 0x304), (0x1FDA, 0x399, 0x300), (0x1FDB, 0x38A, 0x0), (0x1FDD, 0x1FFE, 0x300),
+    (0x1FDE, 0x1FFE, 0x301), (0x1FDF, 0x1FFE, 0x342), (0x1FE0, 0x3C5, 0x306), (0x1FE1, 0x3C5, 0x304),
+    (0x1FE2, 0x3CB, 0x300), (0x1FE3, 0x3B0, 0x0), (0x1FE4, 0x3C1, 0x313), (0x1FE5, 0x3C1, 0x314),
+    (0x1FE6, 0x3C5, 0x342), (0x1FE7, 0x3CB, 0x342), (0x1FE8, 0x3A5, 0x306), (0x1FE9, 0x3A5, 0x304),
+    (0x1FEA, 0x3A5, 0x300), (0x1FEB, 0x38E, 0x0), (0x1FEC, 0x3A1, 0x314), (0x1FED, 0xA8, 0x300),
+    (0x1FEE, 0x385, 0x0), (0x1FEF, 0x60, 0x0), (0x1FF2, 0x1F7C, 0x345), (0x1FF3, 0x3C9, 0x345),
+    (0x1FF4, 0x3CE, 0x345), (0x1FF6, 0x3C9, 0x342), (0x1FF7, 0x1FF6, 0x345), (0x1FF8, 0x39F, 0x300),
+    (0x1FF9, 0x38C, 0x0), (0x1FFA, 0x3A9, 0x300), (0x1FFB, 0x38F, 0x0), (0x1FFC, 0x3A9, 0x345),
+    (0x1FFD, 0xB4, 0x0), (0x2000, 0x2002, 0x0), (0x2001, 0x2003, 0x0), (0x2126, 0x3A9, 0x0),
+    (0x212A, 0x4B, 0x0), (0x212B, 0xC5, 0x0), (0x219A, 0x2190, 0x338), (0x219B, 0x2192, 0x338),
+    (0x21AE, 0x2194, 0x338), (0x21CD, 0x21D0, 0x338), (0x21CE, 0x21D4, 0x338), (0x21CF, 0x21D2, 0x338),
+    (0x2204, 0x2203, 0x338), (0x2209, 0x2208, 0x338), (0x220C, 0x220B, 0x338), (0x2224, 0x2223, 0x338),
+    (0x2226, 0x2225, 0x338), (0x2241, 0x223C, 0x338), (0x2244, 0x2243, 0x338), (0x2247, 0x2245, 0x338),
+    (0x2249, 0x2248, 0x338), (0x2260, 0x3D, 0x338), (0x2262, 0x2261, 0x338), (0x226D, 0x224D, 0x338),
+    (0x226E, 0x3C, 0x338), (0x226F, 0x3E, 0x338), (0x2270, 0x2264, 0x338), (0x2271, 0x2265, 0x338),
+    (0x2274, 0x2272, 0x338), (0x2275, 0x2273, 0x338), (0x2278, 0x2276, 0x338), (0x2279, 0x2277, 0x338),
+    (0x2280, 0x227A, 0x338), (0x2281, 0x227B, 0x338), (0x2284, 0x2282, 0x338), (0x2285, 0x2283, 0x338),
+    (0x2288, 0x2286, 0x338), (0x2289, 0x2287, 0x338), (0x22AC, 0x22A2, 0x338), (0x22AD, 0x22A8, 0x338),
+    (0x22AE, 0x22A9, 0x338), (0x22AF, 0x22AB, 0x338), (0x22E0, 0x227C, 0x338), (0x22E1, 0x227D, 0x338),
+    (0x22E2, 0x2291, 0x338), (0x22E3, 0x2292, 0x338), (0x22EA, 0x22B2, 0x338), (0x22EB, 0x22B3, 0x338),
+    (0x22EC, 0x22B4, 0x338), (0x22ED, 0x22B5, 0x338), (0x2329, 0x3008, 0x0), (0x232A, 0x3009, 0x0),
+    (0x2ADC, 0x2ADD, 0x338), (0x304C, 0x304B, 0x3099), (0x304E, 0x304D, 0x3099), (0x3050, 0x304F, 0x3099),
+    (0x3052, 0x3051, 0x3099), (0x3054, 0x3053, 0x3099), (0x3056, 0x3055, 0x3099), (0x3058, 0x3057, 0x3099),
+    (0x305A, 0x3059, 0x3099), (0x305C, 0x305B, 0x3099), (0x305E, 0x305D, 0x3099), (0x3060, 0x305F, 0x3099),
+    (0x3062, 0x3061, 0x3099), (0x3065, 0x3064, 0x3099), (0x3067, 0x3066, 0x3099), (0x3069, 0x3068, 0x3099),
+    (0x3070, 0x306F, 0x3099), (0x3071, 0x306F, 0x309A), (0x3073, 0x3072, 0x3099), (0x3074, 0x3072, 0x309A),
+    (0x3076, 0x3075, 0x3099), (0x3077, 0x3075, 0x309A), (0x3079, 0x3078, 0x3099), (0x307A, 0x3078, 0x309A),
+    (0x307C, 0x307B, 0x3099), (0x307D, 0x307B, 0x309A), (0x3094, 0x3046, 0x3099), (0x309E, 0x309D, 0x3099),
+    (0x30AC, 0x30AB, 0x3099), (0x30AE, 0x30AD, 0x3099), (0x30B0, 0x30AF, 0x3099), (0x30B2, 0x30B1, 0x3099),
+    (0x30B4, 0x30B3, 0x3099), (0x30B6, 0x30B5, 0x3099), (0x30B8, 0x30B7, 0x3099), (0x30BA, 0x30B9, 0x3099),
+    (0x30BC, 0x30BB, 0x3099), (0x30BE, 0x30BD, 0x3099), (0x30C0, 0x30BF, 0x3099), (0x30C2, 0x30C1, 0x3099),
+    (0x30C5, 0x30C4, 0x3099), (0x30C7, 0x30C6, 0x3099), (0x30C9, 0x30C8, 0x3099), (0x30D0, 0x30CF, 0x3099),
+    (0x30D1, 0x30CF, 0x309A), (0x30D3, 0x30D2, 0x3099), (0x30D4, 0x30D2, 0x309A), (0x30D6, 0x30D5, 0x3099),
+    (0x30D7, 0x30D5, 0x309A), (0x30D9, 0x30D8, 0x3099), (0x30DA, 0x30D8, 0x309A), (0x30DC, 0x30DB, 0x3099),
+    (0x30DD, 0x30DB, 0x309A), (0x30F4, 0x30A6, 0x3099), (0x30F7, 0x30EF, 0x3099), (0x30F8, 0x30F0, 0x3099),
+    (0x30F9, 0x30F1, 0x3099), (0x30FA, 0x30F2, 0x3099), (0x30FE, 0x30FD, 0x3099), (0xF900, 0x8C48, 0x0),
+    (0xF901, 0x66F4, 0x0), (0xF902, 0x8ECA, 0x0), (0xF903, 0x8CC8, 0x0), (0xF904, 0x6ED1, 0x0),
+    (0xF905, 0x4E32, 0x0), (0xF906, 0x53E5, 0x0), (0xF907, 0x9F9C, 0x0), (0xF908, 0x9F9C, 0x0),
+    (0xF909, 0x5951, 0x0), (0xF90A, 0x91D1, 0x0), (0xF90B, 0x5587, 0x0), (0xF90C, 0x5948, 0x0),
+    (0xF90D, 0x61F6, 0x0), (0xF90E, 0x7669, 0x0), (0xF90F, 0x7F85, 0x0), (0xF910, 0x863F, 0x0),
+    (0xF911, 0x87BA, 0x0), (0xF912, 0x88F8, 0x0), (0xF913, 0x908F, 0x0), (0xF914, 0x6A02, 0x0),
+    (0xF915, 0x6D1B, 0x0), (0xF916, 0x70D9, 0x0), (0xF917, 0x73DE, 0x0), (0xF918, 0x843D, 0x0),
+    (0xF919, 0x916A, 0x0), (0xF91A, 0x99F1, 0x0), (0xF91B, 0x4E82, 0x0), (0xF91C, 0x5375, 0x0),
+    (0xF91D, 0x6B04, 0x0), (0xF91E, 0x721B, 0x0), (0xF91F, 0x862D, 0x0), (0xF920, 0x9E1E, 0x0),
+    (0xF921, 0x5D50, 0x0), (0xF922, 0x6FEB, 0x0), (0xF923, 0x85CD, 0x0), (0xF924, 0x8964, 0x0),
+    (0xF925, 0x62C9, 0x0), (0xF926, 0x81D8, 0x0), (0xF927, 0x881F, 0x0), (0xF928, 0x5ECA, 0x0),
+    (0xF929, 0x6717, 0x0), (0xF92A, 0x6D6A, 0x0), (0xF92B, 0x72FC, 0x0), (0xF92C, 0x90CE, 0x0),
+    (0xF92D, 0x4F86, 0x0), (0xF92E, 0x51B7, 0x0), (0xF92F, 0x52DE, 0x0), (0xF930, 0x64C4, 0x0),
+    (0xF931, 0x6AD3, 0x0), (0xF932, 0x7210, 0x0), (0xF933, 0x76E7, 0x0), (0xF934, 0x8001, 0x0),
+    (0xF935, 0x8606, 0x0), (0xF936, 0x865C, 0x0), (0xF937, 0x8DEF, 0x0), (0xF938, 0x9732, 0x0),
+    (0xF939, 0x9B6F, 0x0), (0xF93A, 0x9DFA, 0x0), (0xF93B, 0x788C, 0x0), (0xF93C, 0x797F, 0x0),
+    (0xF93D, 0x7DA0, 0x0), (0xF93E, 0x83C9, 0x0), (0xF93F, 0x9304, 0x0), (0xF940, 0x9E7F, 0x0),
+    (0xF941, 0x8AD6, 0x0), (0xF942, 0x58DF, 0x0), (0xF943, 0x5F04, 0x0), (0xF944, 0x7C60, 0x0),
+    (0xF945, 0x807E, 0x0), (0xF946, 0x7262, 0x0), (0xF947, 0x78CA, 0x0), (0xF948, 0x8CC2, 0x0),
+    (0xF949, 0x96F7, 0x0), (0xF94A, 0x58D8, 0x0), (0xF94B, 0x5C62, 0x0), (0xF94C, 0x6A13, 0x0),
+    (0xF94D, 0x6DDA, 0x0), (0xF94E, 0x6F0F, 0x0), (0xF94F, 0x7D2F, 0x0), (0xF950, 0x7E37, 0x0),
+    (0xF951, 0x964B, 0x0), (0xF952, 0x52D2, 0x0), (0xF953, 0x808B, 0x0), (0xF954, 0x51DC, 0x0),
+    (0xF955, 0x51CC, 0x0), (0xF956, 0x7A1C, 0x0), (0xF957, 0x7DBE, 0x0), (0xF958, 0x83F1, 0x0),
+    (0xF959, 0x9675, 0x0), (0xF95A, 0x8B80, 0x0), (0xF95B, 0x62CF, 0x0), (0xF95C, 0x6A02, 0x0),
+    (0xF95D, 0x8AFE, 0x0), (0xF95E, 0x4E39, 0x0), (0xF95F, 0x5BE7, 0x0), (0xF960, 0x6012, 0x0),
+    (0xF961, 0x7387, 0x0), (0xF962, 0x7570, 0x0), (0xF963, 0x5317, 0x0), (0xF964, 0x78FB, 0x0),
+    (0xF965, 0x4FBF, 0x0), (0xF966, 0x5FA9, 0x0), (0xF967, 0x4E0D, 0x0), (0xF968, 0x6CCC, 0x0),
+    (0xF969, 0x6578, 0x0), (0xF96A, 0x7D22, 0x0), (0xF96B, 0x53C3, 0x0), (0xF96C, 0x585E, 0x0),
+    (0xF96D, 0x7701, 0x0), (0xF96E, 0x8449, 0x0), (0xF96F, 0x8AAA, 0x0), (0xF970, 0x6BBA, 0x0),
+    (0xF971, 0x8FB0, 0x0), (0xF972, 0x6C88, 0x0), (0xF973, 0x62FE, 0x0), (0xF974, 0x82E5, 0x0),
+    (0xF975, 0x63A0, 0x0), (0xF976, 0x7565, 0x0), (0xF977, 0x4EAE, 0x0), (0xF978, 0x5169, 0x0),
+    (0xF979, 0x51C9, 0x0), (0xF97A, 0x6881, 0x0), (0xF97B, 0x7CE7, 0x0), (0xF97C, 0x826F, 0x0),
+    (0xF97D, 0x8AD2, 0x0), (0xF97E, 0x91CF, 0x0), (0xF97F, 0x52F5, 0x0), (0xF980, 0x5442, 0x0),
+    (0xF981, 0x5973, 0x0), (0xF982, 0x5EEC, 0x0), (0xF983, 0x65C5, 0x0), (0xF984, 0x6FFE, 0x0),
+    (0xF985, 0x792A, 0x0), (0xF986, 0x95AD, 0x0), (0xF987, 0x9A6A, 0x0), (0xF988, 0x9E97, 0x0),
+    (0xF989, 0x9ECE, 0x0), (0xF98A, 0x529B, 0x0), (0xF98B, 0x66C6, 0x0), (0xF98C, 0x6B77, 0x0),
+    (0xF98D, 0x8F62, 0x0), (0xF98E, 0x5E74, 0x0), (0xF98F, 0x6190, 0x0), (0xF990, 0x6200, 0x0),
+    (0xF991, 0x649A, 0x0), (0xF992, 0x6F23, 0x0), (0xF993, 0x7149, 0x0), (0xF994, 0x7489, 0x0),
+    (0xF995, 0x79CA, 0x0), (0xF996, 0x7DF4, 0x0), (0xF997, 0x806F, 0x0), (0xF998, 0x8F26, 0x0),
+    (0xF999, 0x84EE, 0x0), (0xF99A, 0x9023, 0x0), (0xF99B, 0x934A, 0x0), (0xF99C, 0x5217, 0x0),
+    (0xF99D, 0x52A3, 0x0), (0xF99E, 0x54BD, 0x0), (0xF99F, 0x70C8, 0x0), (0xF9A0, 0x88C2, 0x0),
+    (0xF9A1, 0x8AAA, 0x0), (0xF9A2, 0x5EC9, 0x0), (0xF9A3, 0x5FF5, 0x0), (0xF9A4, 0x637B, 0x0),
+    (0xF9A5, 0x6BAE, 0x0), (0xF9A6, 0x7C3E, 0x0), (0xF9A7, 0x7375, 0x0), (0xF9A8, 0x4EE4, 0x0),
+    (0xF9A9, 0x56F9, 0x0), (0xF9AA, 0x5BE7, 0x0), (0xF9AB, 0x5DBA, 0x0), (0xF9AC, 0x601C, 0x0),
+    (0xF9AD, 0x73B2, 0x0), (0xF9AE, 0x7469, 0x0), (0xF9AF, 0x7F9A, 0x0), (0xF9B0, 0x8046, 0x0),
+    (0xF9B1, 0x9234, 0x0), (0xF9B2, 0x96F6, 0x0), (0xF9B3, 0x9748, 0x0), (0xF9B4, 0x9818, 0x0),
+    (0xF9B5, 0x4F8B, 0x0), (0xF9B6, 0x79AE, 0x0), (0xF9B7, 0x91B4, 0x0), (0xF9B8, 0x96B8, 0x0),
+    (0xF9B9, 0x60E1, 0x0), (0xF9BA, 0x4E86, 0x0), (0xF9BB, 0x50DA, 0x0), (0xF9BC, 0x5BEE, 0x0),
+    (0xF9BD, 0x5C3F, 0x0), (0xF9BE, 0x6599, 0x0), (0xF9BF, 0x6A02, 0x0), (0xF9C0, 0x71CE, 0x0),
+    (0xF9C1, 0x7642, 0x0), (0xF9C2, 0x84FC, 0x0), (0xF9C3, 0x907C, 0x0), (0xF9C4, 0x9F8D, 0x0),
+    (0xF9C5, 0x6688, 0x0), (0xF9C6, 0x962E, 0x0), (0xF9C7, 0x5289, 0x0), (0xF9C8, 0x677B, 0x0),
+    (0xF9C9, 0x67F3, 0x0), (0xF9CA, 0x6D41, 0x0), (0xF9CB, 0x6E9C, 0x0), (0xF9CC, 0x7409, 0x0),
+    (0xF9CD, 0x7559, 0x0), (0xF9CE, 0x786B, 0x0), (0xF9CF, 0x7D10, 0x0), (0xF9D0, 0x985E, 0x0),
+    (0xF9D1, 0x516D, 0x0), (0xF9D2, 0x622E, 0x0), (0xF9D3, 0x9678, 0x0), (0xF9D4, 0x502B, 0x0),
+    (0xF9D5, 0x5D19, 0x0), (0xF9D6, 0x6DEA, 0x0), (0xF9D7, 0x8F2A, 0x0), (0xF9D8, 0x5F8B, 0x0),
+    (0xF9D9, 0x6144, 0x0), (0xF9DA, 0x6817, 0x0), (0xF9DB, 0x7387, 0x0), (0xF9DC, 0x9686, 0x0),
+    (0xF9DD, 0x5229, 0x0), (0xF9DE, 0x540F, 0x0), (0xF9DF, 0x5C65, 0x0), (0xF9E0, 0x6613, 0x0),
+    (0xF9E1, 0x674E, 0x0), (0xF9E2, 0x68A8, 0x0), (0xF9E3, 0x6CE5, 0x0), (0xF9E4, 0x7406, 0x0),
+    (0xF9E5, 0x75E2, 0x0), (0xF9E6, 0x7F79, 0x0), (0xF9E7, 0x88CF, 0x0), (0xF9E8, 0x88E1, 0x0),
+    (0xF9E9, 0x91CC, 0x0), (0xF9EA, 0x96E2, 0x0), (0xF9EB, 0x533F, 0x0), (0xF9EC, 0x6EBA, 0x0),
+    (0xF9ED, 0x541D, 0x0), (0xF9EE, 0x71D0, 0x0), (0xF9EF, 0x7498, 0x0), (0xF9F0, 0x85FA, 0x0),
+    (0xF9F1, 0x96A3, 0x0), (0xF9F2, 0x9C57, 0x0), (0xF9F3, 0x9E9F, 0x0), (0xF9F4, 0x6797, 0x0),
+    (0xF9F5, 0x6DCB, 0x0), (0xF9F6, 0x81E8, 0x0), (0xF9F7, 0x7ACB, 0x0), (0xF9F8, 0x7B20, 0x0),
+    (0xF9F9, 0x7C92, 0x0), (0xF9FA, 0x72C0, 0x0), (0xF9FB, 0x7099, 0x0), (0xF9FC, 0x8B58, 0x0),
+    (0xF9FD, 0x4EC0, 0x0), (0xF9FE, 0x8336, 0x0), (0xF9FF, 0x523A, 0x0), (0xFA00, 0x5207, 0x0),
+    (0xFA01, 0x5EA6, 0x0), (0xFA02, 0x62D3, 0x0), (0xFA03, 0x7CD6, 0x0), (0xFA04, 0x5B85, 0x0),
+    (0xFA05, 0x6D1E, 0x0), (0xFA06, 0x66B4, 0x0), (0xFA07, 0x8F3B, 0x0), (0xFA08, 0x884C, 0x0),
+    (0xFA09, 0x964D, 0x0), (0xFA0A, 0x898B, 0x0), (0xFA0B, 0x5ED3, 0x0), (0xFA0C, 0x5140, 0x0),
+    (0xFA0D, 0x55C0, 0x0), (0xFA10, 0x585A, 0x0), (0xFA12, 0x6674, 0x0), (0xFA15, 0x51DE, 0x0),
+    (0xFA16, 0x732A, 0x0), (0xFA17, 0x76CA, 0x0), (0xFA18, 0x793C, 0x0), (0xFA19, 0x795E, 0x0),
+    (0xFA1A, 0x7965, 0x0), (0xFA1B, 0x798F, 0x0), (0xFA1C, 0x9756, 0x0), (0xFA1D, 0x7CBE, 0x0),
+    (0xFA1E, 0x7FBD, 0x0), (0xFA20, 0x8612, 0x0), (0xFA22, 0x8AF8, 0x0), (0xFA25, 0x9038, 0x0),
+    (0xFA26, 0x90FD, 0x0), (0xFA2A, 0x98EF, 0x0), (0xFA2B, 0x98FC, 0x0), (0xFA2C, 0x9928, 0x0),
+    (0xFA2D, 0x9DB4, 0x0), (0xFA2E, 0x90DE, 0x0), (0xFA2F, 0x96B7, 0x0), (0xFA30, 0x4FAE, 0x0),
+    (0xFA31, 0x50E7, 0x0), (0xFA32, 0x514D, 0x0), (0xFA33, 0x52C9, 0x0), (0xFA34, 0x52E4, 0x0),
+    (0xFA35, 0x5351, 0x0), (0xFA36, 0x559D, 0x0), (0xFA37, 0x5606, 0x0), (0xFA38, 0x5668, 0x0),
+    (0xFA39, 0x5840, 0x0), (0xFA3A, 0x58A8, 0x0), (0xFA3B, 0x5C64, 0x0), (0xFA3C, 0x5C6E, 0x0),
+    (0xFA3D, 0x6094, 0x0), (0xFA3E, 0x6168, 0x0), (0xFA3F, 0x618E, 0x0), (0xFA40, 0x61F2, 0x0),
+    (0xFA41, 0x654F, 0x0), (0xFA42, 0x65E2, 0x0), (0xFA43, 0x6691, 0x0), (0xFA44, 0x6885, 0x0),
+    (0xFA45, 0x6D77, 0x0), (0xFA46, 0x6E1A, 0x0), (0xFA47, 0x6F22, 0x0), (0xFA48, 0x716E, 0x0),
+    (0xFA49, 0x722B, 0x0), (0xFA4A, 0x7422, 0x0), (0xFA4B, 0x7891, 0x0), (0xFA4C, 0x793E, 0x0),
+    (0xFA4D, 0x7949, 0x0), (0xFA4E, 0x7948, 0x0), (0xFA4F, 0x7950, 0x0), (0xFA50, 0x7956, 0x0),
+    (0xFA51, 0x795D, 0x0), (0xFA52, 0x798D, 0x0), (0xFA53, 0x798E, 0x0), (0xFA54, 0x7A40, 0x0),
+    (0xFA55, 0x7A81, 0x0), (0xFA56, 0x7BC0, 0x0), (0xFA57, 0x7DF4, 0x0), (0xFA58, 0x7E09, 0x0),
+    (0xFA59, 0x7E41, 0x0), (0xFA5A, 0x7F72, 0x0), (0xFA5B, 0x8005, 0x0), (0xFA5C, 0x81ED, 0x0),
+    (0xFA5D, 0x8279, 0x0), (0xFA5E, 0x8279, 0x0), (0xFA5F, 0x8457, 0x0), (0xFA60, 0x8910, 0x0),
+    (0xFA61, 0x8996, 0x0), (0xFA62, 0x8B01, 0x0), (0xFA63, 0x8B39, 0x0), (0xFA64, 0x8CD3, 0x0),
+    (0xFA65, 0x8D08, 0x0), (0xFA66, 0x8FB6, 0x0), (0xFA67, 0x9038, 0x0), (0xFA68, 0x96E3, 0x0),
+    (0xFA69, 0x97FF, 0x0), (0xFA6A, 0x983B, 0x0), (0xFA6B, 0x6075, 0x0), (0xFA6C, 0x242EE, 0x0),
+    (0xFA6D, 0x8218, 0x0), (0xFA70, 0x4E26, 0x0), (0xFA71, 0x51B5, 0x0), (0xFA72, 0x5168, 0x0),
+    (0xFA73, 0x4F80, 0x0), (0xFA74, 0x5145, 0x0), (0xFA75, 0x5180, 0x0), (0xFA76, 0x52C7, 0x0),
+    (0xFA77, 0x52FA, 0x0), (0xFA78, 0x559D, 0x0), (0xFA79, 0x5555, 0x0), (0xFA7A, 0x5599, 0x0),
+    (0xFA7B, 0x55E2, 0x0), (0xFA7C, 0x585A, 0x0), (0xFA7D, 0x58B3, 0x0), (0xFA7E, 0x5944, 0x0),
+    (0xFA7F, 0x5954, 0x0), (0xFA80, 0x5A62, 0x0), (0xFA81, 0x5B28, 0x0), (0xFA82, 0x5ED2, 0x0),
+    (0xFA83, 0x5ED9, 0x0), (0xFA84, 0x5F69, 0x0), (0xFA85, 0x5FAD, 0x0), (0xFA86, 0x60D8, 0x0),
+    (0xFA87, 0x614E, 0x0), (0xFA88, 0x6108, 0x0), (0xFA89, 0x618E, 0x0), (0xFA8A, 0x6160, 0x0),
+    (0xFA8B, 0x61F2, 0x0), (0xFA8C, 0x6234, 0x0), (0xFA8D, 0x63C4, 0x0), (0xFA8E, 0x641C, 0x0),
+    (0xFA8F, 0x6452, 0x0), (0xFA90, 0x6556, 0x0), (0xFA91, 0x6674, 0x0), (0xFA92, 0x6717, 0x0),
+    (0xFA93, 0x671B, 0x0), (0xFA94, 0x6756, 0x0), (0xFA95, 0x6B79, 0x0), (0xFA96, 0x6BBA, 0x0),
+    (0xFA97, 0x6D41, 0x0), (0xFA98, 0x6EDB, 0x0), (0xFA99, 0x6ECB, 0x0), (0xFA9A, 0x6F22, 0x0),
+    (0xFA9B, 0x701E, 0x0), (0xFA9C, 0x716E, 0x0), (0xFA9D, 0x77A7, 0x0), (0xFA9E, 0x7235, 0x0),
+    (0xFA9F, 0x72AF, 0x0), (0xFAA0, 0x732A, 0x0), (0xFAA1, 0x7471, 0x0), (0xFAA2, 0x7506, 0x0),
+    (0xFAA3, 0x753B, 0x0), (0xFAA4, 0x761D, 0x0), (0xFAA5, 0x761F, 0x0), (0xFAA6, 0x76CA, 0x0),
+    (0xFAA7, 0x76DB, 0x0), (0xFAA8, 0x76F4, 0x0), (0xFAA9, 0x774A, 0x0), (0xFAAA, 0x7740, 0x0),
+    (0xFAAB, 0x78CC, 0x0), (0xFAAC, 0x7AB1, 0x0), (0xFAAD, 0x7BC0, 0x0), (0xFAAE, 0x7C7B, 0x0),
+    (0xFAAF, 0x7D5B, 0x0), (0xFAB0, 0x7DF4, 0x0), (0xFAB1, 0x7F3E, 0x0), (0xFAB2, 0x8005, 0x0),
+    (0xFAB3, 0x8352, 0x0), (0xFAB4, 0x83EF, 0x0), (0xFAB5, 0x8779, 0x0), (0xFAB6, 0x8941, 0x0),
+    (0xFAB7, 0x8986, 0x0), (0xFAB8, 0x8996, 0x0), (0xFAB9, 0x8ABF, 0x0), (0xFABA, 0x8AF8, 0x0),
+    (0xFABB, 0x8ACB, 0x0), (0xFABC, 0x8B01, 0x0), (0xFABD, 0x8AFE, 0x0), (0xFABE, 0x8AED, 0x0),
+    (0xFABF, 0x8B39, 0x0), (0xFAC0, 0x8B8A, 0x0), (0xFAC1, 0x8D08, 0x0), (0xFAC2, 0x8F38, 0x0),
+    (0xFAC3, 0x9072, 0x0), (0xFAC4, 0x9199, 0x0), (0xFAC5, 0x9276, 0x0), (0xFAC6, 0x967C, 0x0),
+    (0xFAC7, 0x96E3, 0x0), (0xFAC8, 0x9756, 0x0), (0xFAC9, 0x97DB, 0x0), (0xFACA, 0x97FF, 0x0),
+    (0xFACB, 0x980B, 0x0), (0xFACC, 0x983B, 0x0), (0xFACD, 0x9B12, 0x0), (0xFACE, 0x9F9C, 0x0),
+    (0xFACF, 0x2284A, 0x0), (0xFAD0, 0x22844, 0x0), (0xFAD1, 0x233D5, 0x0), (0xFAD2, 0x3B9D, 0x0),
+    (0xFAD3, 0x4018, 0x0), (0xFAD4, 0x4039, 0x0), (0xFAD5, 0x25249, 0x0), (0xFAD6, 0x25CD0, 0x0),
+    (0xFAD7, 0x27ED3, 0x0), (0xFAD8, 0x9F43, 0x0), (0xFAD9, 0x9F8E, 0x0), (0xFB1D, 0x5D9, 0x5B4),
+    (0xFB1F, 0x5F2, 0x5B7), (0xFB2A, 0x5E9, 0x5C1), (0xFB2B, 0x5E9, 0x5C2), (0xFB2C, 0xFB49, 0x5C1),
+    (0xFB2D, 0xFB49, 0x5C2), (0xFB2E, 0x5D0, 0x5B7), (0xFB2F, 0x5D0, 0x5B8), (0xFB30, 0x5D0, 0x5BC),
+    (0xFB31, 0x5D1, 0x5BC), (0xFB32, 0x5D2, 0x5BC), (0xFB33, 0x5D3, 0x5BC), (0xFB34, 0x5D4, 0x5BC),
+    (0xFB35, 0x5D5, 0x5BC), (0xFB36, 0x5D6, 0x5BC), (0xFB38, 0x5D8, 0x5BC), (0xFB39, 0x5D9, 0x5BC),
+    (0xFB3A, 0x5DA, 0x5BC), (0xFB3B, 0x5DB, 0x5BC), (0xFB3C, 0x5DC, 0x5BC), (0xFB3E, 0x5DE, 0x5BC),
+    (0xFB40, 0x5E0, 0x5BC), (0xFB41, 0x5E1, 0x5BC), (0xFB43, 0x5E3, 0x5BC), (0xFB44, 0x5E4, 0x5BC),
+    (0xFB46, 0x5E6, 0x5BC), (0xFB47, 0x5E7, 0x5BC), (0xFB48, 0x5E8, 0x5BC), (0xFB49, 0x5E9, 0x5BC),
+    (0xFB4A, 0x5EA, 0x5BC), (0xFB4B, 0x5D5, 0x5B9), (0xFB4C, 0x5D1, 0x5BF), (0xFB4D, 0x5DB, 0x5BF),
+    (0xFB4E, 0x5E4, 0x5BF), (0x1109A, 0x11099, 0x110BA), (0x1109C, 0x1109B, 0x110BA), (0x110AB, 0x110A5, 0x110BA),
+    (0x1112E, 0x11131, 0x11127), (0x1112F, 0x11132, 0x11127), (0x1134B, 0x11347, 0x1133E), (0x1134C, 0x11347, 0x11357),
+    (0x114BB, 0x114B9, 0x114BA), (0x114BC, 0x114B9, 0x114B0), (0x114BE, 0x114B9, 0x114BD), (0x115BA, 0x115B8, 0x115AF),
+    (0x115BB, 0x115B9, 0x115AF), (0x11938, 0x11935, 0x11930), (0x1D15E, 0x1D157, 0x1D165), (0x1D15F, 0x1D158, 0x1D165),
+    (0x1D160, 0x1D15F, 0x1D16E), (0x1D161, 0x1D15F, 0x1D16F), (0x1D162, 0x1D15F, 0x1D170), (0x1D163, 0x1D15F, 0x1D171),
+    (0x1D164, 0x1D15F, 0x1D172), (0x1D1BB, 0x1D1B9, 0x1D165), (0x1D1BC, 0x1D1BA, 0x1D165), (0x1D1BD, 0x1D1BB, 0x1D16E),
+    (0x1D1BE, 0x1D1BC, 0x1D16E), (0x1D1BF, 0x1D1BB, 0x1D16F), (0x1D1C0, 0x1D1BC, 0x1D16F), (0x2F800, 0x4E3D, 0x0),
+    (0x2F801, 0x4E38, 0x0), (0x2F802, 0x4E41, 0x0), (0x2F803, 0x20122, 0x0), (0x2F804, 0x4F60, 0x0),
+    (0x2F805, 0x4FAE, 0x0), (0x2F806, 0x4FBB, 0x0), (0x2F807, 0x5002, 0x0), (0x2F808, 0x507A, 0x0),
+    (0x2F809, 0x5099, 0x0), (0x2F80A, 0x50E7, 0x0), (0x2F80B, 0x50CF, 0x0), (0x2F80C, 0x349E, 0x0),
+    (0x2F80D, 0x2063A, 0x0), (0x2F80E, 0x514D, 0x0), (0x2F80F, 0x5154, 0x0), (0x2F810, 0x5164, 0x0),
+    (0x2F811, 0x5177, 0x0), (0x2F812, 0x2051C, 0x0), (0x2F813, 0x34B9, 0x0), (0x2F814, 0x5167, 0x0),
+    (0x2F815, 0x518D, 0x0), (0x2F816, 0x2054B, 0x0), (0x2F817, 0x5197, 0x0), (0x2F818, 0x51A4, 0x0),
+    (0x2F819, 0x4ECC, 0x0), (0x2F81A, 0x51AC, 0x0), (0x2F81B, 0x51B5, 0x0), (0x2F81C, 0x291DF, 0x0),
+    (0x2F81D, 0x51F5, 0x0), (0x2F81E, 0x5203, 0x0), (0x2F81F, 0x34DF, 0x0), (0x2F820, 0x523B, 0x0),
+    (0x2F821, 0x5246, 0x0), (0x2F822, 0x5272, 0x0), (0x2F823, 0x5277, 0x0), (0x2F824, 0x3515, 0x0),
+    (0x2F825, 0x52C7, 0x0), (0x2F826, 0x52C9, 0x0), (0x2F827, 0x52E4, 0x0), (0x2F828, 0x52FA, 0x0),
+    (0x2F829, 0x5305, 0x0), (0x2F82A, 0x5306, 0x0), (0x2F82B, 0x5317, 0x0), (0x2F82C, 0x5349, 0x0),
+    (0x2F82D, 0x5351, 0x0), (0x2F82E, 0x535A, 0x0), (0x2F82F, 0x5373, 0x0), (0x2F830, 0x537D, 0x0),
+    (0x2F831, 0x537F, 0x0), (0x2F832, 0x537F, 0x0), (0x2F833, 0x537F, 0x0), (0x2F834, 0x20A2C, 0x0),
+    (0x2F835, 0x7070, 0x0), (0x2F836, 0x53CA, 0x0), (0x2F837, 0x53DF, 0x0), (0x2F838, 0x20B63, 0x0),
+    (0x2F839, 0x53EB, 0x0), (0x2F83A, 0x53F1, 0x0), (0x2F83B, 0x5406, 0x0), (0x2F83C, 0x549E, 0x0),
+    (0x2F83D, 0x5438, 0x0), (0x2F83E, 0x5448, 0x0), (0x2F83F, 0x5468, 0x0), (0x2F840, 0x54A2, 0x0),
+    (0x2F841, 0x54F6, 0x0), (0x2F842, 0x5510, 0x0), (0x2F843, 0x5553, 0x0), (0x2F844, 0x5563, 0x0),
+    (0x2F845, 0x5584, 0x0), (0x2F846, 0x5584, 0x0), (0x2F847, 0x5599, 0x0), (0x2F848, 0x55AB, 0x0),
+    (0x2F849, 0x55B3, 0x0), (0x2F84A, 0x55C2, 0x0), (0x2F84B, 0x5716, 0x0), (0x2F84C, 0x5606, 0x0),
+    (0x2F84D, 0x5717, 0x0), (0x2F84E, 0x5651, 0x0), (0x2F84F, 0x5674, 0x0), (0x2F850, 0x5207, 0x0),
+    (0x2F851, 0x58EE, 0x0), (0x2F852, 0x57CE, 0x0), (0x2F853, 0x57F4, 0x0), (0x2F854, 0x580D, 0x0),
+    (0x2F855, 0x578B, 0x0), (0x2F856, 0x5832, 0x0), (0x2F857, 0x5831, 0x0), (0x2F858, 0x58AC, 0x0),
+    (0x2F859, 0x214E4, 0x0), (0x2F85A, 0x58F2, 0x0), (0x2F85B, 0x58F7, 0x0), (0x2F85C, 0x5906, 0x0),
+    (0x2F85D, 0x591A, 0x0), (0x2F85E, 0x5922, 0x0), (0x2F85F, 0x5962, 0x0), (0x2F860, 0x216A8, 0x0),
+    (0x2F861, 0x216EA, 0x0), (0x2F862, 0x59EC, 0x0), (0x2F863, 0x5A1B, 0x0), (0x2F864, 0x5A27, 0x0),
+    (0x2F865, 0x59D8, 0x0), (0x2F866, 0x5A66, 0x0), (0x2F867, 0x36EE, 0x0), (0x2F868, 0x36FC, 0x0),
+    (0x2F869, 0x5B08, 0x0), (0x2F86A, 0x5B3E, 0x0), (0x2F86B, 0x5B3E, 0x0), (0x2F86C, 0x219C8, 0x0),
+    (0x2F86D, 0x5BC3, 0x0), (0x2F86E, 0x5BD8, 0x0), (0x2F86F, 0x5BE7, 0x0), (0x2F870, 0x5BF3, 0x0),
+    (0x2F871, 0x21B18, 0x0), (0x2F872, 0x5BFF, 0x0), (0x2F873, 0x5C06, 0x0), (0x2F874, 0x5F53, 0x0),
+    (0x2F875, 0x5C22, 0x0), (0x2F876, 0x3781, 0x0), (0x2F877, 0x5C60, 0x0), (0x2F878, 0x5C6E, 0x0),
+    (0x2F879, 0x5CC0, 0x0), (0x2F87A, 0x5C8D, 0x0), (0x2F87B, 0x21DE4, 0x0), (0x2F87C, 0x5D43, 0x0),
+    (0x2F87D, 0x21DE6, 0x0), (0x2F87E, 0x5D6E, 0x0), (0x2F87F, 0x5D6B, 0x0), (0x2F880, 0x5D7C, 0x0),
+    (0x2F881, 0x5DE1, 0x0), (0x2F882, 0x5DE2, 0x0), (0x2F883, 0x382F, 0x0), (0x2F884, 0x5DFD, 0x0),
+    (0x2F885, 0x5E28, 0x0), (0x2F886, 0x5E3D, 0x0), (0x2F887, 0x5E69, 0x0), (0x2F888, 0x3862, 0x0),
+    (0x2F889, 0x22183, 0x0), (0x2F88A, 0x387C, 0x0), (0x2F88B, 0x5EB0, 0x0), (0x2F88C, 0x5EB3, 0x0),
+    (0x2F88D, 0x5EB6, 0x0), (0x2F88E, 0x5ECA, 0x0), (0x2F88F, 0x2A392, 0x0), (0x2F890, 0x5EFE, 0x0),
+    (0x2F891, 0x22331, 0x0), (0x2F892, 0x22331, 0x0), (0x2F893, 0x8201, 0x0), (0x2F894, 0x5F22, 0x0),
+    (0x2F895, 0x5F22, 0x0), (0x2F896, 0x38C7, 0x0), (0x2F897, 0x232B8, 0x0), (0x2F898, 0x261DA, 0x0),
+    (0x2F899, 0x5F62, 0x0), (0x2F89A, 0x5F6B, 0x0), (0x2F89B, 0x38E3, 0x0), (0x2F89C, 0x5F9A, 0x0),
+    (0x2F89D, 0x5FCD, 0x0), (0x2F89E, 0x5FD7, 0x0), (0x2F89F, 0x5FF9, 0x0), (0x2F8A0, 0x6081, 0x0),
+    (0x2F8A1, 0x393A, 0x0), (0x2F8A2, 0x391C, 0x0), (0x2F8A3, 0x6094, 0x0), (0x2F8A4, 0x226D4, 0x0),
+    (0x2F8A5, 0x60C7, 0x0), (0x2F8A6, 0x6148, 0x0), (0x2F8A7, 0x614C, 0x0), (0x2F8A8, 0x614E, 0x0),
+    (0x2F8A9, 0x614C, 0x0), (0x2F8AA, 0x617A, 0x0), (0x2F8AB, 0x618E, 0x0), (0x2F8AC, 0x61B2, 0x0),
+    (0x2F8AD, 0x61A4, 0x0), (0x2F8AE, 0x61AF, 0x0), (0x2F8AF, 0x61DE, 0x0), (0x2F8B0, 0x61F2, 0x0),
+    (0x2F8B1, 0x61F6, 0x0), (0x2F8B2, 0x6210, 0x0), (0x2F8B3, 0x621B, 0x0), (0x2F8B4, 0x625D, 0x0),
+    (0x2F8B5, 0x62B1, 0x0), (0x2F8B6, 0x62D4, 0x0), (0x2F8B7, 0x6350, 0x0), (0x2F8B8, 0x22B0C, 0x0),
+    (0x2F8B9, 0x633D, 0x0), (0x2F8BA, 0x62FC, 0x0), (0x2F8BB, 0x6368, 0x0), (0x2F8BC, 0x6383, 0x0),
+    (0x2F8BD, 0x63E4, 0x0), (0x2F8BE, 0x22BF1, 0x0), (0x2F8BF, 0x6422, 0x0), (0x2F8C0, 0x63C5, 0x0),
+    (0x2F8C1, 0x63A9, 0x0), (0x2F8C2, 0x3A2E, 0x0), (0x2F8C3, 0x6469, 0x0), (0x2F8C4, 0x647E, 0x0),
+    (0x2F8C5, 0x649D, 0x0), (0x2F8C6, 0x6477, 0x0), (0x2F8C7, 0x3A6C, 0x0), (0x2F8C8, 0x654F, 0x0),
+    (0x2F8C9, 0x656C, 0x0), (0x2F8CA, 0x2300A, 0x0), (0x2F8CB, 0x65E3, 0x0), (0x2F8CC, 0x66F8, 0x0),
+    (0x2F8CD, 0x6649, 0x0), (0x2F8CE, 0x3B19, 0x0), (0x2F8CF, 0x6691, 0x0), (0x2F8D0, 0x3B08, 0x0),
+    (0x2F8D1, 0x3AE4, 0x0), (0x2F8D2, 0x5192, 0x0), (0x2F8D3, 0x5195, 0x0), (0x2F8D4, 0x6700, 0x0),
+    (0x2F8D5, 0x669C, 0x0), (0x2F8D6, 0x80AD, 0x0), (0x2F8D7, 0x43D9, 0x0), (0x2F8D8, 0x6717, 0x0),
+    (0x2F8D9, 0x671B, 0x0), (0x2F8DA, 0x6721, 0x0), (0x2F8DB, 0x675E, 0x0), (0x2F8DC, 0x6753, 0x0),
+    (0x2F8DD, 0x233C3, 0x0), (0x2F8DE, 0x3B49, 0x0), (0x2F8DF, 0x67FA, 0x0), (0x2F8E0, 0x6785, 0x0),
+    (0x2F8E1, 0x6852, 0x0), (0x2F8E2, 0x6885, 0x0), (0x2F8E3, 0x2346D, 0x0), (0x2F8E4, 0x688E, 0x0),
+    (0x2F8E5, 0x681F, 0x0), (0x2F8E6, 0x6914, 0x0), (0x2F8E7, 0x3B9D, 0x0), (0x2F8E8, 0x6942, 0x0),
+    (0x2F8E9, 0x69A3, 0x0), (0x2F8EA, 0x69EA, 0x0), (0x2F8EB, 0x6AA8, 0x0), (0x2F8EC, 0x236A3, 0x0),
+    (0x2F8ED, 0x6ADB, 0x0), (0x2F8EE, 0x3C18, 0x0), (0x2F8EF, 0x6B21, 0x0), (0x2F8F0, 0x238A7, 0x0),
+    (0x2F8F1, 0x6B54, 0x0), (0x2F8F2, 0x3C4E, 0x0), (0x2F8F3, 0x6B72, 0x0), (0x2F8F4, 0x6B9F, 0x0),
+    (0x2F8F5, 0x6BBA, 0x0), (0x2F8F6, 0x6BBB, 0x0), (0x2F8F7, 0x23A8D, 0x0), (0x2F8F8, 0x21D0B, 0x0),
+    (0x2F8F9, 0x23AFA, 0x0), (0x2F8FA, 0x6C4E, 0x0), (0x2F8FB, 0x23CBC, 0x0), (0x2F8FC, 0x6CBF, 0x0),
+    (0x2F8FD, 0x6CCD, 0x0), (0x2F8FE, 0x6C67, 0x0), (0x2F8FF, 0x6D16, 0x0), (0x2F900, 0x6D3E, 0x0),
+    (0x2F901, 0x6D77, 0x0), (0x2F902, 0x6D41, 0x0), (0x2F903, 0x6D69, 0x0), (0x2F904, 0x6D78, 0x0),
+    (0x2F905, 0x6D85, 0x0), (0x2F906, 0x23D1E, 0x0), (0x2F907, 0x6D34, 0x0), (0x2F908, 0x6E2F, 0x0),
+    (0x2F909, 0x6E6E, 0x0), (0x2F90A, 0x3D33, 0x0), (0x2F90B, 0x6ECB, 0x0), (0x2F90C, 0x6EC7, 0x0),
+    (0x2F90D, 0x23ED1, 0x0), (0x2F90E, 0x6DF9, 0x0), (0x2F90F, 0x6F6E, 0x0), (0x2F910, 0x23F5E, 0x0),
+    (0x2F911, 0x23F8E, 0x0), (0x2F912, 0x6FC6, 0x0), (0x2F913, 0x7039, 0x0), (0x2F914, 0x701E, 0x0),
+    (0x2F915, 0x701B, 0x0), (0x2F916, 0x3D96, 0x0), (0x2F917, 0x704A, 0x0), (0x2F918, 0x707D, 0x0),
+    (0x2F919, 0x7077, 0x0), (0x2F91A, 0x70AD, 0x0), (0x2F91B, 0x20525, 0x0), (0x2F91C, 0x7145, 0x0),
+    (0x2F91D, 0x24263, 0x0), (0x2F91E, 0x719C, 0x0), (0x2F91F, 0x243AB, 0x0), (0x2F920, 0x7228, 0x0),
+    (0x2F921, 0x7235, 0x0), (0x2F922, 0x7250, 0x0), (0x2F923, 0x24608, 0x0), (0x2F924, 0x7280, 0x0),
+    (0x2F925, 0x7295, 0x0), (0x2F926, 0x24735, 0x0), (0x2F927, 0x24814, 0x0), (0x2F928, 0x737A, 0x0),
+    (0x2F929, 0x738B, 0x0), (0x2F92A, 0x3EAC, 0x0), (0x2F92B, 0x73A5, 0x0), (0x2F92C, 0x3EB8, 0x0),
+    (0x2F92D, 0x3EB8, 0x0), (0x2F92E, 0x7447, 0x0), (0x2F92F, 0x745C, 0x0), (0x2F930, 0x7471, 0x0),
+    (0x2F931, 0x7485, 0x0), (0x2F932, 0x74CA, 0x0), (0x2F933, 0x3F1B, 0x0), (0x2F934, 0x7524, 0x0),
+    (0x2F935, 0x24C36, 0x0), (0x2F936, 0x753E, 0x0), (0x2F937, 0x24C92, 0x0), (0x2F938, 0x7570, 0x0),
+    (0x2F939, 0x2219F, 0x0), (0x2F93A, 0x7610, 0x0), (0x2F93B, 0x24FA1, 0x0), (0x2F93C, 0x24FB8, 0x0),
+    (0x2F93D, 0x25044, 0x0), (0x2F93E, 0x3FFC, 0x0), (0x2F93F, 0x4008, 0x0), (0x2F940, 0x76F4, 0x0),
+    (0x2F941, 0x250F3, 0x0), (0x2F942, 0x250F2, 0x0), (0x2F943, 0x25119, 0x0), (0x2F944, 0x25133, 0x0),
+    (0x2F945, 0x771E, 0x0), (0x2F946, 0x771F, 0x0), (0x2F947, 0x771F, 0x0), (0x2F948, 0x774A, 0x0),
+    (0x2F949, 0x4039, 0x0), (0x2F94A, 0x778B, 0x0), (0x2F94B, 0x4046, 0x0), (0x2F94C, 0x4096, 0x0),
+    (0x2F94D, 0x2541D, 0x0), (0x2F94E, 0x784E, 0x0), (0x2F94F, 0x788C, 0x0), (0x2F950, 0x78CC, 0x0),
+    (0x2F951, 0x40E3, 0x0), (0x2F952, 0x25626, 0x0), (0x2F953, 0x7956, 0x0), (0x2F954, 0x2569A, 0x0),
+    (0x2F955, 0x256C5, 0x0), (0x2F956, 0x798F, 0x0), (0x2F957, 0x79EB, 0x0), (0x2F958, 0x412F, 0x0),
+    (0x2F959, 0x7A40, 0x0), (0x2F95A, 0x7A4A, 0x0), (0x2F95B, 0x7A4F, 0x0), (0x2F95C, 0x2597C, 0x0),
+    (0x2F95D, 0x25AA7, 0x0), (0x2F95E, 0x25AA7, 0x0), (0x2F95F, 0x7AEE, 0x0), (0x2F960, 0x4202, 0x0),
+    (0x2F961, 0x25BAB, 0x0), (0x2F962, 0x7BC6, 0x0), (0x2F963, 0x7BC9, 0x0), (0x2F964, 0x4227, 0x0),
+    (0x2F965, 0x25C80, 0x0), (0x2F966, 0x7CD2, 0x0), (0x2F967, 0x42A0, 0x0), (0x2F968, 0x7CE8, 0x0),
+    (0x2F969, 0x7CE3, 0x0), (0x2F96A, 0x7D00, 0x0), (0x2F96B, 0x25F86, 0x0), (0x2F96C, 0x7D63, 0x0),
+    (0x2F96D, 0x4301, 0x0), (0x2F96E, 0x7DC7, 0x0), (0x2F96F, 0x7E02, 0x0), (0x2F970, 0x7E45, 0x0),
+    (0x2F971, 0x4334, 0x0), (0x2F972, 0x26228, 0x0), (0x2F973, 0x26247, 0x0), (0x2F974, 0x4359, 0x0),
+    (0x2F975, 0x262D9, 0x0), (0x2F976, 0x7F7A, 0x0), (0x2F977, 0x2633E, 0x0), (0x2F978, 0x7F95, 0x0),
+    (0x2F979, 0x7FFA, 0x0), (0x2F97A, 0x8005, 0x0), (0x2F97B, 0x264DA, 0x0), (0x2F97C, 0x26523, 0x0),
+    (0x2F97D, 0x8060, 0x0), (0x2F97E, 0x265A8, 0x0), (0x2F97F, 0x8070, 0x0), (0x2F980, 0x2335F, 0x0),
+    (0x2F981, 0x43D5, 0x0), (0x2F982, 0x80B2, 0x0), (0x2F983, 0x8103, 0x0), (0x2F984, 0x440B, 0x0),
+    (0x2F985, 0x813E, 0x0), (0x2F986, 0x5AB5, 0x0), (0x2F987, 0x267A7, 0x0), (0x2F988, 0x267B5, 0x0),
+    (0x2F989, 0x23393, 0x0), (0x2F98A, 0x2339C, 0x0), (0x2F98B, 0x8201, 0x0), (0x2F98C, 0x8204, 0x0),
+    (0x2F98D, 0x8F9E, 0x0), (0x2F98E, 0x446B, 0x0), (0x2F98F, 0x8291, 0x0), (0x2F990, 0x828B, 0x0),
+    (0x2F991, 0x829D, 0x0), (0x2F992, 0x52B3, 0x0), (0x2F993, 0x82B1, 0x0), (0x2F994, 0x82B3, 0x0),
+    (0x2F995, 0x82BD, 0x0), (0x2F996, 0x82E6, 0x0), (0x2F997, 0x26B3C, 0x0), (0x2F998, 0x82E5, 0x0),
+    (0x2F999, 0x831D, 0x0), (0x2F99A, 0x8363, 0x0), (0x2F99B, 0x83AD, 0x0), (0x2F99C, 0x8323, 0x0),
+    (0x2F99D, 0x83BD, 0x0), (0x2F99E, 0x83E7, 0x0), (0x2F99F, 0x8457, 0x0), (0x2F9A0, 0x8353, 0x0),
+    (0x2F9A1, 0x83CA, 0x0), (0x2F9A2, 0x83CC, 0x0), (0x2F9A3, 0x83DC, 0x0), (0x2F9A4, 0x26C36, 0x0),
+    (0x2F9A5, 0x26D6B, 0x0), (0x2F9A6, 0x26CD5, 0x0), (0x2F9A7, 0x452B, 0x0), (0x2F9A8, 0x84F1, 0x0),
+    (0x2F9A9, 0x84F3, 0x0), (0x2F9AA, 0x8516, 0x0), (0x2F9AB, 0x273CA, 0x0), (0x2F9AC, 0x8564, 0x0),
+    (0x2F9AD, 0x26F2C, 0x0), (0x2F9AE, 0x455D, 0x0), (0x2F9AF, 0x4561, 0x0), (0x2F9B0, 0x26FB1, 0x0),
+    (0x2F9B1, 0x270D2, 0x0), (0x2F9B2, 0x456B, 0x0), (0x2F9B3, 0x8650, 0x0), (0x2F9B4, 0x865C, 0x0),
+    (0x2F9B5, 0x8667, 0x0), (0x2F9B6, 0x8669, 0x0), (0x2F9B7, 0x86A9, 0x0), (0x2F9B8, 0x8688, 0x0),
+    (0x2F9B9, 0x870E, 0x0), (0x2F9BA, 0x86E2, 0x0), (0x2F9BB, 0x8779, 0x0), (0x2F9BC, 0x8728, 0x0),
+    (0x2F9BD, 0x876B, 0x0), (0x2F9BE, 0x8786, 0x0), (0x2F9BF, 0x45D7, 0x0), (0x2F9C0, 0x87E1, 0x0),
+    (0x2F9C1, 0x8801, 0x0), (0x2F9C2, 0x45F9, 0x0), (0x2F9C3, 0x8860, 0x0), (0x2F9C4, 0x8863, 0x0),
+    (0x2F9C5, 0x27667, 0x0), (0x2F9C6, 0x88D7, 0x0), (0x2F9C7, 0x88DE, 0x0), (0x2F9C8, 0x4635, 0x0),
+    (0x2F9C9, 0x88FA, 0x0), (0x2F9CA, 0x34BB, 0x0), (0x2F9CB, 0x278AE, 0x0), (0x2F9CC, 0x27966, 0x0),
+    (0x2F9CD, 0x46BE, 0x0), (0x2F9CE, 0x46C7, 0x0), (0x2F9CF, 0x8AA0, 0x0), (0x2F9D0, 0x8AED, 0x0),
+    (0x2F9D1, 0x8B8A, 0x0), (0x2F9D2, 0x8C55, 0x0), (0x2F9D3, 0x27CA8, 0x0), (0x2F9D4, 0x8CAB, 0x0),
+    (0x2F9D5, 0x8CC1, 0x0), (0x2F9D6, 0x8D1B, 0x0), (0x2F9D7, 0x8D77, 0x0), (0x2F9D8, 0x27F2F, 0x0),
+    (0x2F9D9, 0x20804, 0x0), (0x2F9DA, 0x8DCB, 0x0), (0x2F9DB, 0x8DBC, 0x0), (0x2F9DC, 0x8DF0, 0x0),
+    (0x2F9DD, 0x208DE, 0x0), (0x2F9DE, 0x8ED4, 0x0), (0x2F9DF, 0x8F38, 0x0), (0x2F9E0, 0x285D2, 0x0),
+    (0x2F9E1, 0x285ED, 0x0), (0x2F9E2, 0x9094, 0x0), (0x2F9E3, 0x90F1, 0x0), (0x2F9E4, 0x9111, 0x0),
+    (0x2F9E5, 0x2872E, 0x0), (0x2F9E6, 0x911B, 0x0), (0x2F9E7, 0x9238, 0x0), (0x2F9E8, 0x92D7, 0x0),
+    (0x2F9E9, 0x92D8, 0x0), (0x2F9EA, 0x927C, 0x0), (0x2F9EB, 0x93F9, 0x0), (0x2F9EC, 0x9415, 0x0),
+    (0x2F9ED, 0x28BFA, 0x0), (0x2F9EE, 0x958B, 0x0), (0x2F9EF, 0x4995, 0x0), (0x2F9F0, 0x95B7, 0x0),
+    (0x2F9F1, 0x28D77, 0x0), (0x2F9F2, 0x49E6, 0x0), (0x2F9F3, 0x96C3, 0x0), (0x2F9F4, 0x5DB2, 0x0),
+    (0x2F9F5, 0x9723, 0x0), (0x2F9F6, 0x29145, 0x0), (0x2F9F7, 0x2921A, 0x0), (0x2F9F8, 0x4A6E, 0x0),
+    (0x2F9F9, 0x4A76, 0x0), (0x2F9FA, 0x97E0, 0x0), (0x2F9FB, 0x2940A, 0x0), (0x2F9FC, 0x4AB2, 0x0),
+    (0x2F9FD, 0x29496, 0x0), (0x2F9FE, 0x980B, 0x0), (0x2F9FF, 0x980B, 0x0), (0x2FA00, 0x9829, 0x0),
+    (0x2FA01, 0x295B6, 0x0), (0x2FA02, 0x98E2, 0x0), (0x2FA03, 0x4B33, 0x0), (0x2FA04, 0x9929, 0x0),
+    (0x2FA05, 0x99A7, 0x0), (0x2FA06, 0x99C2, 0x0), (0x2FA07, 0x99FE, 0x0), (0x2FA08, 0x4BCE, 0x0),
+    (0x2FA09, 0x29B30, 0x0), (0x2FA0A, 0x9B12, 0x0), (0x2FA0B, 0x9C40, 0x0), (0x2FA0C, 0x9CFD, 0x0),
+    (0x2FA0D, 0x4CCE, 0x0), (0x2FA0E, 0x4CED, 0x0), (0x2FA0F, 0x9D67, 0x0), (0x2FA10, 0x2A0CE, 0x0),
+    (0x2FA11, 0x4CF8, 0x0), (0x2FA12, 0x2A105, 0x0), (0x2FA13, 0x2A20E, 0x0), (0x2FA14, 0x2A291, 0x0),
+    (0x2FA15, 0x9EBB, 0x0), (0x2FA16, 0x4D56, 0x0), (0x2FA17, 0x9EF9, 0x0), (0x2FA18, 0x9EFE, 0x0),
+    (0x2FA19, 0x9F05, 0x0), (0x2FA1A, 0x9F0F, 0x0), (0x2FA1B, 0x9F16, 0x0), (0x2FA1C, 0x9F3B, 0x0),
+    (0x2FA1D, 0x2A600, 0x0),
+];
+
+/// The pairs that compose into a primary composite, sorted
+pub(super) const COMPOSITION: &[(u32, u32, u32)] = &[
+    (0x3C, 0x338, 0x226E), (0x3D, 0x338, 0x2260), (0x3E, 0x338, 0x226F), (0x41, 0x300, 0xC0),
+    (0x41, 0x301, 0xC1), (0x41, 0x302, 0xC2), (0x41, 0x303, 0xC3), (0x41, 0x304, 0x100),
+    (0x41, 0x306, 0x102), (0x41, 0x307, 0x226), (0x41, 0x308, 0xC4), (0x41, 0x309, 0x1EA2),
+    (0x41, 0x30A, 0xC5), (0x41, 0x30C, 0x1CD), (0x41, 0x30F, 0x200), (0x41, 0x311, 0x202),
+    (0x41, 0x323, 0x1EA0), (0x41, 0x325, 0x1E00), (0x41, 0x328, 0x104), (0x42, 0x307, 0x1E02),
+    (0x42, 0x323, 0x1E04), (0x42, 0x331, 0x1E06), (0x43, 0x301, 0x106), (0x43, 0x302, 0x108),
+    (0x43, 0x307, 0x10A), (0x43, 0x30C, 0x10C), (0x43, 0x327, 0xC7), (0x44, 0x307, 0x1E0A),
+    (0x44, 0x30C, 0x10E), (0x44, 0x323, 0x1E0C), (0x44, 0x327, 0x1E10), (0x44, 0x32D, 0x1E12),
+    (0x44, 0x331, 0x1E0E), (0x45, 0x300, 0xC8), (0x45, 0x301, 0xC9), (0x45, 0x302, 0xCA),
+    (0x45, 0x303, 0x1EBC), (0x45, 0x304, 0x112), (0x45, 0x306, 0x114), (0x45, 0x307, 0x116),
+    (0x45, 0x308, 0xCB), (0x45, 0x309, 0x1EBA), (0x45, 0x30C, 0x11A), (0x45, 0x30F, 0x204),
+    (0x45, 0x311, 0x206), (0x45, 0x323, 0x1EB8), (0x45, 0x327, 0x228), (0x45, 0x328, 0x118),
+    (0x45, 0x32D, 0x1E18), (0x45, 0x330, 0x1E1A), (0x46, 0x307, 0x1E1E), (0x47, 0x301, 0x1F4),
+    (0x47, 0x302, 0x11C), (0x47, 0x304, 0x1E20), (0x47, 0x306, 0x11E), (0x47, 0x307, 0x120),
+    (0x47, 0x30C, 0x1E6), (0x47, 0x327, 0x122), (0x48, 0x302, 0x124), (0x48, 0x307, 0x1E22),
+    (0x48, 0x308, 0x1E26), (0x48, 0x30C, 0x21E), (0x48, 0x323, 0x1E24), (0x48, 0x327, 0x1E28),
+    (0x48, 0x32E, 0x1E2A), (0x49, 0x300, 0xCC), (0x49, 0x301, 0xCD), (0x49, 0x302, 0xCE),
+    (0x49, 0x303, 0x128), (0x49, 0x304, 0x12A), (0x49, 0x306, 0x12C), (0x49, 0x307, 0x130),
+    (0x49, 0x308, 0xCF), (0x49, 0x309, 0x1EC8), (0x49, 0x30C, 0x1CF), (0x49, 0x30F, 0x208),
+    (0x49, 0x311, 0x20A), (0x49, 0x323, 0x1ECA), (0x49, 0x328, 0x12E), (0x49, 0x330, 0x1E2C),
+    (0x4A, 0x302, 0x134), (0x4B, 0x301, 0x1E30), (0x4B, 0x30C, 0x1E8), (0x4B, 0x323, 0x1E32),
+    (0x4B, 0x327, 0x136), (0x4B, 0x331, 0x1E34), (0x4C, 0x301, 0x139), (0x4C, 0x30C, 0x13D),
+    (0x4C, 0x323, 0x1E36), (0x4C, 0x327, 0x13B), (0x4C, 0x32D, 0x1E3C), (0x4C, 0x331, 0x1E3A),
+    (0x4D, 0x301, 0x1E3E), (0x4D, 0x307, 0x1E40), (0x4D, 0x323, 0x1E42), (0x4E, 0x300, 0x1F8),
+    (0x4E, 0x301, 0x143), (0x4E, 0x303, 0xD1), (0x4E, 0x307, 0x1E44), (0x4E, 0x30C, 0x147),
+    (0x4E, 0x323, 0x1E46), (0x4E, 0x327, 0x145), (0x4E, 0x32D, 0x1E4A), (0x4E, 0x331, 0x1E48),
+    (0x4F, 0x300, 0xD2), (0x4F, 0x301, 0xD3), (0x4F, 0x302, 0xD4), (0x4F, 0x303, 0xD5),
+    (0x4F, 0x304, 0x14C), (0x4F, 0x306, 0x14E), (0x4F, 0x307, 0x22E), (0x4F, 0x308, 0xD6),
+    (0x4F, 0x309, 0x1ECE), (0x4F, 0x30B, 0x150), (0x4F, 0x30C, 0x1D1), (0x4F, 0x30F, 0x20C),
+    (0x4F, 0x311, 0x20E), (0x4F, 0x31B, 0x1A0), (0x4F, 0x323, 0x1ECC), (0x4F, 0x328, 0x1EA),
+    (0x50, 0x301, 0x1E54), (0x50, 0x307, 0x1E56), (0x52, 0x301, 0x154), (0x52, 0x307, 0x1E58),
+    (0x52, 0x30C, 0x158), (0x52, 0x30F, 0x210), (0x52, 0x311, 0x212), (0x52, 0x323, 0x1E5A),
+    (0x52, 0x327, 0x156), (0x52, 0x331, 0x1E5E), (0x53, 0x301, 0x15A), (0x53, 0x302, 0x15C),
+    (0x53, 0x307, 0x1E60), (0x53, 0x30C, 0x160), (0x53, 0x323, 0x1E62), (0x53, 0x326, 0x218),
+    (0x53, 0x327, 0x15E), (0x54, 0x307, 0x1E6A), (0x54, 0x30C, 0x164), (0x54, 0x323, 0x1E6C),
+    (0x54, 0x326, 0x21A), (0x54, 0x327, 0x162), (0x54, 0x32D, 0x1E70), (0x54, 0x331, 0x1E6E),
+    (0x55, 0x300, 0xD9), (0x55, 0x301, 0xDA), (0x55, 0x302, 0xDB), (0x55, 0x303, 0x168),
+    (0x55, 0x304, 0x16A), (0x55, 0x306, 0x16C), (0x55, 0x308, 0xDC), (0x55, 0x309, 0x1EE6),
+    (0x55, 0x30A, 0x16E), (0x55, 0x30B, 0x170), (0x55, 0x30C, 0x1D3), (0x55, 0x30F, 0x214),
+    (0x55, 0x311, 0x216), (0x55, 0x31B, 0x1AF), (0x55, 0x323, 0x1EE4), (0x55, 0x324, 0x1E72),
+    (0x55, 0x328, 0x172), (0x55, 0x32D, 0x1E76), (0x55, 0x330, 0x1E74), (0x56, 0x303, 0x1E7C),
+    (0x56, 0x323, 0x1E7E), (0x57, 0x300, 0x1E80), (0x57, 0x301, 0x1E82), (0x57, 0x302, 0x174),
+    (0x57, 0x307, 0x1E86), (0x57, 0x308, 0x1E84), (0x57, 0x323, 0x1E88), (0x58, 0x307, 0x1E8A),
+    (0x58, 0x308, 0x1E8C), (0x59, 0x300, 0x1EF2), (0x59, 0x301, 0xDD), (0x59, 0x302, 0x176),
+    (0x59, 0x303, 0x1EF8), (0x59, 0x304, 0x232), (0x59, 0x307, 0x1E8E), (0x59, 0x308, 0x178),
+    (0x59, 0x309, 0x1EF6), (0x59, 0x323, 0x1EF4), (0x5A, 0x301, 0x179), (0x5A, 0x302, 0x1E90),
+    (0x5A, 0x307, 0x17B), (0x5A, 0x30C, 0x17D), (0x5A, 0x323, 0x1E92), (0x5A, 0x331, 0x1E94),
+    (0x61, 0x300, 0xE0), (0x61, 0x301, 0xE1), (0x61, 0x302, 0xE2), (0x61, 0x303, 0xE3),
+    (0x61, 0x304, 0x101), (0x61, 0x306, 0x103), (0x61, 0x307, 0x227), (0x61, 0x308, 0xE4),
+    (0x61, 0x309, 0x1EA3), (0x61, 0x30A, 0xE5), (0x61, 0x30C, 0x1CE), (0x61, 0x30F, 0x201),
+    (0x61, 0x311, 0x203), (0x61, 0x323, 0x1EA1), (0x61, 0x325, 0x1E01), (0x61, 0x328, 0x105),
+    (0x62, 0x307, 0x1E03), (0x62, 0x323, 0x1E05), (0x62, 0x331, 0x1E07), (0x63, 0x301, 0x107),
+    (0x63, 0x302, 0x109), (0x63, 0x307, 0x10B), (0x63, 0x30C, 0x10D), (0x63, 0x327, 0xE7),
+    (0x64, 0x307, 0x1E0B), (0x64, 0x30C, 0x10F), (0x64, 0x323, 0x1E0D), (0x64, 0x327, 0x1E11),
+    (0x64, 0x32D, 0x1E13), (0x64, 0x331, 0x1E0F), (0x65, 0x300, 0xE8), (0x65, 0x301, 0xE9),
+    (0x65, 0x302, 0xEA), (0x65, 0x303, 0x1EBD), (0x65, 0x304, 0x113), (0x65, 0x306, 0x115),
+    (0x65, 0x307, 0x117), (0x65, 0x308, 0xEB), (0x65, 0x309, 0x1EBB), (0x65, 0x30C, 0x11B),
+    (0x65, 0x30F, 0x205), (0x65, 0x311, 0x207), (0x65, 0x323, 0x1EB9), (0x65, 0x327, 0x229),
+    (0x65, 0x328, 0x119), (0x65, 0x32D, 0x1E19), (0x65, 0x330, 0x1E1B), (0x66, 0x307, 0x1E1F),
+    (0x67, 0x301, 0x1F5), (0x67, 0x302, 0x11D), (0x67, 0x304, 0x1E21), (0x67, 0x306, 0x11F),
+    (0x67, 0x307, 0x121), (0x67, 0x30C, 0x1E7), (0x67, 0x327, 0x123), (0x68, 0x302, 0x125),
+    (0x68, 0x307, 0x1E23), (0x68, 0x308, 0x1E27), (0x68, 0x30C, 0x21F), (0x68, 0x323, 0x1E25),
+    (0x68, 0x327, 0x1E29), (0x68, 0x32E, 0x1E2B), (0x68, 0x331, 0x1E96), (0x69, 0x300, 0xEC),
+    (0x69, 0x301, 0xED), (0x69, 0x302, 0xEE), (0x69, 0x303, 0x129), (0x69, 0x304, 0x12B),
+    (0x69, 0x306, 0x12D), (0x69, 0x308, 0xEF), (0x69, 0x309, 0x1EC9), (0x69, 0x30C, 0x1D0),
+    (0x69, 0x30F, 0x209), (0x69, 0x311, 0x20B), (0x69, 0x323, 0x1ECB), (0x69, 0x328, 0x12F),
+    (0x69, 0x330, 0x1E2D), (0x6A, 0x302, 0x135), (0x6A, 0x30C, 0x1F0), (0x6B, 0x301, 0x1E31),
+    (0x6B, 0x30C, 0x1E9), (0x6B, 0x323, 0x1E33), (0x6B, 0x327, 0x137), (0x6B, 0x331, 0x1E35),
+    (0x6C, 0x301, 0x13A), (0x6C, 0x30C, 0x13E), (0x6C, 0x323, 0x1E37), (0x6C, 0x327, 0x13C),
+    (0x6C, 0x32D, 0x1E3D), (0x6C, 0x331, 0x1E3B), (0x6D, 0x301, 0x1E3F), (0x6D, 0x307, 0x1E41),
+    (0x6D, 0x323, 0x1E43), (0x6E, 0x300, 0x1F9), (0x6E, 0x301, 0x144), (0x6E, 0x303, 0xF1),
+    (0x6E, 0x307, 0x1E45), (0x6E, 0x30C, 0x148), (0x6E, 0x323, 0x1E47), (0x6E, 0x327, 0x146),
+    (0x6E, 0x32D, 0x1E4B), (0x6E, 0x331, 0x1E49), (0x6F, 0x300, 0xF2), (0x6F, 0x301, 0xF3),
+    (0x6F, 0x302, 0xF4), (0x6F, 0x303, 0xF5), (0x6F, 0x304, 0x14D), (0x6F, 0x306, 0x14F),
+    (0x6F, 0x307, 0x22F), (0x6F, 0x308, 0xF6), (0x6F, 0x309, 0x1ECF), (0x6F, 0x30B, 0x151),
+    (0x6F, 0x30C, 0x1D2), (0x6F, 0x30F, 0x20D), (0x6F, 0x311, 0x20F), (0x6F, 0x31B, 0x1A1),
+    (0x6F, 0x323, 0x1ECD), (0x6F, 0x328, 0x1EB), (0x70, 0x301, 0x1E55), (0x70, 0x307, 0x1E57),
+    (0x72, 0x301, 0x155), (0x72, 0x307, 0x1E59), (0x72, 0x30C, 0x159), (0x72, 0x30F, 0x211),
+    (0x72, 0x311, 0x213), (0x72, 0x323, 0x1E5B), (0x72, 0x327, 0x157), (0x72, 0x331, 0x1E5F),
+    (0x73, 0x301, 0x15B), (0x73, 0x302, 0x15D), (0x73, 0x307, 0x1E61), (0x73, 0x30C, 0x161),
+    (0x73, 0x323, 0x1E63), (0x73, 0x326, 0x219), (0x73, 0x327, 0x15F), (0x74, 0x307, 0x1E6B),
+    (0x74, 0x308, 0x1E97), (0x74, 0x30C, 0x165), (0x74, 0x323, 0x1E6D), (0x74, 0x326, 0x21B),
+    (0x74, 0x327, 0x163), (0x74, 0x32D, 0x1E71), (0x74, 0x331, 0x1E6F), (0x75, 0x300, 0xF9),
+    (0x75, 0x301, 0xFA), (0x75, 0x302, 0xFB), (0x75, 0x303, 0x169), (0x75, 0x304, 0x16B),
+    (0x75, 0x306, 0x16D), (0x75, 0x308, 0xFC), (0x75, 0x309, 0x1EE7), (0x75, 0x30A, 0x16F),
+    (0x75, 0x30B, 0x171), (0x75, 0x30C, 0x1D4), (0x75, 0x30F, 0x215), (0x75, 0x311, 0x217),
+    (0x75, 0x31B, 0x1B0), (0x75, 0x323, 0x1EE5), (0x75, 0x324, 0x1E73), (0x75, 0x328, 0x173),
+    (0x75, 0x32D, 0x1E77), (0x75, 0x330, 0x1E75), (0x76, 0x303, 0x1E7D), (0x76, 0x323, 0x1E7F),
+    (0x77, 0x300, 0x1E81), (0x77, 0x301, 0x1E83), (0x77, 0x302, 0x175), (0x77, 0x307, 0x1E87),
+    (0x77, 0x308, 0x1E85), (0x77, 0x30A, 0x1E98), (0x77, 0x323, 0x1E89), (0x78, 0x307, 0x1E8B),
+    (0x78, 0x308, 0x1E8D), (0x79, 0x300, 0x1EF3), (0x79, 0x301, 0xFD), (0x79, 0x302, 0x177),
+    (0x79, 0x303, 0x1EF9), (0x79, 0x304, 0x233), (0x79, 0x307, 0x1E8F), (0x79, 0x308, 0xFF),
+    (0x79, 0x309, 0x1EF7), (0x79, 0x30A, 0x1E99), (0x79, 0x323, 0x1EF5), (0x7A, 0x301, 0x17A),
+    (0x7A, 0x302, 0x1E91), (0x7A, 0x307, 0x17C), (0x7A, 0x30C, 0x17E), (0x7A, 0x323, 0x1E93),
+    (0x7A, 0x331, 0x1E95), (0xA8, 0x300, 0x1FED), (0xA8, 0x301, 0x385), (0xA8, 0x342, 0x1FC1),
+    (0xC2, 0x300, 0x1EA6), (0xC2, 0x301, 0x1EA4), (0xC2, 0x303, 0x1EAA), (0xC2, 0x309, 0x1EA8),
+    (0xC4, 0x304, 0x1DE), (0xC5, 0x301, 0x1FA), (0xC6, 0x301, 0x1FC), (0xC6, 0x304, 0x1E2),
+    (0xC7, 0x301, 0x1E08), (0xCA, 0x300, 0x1EC0), (0xCA, 0x301, 0x1EBE), (0xCA, 0x303, 0x1EC4),
+    (0xCA, 0x309, 0x1EC2), (0xCF, 0x301, 0x1E2E), (0xD4, 0x300, 0x1ED2), (0xD4, 0x301, 0x1ED0),
+    (0xD4, 0x303, 0x1ED6), (0xD4, 0x309, 0x1ED4), (0xD5, 0x301, 0x1E4C), (0xD5, 0x304, 0x22C),
+    (0xD5, 0x308, 0x1E4E), (0xD6, 0x304, 0x22A), (0xD8, 0x301, 0x1FE), (0xDC, 0x300, 0x1DB),
+    (0xDC, 0x301, 0x1D7), (0xDC, 0x304, 0x1D5), (0xDC, 0x30C, 0x1D9), (0xE2, 0x300, 0x1EA7),
+    (0xE2, 0x301, 0x1EA5), (0xE2, 0x303, 0x1EAB), (0xE2, 0x309, 0x1EA9), (0xE4, 0x304, 0x1DF),
+    (0xE5, 0x301, 0x1FB), (0xE6, 0x301, 0x1FD), (0xE6, 0x304, 0x1E3), (0xE7, 0x301, 0x1E09),
+    (0xEA, 0x300, 0x1EC1), (0xEA, 0x301, 0x1EBF), (0xEA, 0x303, 0x1EC5), (0xEA, 0x309, 0x1EC3),
+    (0xEF, 0x301, 0x1E2F), (0xF4, 0x300, 0x1ED3), (0xF4, 0x301, 0x1ED1), (0xF4, 0x303, 0x1ED7),
+    (0xF4, 0x309, 0x1ED5), (0xF5, 0x301, 0x1E4D), (0xF5, 0x304, 0x22D), (0xF5, 0x308, 0x1E4F),
+    (0xF6, 0x304, 0x22B), (0xF8, 0x301, 0x1FF), (0xFC, 0x300, 0x1DC), (0xFC, 0x301, 0x1D8),
+    (0xFC, 0x304, 0x1D6), (0xFC, 0x30C, 0x1DA), (0x102, 0x300, 0x1EB0), (0x102, 0x301, 0x1EAE),
+    (0x102, 0x303, 0x1EB4), (0x102, 0x309, 0x1EB2), (0x103, 0x300, 0x1EB1), (0x103, 0x301, 0x1EAF),
+    (0x103, 0x303, 0x1EB5), (0x103, 0x309, 0x1EB3), (0x112, 0x300, 0x1E14), (0x112, 0x301, 0x1E16),
+    (0x113, 0x300, 0x1E15), (0x113, 0x301, 0x1E17), (0x14C, 0x300, 0x1E50), (0x14C, 0x301, 0x1E52),
+    (0x14D, 0x300, 0x1E51), (0x14D, 0x301, 0x1E53), (0x15A, 0x307, 0x1E64), (0x15B, 0x307, 0x1E65),
+    (0x160, 0x307, 0x1E66), (0x161, 0x307, 0x1E67), (0x168, 0x301, 0x1E78), (0x169, 0x301, 0x1E79),
+    (0x16A, 0x308, 0x1E7A), (0x16B, 0x308, 0x1E7B), (0x17F, 0x307, 0x1E9B), (0x1A0, 0x300, 0x1EDC),
+    (0x1A0, 0x301, 0x1EDA), (0x1A0, 0x303, 0x1EE0), (0x1A0, 0x309, 0x1EDE), (0x1A0, 0x323, 0x1EE2),
+    (0x1A1, 0x300, 0x1EDD), (0x1A1, 0x301, 0x1EDB), (0x1A1, 0x303, 0x1EE1), (0x1A1, 0x309, 0x1EDF),
+    (0x1A1, 0x323, 0x1EE3), (0x1AF, 0x300, 0x1EEA), (0x1AF, 0x301, 0x1EE8), (0x1AF, 0x303, 0x1EEE),
+    (0x1AF, 0x309, 0x1EEC), (0x1AF, 0x323, 0x1EF0), (0x1B0, 0x300, 0x1EEB), (0x1B0, 0x301, 0x1EE9),
+    (0x1B0, 0x303, 0x1EEF), (0x1B0, 0x309, 0x1EED), (0x1B0, 0x323, 0x1EF1), (0x1B7, 0x30C, 0x1EE),
+    (0x1EA, 0x304, 0x1EC), (0x1EB, 0x304, 0x1ED), (0x226, 0x304, 0x1E0), (0x227, 0x304, 0x1E1),
+    (0x228, 0x306, 0x1E1C), (0x229, 0x306, 0x1E1D), (0x22E, 0x304, 0x230), (0x22F, 0x304, 0x231),
+    (0x292, 0x30C, 0x1EF), (0x391, 0x300, 0x1FBA), (0x391, 0x301, 0x386), (0x391, 0x304, 0x1FB9),
+    (0x391, 0x306, 0x1FB8), (0x391, 0x313, 0x1F08), (0x391, 0x314, 0x1F09), (0x391, 0x345, 0x1FBC),
+    (0x395, 0x300, 0x1FC8), (0x395, 0x301, 0x388), (0x395, 0x313, 0x1F18), (0x395, 0x314, 0x1F19),
+    (0x397, 0x300, 0x1FCA), (0x397, 0x301, 0x389), (0x397, 0x313, 0x1F28), (0x397, 0x314, 0x1F29),
+    (0x397, 0x345, 0x1FCC), (0x399, 0x300, 0x1FDA), (0x399, 0x301, 0x38A), (0x399, 0x304, 0x1FD9),
+    (0x399, 0x306, 0x1FD8), (0x399, 0x308, 0x3AA), (0x399, 0x313, 0x1F38), (0x399, 0x314, 0x1F39),
+    (0x39F, 0x300, 0x1FF8), (0x39F, 0x301, 0x38C), (0x39F, 0x313, 0x1F48), (0x39F, 0x314, 0x1F49),
+    (0x3A1, 0x314, 0x1FEC), (0x3A5, 0x300, 0x1FEA), (0x3A5, 0x301, 0x38E), (0x3A5, 0x304, 0x1FE9),
+    (0x3A5, 0x306, 0x1FE8), (0x3A5, 0x308, 0x3AB), (0x3A5, 0x314, 0x1F59), (0x3A9, 0x300, 0x1FFA),
+    (0x3A9, 0x301, 0x38F), (0x3A9, 0x313, 0x1F68), (0x3A9, 0x314, 0x1F69), (0x3A9, 0x345, 0x1FFC),
+    (0x3AC, 0x345, 0x1FB4), (0x3AE, 0x345, 0x1FC4), (0x3B1, 0x300, 0x1F70), (0x3B1, 0x301, 0x3AC),
+    (0x3B1, 0x304, 0x1FB1), (0x3B1, 0x306, 0x1FB0), (0x3B1, 0x313, 0x1F00), (0x3B1, 0x314, 0x1F01),
+    (0x3B1, 0x342, 0x1FB6), (0x3B1, 0x345, 0x1FB3), (0x3B5, 0x300, 0x1F72), (0x3B5, 0x301, 0x3AD),
+    (0x3B5, 0x313, 0x1F10), (0x3B5, 0x314, 0x1F11), (0x3B7, 0x300, 0x1F74), (0x3B7, 0x301, 0x3AE),
+    (0x3B7, 0x313, 0x1F20), (0x3B7, 0x314, 0x1F21), (0x3B7, 0x342, 0x1FC6), (0x3B7, 0x345, 0x1FC3),
+    (0x3B9, 0x300, 0x1F76), (0x3B9, 0x301, 0x3AF), (0x3B9, 0x304, 0x1FD1), (0x3B9, 0x306, 0x1FD0),
+    (0x3B9, 0x308, 0x3CA), (0x3B9, 0x313, 0x1F30), (0x3B9, 0x314, 0x1F31), (0x3B9, 0x342, 0x1FD6),
+    (0x3BF, 0x300, 0x1F78), (0x3BF, 0x301, 0x3CC), (0x3BF, 0x313, 0x1F40), (0x3BF, 0x314, 0x1F41),
+    (0x3C1, 0x313, 0x1FE4), (0x3C1, 0x314, 0x1FE5), (0x3C5, 0x300, 0x1F7A), (0x3C5, 0x301, 0x3CD),
+    (0x3C5, 0x304, 0x1FE1), (0x3C5, 0x306, 0x1FE0), (0x3C5, 0x308, 0x3CB), (0x3C5, 0x313, 0x1F50),
+    (0x3C5, 0x314, 0x1F51), (0x3C5, 0x342, 0x1FE6), (0x3C9, 0x300, 0x1F7C), (0x3C9, 0x301, 0x3CE),
+    (0x3C9, 0x313, 0x1F60), (0x3C9, 0x314, 0x1F61), (0x3C9, 0x342, 0x1FF6), (0x3C9, 0x345, 0x1FF3),
+    (0x3CA, 0x300, 0x1FD2), (0x3CA, 0x301, 0x390), (0x3CA, 0x342, 0x1FD7), (0x3CB, 0x300, 0x1FE2),
+    (0x3CB, 0x301, 0x3B0), (0x3CB, 0x342, 0x1FE7), (0x3CE, 0x345, 0x1FF4), (0x3D2, 0x301, 0x3D3),
+    (0x3D2, 0x308, 0x3D4), (0x406, 0x308, 0x407), (0x410, 0x306, 0x4D0), (0x410, 0x308, 0x4D2),
+    (0x413, 0x301, 0x403), (0x415, 0x300, 0x400), (0x415, 0x306, 0x4D6), (0x415, 0x308, 0x401),
+    (0x416, 0x306, 0x4C1), (0x416, 0x308, 0x4DC), (0x417, 0x308, 0x4DE), (0x418, 0x300, 0x40D),
+    (0x418, 0x304, 0x4E2), (0x418, 0x306, 0x419), (0x418, 0x308, 0x4E4), (0x41A, 0x301, 0x40C),
+    (0x41E, 0x308, 0x4E6), (0x423, 0x304, 0x4EE), (0x423, 0x306, 0x40E), (0x423, 0x308, 0x4F0),
+    (0x423, 0x30B, 0x4F2), (0x427, 0x308, 0x4F4), (0x42B, 0x308, 0x4F8), (0x42D, 0x308, 0x4EC),
+    (0x430, 0x306, 0x4D1), (0x430, 0x308, 0x4D3), (0x433, 0x301, 0x453), (0x435, 0x300, 0x450),
+    (0x435, 0x306, 0x4D7), (0x435, 0x308, 0x451), (0x436, 0x306, 0x4C2), (0x436, 0x308, 0x4DD),
+    (0x437, 0x308, 0x4DF), (0x438, 0x300, 0x45D), (0x438, 0x304, 0x4E3), (0x438, 0x306, 0x439),
+    (0x438, 0x308, 0x4E5), (0x43A, 0x301, 0x45C), (0x43E, 0x308, 0x4E7), (0x443, 0x304, 0x4EF),
+    (0x443, 0x306, 0x45E), (0x443, 0x308, 0x4F1), (0x443, 0x30B, 0x4F3), (0x447, 0x308, 0x4F5),
+    (0x44B, 0x308, 0x4F9), (0x44D, 0x308, 0x4ED), (0x456, 0x308, 0x457), (0x474, 0x30F, 0x476),
+    (0x475, 0x30F, 0x477), (0x4D8, 0x308, 0x4DA), (0x4D9, 0x308, 0x4DB), (0x4E8, 0x308, 0x4EA),
+    (0x4E9, 0x308, 0x4EB), (0x627, 0x653, 0x622), (0x627, 0x654, 0x623), (0x627, 0x655, 0x625),
+    (0x648, 0x654, 0x624), (0x64A, 0x654, 0x626), (0x6C1, 0x654, 0x6C2), (0x6D2, 0x654, 0x6D3),
+    (0x6D5, 0x654, 0x6C0), (0x928, 0x93C, 0x929), (0x930, 0x93C, 0x931), (0x933, 0x93C, 0x934),
+    (0x9C7, 0x9BE, 0x9CB), (0x9C7, 0x9D7, 0x9CC), (0xB47, 0xB3E, 0xB4B), (0xB47, 0xB56, 0xB48),
+    (0xB47, 0xB57, 0xB4C), (0xB92, 0xBD7, 0xB94), (0xBC6, 0xBBE, 0xBCA), (0xBC6, 0xBD7, 0xBCC),
+    (0xBC7, 0xBBE, 0xBCB), (0xC46, 0xC56, 0xC48), (0xCBF, 0xCD5, 0xCC0), (0xCC6, 0xCC2, 0xCCA),
+    (0xCC6, 0xCD5, 0xCC7), (0xCC6, 0xCD6, 0xCC8), (0xCCA, 0xCD5, 0xCCB), (0xD46, 0xD3E, 0xD4A),
+    (0xD46, 0xD57, 0xD4C), (0xD47, 0xD3E, 0xD4B), (0xDD9, 0xDCA, 0xDDA), (0xDD9, 0xDCF, 0xDDC),
+    (0xDD9, 0xDDF, 0xDDE), (0xDDC, 0xDCA, 0xDDD), (0x1025, 0x102E, 0x1026), (0x1B05, 0x1B35, 0x1B06),
+    (0x1B07, 0x1B35, 0x1B08), (0x1B09, 0x1B35, 0x1B0A), (0x1B0B, 0x1B35, 0x1B0C), (0x1B0D, 0x1B35, 0x1B0E),
+    (0x1B11, 0x1B35, 0x1B12), (0x1B3A, 0x1B35, 0x1B3B), (0x1B3C, 0x1B35, 0x1B3D), (0x1B3E, 0x1B35, 0x1B40),
+    (0x1B3F, 0x1B35, 0x1B41), (0x1B42, 0x1B35, 0x1B43), (0x1E36, 0x304, 0x1E38), (0x1E37, 0x304, 0x1E39),
+    (0x1E5A, 0x304, 0x1E5C), (0x1E5B, 0x304, 0x1E5D), (0x1E62, 0x307, 0x1E68), (0x1E63, 0x307, 0x1E69),
+    (0x1EA0, 0x302, 0x1EAC), (0x1EA0, 0x306, 0x1EB6), (0x1EA1, 0x302, 0x1EAD), (0x1EA1, 0x306, 0x1EB7),
+    (0x1EB8, 0x302, 0x1EC6), (0x1EB9, 0x302, 0x1EC7), (0x1ECC, 0x302, 0x1ED8), (0x1ECD, 0x302, 0x1ED9),
+    (0x1F00, 0x300, 0x1F02), (0x1F00, 0x301, 0x1F04), (0x1F00, 0x342, 0x1F06), (0x1F00, 0x345, 0x1F80),
+    (0x1F01, 0x300, 0x1F03), (0x1F01, 0x301, 0x1F05), (0x1F01, 0x342, 0x1F07), (0x1F01, 0x345, 0x1F81),
+    (0x1F02, 0x345, 0x1F82), (0x1F03, 0x345, 0x1F83), (0x1F04, 0x345, 0x1F84), (0x1F05, 0x345, 0x1F85),
+    (0x1F06, 0x345, 0x1F86), (0x1F07, 0x345, 0x1F87), (0x1F08, 0x300, 0x1F0A), (0x1F08, 0x301, 0x1F0C),
+    (0x1F08, 0x342, 0x1F0E), (0x1F08, 0x345, 0x1F88), (0x1F09, 0x300, 0x1F0B), (0x1F09, 0x301, 0x1F0D),
+    (0x1F09, 0x342, 0x1F0F), (0x1F09, 0x345, 0x1F89), (0x1F0A, 0x345, 0x1F8A), (0x1F0B, 0x345, 0x1F8B),
+    (0x1F0C, 0x345, 0x1F8C), (0x1F0D, 0x345, 0x1F8D), (0x1F0E, 0x345, 0x1F8E), (0x1F0F, 0x345, 0x1F8F),
+    (0x1F10, 0x300, 0x1F12), (0x1F10, 0x301, 0x1F14), (0x1F11, 0x300, 0x1F13), (0x1F11, 0x301, 0x1F15),
+    (0x1F18, 0x300, 0x1F1A), (0x1F18, 0x301, 0x1F1C), (0x1F19, 0x300, 0x1F1B), (0x1F19, 0x301, 0x1F1D),
+    (0x1F20, 0x300, 0x1F22), (0x1F20, 0x301, 0x1F24), (0x1F20, 0x342, 0x1F26), (0x1F20, 0x345, 0x1F90),
+    (0x1F21, 0x300, 0x1F23), (0x1F21, 0x301, 0x1F25), (0x1F21, 0x342, 0x1F27), (0x1F21, 0x345, 0x1F91),
+    (0x1F22, 0x345, 0x1F92), (0x1F23, 0x345, 0x1F93), (0x1F24, 0x345, 0x1F94), (0x1F25, 0x345, 0x1F95),
+    (0x1F26, 0x345, 0x1F96), (0x1F27, 0x345, 0x1F97), (0x1F28, 0x300, 0x1F2A), (0x1F28, 0x301, 0x1F2C),
+    (0x1F28, 0x342, 0x1F2E), (0x1F28, 0x345, 0x1F98), (0x1F29, 0x300, 0x1F2B), (0x1F29, 0x301, 0x1F2D),
+    (0x1F29, 0x342, 0x1F2F), (0x1F29, 0x345, 0x1F99), (0x1F2A, 0x345, 0x1F9A), (0x1F2B, 0x345, 0x1F9B),
+    (0x1F2C, 0x345, 0x1F9C), (0x1F2D, 0x345, 0x1F9D), (0x1F2E, 0x345, 0x1F9E), (0x1F2F, 0x345, 0x1F9F),
+    (0x1F30, 0x300, 0x1F32), (0x1F30, 0x301, 0x1F34), (0x1F30, 0x342, 0x1F36), (0x1F31, 0x300, 0x1F33),
+    (0x1F31, 0x301, 0x1F35), (0x1F31, 0x342, 0x1F37), (0x1F38, 0x300, 0x1F3A), (0x1F38, 0x301, 0x1F3C),
+    (0x1F38, 0x342, 0x1F3E), (0x1F39, 0x300, 0x1F3B), (0x1F39, 0x301, 0x1F3D), (0x1F39, 0x342, 0x1F3F),
+    (0x1F40, 0x300, 0x1F42), (0x1F40, 0x301, 0x1F44), (0x1F41, 0x300, 0x1F43), (0x1F41, 0x301, 0x1F45),
+    (0x1F48, 0x300, 0x1F4A), (0x1F48, 0x301, 0x1F4C), (0x1F49, 0x300, 0x1F4B), (0x1F49, 0x301, 0x1F4D),
+    (0x1F50, 0x300, 0x1F52), (0x1F50, 0x301, 0x1F54), (0x1F50, 0x342, 0x1F56), (0x1F51, 0x300, 0x1F53),
+    (0x1F51, 0x301, 0x1F55), (0x1F51, 0x342, 0x1F57), (0x1F59, 0x300, 0x1F5B), (0x1F59, 0x301, 0x1F5D),
+    (0x1F59, 0x342, 0x1F5F), (0x1F60, 0x300, 0x1F62), (0x1F60, 0x301, 0x1F64), (0x1F60, 0x342, 0x1F66),
+    (0x1F60, 0x345, 0x1FA0), (0x1F61, 0x300, 0x1F63), (0x1F61, 0x301, 0x1F65), (0x1F61, 0x342, 0x1F67),
+    (0x1F61, 0x345, 0x1FA1), (0x1F62, 0x345, 0x1FA2), (0x1F63, 0x345, 0x1FA3), (0x1F64, 0x345, 0x1FA4),
+    (0x1F65, 0x345, 0x1FA5), (0x1F66, 0x345, 0x1FA6), (0x1F67, 0x345, 0x1FA7), (0x1F68, 0x300, 0x1F6A),
+    (0x1F68, 0x301, 0x1F6C), (0x1F68, 0x342, 0x1F6E), (0x1F68, 0x345, 0x1FA8), (0x1F69, 0x300, 0x1F6B),
+    (0x1F69, 0x301, 0x1F6D), (0x1F69, 0x342, 0x1F6F), (0x1F69, 0x345, 0x1FA9), (0x1F6A, 0x345, 0x1FAA),
+    (0x1F6B, 0x345, 0x1FAB), (0x1F6C, 0x345, 0x1FAC), (0x1F6D, 0x345, 0x1FAD), (0x1F6E, 0x345, 0x1FAE),
+    (0x1F6F, 0x345, 0x1FAF), (0x1F70, 0x345, 0x1FB2), (0x1F74, 0x345, 0x1FC2), (0x1F7C, 0x345, 0x1FF2),
+    (0x1FB6, 0x345, 0x1FB7), (0x1FBF, 0x300, 0x1FCD), (0x1FBF, 0x301, 0x1FCE), (0x1FBF, 0x342, 0x1FCF),
+    (0x1FC6, 0x345, 0x1FC7), (0x1FF6, 0x345, 0x1FF7), (0x1FFE, 0x300, 0x1FDD), (0x1FFE, 0x301, 0x1FDE),
+    (0x1FFE, 0x342, 0x1FDF), (0x2190, 0x338, 0x219A), (0x2192, 0x338, 0x219B), (0x2194, 0x338, 0x21AE),
+    (0x21D0, 0x338, 0x21CD), (0x21D2, 0x338, 0x21CF), (0x21D4, 0x338, 0x21CE), (0x2203, 0x338, 0x2204),
+    (0x2208, 0x338, 0x2209), (0x220B, 0x338, 0x220C), (0x2223, 0x338, 0x2224), (0x2225, 0x338, 0x2226),
+    (0x223C, 0x338, 0x2241), (0x2243, 0x338, 0x2244), (0x2245, 0x338, 0x2247), (0x2248, 0x338, 0x2249),
+    (0x224D, 0x338, 0x226D), (0x2261, 0x338, 0x2262), (0x2264, 0x338, 0x2270), (0x2265, 0x338, 0x2271),
+    (0x2272, 0x338, 0x2274), (0x2273, 0x338, 0x2275), (0x2276, 0x338, 0x2278), (0x2277, 0x338, 0x2279),
+    (0x227A, 0x338, 0x2280), (0x227B, 0x338, 0x2281), (0x227C, 0x338, 0x22E0), (0x227D, 0x338, 0x22E1),
+    (0x2282, 0x338, 0x2284), (0x2283, 0x338, 0x2285), (0x2286, 0x338, 0x2288), (0x2287, 0x338, 0x2289),
+    (0x2291, 0x338, 0x22E2), (0x2292, 0x338, 0x22E3), (0x22A2, 0x338, 0x22AC), (0x22A8, 0x338, 0x22AD),
+    (0x22A9, 0x338, 0x22AE), (0x22AB, 0x338, 0x22AF), (0x22B2, 0x338, 0x22EA), (0x22B3, 0x338, 0x22EB),
+    (0x22B4, 0x338, 0x22EC), (0x22B5, 0x338, 0x22ED), (0x3046, 0x3099, 0x3094), (0x304B, 0x3099, 0x304C),
+    (0x304D, 0x3099, 0x304E), (0x304F, 0x3099, 0x3050), (0x3051, 0x3099, 0x3052), (0x3053, 0x3099, 0x3054),
+    (0x3055, 0x3099, 0x3056), (0x3057, 0x3099, 0x3058), (0x3059, 0x3099, 0x305A), (0x305B, 0x3099, 0x305C),
+    (0x305D, 0x3099, 0x305E), (0x305F, 0x3099, 0x3060), (0x3061, 0x3099, 0x3062), (0x3064, 0x3099, 0x3065),
+    (0x3066, 0x3099, 0x3067), (0x3068, 0x3099, 0x3069), (0x306F, 0x3099, 0x3070), (0x306F, 0x309A, 0x3071),
+    (0x3072, 0x3099, 0x3073), (0x3072, 0x309A, 0x3074), (0x3075, 0x3099, 0x3076), (0x3075, 0x309A, 0x3077),
+    (0x3078, 0x3099, 0x3079), (0x3078, 0x309A, 0x307A), (0x307B, 0x3099, 0x307C), (0x307B, 0x309A, 0x307D),
+    (0x309D, 0x3099, 0x309E), (0x30A6, 0x3099, 0x30F4), (0x30AB, 0x3099, 0x30AC), (0x30AD, 0x3099, 0x30AE),
+    (0x30AF, 0x3099, 0x30B0), (0x30B1, 0x3099, 0x30B2), (0x30B3, 0x3099, 0x30B4), (0x30B5, 0x3099, 0x30B6),
+    (0x30B7, 0x3099, 0x30B8), (0x30B9, 0x3099, 0x30BA), (0x30BB, 0x3099, 0x30BC), (0x30BD, 0x3099, 0x30BE),
+    (0x30BF, 0x3099, 0x30C0), (0x30C1, 0x3099, 0x30C2), (0x30C4, 0x3099, 0x30C5), (0x30C6, 0x3099, 0x30C7),
+    (0x30C8, 0x3099, 0x30C9), (0x30CF, 0x3099, 0x30D0), (0x30CF, 0x309A, 0x30D1), (0x30D2, 0x3099, 0x30D3),
+    (0x30D2, 0x309A, 0x30D4), (0x30D5, 0x3099, 0x30D6), (0x30D5, 0x309A, 0x30D7), (0x30D8, 0x3099, 0x30D9),
+    (0x30D8, 0x309A, 0x30DA), (0x30DB, 0x3099, 0x30DC), (0x30DB, 0x309A, 0x30DD), (0x30EF, 0x3099, 0x30F7),
+    (0x30F0, 0x3099, 0x30F8), (0x30F1, 0x3099, 0x30F9), (0x30F2, 0x3099, 0x30FA), (0x30FD, 0x3099, 0x30FE),
+    (0x11099, 0x110BA, 0x1109A), (0x1109B, 0x110BA, 0x1109C), (0x110A5, 0x110BA, 0x110AB), (0x11131, 0x11127, 0x1112E),
+    (0x11132, 0x11127, 0x1112F), (0x11347, 0x1133E, 0x1134B), (0x11347, 0x11357, 0x1134C), (0x114B9, 0x114B0, 0x114BC),
+    (0x114B9, 0x114BA, 0x114BB), (0x114B9, 0x114BD, 0x114BE), (0x115B8, 0x115AF, 0x115BA), (0x115B9, 0x115AF, 0x115BB),
+    (0x11935, 0x11930, 0x11938),
+];
+
+/// The marks, of general category Mn, Mc or Me
+pub(super) const MARK: &[(u32, u32)] = &[
+    (0x300, 0x36F), (0x483, 0x489), (0x591, 0x5BD), (0x5BF, 0x5BF), (0x5C1, 0x5C2), (0x5C4, 0x5C5),
+    (0x5C7, 0x5C7), (0x610, 0x61A), (0x64B, 0x65F), (0x670, 0x670), (0x6D6, 0x6DC), (0x6DF, 0x6E4),
+    (0x6E7, 0x6E8), (0x6EA, 0x6ED), (0x711, 0x711), (0x730, 0x74A), (0x7A6, 0x7B0), (0x7EB, 0x7F3),
+    (0x7FD, 0x7FD), (0x816, 0x819), (0x81B, 0x823), (0x825, 0x827), (0x829, 0x82D), (0x859, 0x85B),
+    (0x898, 0x89F), (0x8CA, 0x8E1), (0x8E3, 0x903), (0x93A, 0x93C), (0x93E, 0x94F), (0x951, 0x957),
+    (0x962, 0x963), (0x981, 0x983), (0x9BC, 0x9BC), (0x9BE, 0x9C4), (0x9C7, 0x9C8), (0x9CB, 0x9CD),
+    (0x9D7, 0x9D7), (0x9E2, 0x9E3), (0x9FE, 0x9FE), (0xA01, 0xA03), (0xA3C, 0xA3C), (0xA3E, 0xA42),
+    (0xA47, 0xA48), (0xA4B, 0xA4D), (0xA51, 0xA51), (0xA70, 0xA71), (0xA75, 0xA75), (0xA81, 0xA83),
+    (0xABC, 0xABC), (0xABE, 0xAC5), (0xAC7, 0xAC9), (0xACB, 0xACD), (0xAE2, 0xAE3), (0xAFA, 0xAFF),
+    (0xB01, 0xB03), (0xB3C, 0xB3C), (0xB3E, 0xB44), (0xB47, 0xB48), (0xB4B, 0xB4D), (0xB55, 0xB57),
+    (0xB62, 0xB63), (0xB82, 0xB82), (0xBBE, 0xBC2), (0xBC6, 0xBC8), (0xBCA, 0xBCD), (0xBD7, 0xBD7),
+    (0xC00, 0xC04), (0xC3C, 0xC3C), (0xC3E, 0xC44), (0xC46, 0xC48), (0xC4A, 0xC4D), (0xC55, 0xC56),
+    (0xC62, 0xC63), (0xC81, 0xC83), (0xCBC, 0xCBC), (0xCBE, 0xCC4), (0xCC6, 0xCC8), (0xCCA, 0xCCD),
+    (0xCD5, 0xCD6), (0xCE2, 0xCE3), (0xD00, 0xD03), (0xD3B, 0xD3C), (0xD3E, 0xD44), (0xD46, 0xD48),
+    (0xD4A, 0xD4D), (0xD57, 0xD57), (0xD62, 0xD63), (0xD81, 0xD83), (0xDCA, 0xDCA), (0xDCF, 0xDD4),
+    (0xDD6, 0xDD6), (0xDD8, 0xDDF), (0xDF2, 0xDF3), (0xE31, 0xE31), (0xE34, 0xE3A), (0xE47, 0xE4E),
+    (0xEB1, 0xEB1), (0xEB4, 0xEBC), (0xEC8, 0xECD), (0xF18, 0xF19), (0xF35, 0xF35), (0xF37, 0xF37),
+    (0xF39, 0xF39), (0xF3E, 0xF3F), (0xF71, 0xF84), (0xF86, 0xF87), (0xF8D, 0xF97), (0xF99, 0xFBC),
+    (0xFC6, 0xFC6), (0x102B, 0x103E), (0x1056, 0x1059), (0x105E, 0x1060), (0x1062, 0x1064), (0x1067, 0x106D),
+    (0x1071, 0x1074), (0x1082, 0x108D), (0x108F, 0x108F), (0x109A, 0x109D), (0x135D, 0x135F), (0x1712, 0x1715),
+    (0x1732, 0x1734), (0x1752, 0x1753), (0x1772, 0x1773), (0x17B4, 0x17D3), (0x17DD, 0x17DD), (0x180B, 0x180D),
+    (0x180F, 0x180F), (0x1885, 0x1886), (0x18A9, 0x18A9), (0x1920, 0x192B), (0x1930, 0x193B), (0x1A17, 0x1A1B),
+    (0x1A55, 0x1A5E), (0x1A60, 0x1A7C), (0x1A7F, 0x1A7F), (0x1AB0, 0x1ACE), (0x1B00, 0x1B04), (0x1B34, 0x1B44),
+    (0x1B6B, 0x1B73), (0x1B80, 0x1B82), (0x1BA1, 0x1BAD), (0x1BE6, 0x1BF3), (0x1C24, 0x1C37), (0x1CD0, 0x1CD2),
+    (0x1CD4, 0x1CE8), (0x1CED, 0x1CED), (0x1CF4, 0x1CF4), (0x1CF7, 0x1CF9), (0x1DC0, 0x1DFF), (0x20D0, 0x20F0),
+    (0x2CEF, 0x2CF1), (0x2D7F, 0x2D7F), (0x2DE0, 0x2DFF), (0x302A, 0x302F), (0x3099, 0x309A), (0xA66F, 0xA672),
+    (0xA674, 0xA67D), (0xA69E, 0xA69F), (0xA6F0, 0xA6F1), (0xA802, 0xA802), (0xA806, 0xA806), (0xA80B, 0xA80B),
+    (0xA823, 0xA827), (0xA82C, 0xA82C), (0xA880, 0xA881), (0xA8B4, 0xA8C5), (0xA8E0, 0xA8F1), (0xA8FF, 0xA8FF),
+    (0xA926, 0xA92D), (0xA947, 0xA953), (0xA980, 0xA983), (0xA9B3, 0xA9C0), (0xA9E5, 0xA9E5), (0xAA29, 0xAA36),
+    (0xAA43, 0xAA43), (0xAA4C, 0xAA4D), (0xAA7B, 0xAA7D), (0xAAB0, 0xAAB0), (0xAAB2, 0xAAB4), (0xAAB7, 0xAAB8),
+    (0xAABE, 0xAABF), (0xAAC1, 0xAAC1), (0xAAEB, 0xAAEF), (0xAAF5, 0xAAF6), (0xABE3, 0xABEA), (0xABEC, 0xABED),
+    (0xFB1E, 0xFB1E), (0xFE00, 0xFE0F), (0xFE20, 0xFE2F), (0x101FD, 0x101FD), (0x102E0, 0x102E0), (0x10376, 0x1037A),
+    (0x10A01, 0x10A03), (0x10A05, 0x10A06), (0x10A0C, 0x10A0F), (0x10A38, 0x10A3A), (0x10A3F, 0x10A3F), (0x10AE5, 0x10AE6),
+    (0x10D24, 0x10D27), (0x10EAB, 0x10EAC), (0x10F46, 0x10F50), (0x10F82, 0x10F85), (0x11000, 0x11002), (0x11038, 0x11046),
+    (0x11070, 0x11070), (0x11073, 0x11074), (0x1107F, 0x11082), (0x110B0, 0x110BA), (0x110C2, 0x110C2), (0x11100, 0x11102),
+    (0x11127, 0x11134), (0x11145, 0x11146), (0x11173, 0x11173), (0x11180, 0x11182), (0x111B3, 0x111C0), (0x111C9, 0x111CC),
+    (0x111CE, 0x111CF), (0x1122C, 0x11237), (0x1123E, 0x1123E), (0x112DF, 0x112EA), (0x11300, 0x11303), (0x1133B, 0x1133C),
+    (0x1133E, 0x11344), (0x11347, 0x11348), (0x1134B, 0x1134D), (0x11357, 0x11357), (0x11362, 0x11363), (0x11366, 0x1136C),
+    (0x11370, 0x11374), (0x11435, 0x11446), (0x1145E, 0x1145E), (0x114B0, 0x114C3), (0x115AF, 0x115B5), (0x115B8, 0x115C0),
+    (0x115DC, 0x115DD), (0x11630, 0x11640), (0x116AB, 0x116B7), (0x1171D, 0x1172B), (0x1182C, 0x1183A), (0x11930, 0x11935),
+    (0x11937, 0x11938), (0x1193B, 0x1193E), (0x11940, 0x11940), (0x11942, 0x11943), (0x119D1, 0x119D7), (0x119DA, 0x119E0),
+    (0x119E4, 0x119E4), (0x11A01, 0x11A0A), (0x11A33, 0x11A39), (0x11A3B, 0x11A3E), (0x11A47, 0x11A47), (0x11A51, 0x11A5B),
+    (0x11A8A, 0x11A99), (0x11C2F, 0x11C36), (0x11C38, 0x11C3F), (0x11C92, 0x11CA7), (0x11CA9, 0x11CB6), (0x11D31, 0x11D36),
+    (0x11D3A, 0x11D3A), (0x11D3C, 0x11D3D), (0x11D3F, 0x11D45), (0x11D47, 0x11D47), (0x11D8A, 0x11D8E), (0x11D90, 0x11D91),
+    (0x11D93, 0x11D97), (0x11EF3, 0x11EF6), (0x16AF0, 0x16AF4), (0x16B30, 0x16B36), (0x16F4F, 0x16F4F), (0x16F51, 0x16F87),
+    (0x16F8F, 0x16F92), (0x16FE4, 0x16FE4), (0x16FF0, 0x16FF1), (0x1BC9D, 0x1BC9E), (0x1CF00, 0x1CF2D), (0x1CF30, 0x1CF46),
+    (0x1D165, 0x1D169), (0x1D16D, 0x1D172), (0x1D17B, 0x1D182), (0x1D185, 0x1D18B), (0x1D1AA, 0x1D1AD), (0x1D242, 0x1D244),
+    (0x1DA00, 0x1DA36), (0x1DA3B, 0x1DA6C), (0x1DA75, 0x1DA75), (0x1DA84, 0x1DA84), (0x1DA9B, 0x1DA9F), (0x1DAA1, 0x1DAAF),
+    (0x1E000, 0x1E006), (0x1E008, 0x1E018), (0x1E01B, 0x1E021), (0x1E023, 0x1E024), (0x1E026, 0x1E02A), (0x1E130, 0x1E136),
+    (0x1E2AE, 0x1E2AE), (0x1E2EC, 0x1E2EF), (0x1E8D0, 0x1E8D6), (0x1E944, 0x1E94A), (0xE0100, 0xE01EF),
+];
+
+/// The Bidi classes that are not L
+pub(super) const BIDI_CLASS: &[(u32, u32, BidiClass)] = &[
+    (0x0, 0x8, B::BN), (0x9, 0xD, B::Other), (0xE, 0x1B, B::BN), (0x1C, 0x20, B::Other),
+    (0x21, 0x22, B::ON), (0x23, 0x25, B::ET), (0x26, 0x2A, B::ON), (0x2B, 0x2B, B::ES),
+    (0x2C, 0x2C, B::CS), (0x2D, 0x2D, B::ES), (0x2E, 0x2F, B::CS), (0x30, 0x39, B::EN),
+    (0x3A, 0x3A, B::CS), (0x3B, 0x40, B::ON), (0x5B, 0x60, B::ON), (0x7B, 0x7E, B::ON),
+    (0x7F, 0x84, B::BN), (0x85, 0x85, B::Other), (0x86, 0x9F, B::BN), (0xA0, 0xA0, B::CS),
+    (0xA1, 0xA1, B::ON), (0xA2, 0xA5, B::ET), (0xA6, 0xA9, B::ON), (0xAB, 0xAC, B::ON),
+    (0xAD, 0xAD, B::BN), (0xAE, 0xAF, B::ON), (0xB0, 0xB1, B::ET), (0xB2, 0xB3, B::EN),
+    (0xB4, 0xB4, B::ON), (0xB6, 0xB8, B::ON), (0xB9, 0xB9, B::EN), (0xBB, 0xBF, B::ON),
+    (0xD7, 0xD7, B::ON), (0xF7, 0xF7, B::ON), (0x2B9, 0x2BA, B::ON), (0x2C2, 0x2CF, B::ON),
+    (0x2D2, 0x2DF, B::ON), (0x2E5, 0x2ED, B::ON), (0x2EF, 0x2FF, B::ON), (0x300, 0x36F, B::NSM),
+    (0x374, 0x375, B::ON), (0x37E, 0x37E, B::ON), (0x384, 0x385, B::ON), (0x387, 0x387, B::ON),
+    (0x3F6, 0x3F6, B::ON), (0x483, 0x489, B::NSM), (0x58A, 0x58A, B::ON), (0x58D, 0x58E, B::ON),
+    (0x58F, 0x58F, B::ET), (0x591, 0x5BD, B::NSM), (0x5BE, 0x5BE, B::R), (0x5BF, 0x5BF, B::NSM),
+    (0x5C0, 0x5C0, B::R), (0x5C1, 0x5C2, B::NSM), (0x5C3, 0x5C3, B::R), (0x5C4, 0x5C5, B::NSM),
+    (0x5C6, 0x5C6, B::R), (0x5C7, 0x5C7, B::NSM), (0x5D0, 0x5EA, B::R), (0x5EF, 0x5F4, B::R),
+    (0x600, 0x605, B::AN), (0x606, 0x607, B::ON), (0x608, 0x608, B::AL), (0x609, 0x60A, B::ET),
+    (0x60B, 0x60B, B::AL), (0x60C, 0x60C, B::CS), (0x60D, 0x60D, B::AL), (0x60E, 0x60F, B::ON),
+    (0x610, 0x61A, B::NSM), (0x61B, 0x64A, B::AL), (0x64B, 0x65F, B::NSM), (0x660, 0x669, B::AN),
+    (0x66A, 0x66A, B::ET), (0x66B, 0x66C, B::AN), (0x66D, 0x66F, B::AL), (0x670, 0x670, B::NSM),
+    (0x671, 0x6D5, B::AL), (0x6D6, 0x6DC, B::NSM), (0x6DD, 0x6DD, B::AN), (0x6DE, 0x6DE, B::ON),
+    (0x6DF, 0x6E4, B::NSM), (0x6E5, 0x6E6, B::AL), (0x6E7, 0x6E8, B::NSM), (0x6E9, 0x6E9, B::ON),
+    (0x6EA, 0x6ED, B::NSM), (0x6EE, 0x6EF, B::AL), (0x6F0, 0x6F9, B::EN), (0x6FA, 0x70D, B::AL),
+    (0x70F, 0x710, B::AL), (0x711, 0x711, B::NSM), (0x712, 0x72F, B::AL), (0x730, 0x74A, B::NSM),
+    (0x74D, 0x7A5, B::AL), (0x7A6, 0x7B0, B::NSM), (0x7B1, 0x7B1, B::AL), (0x7C0, 0x7EA, B::R),
+    (0x7EB, 0x7F3, B::NSM), (0x7F4, 0x7F5, B::R), (0x7F6, 0x7F9, B::ON), (0x7FA, 0x7FA, B::R),
+    (0x7FD, 0x7FD, B::NSM), (0x7FE, 0x815, B::R), (0x816, 0x819, B::NSM), (0x81A, 0x81A, B::R),
+    (0x81B, 0x823, B::NSM), (0x824, 0x824, B::R), (0x825, 0x827, B::NSM), (0x828, 0x828, B::R),
+    (0x829, 0x82D, B::NSM), (0x830, 0x83E, B::R), (0x840, 0x858, B::R), (0x859, 0x85B, B::NSM),
+    (0x85E, 0x85E, B::R), (0x860, 0x86A, B::AL), (0x870, 0x88E, B::AL), (0x890, 0x891, B::AN),
+    (0x898, 0x89F, B::NSM), (0x8A0, 0x8C9, B::AL), (0x8CA, 0x8E1, B::NSM), (0x8E2, 0x8E2, B::AN),
+    (0x8E3, 0x902, B::NSM), (0x93A, 0x93A, B::NSM), (0x93C, 0x93C, B::NSM), (0x941, 0x948, B::NSM),
+    (0x94D, 0x94D, B::NSM), (0x951, 0x957, B::NSM), (0x962, 0x963, B::NSM), (0x981, 0x981, B::NSM),
+    (0x9BC, 0x9BC, B::NSM), (0x9C1, 0x9C4, B::NSM), (0x9CD, 0x9CD, B::NSM), (0x9E2, 0x9E3, B::NSM),
+    (0x9F2, 0x9F3, B::ET), (0x9FB, 0x9FB, B::ET), (0x9FE, 0x9FE, B::NSM), (0xA01, 0xA02, B::NSM),
+    (0xA3C, 0xA3C, B::NSM), (0xA41, 0xA42, B::NSM), (0xA47, 0xA48, B::NSM), (0xA4B, 0xA4D, B::NSM),
+    (0xA51, 0xA51, B::NSM), (0xA70, 0xA71, B::NSM), (0xA75, 0xA75, B::NSM), (0xA81, 0xA82, B::NSM),
+    (0xABC, 0xABC, B::NSM), (0xAC1, 0xAC5, B::NSM), (0xAC7, 0xAC8, B::NSM), (0xACD, 0xACD, B::NSM),
+    (0xAE2, 0xAE3, B::NSM), (0xAF1, 0xAF1, B::ET), (0xAFA, 0xAFF, B::NSM), (0xB01, 0xB01, B::NSM),
+    (0xB3C, 0xB3C, B::NSM), (0xB3F, 0xB3F, B::NSM), (0xB41, 0xB44, B::NSM), (0xB4D, 0xB4D, B::NSM),
+    (0xB55, 0xB56, B::NSM), (0xB62, 0xB63, B::NSM), (0xB82, 0xB82, B::NSM), (0xBC0, 0xBC0, B::NSM),
+    (0xBCD, 0xBCD, B::NSM), (0xBF3, 0xBF8, B::ON), (0xBF9, 0xBF9, B::ET), (0xBFA, 0xBFA, B::ON),
+    (0xC00, 0xC00, B::NSM), (0xC04, 0xC04, B::NSM), (0xC3C, 0xC3C, B::NSM), (0xC3E, 0xC40, B::NSM),
+    (0xC46, 0xC48, B::NSM), (0xC4A, 0xC4D, B::NSM), (0xC55, 0xC56, B::NSM), (0xC62, 0xC63, B::NSM),
+    (0xC78, 0xC7E, B::ON), (0xC81, 0xC81, B::NSM), (0xCBC, 0xCBC, B::NSM), (0xCCC, 0xCCD, B::NSM),
+    (0xCE2, 0xCE3, B::NSM), (0xD00, 0xD01, B::NSM), (0xD3B, 0xD3C, B::NSM), (0xD41, 0xD44, B::NSM),
+    (0xD4D, 0xD4D, B::NSM), (0xD62, 0xD63, B::NSM), (0xD81, 0xD81, B::NSM), (0xDCA, 0xDCA, B::NSM),
+    (0xDD2, 0xDD4, B::NSM), (0xDD6, 0xDD6, B::NSM), (0xE31, 0xE31, B::NSM), (0xE34, 0xE3A, B::NSM),
+    (0xE3F, 0xE3F, B::ET), (0xE47, 0xE4E, B::NSM), (0xEB1, 0xEB1, B::NSM), (0xEB4, 0xEBC, B::NSM),
+    (0xEC8, 0xECD, B::NSM), (0xF18, 0xF19, B::NSM), (0xF35, 0xF35, B::NSM), (0xF37, 0xF37, B::NSM),
+    (0xF39, 0xF39, B::NSM), (0xF3A, 0xF3D, B::ON), (0xF71, 0xF7E, B::NSM), (0xF80, 0xF84, B::NSM),
+    (0xF86, 0xF87, B::NSM), (0xF8D, 0xF97, B::NSM), (0xF99, 0xFBC, B::NSM), (0xFC6, 0xFC6, B::NSM),
+    (0x102D, 0x1030, B::NSM), (0x1032, 0x1037, B::NSM), (0x1039, 0x103A, B::NSM), (0x103D, 0x103E, B::NSM),
+    (0x1058, 0x1059, B::NSM), (0x105E, 0x1060, B::NSM), (0x1071, 0x1074, B::NSM), (0x1082, 0x1082, B::NSM),
+    (0x1085, 0x1086, B::NSM), (0x108D, 0x108D, B::NSM), (0x109D, 0x109D, B::NSM), (0x135D, 0x135F, B::NSM),
+    (0x1390, 0x1399, B::ON), (0x1400, 0x1400, B::ON), (0x1680, 0x1680, B::Other), (0x169B, 0x169C, B::ON),
+    (0x1712, 0x1714, B::NSM), (0x1732, 0x1733, B::NSM), (0x1752, 0x1753, B::NSM), (0x1772, 0x1773, B::NSM),
+    (0x17B4, 0x17B5, B::NSM), (0x17B7, 0x17BD, B::NSM), (0x17C6, 0x17C6, B::NSM), (0x17C9, 0x17D3, B::NSM),
+    (0x17DB, 0x17DB, B::ET), (0x17DD, 0x17DD, B::NSM), (0x17F0, 0x17F9, B::ON), (0x1800, 0x180A, B::ON),
+    (0x180B, 0x180D, B::NSM), (0x180E, 0x180E, B::BN), (0x180F, 0x180F, B::NSM), (0x1885, 0x1886, B::NSM),
+    (0x18A9, 0x18A9, B::NSM), (0x1920, 0x1922, B::NSM), (0x1927, 0x1928, B::NSM), (0x1932, 0x1932, B::NSM),
+    (0x1939, 0x193B, B::NSM), (0x1940, 0x1940, B::ON), (0x1944, 0x1945, B::ON), (0x19DE, 0x19FF, B::ON),
+    (0x1A17, 0x1A18, B::NSM), (0x1A1B, 0x1A1B, B::NSM), (0x1A56, 0x1A56, B::NSM), (0x1A58, 0x1A5E, B::NSM),
+    (0x1A60, 0x1A60, B::NSM), (0x1A62, 0x1A62, B::NSM), (0x1A65, 0x1A6C, B::NSM), (0x1A73, 0x1A7C, B::NSM),
+    (0x1A7F, 0x1A7F, B::NSM), (0x1AB0, 0x1ACE, B::NSM), (0x1B00, 0x1B03, B::NSM), (0x1B34, 0x1B34, B::NSM),
+    (0x1B36, 0x1B3A, B::NSM), (0x1B3C, 0x1B3C, B::NSM), (0x1B42, 0x1B42, B::NSM), (0x1B6B, 0x1B73, B::NSM),
+    (0x1B80, 0x1B81, B::NSM), (0x1BA2, 0x1BA5, B::NSM), (0x1BA8, 0x1BA9, B::NSM), (0x1BAB, 0x1BAD, B::NSM),
+    (0x1BE6, 0x1BE6, B::NSM), (0x1BE8, 0x1BE9, B::NSM), (0x1BED, 0x1BED, B::NSM), (0x1BEF, 0x1BF1, B::NSM),
+    (0x1C2C, 0x1C33, B::NSM), (0x1C36, 0x1C37, B::NSM), (0x1CD0, 0x1CD2, B::NSM), (0x1CD4, 0x1CE0, B::NSM),
+    (0x1CE2, 0x1CE8, B::NSM), (0x1CED, 0x1CED, B::NSM), (0x1CF4, 0x1CF4, B::NSM), (0x1CF8, 0x1CF9, B::NSM),
+    (0x1DC0, 0x1DFF, B::NSM), (0x1FBD, 0x1FBD, B::ON), (0x1FBF, 0x1FC1, B::ON), (0x1FCD, 0x1FCF, B::ON),
+    (0x1FDD, 0x1FDF, B::ON), (0x1FED, 0x1FEF, B::ON), (0x1FFD, 0x1FFE, B::ON), (0x2000, 0x200A, B::Other),
+    (0x200B, 0x200D, B::BN), (0x200F, 0x200F, B::R), (0x2010, 0x2027, B::ON), (0x2028, 0x202E, B::Other),
+    (0x202F, 0x202F, B::CS), (0x2030, 0x2034, B::ET), (0x2035, 0x2043, B::ON), (0x2044, 0x2044, B::CS),
+    (0x2045, 0x205E, B::ON), (0x205F, 0x205F, B::Other), (0x2060, 0x2064, B::BN), (0x2066, 0x2069, B::Other),
+    (0x206A, 0x206F, B::BN), (0x2070, 0x2070, B::EN), (0x2074, 0x2079, B::EN), (0x207A, 0x207B, B::ES),
+    (0x207C, 0x207E, B::ON), (0x2080, 0x2089, B::EN), (0x208A, 0x208B, B::ES), (0x208C, 0x208E, B::ON),
+    (0x20A0, 0x20C0, B::ET), (0x20D0, 0x20F0, B::NSM), (0x2100, 0x2101, B::ON), (0x2103, 0x2106, B::ON),
+    (0x2108, 0x2109, B::ON), (0x2114, 0x2114, B::ON), (0x2116, 0x2118, B::ON), (0x211E, 0x2123, B::ON),
+    (0x2125, 0x2125, B::ON), (0x2127, 0x2127, B::ON), (0x2129, 0x2129, B::ON), (0x212E, 0x212E, B::ET),
+    (0x213A, 0x213B, B::ON), (0x2140, 0x2144, B::ON), (0x214A, 0x214D, B::ON), (0x2150, 0x215F, B::ON),
+    (0x2189, 0x218B, B::ON), (0x2190, 0x2211, B::ON), (0x2212, 0x2212, B::ES), (0x2213, 0x2213, B::ET),
+    (0x2214, 0x2335, B::ON), (0x237B, 0x2394, B::ON), (0x2396, 0x2426, B::ON), (0x2440, 0x244A, B::ON),
+    (0x2460, 0x2487, B::ON), (0x2488, 0x249B, B::EN), (0x24EA, 0x26AB, B::ON), (0x26AD, 0x27FF, B::ON),
+    (0x2900, 0x2B73, B::ON), (0x2B76, 0x2B95, B::ON), (0x2B97, 0x2BFF, B::ON), (0x2CE5, 0x2CEA, B::ON),
+    (0x2CEF, 0x2CF1, B::NSM), (0x2CF9, 0x2CFF, B::ON), (0x2D7F, 0x2D7F, B::NSM), (0x2DE0, 0x2DFF, B::NSM),
+    (0x2E00, 0x2E5D, B::ON), (0x2E80, 0x2E99, B::ON), (0x2E9B, 0x2EF3, B::ON), (0x2F00, 0x2FD5, B::ON),
+    (0x2FF0, 0x2FFB, B::ON), (0x3000, 0x3000, B::Other), (0x3001, 0x3004, B::ON), (0x3008, 0x3020, B::ON),
+    (0x302A, 0x302D, B::NSM), (0x3030, 0x3030, B::ON), (0x3036, 0x3037, B::ON), (0x303D, 0x303F, B::ON),
+    (0x3099, 0x309A, B::NSM), (0x309B, 0x309C, B::ON), (0x30A0, 0x30A0, B::ON), (0x30FB, 0x30FB, B::ON),
+    (0x31C0, 0x31E3, B::ON), (0x321D, 0x321E, B::ON), (0x3250, 0x325F, B::ON), (0x327C, 0x327E, B::ON),
+    (0x32B1, 0x32BF, B::ON), (0x32CC, 0x32CF, B::ON), (0x3377, 0x337A, B::ON), (0x33DE, 0x33DF, B::ON),
+    (0x33FF, 0x33FF, B::ON), (0x4DC0, 0x4DFF, B::ON), (0xA490, 0xA4C6, B::ON), (0xA60D, 0xA60F, B::ON),
+    (0xA66F, 0xA672, B::NSM), (0xA673, 0xA673, B::ON), (0xA674, 0xA67D, B::NSM), (0xA67E, 0xA67F, B::ON),
+    (0xA69E, 0xA69F, B::NSM), (0xA6F0, 0xA6F1, B::NSM), (0xA700, 0xA721, B::ON), (0xA788, 0xA788, B::ON),
+    (0xA802, 0xA802, B::NSM), (0xA806, 0xA806, B::NSM), (0xA80B, 0xA80B, B::NSM), (0xA825, 0xA826, B::NSM),
+    (0xA828, 0xA82B, B::ON), (0xA82C, 0xA82C, B::NSM), (0xA838, 0xA839, B::ET), (0xA874, 0xA877, B::ON),
+    (0xA8C4, 0xA8C5, B::NSM), (0xA8E0, 0xA8F1, B::NSM), (0xA8FF, 0xA8FF, B::NSM), (0xA926, 0xA92D, B::NSM),
+    (0xA947, 0xA951, B::NSM), (0xA980, 0xA982, B::NSM), (0xA9B3, 0xA9B3, B::NSM), (0xA9B6, 0xA9B9, B::NSM),
+    (0xA9BC, 0xA9BD, B::NSM), (0xA9E5, 0xA9E5, B::NSM), (0xAA29, 0xAA2E, B::NSM), (0xAA31, 0xAA32, B::NSM),
+    (0xAA35, 0xAA36, B::NSM), (0xAA43, 0xAA43, B::NSM), (0xAA4C, 0xAA4C, B::NSM), (0xAA7C, 0xAA7C, B::NSM),
+    (0xAAB0, 0xAAB0, B::NSM), (0xAAB2, 0xAAB4, B::NSM), (0xAAB7, 0xAAB8, B::NSM), (0xAABE, 0xAABF, B::NSM),
+    (0xAAC1, 0xAAC1, B::NSM), (0xAAEC, 0xAAED, B::NSM), (0xAAF6, 0xAAF6, B::NSM), (0xAB6A, 0xAB6B, B::ON),
+    (0xABE5, 0xABE5, B::NSM), (0xABE8, 0xABE8, B::NSM), (0xABED, 0xABED, B::NSM), (0xFB1D, 0xFB1D, B::R),
+    (0xFB1E, 0xFB1E, B::NSM), (0xFB1F, 0xFB28, B::R), (0xFB29, 0xFB29, B::ES), (0xFB2A, 0xFB36, B::R),
+    (0xFB38, 0xFB3C, B::R), (0xFB3E, 0xFB3E, B::R), (0xFB40, 0xFB41, B::R), (0xFB43, 0xFB44, B::R),
+    (0xFB46, 0xFB4F, B::R), (0xFB50, 0xFBC2, B::AL), (0xFBD3, 0xFD3D, B::AL), (0xFD3E, 0xFD4F, B::ON),
+    (0xFD50, 0xFD8F, B::AL), (0xFD92, 0xFDC7, B::AL), (0xFDCF, 0xFDCF, B::ON), (0xFDF0, 0xFDFC, B::AL),
+    (0xFDFD, 0xFDFF, B::ON), (0xFE00, 0xFE0F, B::NSM), (0xFE10, 0xFE19, B::ON), (0xFE20, 0xFE2F, B::NSM),
+    (0xFE30, 0xFE4F, B::ON), (0xFE50, 0xFE50, B::CS), (0xFE51, 0xFE51, B::ON), (0xFE52, 0xFE52, B::CS),
+    (0xFE54, 0xFE54, B::ON), (0xFE55, 0xFE55, B::CS), (0xFE56, 0xFE5E, B::ON), (0xFE5F, 0xFE5F, B::ET),
+    (0xFE60, 0xFE61, B::ON), (0xFE62, 0xFE63, B::ES), (0xFE64, 0xFE66, B::ON), (0xFE68, 0xFE68, B::ON),
+    (0xFE69, 0xFE6A, B::ET), (0xFE6B, 0xFE6B, B::ON), (0xFE70, 0xFE74, B::AL), (0xFE76, 0xFEFC, B::AL),
+    (0xFEFF, 0xFEFF, B::BN), (0xFF01, 0xFF02, B::ON), (0xFF03, 0xFF05, B::ET), (0xFF06, 0xFF0A, B::ON),
+    (0xFF0B, 0xFF0B, B::ES), (0xFF0C, 0xFF0C, B::CS), (0xFF0D, 0xFF0D, B::ES), (0xFF0E, 0xFF0F, B::CS),
+    (0xFF10, 0xFF19, B::EN), (0xFF1A, 0xFF1A, B::CS), (0xFF1B, 0xFF20, B::ON), (0xFF3B, 0xFF40, B::ON),
+    (0xFF5B, 0xFF65, B::ON), (0xFFE0, 0xFFE1, B::ET), (0xFFE2, 0xFFE4, B::ON), (0xFFE5, 0xFFE6, B::ET),
+    (0xFFE8, 0xFFEE, B::ON), (0xFFF9, 0xFFFD, B::ON), (0x10101, 0x10101, B::ON), (0x10140, 0x1018C, B::ON),
+    (0x10190, 0x1019C, B::ON), (0x101A0, 0x101A0, B::ON), (0x101FD, 0x101FD, B::NSM), (0x102E0, 0x102E0, B::NSM),
+    (0x102E1, 0x102FB, B::EN), (0x10376, 0x1037A, B::NSM), (0x10800, 0x10805, B::R), (0x10808, 0x10808, B::R),
+    (0x1080A, 0x10835, B::R), (0x10837, 0x10838, B::R), (0x1083C, 0x1083C, B::R), (0x1083F, 0x10855, B::R),
+    (0x10857, 0x1089E, B::R), (0x108A7, 0x108AF, B::R), (0x108E0, 0x108F2, B::R), (0x108F4, 0x108F5, B::R),
+    (0x108FB, 0x1091B, B::R), (0x1091F, 0x1091F, B::ON), (0x10920, 0x10939, B::R), (0x1093F, 0x1093F, B::R),
+    (0x10980, 0x109B7, B::R), (0x109BC, 0x109CF, B::R), (0x109D2, 0x10A00, B::R), (0x10A01, 0x10A03, B::NSM),
+    (0x10A05, 0x10A06, B::NSM), (0x10A0C, 0x10A0F, B::NSM), (0x10A10, 0x10A13, B::R), (0x10A15, 0x10A17, B::R),
+    (0x10A19, 0x10A35, B::R), (0x10A38, 0x10A3A, B::NSM), (0x10A3F, 0x10A3F, B::NSM), (0x10A40, 0x10A48, B::R),
+    (0x10A50, 0x10A58, B::R), (0x10A60, 0x10A9F, B::R), (0x10AC0, 0x10AE4, B::R), (0x10AE5, 0x10AE6, B::NSM),
+    (0x10AEB, 0x10AF6, B::R), (0x10B00, 0x10B35, B::R), (0x10B39, 0x10B3F, B::ON), (0x10B40, 0x10B55, B::R),
+    (0x10B58, 0x10B72, B::R), (0x10B78, 0x10B91, B::R), (0x10B99, 0x10B9C, B::R), (0x10BA9, 0x10BAF, B::R),
+    (0x10C00, 0x10C48, B::R), (0x10C80, 0x10CB2, B::R), (0x10CC0, 0x10CF2, B::R), (0x10CFA, 0x10CFF, B::R),
+    (0x10D00, 0x10D23, B::AL), (0x10D24, 0x10D27, B::NSM), (0x10D30, 0x10D39, B::AN), (0x10E60, 0x10E7E, B::AN),
+    (0x10E80, 0x10EA9, B::R), (0x10EAB, 0x10EAC, B::NSM), (0x10EAD, 0x10EAD, B::R), (0x10EB0, 0x10EB1, B::R),
+    (0x10F00, 0x10F27, B::R), (0x10F30, 0x10F45, B::AL), (0x10F46, 0x10F50, B::NSM), (0x10F51, 0x10F59, B::AL),
+    (0x10F70, 0x10F81, B::R), (0x10F82, 0x10F85, B::NSM), (0x10F86, 0x10F89, B::R), (0x10FB0, 0x10FCB, B::R),
+    (0x10FE0, 0x10FF6, B::R), (0x11001, 0x11001, B::NSM), (0x11038, 0x11046, B::NSM), (0x11052, 0x11065, B::ON),
+    (0x11070, 0x11070, B::NSM), (0x11073, 0x11074, B::NSM), (0x1107F, 0x11081, B::NSM), (0x110B3, 0x110B6, B::NSM),
+    (0x110B9, 0x110BA, B::NSM), (0x110C2, 0x110C2, B::NSM), (0x11100, 0x11102, B::NSM), (0x11127, 0x1112B, B::NSM),
+    (0x1112D, 0x11134, B::NSM), (0x11173, 0x11173, B::NSM), (0x11180, 0x11181, B::NSM), (0x111B6, 0x111BE, B::NSM),
+    (0x111C9, 0x111CC, B::NSM), (0x111CF, 0x111CF, B::NSM), (0x1122F, 0x11231, B::NSM), (0x11234, 0x11234, B::NSM),
+    (0x11236, 0x11237, B::NSM), (0x1123E, 0x1123E, B::NSM), (0x112DF, 0x112DF, B::NSM), (0x112E3, 0x112EA, B::NSM),
+    (0x11300, 0x11301, B::NSM), (0x1133B, 0x1133C, B::NSM), (0x11340, 0x11340, B::NSM), (0x11366, 0x1136C, B::NSM),
+    (0x11370, 0x11374, B::NSM), (0x11438, 0x1143F, B::NSM), (0x11442, 0x11444, B::NSM), (0x11446, 0x11446, B::NSM),
+    (0x1145E, 0x1145E, B::NSM), (0x114B3, 0x114B8, B::NSM), (0x114BA, 0x114BA, B::NSM), (0x114BF, 0x114C0, B::NSM),
+    (0x114C2, 0x114C3, B::NSM), (0x115B2, 0x115B5, B::NSM), (0x115BC, 0x115BD, B::NSM), (0x115BF, 0x115C0, B::NSM),
+    (0x115DC, 0x115DD, B::NSM), (0x11633, 0x1163A, B::NSM), (0x1163D, 0x1163D, B::NSM), (0x1163F, 0x11640, B::NSM),
+    (0x11660, 0x1166C, B::ON), (0x116AB, 0x116AB, B::NSM), (0x116AD, 0x116AD, B::NSM), (0x116B0, 0x116B5, B::NSM),
+    (0x116B7, 0x116B7, B::NSM), (0x1171D, 0x1171F, B::NSM), (0x11722, 0x11725, B::NSM), (0x11727, 0x1172B, B::NSM),
+    (0x1182F, 0x11837, B::NSM), (0x11839, 0x1183A, B::NSM), (0x1193B, 0x1193C, B::NSM), (0x1193E, 0x1193E, B::NSM),
+    (0x11943, 0x11943, B::NSM), (0x119D4, 0x119D7, B::NSM), (0x119DA, 0x119DB, B::NSM), (0x119E0, 0x119E0, B::NSM),
+    (0x11A01, 0x11A06, B::NSM), (0x11A09, 0x11A0A, B::NSM), (0x11A33, 0x11A38, B::NSM), (0x11A3B, 0x11A3E, B::NSM),
+    (0x11A47, 0x11A47, B::NSM), (0x11A51, 0x11A56, B::NSM), (0x11A59, 0x11A5B, B::NSM), (0x11A8A, 0x11A96, B::NSM),
+    (0x11A98, 0x11A99, B::NSM), (0x11C30, 0x11C36, B::NSM), (0x11C38, 0x11C3D, B::NSM), (0x11C92, 0x11CA7, B::NSM),
+    (0x11CAA, 0x11CB0, B::NSM), (0x11CB2, 0x11CB3, B::NSM), (0x11CB5, 0x11CB6, B::NSM), (0x11D31, 0x11D36, B::NSM),
+    (0x11D3A, 0x11D3A, B::NSM), (0x11D3C, 0x11D3D, B::NSM), (0x11D3F, 0x11D45, B::NSM), (0x11D47, 0x11D47, B::NSM),
+    (0x11D90, 0x11D91, B::NSM), (0x11D95, 0x11D95, B::NSM), (0x11D97, 0x11D97, B::NSM), (0x11EF3, 0x11EF4, B::NSM),
+    (0x11FD5, 0x11FDC, B::ON), (0x11FDD, 0x11FE0, B::ET), (0x11FE1, 0x11FF1, B::ON), (0x16AF0, 0x16AF4, B::NSM),
+    (0x16B30, 0x16B36, B::NSM), (0x16F4F, 0x16F4F, B::NSM), (0x16F8F, 0x16F92, B::NSM), (0x16FE2, 0x16FE2, B::ON),
+    (0x16FE4, 0x16FE4, B::NSM), (0x1BC9D, 0x1BC9E, B::NSM), (0x1BCA0, 0x1BCA3, B::BN), (0x1CF00, 0x1CF2D, B::NSM),
+    (0x1CF30, 0x1CF46, B::NSM), (0x1D167, 0x1D169, B::NSM), (0x1D173, 0x1D17A, B::BN), (0x1D17B, 0x1D182, B::NSM),
+    (0x1D185, 0x1D18B, B::NSM), (0x1D1AA, 0x1D1AD, B::NSM), (0x1D1E9, 0x1D1EA, B::ON), (0x1D200, 0x1D241, B::ON),
+    (0x1D242, 0x1D244, B::NSM), (0x1D245, 0x1D245, B::ON), (0x1D300, 0x1D356, B::ON), (0x1D6DB, 0x1D6DB, B::ON),
+    (0x1D715, 0x1D715, B::ON), (0x1D74F, 0x1D74F, B::ON), (0x1D789, 0x1D789, B::ON), (0x1D7C3, 0x1D7C3, B::ON),
+    (0x1D7CE, 0x1D7FF, B::EN), (0x1DA00, 0x1DA36, B::NSM), (0x1DA3B, 0x1DA6C, B::NSM), (0x1DA75, 0x1DA75, B::NSM),
+    (0x1DA84, 0x1DA84, B::NSM), (0x1DA9B, 0x1DA9F, B::NSM), (0x1DAA1, 0x1DAAF, B::NSM), (0x1E000, 0x1E006, B::NSM),
+    (0x1E008, 0x1E018, B::NSM), (0x1E01B, 0x1E021, B::NSM), (0x1E023, 0x1E024, B::NSM), (0x1E026, 0x1E02A, B::NSM),
+    (0x1E130, 0x1E136, B::NSM), (0x1E2AE, 0x1E2AE, B::NSM), (0x1E2EC, 0x1E2EF, B::NSM), (0x1E2FF, 0x1E2FF, B::ET),
+    (0x1E800, 0x1E8C4, B::R), (0x1E8C7, 0x1E8CF, B::R), (0x1E8D0, 0x1E8D6, B::NSM), (0x1E900, 0x1E943, B::R),
+    (0x1E944, 0x1E94A, B::NSM), (0x1E94B, 0x1E94B, B::R), (0x1E950, 0x1E959, B::R), (0x1E95E, 0x1E95F, B::R),
+    (0x1EC71, 0x1ECB4, B::AL), (0x1ED01, 0x1ED3D, B::AL), (0x1EE00, 0x1EE03, B::AL), (0x1EE05, 0x1EE1F, B::AL),
+    (0x1EE21, 0x1EE22, B::AL), (0x1EE24, 0x1EE24, B::AL), (0x1EE27, 0x1EE27, B::AL), (0x1EE29, 0x1EE32, B::AL),
+    (0x1EE34, 0x1EE37, B::AL), (0x1EE39, 0x1EE39, B::AL), (0x1EE3B, 0x1EE3B, B::AL), (0x1EE42, 0x1EE42, B::AL),
+    (0x1EE47, 0x1EE47, B::AL), (0x1EE49, 0x1EE49, B::AL), (0x1EE4B, 0x1EE4B, B::AL), (0x1EE4D, 0x1EE4F, B::AL),
+    (0x1EE51, 0x1EE52, B::AL), (0x1EE54, 0x1EE54, B::AL), (0x1EE57, 0x1EE57, B::AL), (0x1EE59, 0x1EE59, B::AL),
+    (0x1EE5B, 0x1EE5B, B::AL), (0x1EE5D, 0x1EE5D, B::AL), (0x1EE5F, 0x1EE5F, B::AL), (0x1EE61, 0x1EE62, B::AL),
+    (0x1EE64, 0x1EE64, B::AL), (0x1EE67, 0x1EE6A, B::AL), (0x1EE6C, 0x1EE72, B::AL), (0x1EE74, 0x1EE77, B::AL),
+    (0x1EE79, 0x1EE7C, B::AL), (0x1EE7E, 0x1EE7E, B::AL), (0x1EE80, 0x1EE89, B::AL), (0x1EE8B, 0x1EE9B, B::AL),
+    (0x1EEA1, 0x1EEA3, B::AL), (0x1EEA5, 0x1EEA9, B::AL), (0x1EEAB, 0x1EEBB, B::AL), (0x1EEF0, 0x1EEF1, B::ON),
+    (0x1F000, 0x1F02B, B::ON), (0x1F030, 0x1F093, B::ON), (0x1F0A0, 0x1F0AE, B::ON), (0x1F0B1, 0x1F0BF, B::ON),
+    (0x1F0C1, 0x1F0CF, B::ON), (0x1F0D1, 0x1F0F5, B::ON), (0x1F100, 0x1F10A, B::EN), (0x1F10B, 0x1F10F, B::ON),
+    (0x1F12F, 0x1F12F, B::ON), (0x1F16A, 0x1F16F, B::ON), (0x1F1AD, 0x1F1AD, B::ON), (0x1F260, 0x1F265, B::ON),
+    (0x1F300, 0x1F6D7, B::ON), (0x1F6DD, 0x1F6EC, B::ON), (0x1F6F0, 0x1F6FC, B::ON), (0x1F700, 0x1F773, B::ON),
+    (0x1F780, 0x1F7D8, B::ON), (0x1F7E0, 0x1F7EB, B::ON), (0x1F7F0, 0x1F7F0, B::ON), (0x1F800, 0x1F80B, B::ON),
+    (0x1F810, 0x1F847, B::ON), (0x1F850, 0x1F859, B::ON), (0x1F860, 0x1F887, B::ON), (0x1F890, 0x1F8AD, B::ON),
+    (0x1F8B0, 0x1F8B1, B::ON), (0x1F900, 0x1FA53, B::ON), (0x1FA60, 0x1FA6D, B::ON), (0x1FA70, 0x1FA74, B::ON),
+    (0x1FA78, 0x1FA7C, B::ON), (0x1FA80, 0x1FA86, B::ON), (0x1FA90, 0x1FAAC, B::ON), (0x1FAB0, 0x1FABA, B::ON),
+    (0x1FAC0, 0x1FAC5, B::ON), (0x1FAD0, 0x1FAD9, B::ON), (0x1FAE0, 0x1FAE7, B::ON), (0x1FAF0, 0x1FAF6, B::ON),
+    (0x1FB00, 0x1FB92, B::ON), (0x1FB94, 0x1FBCA, B::ON), (0x1FBF0, 0x1FBF9, B::EN), (0xE0001, 0xE0001, B::BN),
+    (0xE0020, 0xE007F, B::BN), (0xE0100, 0xE01EF, B::NSM),
+];
+
+/// The joining types of the code points that join
+pub(super) const JOINING_TYPE: &[(u32, u32, JoiningType)] = &[
+    (0xAD, 0xAD, J::T), (0x300, 0x36F, J::T), (0x483, 0x489, J::T), (0x591, 0x5BD, J::T),
+    (0x5BF, 0x5BF, J::T), (0x5C1, 0x5C2, J::T), (0x5C4, 0x5C5, J::T), (0x5C7, 0x5C7, J::T),
+    (0x610, 0x61A, J::T), (0x61C, 0x61C, J::T), (0x620, 0x620, J::D), (0x622, 0x625, J::R),
+    (0x626, 0x626, J::D), (0x627, 0x627, J::R), (0x628, 0x628, J::D), (0x629, 0x629, J::R),
+    (0x62A, 0x62E, J::D), (0x62F, 0x632, J::R), (0x633, 0x63F, J::D), (0x640, 0x640, J::C),
+    (0x641, 0x647, J::D), (0x648, 0x648, J::R), (0x649, 0x64A, J::D), (0x64B, 0x65F, J::T),
+    (0x66E, 0x66F, J::D), (0x670, 0x670, J::T), (0x671, 0x673, J::R), (0x675, 0x677, J::R),
+    (0x678, 0x687, J::D), (0x688, 0x699, J::R), (0x69A, 0x6BF, J::D), (0x6C0, 0x6C0, J::R),
+    (0x6C1, 0x6C2, J::D), (0x6C3, 0x6CB, J::R), (0x6CC, 0x6CC, J::D), (0x6CD, 0x6CD, J::R),
+    (0x6CE, 0x6CE, J::D), (0x6CF, 0x6CF, J::R), (0x6D0, 0x6D1, J::D), (0x6D2, 0x6D3, J::R),
+    (0x6D5, 0x6D5, J::R), (0x6D6, 0x6DC, J::T), (0x6DF, 0x6E4, J::T), (0x6E7, 0x6E8, J::T),
+    (0x6EA, 0x6ED, J::T), (0x6EE, 0x6EF, J::R), (0x6FA, 0x6FC, J::D), (0x6FF, 0x6FF, J::D),
+    (0x70F, 0x70F, J::T), (0x710, 0x710, J::R), (0x711, 0x711, J::T), (0x712, 0x714, J::D),
+    (0x715, 0x719, J::R), (0x71A, 0x71D, J::D), (0x71E, 0x71E, J::R), (0x71F, 0x727, J::D),
+    (0x728, 0x728, J::R), (0x729, 0x729, J::D), (0x72A, 0x72A, J::R), (0x72B, 0x72B, J::D),
+    (0x72C, 0x72C, J::R), (0x72D, 0x72E, J::D), (0x72F, 0x72F, J::R), (0x730, 0x74A, J::T),
+    (0x74D, 0x74D, J::R), (0x74E, 0x758, J::D), (0x759, 0x75B, J::R), (0x75C, 0x76A, J::D),
+    (0x76B, 0x76C, J::R), (0x76D, 0x770, J::D), (0x771, 0x771, J::R), (0x772, 0x772, J::D),
+    (0x773, 0x774, J::R), (0x775, 0x777, J::D), (0x778, 0x779, J::R), (0x77A, 0x77F, J::D),
+    (0x7A6, 0x7B0, J::T), (0x7CA, 0x7EA, J::D), (0x7EB, 0x7F3, J::T), (0x7FA, 0x7FA, J::C),
+    (0x7FD, 0x7FD, J::T), (0x816, 0x819, J::T), (0x81B, 0x823, J::T), (0x825, 0x827, J::T),
+    (0x829, 0x82D, J::T), (0x840, 0x840, J::R), (0x841, 0x845, J::D), (0x846, 0x847, J::R),
+    (0x848, 0x848, J::D), (0x849, 0x849, J::R), (0x84A, 0x853, J::D), (0x854, 0x854, J::R),
+    (0x855, 0x855, J::D), (0x856, 0x858, J::R), (0x859, 0x85B, J::T), (0x860, 0x860, J::D),
+    (0x862, 0x865, J::D), (0x867, 0x867, J::R), (0x868, 0x868, J::D), (0x869, 0x86A, J::R),
+    (0x870, 0x882, J::R), (0x883, 0x885, J::C), (0x886, 0x886, J::D), (0x889, 0x88D, J::D),
+    (0x88E, 0x88E, J::R), (0x898, 0x89F, J::T), (0x8A0, 0x8A9, J::D), (0x8AA, 0x8AC, J::R),
+    (0x8AE, 0x8AE, J::R), (0x8AF, 0x8B0, J::D), (0x8B1, 0x8B2, J::R), (0x8B3, 0x8B8, J::D),
+    (0x8B9, 0x8B9, J::R), (0x8BA, 0x8C8, J::D), (0x8CA, 0x8E1, J::T), (0x8E3, 0x902, J::T),
+    (0x93A, 0x93A, J::T), (0x93C, 0x93C, J::T), (0x941, 0x948, J::T), (0x94D, 0x94D, J::T),
+    (0x951, 0x957, J::T), (0x962, 0x963, J::T), (0x981, 0x981, J::T), (0x9BC, 0x9BC, J::T),
+    (0x9C1, 0x9C4, J::T), (0x9CD, 0x9CD, J::T), (0x9E2, 0x9E3, J::T), (0x9FE, 0x9FE, J::T),
+    (0xA01, 0xA02, J::T), (0xA3C, 0xA3C, J::T), (0xA41, 0xA42, J::T), (0xA47, 0xA48, J::T),
+    (0xA4B, 0xA4D, J::T), (0xA51, 0xA51, J::T), (0xA70, 0xA71, J::T), (0xA75, 0xA75, J::T),
+    (0xA81, 0xA82, J::T), (0xABC, 0xABC, J::T), (0xAC1, 0xAC5, J::T), (0xAC7, 0xAC8, J::T),
+    (0xACD, 0xACD, J::T), (0xAE2, 0xAE3, J::T), (0xAFA, 0xAFF, J::T), (0xB01, 0xB01, J::T),
+    (0xB3C, 0xB3C, J::T), (0xB3F, 0xB3F, J::T), (0xB41, 0xB44, J::T), (0xB4D, 0xB4D, J::T),
+    (0xB55, 0xB56, J::T), (0xB62, 0xB63, J::T), (0xB82, 0xB82, J::T), (0xBC0, 0xBC0, J::T),
+    (0xBCD, 0xBCD, J::T), (0xC00, 0xC00, J::T), (0xC04, 0xC04, J::T), (0xC3C, 0xC3C, J::T),
+    (0xC3E, 0xC40, J::T), (0xC46, 0xC48, J::T), (0xC4A, 0xC4D, J::T), (0xC55, 0xC56, J::T),
+    (0xC62, 0xC63, J::T), (0xC81, 0xC81, J::T), (0xCBC, 0xCBC, J::T), (0xCBF, 0xCBF, J::T),
+    (0xCC6, 0xCC6, J::T), (0xCCC, 0xCCD, J::T), (0xCE2, 0xCE3, J::T), (0xD00, 0xD01, J::T),
+    (0xD3B, 0xD3C, J::T), (0xD41, 0xD44, J::T), (0xD4D, 0xD4D, J::T), (0xD62, 0xD63, J::T),
+    (0xD81, 0xD81, J::T), (0xDCA, 0xDCA, J::T), (0xDD2, 0xDD4, J::T), (0xDD6, 0xDD6, J::T),
+    (0xE31, 0xE31, J::T), (0xE34, 0xE3A, J::T), (0xE47, 0xE4E, J::T), (0xEB1, 0xEB1, J::T),
+    (0xEB4, 0xEBC, J::T), (0xEC8, 0xECE, J::T), (0xF18, 0xF19, J::T), (0xF35, 0xF35, J::T),
+    (0xF37, 0xF37, J::T), (0xF39, 0xF39, J::T), (0xF71, 0xF7E, J::T), (0xF80, 0xF84, J::T),
+    (0xF86, 0xF87, J::T), (0xF8D, 0xF97, J::T), (0xF99, 0xFBC, J::T), (0xFC6, 0xFC6, J::T),
+    (0x102D, 0x1030, J::T), (0x1032, 0x1037, J::T), (0x1039, 0x103A, J::T), (0x103D, 0x103E, J::T),
+    (0x1058, 0x1059, J::T), (0x105E, 0x1060, J::T), (0x1071, 0x1074, J::T), (0x1082, 0x1082, J::T),
+    (0x1085, 0x1086, J::T), (0x108D, 0x108D, J::T), (0x109D, 0x109D, J::T), (0x135D, 0x135F, J::T),
+    (0x1712, 0x1714, J::T), (0x1732, 0x1733, J::T), (0x1752, 0x1753, J::T), (0x1772, 0x1773, J::T),
+    (0x17B4, 0x17B5, J::T), (0x17B7, 0x17BD, J::T), (0x17C6, 0x17C6, J::T), (0x17C9, 0x17D3, J::T),
+    (0x17DD, 0x17DD, J::T), (0x1807, 0x1807, J::D), (0x180A, 0x180A, J::C), (0x180B, 0x180D, J::T),
+    (0x180F, 0x180F, J::T), (0x1820, 0x1878, J::D), (0x1885, 0x1886, J::T), (0x1887, 0x18A8, J::D),
+    (0x18A9, 0x18A9, J::T), (0x18AA, 0x18AA, J::D), (0x1920, 0x1922, J::T), (0x1927, 0x1928, J::T),
+    (0x1932, 0x1932, J::T), (0x1939, 0x193B, J::T), (0x1A17, 0x1A18, J::T), (0x1A1B, 0x1A1B, J::T),
+    (0x1A56, 0x1A56, J::T), (0x1A58, 0x1A5E, J::T), (0x1A60, 0x1A60, J::T), (0x1A62, 0x1A62, J::T),
+    (0x1A65, 0x1A6C, J::T), (0x1A73, 0x1A7C, J::T), (0x1A7F, 0x1A7F, J::T), (0x1AB0, 0x1ACE, J::T),
+    (0x1B00, 0x1B03, J::T), (0x1B34, 0x1B34, J::T), (0x1B36, 0x1B3A, J::T), (0x1B3C, 0x1B3C, J::T),
+    (0x1B42, 0x1B42, J::T), (0x1B6B, 0x1B73, J::T), (0x1B80, 0x1B81, J::T), (0x1BA2, 0x1BA5, J::T),
+    (0x1BA8, 0x1BA9, J::T), (0x1BAB, 0x1BAD, J::T), (0x1BE6, 0x1BE6, J::T), (0x1BE8, 0x1BE9, J::T),
+    (0x1BED, 0x1BED, J::T), (0x1BEF, 0x1BF1, J::T), (0x1C2C, 0x1C33, J::T), (0x1C36, 0x1C37, J::T),
+    (0x1CD0, 0x1CD2, J::T), (0x1CD4, 0x1CE0, J::T), (0x1CE2, 0x1CE8, J::T), (0x1CED, 0x1CED, J::T),
+    (0x1CF4, 0x1CF4, J::T), (0x1CF8, 0x1CF9, J::T), (0x1DC0, 0x1DFF, J::T), (0x200B, 0x200B, J::T),
+    (0x200D, 0x200D, J::C), (0x200E, 0x200F, J::T), (0x202A, 0x202E, J::T), (0x2060, 0x2064, J::T),
+    (0x206A, 0x206F, J::T), (0x20D0, 0x20F0, J::T), (0x2CEF, 0x2CF1, J::T), (0x2D7F, 0x2D7F, J::T),
+    (0x2DE0, 0x2DFF, J::T), (0x302A, 0x302D, J::T), (0x3099, 0x309A, J::T), (0xA66F, 0xA672, J::T),
+    (0xA674, 0xA67D, J::T), (0xA69E, 0xA69F, J::T), (0xA6F0, 0xA6F1, J::T), (0xA802, 0xA802, J::T),
+    (0xA806, 0xA806, J::T), (0xA80B, 0xA80B, J::T), (0xA825, 0xA826, J::T), (0xA82C, 0xA82C, J::T),
+    (0xA840, 0xA871, J::D), (0xA872, 0xA872, J::L), (0xA8C4, 0xA8C5, J::T), (0xA8E0, 0xA8F1, J::T),
+    (0xA8FF, 0xA8FF, J::T), (0xA926, 0xA92D, J::T), (0xA947, 0xA951, J::T), (0xA980, 0xA982, J::T),
+    (0xA9B3, 0xA9B3, J::T), (0xA9B6, 0xA9B9, J::T), (0xA9BC, 0xA9BD, J::T), (0xA9E5, 0xA9E5, J::T),
+    (0xAA29, 0xAA2E, J::T), (0xAA31, 0xAA32, J::T), (0xAA35, 0xAA36, J::T), (0xAA43, 0xAA43, J::T),
+    (0xAA4C, 0xAA4C, J::T), (0xAA7C, 0xAA7C, J::T), (0xAAB0, 0xAAB0, J::T), (0xAAB2, 0xAAB4, J::T),
+    (0xAAB7, 0xAAB8, J::T), (0xAABE, 0xAABF, J::T), (0xAAC1, 0xAAC1, J::T), (0xAAEC, 0xAAED, J::T),
+    (0xAAF6, 0xAAF6, J::T), (0xABE5, 0xABE5, J::T), (0xABE8, 0xABE8, J::T), (0xABED, 0xABED, J::T),
+    (0xFB1E, 0xFB1E, J::T), (0xFE00, 0xFE0F, J::T), (0xFE20, 0xFE2F, J::T), (0xFEFF, 0xFEFF, J::T),
+    (0xFFF9, 0xFFFB, J::T), (0x101FD, 0x101FD, J::T), (0x102E0, 0x102E0, J::T), (0x10376, 0x1037A, J::T),
+    (0x10A01, 0x10A03, J::T), (0x10A05, 0x10A06, J::T), (0x10A0C, 0x10A0F, J::T), (0x10A38, 0x10A3A, J::T),
+    (0x10A3F, 0x10A3F, J::T), (0x10AC0, 0x10AC4, J::D), (0x10AC5, 0x10AC5, J::R), (0x10AC7, 0x10AC7, J::R),
+    (0x10AC9, 0x10ACA, J::R), (0x10ACD, 0x10ACD, J::L), (0x10ACE, 0x10AD2, J::R), (0x10AD3, 0x10AD6, J::D),
+    (0x10AD7, 0x10AD7, J::L), (0x10AD8, 0x10ADC, J::D), (0x10ADD, 0x10ADD, J::R), (0x10ADE, 0x10AE0, J::D),
+    (0x10AE1, 0x10AE1, J::R), (0x10AE4, 0x10AE4, J::R), (0x10AE5, 0x10AE6, J::T), (0x10AEB, 0x10AEE, J::D),
+    (0x10AEF, 0x10AEF, J::R), (0x10B80, 0x10B80, J::D), (0x10B81, 0x10B81, J::R), (0x10B82, 0x10B82, J::D),
+    (0x10B83, 0x10B85, J::R), (0x10B86, 0x10B88, J::D), (0x10B89, 0x10B89, J::R), (0x10B8A, 0x10B8B, J::D),
+    (0x10B8C, 0x10B8C, J::R), (0x10B8D, 0x10B8D, J::D), (0x10B8E, 0x10B8F, J::R), (0x10B90, 0x10B90, J::D),
+    (0x10B91, 0x10B91, J::R), (0x10BA9, 0x10BAC, J::R), (0x10BAD, 0x10BAE, J::D), (0x10D00, 0x10D00, J::L),
+    (0x10D01, 0x10D21, J::D), (0x10D22, 0x10D22, J::R), (0x10D23, 0x10D23, J::D), (0x10D24, 0x10D27, J::T),
+    (0x10EAB, 0x10EAC, J::T), (0x10EFD, 0x10EFF, J::T), (0x10F30, 0x10F32, J::D), (0x10F33, 0x10F33, J::R),
+    (0x10F34, 0x10F44, J::D), (0x10F46, 0x10F50, J::T), (0x10F51, 0x10F53, J::D), (0x10F54, 0x10F54, J::R),
+    (0x10F70, 0x10F73, J::D), (0x10F74, 0x10F75, J::R), (0x10F76, 0x10F81, J::D), (0x10F82, 0x10F85, J::T),
+    (0x10FB0, 0x10FB0, J::D), (0x10FB2, 0x10FB3, J::D), (0x10FB4, 0x10FB6, J::R), (0x10FB8, 0x10FB8, J::D),
+    (0x10FB9, 0x10FBA, J::R), (0x10FBB, 0x10FBC, J::D), (0x10FBD, 0x10FBD, J::R), (0x10FBE, 0x10FBF, J::D),
+    (0x10FC1, 0x10FC1, J::D), (0x10FC2, 0x10FC3, J::R), (0x10FC4, 0x10FC4, J::D), (0x10FC9, 0x10FC9, J::R),
+    (0x10FCA, 0x10FCA, J::D), (0x10FCB, 0x10FCB, J::L), (0x11001, 0x11001, J::T), (0x11038, 0x11046, J::T),
+    (0x11070, 0x11070, J::T), (0x11073, 0x11074, J::T), (0x1107F, 0x11081, J::T), (0x110B3, 0x110B6, J::T),
+    (0x110B9, 0x110BA, J::T), (0x110C2, 0x110C2, J::T), (0x11100, 0x11102, J::T), (0x11127, 0x1112B, J::T),
+    (0x1112D, 0x11134, J::T), (0x11173, 0x11173, J::T), (0x11180, 0x11181, J::T), (0x111B6, 0x111BE, J::T),
+    (0x111C9, 0x111CC, J::T), (0x111CF, 0x111CF, J::T), (0x1122F, 0x11231, J::T), (0x11234, 0x11234, J::T),
+    (0x11236, 0x11237, J::T), (0x1123E, 0x1123E, J::T), (0x11241, 0x11241, J::T), (0x112DF, 0x112DF, J::T),
+    (0x112E3, 0x112EA, J::T), (0x11300, 0x11301, J::T), (0x1133B, 0x1133C, J::T), (0x11340, 0x11340, J::T),
+    (0x11366, 0x1136C, J::T), (0x11370, 0x11374, J::T), (0x11438, 0x1143F, J::T), (0x11442, 0x11444, J::T),
+    (0x11446, 0x11446, J::T), (0x1145E, 0x1145E, J::T), (0x114B3, 0x114B8, J::T), (0x114BA, 0x114BA, J::T),
+    (0x114BF, 0x114C0, J::T), (0x114C2, 0x114C3, J::T), (0x115B2, 0x115B5, J::T), (0x115BC, 0x115BD, J::T),
+    (0x115BF, 0x115C0, J::T), (0x115DC, 0x115DD, J::T), (0x11633, 0x1163A, J::T), (0x1163D, 0x1163D, J::T),
+    (0x1163F, 0x11640, J::T), (0x116AB, 0x116AB, J::T), (0x116AD, 0x116AD, J::T), (0x116B0, 0x116B5, J::T),
+    (0x116B7, 0x116B7, J::T), (0x1171D, 0x1171F, J::T), (0x11722, 0x11725, J::T), (0x11727, 0x1172B, J::T),
+    (0x1182F, 0x11837, J::T), (0x11839, 0x1183A, J::T), (0x1193B, 0x1193C, J::T), (0x1193E, 0x1193E, J::T),
+    (0x11943, 0x11943, J::T), (0x119D4, 0x119D7, J::T), (0x119DA, 0x119DB, J::T), (0x119E0, 0x119E0, J::T),
+    (0x11A01, 0x11A0A, J::T), (0x11A33, 0x11A38, J::T), (0x11A3B, 0x11A3E, J::T), (0x11A47, 0x11A47, J::T),
+    (0x11A51, 0x11A56, J::T), (0x11A59, 0x11A5B, J::T), (0x11A8A, 0x11A96, J::T), (0x11A98, 0x11A99, J::T),
+    (0x11C30, 0x11C36, J::T), (0x11C38, 0x11C3D, J::T), (0x11C3F, 0x11C3F, J::T), (0x11C92, 0x11CA7, J::T),
+    (0x11CAA, 0x11CB0, J::T), (0x11CB2, 0x11CB3, J::T), (0x11CB5, 0x11CB6, J::T), (0x11D31, 0x11D36, J::T),
+    (0x11D3A, 0x11D3A, J::T), (0x11D3C, 0x11D3D, J::T), (0x11D3F, 0x11D45, J::T), (0x11D47, 0x11D47, J::T),
+    (0x11D90, 0x11D91, J::T), (0x11D95, 0x11D95, J::T), (0x11D97, 0x11D97, J::T), (0x11EF3, 0x11EF4, J::T),
+    (0x11F00, 0x11F01, J::T), (0x11F36, 0x11F3A, J::T), (0x11F40, 0x11F40, J::T), (0x11F42, 0x11F42, J::T),
+    (0x13430, 0x13440, J::T), (0x13447, 0x13455, J::T), (0x16AF0, 0x16AF4, J::T), (0x16B30, 0x16B36, J::T),
+    (0x16F4F, 0x16F4F, J::T), (0x16F8F, 0x16F92, J::T), (0x16FE4, 0x16FE4, J::T), (0x1BC9D, 0x1BC9E, J::T),
+    (0x1BCA0, 0x1BCA3, J::T), (0x1CF00, 0x1CF2D, J::T), (0x1CF30, 0x1CF46, J::T), (0x1D167, 0x1D169, J::T),
+    (0x1D173, 0x1D182, J::T), (0x1D185, 0x1D18B, J::T), (0x1D1AA, 0x1D1AD, J::T), (0x1D242, 0x1D244, J::T),
+    (0x1DA00, 0x1DA36, J::T), (0x1DA3B, 0x1DA6C, J::T), (0x1DA75, 0x1DA75, J::T), (0x1DA84, 0x1DA84, J::T),
+    (0x1DA9B, 0x1DA9F, J::T), (0x1DAA1, 0x1DAAF, J::T), (0x1E000, 0x1E006, J::T), (0x1E008, 0x1E018, J::T),
+    (0x1E01B, 0x1E021, J::T), (0x1E023, 0x1E024, J::T), (0x1E026, 0x1E02A, J::T), (0x1E08F, 0x1E08F, J::T),
+    (0x1E130, 0x1E136, J::T), (0x1E2AE, 0x1E2AE, J::T), (0x1E2EC, 0x1E2EF, J::T), (0x1E4EC, 0x1E4EF, J::T),
+    (0x1E8D0, 0x1E8D6, J::T), (0x1E900, 0x1E943, J::D), (0x1E944, 0x1E94B, J::T), (0xE0001, 0xE0001, J::T),
+    (0xE0020, 0xE007F, J::T), (0xE0100, 0xE01EF, J::T),
+];
+
@@ -0,0 +1,212 @@
+//! IPv4 hosts, read either the strict way of RFC 3986, "127.0.0.1" only,
+//! or the way browsers read them, following the
+//! [WHATWG URL standard](https://url.spec.whatwg.org/#concept-ipv4-parser):
+//! "0x7f.1", "017700000001" and "2130706433" are all "127.0.0.1" there.
+//! The WHATWG mode matters when checking where a URI leads, as SSRF filters do.
+
+use std::{error::Error, fmt};
+
+/// How to read an IPv4
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub enum Ipv4Mode {
+    /// dec-octet "." dec-octet "." dec-octet "." dec-octet, without leading zeros
+    #[default]
+    Strict,
+    /// one to four parts, in decimal, octal ("0177") or hexadecimal ("0x7f"),
+    /// the last one filling the remaining bytes
+    Whatwg,
+}
+
+/// Why a host is not a valid IPv4
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Ipv4Error {
+    /// the host is not an IPv4 at all, but may be a domain name
+    NotAnAddress,
+    /// "1.2.3" in strict mode, "1.2.3.4.5" in both modes
+    WrongPartCount,
+    /// "1..2.3"
+    EmptyPart,
+    /// "010.0.0.1" in strict mode, where it could be read as octal
+    LeadingZero,
+    /// "0x7g.1" or "09.1" in WHATWG mode
+    InvalidDigit,
+    /// "192.168.0.256", or a last part too big for the remaining bytes
+    OutOfRange,
+}
+
+impl Ipv4Error {
+    /// A short description, used as the context of parse errors
+    pub fn reason(&self) -> &'static str {
+        match self {
+            Ipv4Error::NotAnAddress => "not an IPv4 address",
+            Ipv4Error::WrongPartCount => "wrong number of parts in IPv4 address",
+            Ipv4Error::EmptyPart => "empty part in IPv4 address",
+            Ipv4Error::LeadingZero => "leading zero in IPv4 address",
+            Ipv4Error::InvalidDigit => "invalid digit in IPv4 address",
+            Ipv4Error::OutOfRange => "IPv4 address part out of range",
+        }
+    }
+}
+
+impl fmt::Display for Ipv4Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.reason())
+    }
+}
+
+impl Error for Ipv4Error {}
+
+/// Reads a whole host as an IPv4.
+///
+/// ```
+/// use uri_parser::{parse_ipv4, Ipv4Error, Ipv4Mode};
+///
+/// assert_eq!(parse_ipv4("0x7f.1", Ipv4Mode::Whatwg), Ok([127, 0, 0, 1]));
+/// assert_eq!(parse_ipv4("0x7f.1", Ipv4Mode::Strict), Err(Ipv4Error::NotAnAddress));
+/// assert_eq!(parse_ipv4("192.168.0.1444", Ipv4Mode::Strict), Err(Ipv4Error::OutOfRange));
+/// ```
+pub fn parse_ipv4(host: &str, mode: Ipv4Mode) -> Result<[u8; 4], Ipv4Error> {
+    match mode {
+        Ipv4Mode::Strict => parse_strict(host),
+        Ipv4Mode::Whatwg => parse_whatwg(host),
+    }
+}
+
+fn parse_strict(host: &str) -> Result<[u8; 4], Ipv4Error> {
+    if host.is_empty() || !host.chars().all(|c| c.is_ascii_digit() || c == '.') {
+        return Err(Ipv4Error::NotAnAddress);
+    }
+    if host.split('.').count() != 4 {
+        return Err(Ipv4Error::WrongPartCount);
+    }
+
+    let mut octets = [0u8; 4];
+    for (octet, part) in octets.iter_mut().zip(host.split('.')) {
+        if part.is_empty() {
+            return Err(Ipv4Error::EmptyPart);
+        }
+        if part.len() > 1 && part.starts_with('0') {
+            return Err(Ipv4Error::LeadingZero);
+        }
+        *octet = part.parse().map_err(|_| Ipv4Error::OutOfRange)?;
+    }
+    Ok(octets)
+}
+
+// The IPv4 number parser of the WHATWG: "0x" starts a hexadecimal number,
+// "0" an octal one. "0x" alone is zero.
+pub(crate) fn parse_whatwg_number(part: &str) -> Result<u64, Ipv4Error> {
+    if part.is_empty() {
+        return Err(Ipv4Error::EmptyPart);
+    }
+    let (digits, radix) = match part.strip_prefix("0x").or_else(|| part.strip_prefix("0X")) {
+        Some(hex) => (hex, 16),
+        None if part.len() > 1 && part.starts_with('0') => (&part[1..], 8),
+        None => (part, 10),
+    };
+    if digits.is_empty() {
+        return Ok(0);
+    }
+    if !digits.chars().all(|c| c.is_digit(radix)) {
+        return Err(Ipv4Error::InvalidDigit);
+    }
+    // a number that does not fit is out of range anyway
+    Ok(u64::from_str_radix(digits, radix).unwrap_or(u64::MAX))
+}
+
+fn parse_whatwg(host: &str) -> Result<[u8; 4], Ipv4Error> {
+    // "127.0.0.1." is fine
+    let host = host.strip_suffix('.').unwrap_or(host);
+
+    // only a host that ends in a number is an IPv4, "example.com" is a domain
+    let last = host.rsplit('.').next().unwrap_or(host);
+    let ends_in_number = (!last.is_empty() && last.chars().all(|c| c.is_ascii_digit()))
+        || parse_whatwg_number(last).is_ok();
+    if !ends_in_number {
+        return Err(Ipv4Error::NotAnAddress);
+    }
+    let count = host.split('.').count();
+    if count > 4 {
+        return Err(Ipv4Error::WrongPartCount);
+    }
+
+    let mut parsed = [0u64; 4];
+    for (number, part) in parsed.iter_mut().zip(host.split('.')) {
+        *number = parse_whatwg_number(part)?;
+    }
+    let numbers = &parsed[..count];
+    let (last, firsts) = numbers.split_last().unwrap_or((&0, &[]));
+    if firsts.iter().any(|&n| n > 255) {
+        return Err(Ipv4Error::OutOfRange);
+    }
+    // the last number fills the remaining bytes: "127.1" is "127.0.0.1"
+    if *last >= 256u64.pow(5 - numbers.len() as u32) {
+        return Err(Ipv4Error::OutOfRange);
+    }
+
+    let mut address = *last;
+    for (i, &n) in firsts.iter().enumerate() {
+        address += n << (8 * (3 - i));
+    }
+    Ok((address as u32).to_be_bytes())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_strict() {
+        let strict = |host| parse_ipv4(host, Ipv4Mode::Strict);
+        assert_eq!(strict("192.168.0.1"), Ok([192, 168, 0, 1]));
+        assert_eq!(strict("0.0.0.0"), Ok([0, 0, 0, 0]));
+        assert_eq!(strict("255.255.255.255"), Ok([255, 255, 255, 255]));
+
+        assert_eq!(strict("192.168.0.1444"), Err(Ipv4Error::OutOfRange));
+        assert_eq!(strict("192.168.0.256"), Err(Ipv4Error::OutOfRange));
+        assert_eq!(strict("192.168.00.1"), Err(Ipv4Error::LeadingZero));
+        assert_eq!(strict("010.0.0.1"), Err(Ipv4Error::LeadingZero));
+        assert_eq!(strict("1.2.3"), Err(Ipv4Error::WrongPartCount));
+        assert_eq!(strict("1.2.3.4."), Err(Ipv4Error::WrongPartCount));
+        assert_eq!(strict("1..2.3"), Err(Ipv4Error::EmptyPart));
+        assert_eq!(strict("2130706433"), Err(Ipv4Error::WrongPartCount));
+        assert_eq!(strict("1.2.3.4a"), Err(Ipv4Error::NotAnAddress));
+        assert_eq!(strict("0x7f.1"), Err(Ipv4Error::NotAnAddress));
+        assert_eq!(strict(""), Err(Ipv4Error::NotAnAddress));
+    }
+
+    #[test]
+    fn test_whatwg() {
+        let whatwg = |host| parse_ipv4(host, Ipv4Mode::Whatwg);
+        for host in [
+            "127.0.0.1",
+            "127.0.0.1.",
+            "0x7f.1",
+            "0x7F.0.0.1",
+            "0177.0.0.1",
+            "017700000001",
+            "2130706433",
+            "0x7f000001",
+            "127.1",
+            "127.0.1",
+        ] {
+            assert_eq!(whatwg(host), Ok([127, 0, 0, 1]), "{}", host);
+        }
+        assert_eq!(whatwg("0x"), Ok([0, 0, 0, 0]));
+        assert_eq!(whatwg("192.168.0.010"), Ok([192, 168, 0, 8]));
+
+        assert_eq!(whatwg("example.com"), Err(Ipv4Error::NotAnAddress));
+        assert_eq!(whatwg("1.2.3.example"), Err(Ipv4Error::NotAnAddress));
+        assert_eq!(whatwg("1.2.3.4.5"), Err(Ipv4Error::WrongPartCount));
+        assert_eq!(whatwg("09.1"), Err(Ipv4Error::InvalidDigit));
+        assert_eq!(whatwg("0x7g.1"), Err(Ipv4Error::InvalidDigit));
+        assert_eq!(whatwg("1..1"), Err(Ipv4Error::EmptyPart));
+        assert_eq!(whatwg("256.0.0.1"), Err(Ipv4Error::OutOfRange));
+        assert_eq!(whatwg("1.2.65536"), Err(Ipv4Error::OutOfRange));
+        assert_eq!(whatwg("4294967296"), Err(Ipv4Error::OutOfRange));
+        assert_eq!(
+            whatwg("99999999999999999999999"),
+            Err(Ipv4Error::OutOfRange)
+        );
+    }
+}
@@ -0,0 +1,158 @@
+//! URI normalization, as described in
+//! [RFC 3986 section 6](https://www.rfc-editor.org/rfc/rfc3986#section-6):
+//! "HTTP://Example.ORG:80/a/./b/../c?x=%7e" and "http://example.org/a/c?x=~"
+//! are written differently, but they are the same resource.
+
+use std::borrow::Cow;
+
+use crate::uri::{
+    authority::Authority, host::Host, path::Path, percent::normalize_percent_encoding,
+    resolve::remove_dot_segments, URI,
+};
+
+// Lowercases a name without losing the borrow when it is already lowercase
+fn lowercase<'a>(name: &Cow<'a, str>) -> Cow<'a, str> {
+    if name.bytes().any(|b| b.is_ascii_uppercase()) {
+        Cow::Owned(name.to_ascii_lowercase())
+    } else {
+        name.clone()
+    }
+}
+
+// Normalizes a component without losing the borrow when nothing changes
+fn normalize_component<'a>(component: &Cow<'a, str>) -> Cow<'a, str> {
+    match component {
+        Cow::Borrowed(borrowed) => normalize_percent_encoding(borrowed),
+        Cow::Owned(owned) => Cow::Owned(normalize_percent_encoding(owned).into_owned()),
+    }
+}
+
+impl<'a> URI<'a> {
+    /// Syntax-based normalization:
+    /// - the scheme and the host are lowercased
+    /// - percent-encoding uses uppercase hex digits, and unreserved characters are decoded
+    /// - the "." and ".." segments of the path are removed, but for the "." of
+    ///   "file:/.//a", without which "//a" would be read as a host
+    ///
+    /// and scheme-based normalization, for the schemes with a known default port:
+    /// - the default port is removed, "http://example.org:80" gives "http://example.org"
+    /// - an empty path becomes "/"
+    pub fn normalize(&self) -> URI<'a> {
+        // the scheme is already lowercased by the parser
+        let scheme = self.scheme.clone();
+
+        let authority = self.authority.as_ref().map(|authority| Authority {
+            user: normalize_component(&authority.user),
+            password: authority.password.as_ref().map(normalize_component),
+        });
+
+        let host = self.host.as_ref().map(|host| match host {
+            Host::HOST(name) => Host::HOST(lowercase(name)),
+            Host::IPVFUTURE(address) => Host::IPVFUTURE(lowercase(address)),
+            other => other.clone(),
+        });
+
+        let default_port = scheme.default_port();
+        let port = self.port.filter(|port| Some(*port) != default_port);
+
+        let path = match &self.path {
+            // "%2F" stays encoded, so the slashes still separate the same segments
+            Some(path @ (Path::Absolute(segments) | Path::Relative(segments))) => Some(
+                remove_dot_segments(path.with_segments(normalize_component(segments))),
+            ),
+            // "http://example.org" and "http://example.org/" are the same
+            None if host.is_some() && default_port.is_some() => {
+                Some(Path::Absolute(Cow::Borrowed("")))
+            }
+            None => None,
+        };
+        // removing the dot segments of "file:/.//a" would give "//a", read as a host
+        let path = match host {
+            None => path.map(Path::without_authority),
+            Some(_) => path,
+        };
+
+        let query = self.query.as_ref().map(normalize_component);
+
+        let fragment = self.fragment.as_ref().map(normalize_component);
+
+        URI {
+            scheme,
+            authority,
+            host,
+            port,
+            path,
+            query,
+            fragment,
+        }
+    }
+
+    /// Whether both URIs designate the same resource, once normalized
+    pub fn equivalent(&self, other: &URI<'_>) -> bool {
+        self.normalize() == other.normalize()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::uri::uri_parser;
+
+    fn assert_equivalent(a: &str, b: &str) {
+        let (_, uri_a) = uri_parser(a).unwrap();
+        let (_, uri_b) = uri_parser(b).unwrap();
+        assert!(
+            uri_a.equivalent(&uri_b),
+            "{} should be equivalent to {}",
+            a,
+            b
+        );
+    }
+
+    fn assert_not_equivalent(a: &str, b: &str) {
+        let (_, uri_a) = uri_parser(a).unwrap();
+        let (_, uri_b) = uri_parser(b).unwrap();
+        assert!(!uri_a.equivalent(&uri_b), "{} should differ from {}", a, b);
+    }
+
+    #[test]
+    fn test_normalize() {
+        let (_, uri) = uri_parser("HTTP://Example.ORG:80/a/./b/../c?x=%7e").unwrap();
+        let (_, expected) = uri_parser("http://example.org/a/c?x=~").unwrap();
+        assert_eq!(uri.normalize(), expected);
+
+        let (_, uri) = uri_parser("https://User%3a:p%7Ess@h:443#%2f").unwrap();
+        let (_, expected) = uri_parser("https://User%3A:p~ss@h/#%2F").unwrap();
+        assert_eq!(uri.normalize(), expected);
+
+        // the result can be written and read back
+        let (_, uri) = uri_parser("file:/././/a/../b").unwrap();
+        let normalized = uri.normalize();
+        assert_eq!(normalized.to_string(), "file:/.//b");
+        assert_eq!(uri_parser("file:/.//b"), Ok(("", normalized.clone())));
+        assert_eq!(normalized.normalize(), normalized);
+    }
+
+    #[test]
+    fn test_equivalent() {
+        assert_equivalent(
+            "HTTP://Example.ORG:80/a/./b/../c?x=%7e",
+            "http://example.org/a/c?x=~",
+        );
+        assert_equivalent("http://example.org", "http://example.org/");
+        assert_equivalent("https://example.org:443/", "https://example.org/");
+        assert_equivalent("http://example.org/%7Euser", "http://example.org/~user");
+        assert_equivalent("http://[::1]:80/", "http://[0:0::1]/");
+        assert_equivalent("mailto:a%2fb@example.org", "MAILTO:a%2Fb@example.org");
+
+        // the user and the path are case-sensitive
+        assert_not_equivalent("http://example.org/a", "http://example.org/A");
+        assert_not_equivalent("http://Bob@example.org/", "http://bob@example.org/");
+        // a port is only removed when it is the default one
+        assert_not_equivalent("http://example.org:8080/", "http://example.org/");
+        assert_not_equivalent("https://example.org:80/", "https://example.org/");
+        // "/" is only added to schemes we know about
+        assert_not_equivalent("foo://example.org", "foo://example.org/");
+        // "%2F" is not "/"
+        assert_not_equivalent("http://example.org/a%2Fb", "http://example.org/a/b");
+    }
+}
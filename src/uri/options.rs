@@ -0,0 +1,328 @@
+//! Parsing profiles: the strict grammar of RFC 3986, or the forgiving one of the
+//! [WHATWG URL standard](https://url.spec.whatwg.org/#concept-basic-url-parser)
+//! that browsers follow, where " http:\\\\example.org\\a\tb " leads to
+//! "http://example.org/ab". Both share the component parsers, the WHATWG profile
+//! cleans the input first, reads hosts the way browsers do, and removes the dot
+//! segments of the path.
+
+use std::{borrow::Cow, fmt::Write};
+
+use crate::uri::{
+    ipv4::Ipv4Mode,
+    percent::{is_fragment_char, is_pchar, is_query_char},
+    scheme::{scheme_parser, Scheme},
+};
+
+/// How to read a URI. Each rule of the WHATWG profile can be picked on its own.
+///
+/// ```
+/// use uri_parser::{uri_parser_with, Host, ParserOptions};
+///
+/// let (_, uri) = uri_parser_with(ParserOptions::whatwg())(" https:\\\\0x7f.1:443\\a\\b ").unwrap();
+/// assert_eq!(uri.host(), Some(&Host::IP([127, 0, 0, 1])));
+/// assert_eq!(uri.port(), None);
+/// assert_eq!(uri.to_string(), "https://127.0.0.1/a/b");
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct ParserOptions {
+    /// removes the C0 control characters and spaces around the input
+    pub trim_c0_control_or_space: bool,
+    /// removes the tabs and newlines found anywhere in the input, "ht\ntp" is "http"
+    pub strip_tab_and_newline: bool,
+    /// reads "\" as "/" before the query of a special scheme, like http or file
+    pub backslash_as_slash: bool,
+    /// reads "http:example.org" and "http:///example.org" as "http://example.org",
+    /// for the special schemes but file
+    pub ignore_authority_slashes: bool,
+    /// leaves out the port when it is the default one of the scheme, "http://a:80" is "http://a"
+    pub remove_default_port: bool,
+    /// how to read IPv4 hosts
+    pub ipv4_mode: Ipv4Mode,
+    /// accepts underscores in host names, "_sip._tcp.example.com", and the hyphens
+    /// that browsers accept in internationalized ones
+    pub lenient_hostnames: bool,
+    /// lowercases the host names of the special schemes, "EXAMPLE.org" is "example.org".
+    /// Internationalized names always are, in their ASCII form.
+    pub lowercase_hostnames: bool,
+    /// percent-decodes the host names of the special schemes and maps them with UTS #46
+    /// before looking for an IPv4, "%31%32%37.0.0.1" and "１２７.0.0.1" are "127.0.0.1"
+    pub domain_to_ascii: bool,
+    /// percent-encodes the code points that the path, the query and the fragment can not
+    /// hold, "/a b" is "/a%20b". Browsers leave a few of them as they are, like "|" or a "%"
+    /// that starts no percent-encoding: these are encoded too, for the URI to follow RFC 3986.
+    pub percent_encode_components: bool,
+    /// removes the "." and ".." segments of an absolute path, also written "%2e" and
+    /// "%2e%2e", "/a/./%2E%2e/b" is "/b"
+    pub remove_dot_segments: bool,
+}
+
+/// The RFC 3986 profile
+impl Default for ParserOptions {
+    fn default() -> Self {
+        ParserOptions::rfc3986()
+    }
+}
+
+impl ParserOptions {
+    /// The input is read as it is, following the grammar of RFC 3986
+    pub fn rfc3986() -> Self {
+        ParserOptions {
+            trim_c0_control_or_space: false,
+            strip_tab_and_newline: false,
+            backslash_as_slash: false,
+            ignore_authority_slashes: false,
+            remove_default_port: false,
+            ipv4_mode: Ipv4Mode::Strict,
+            lenient_hostnames: false,
+            lowercase_hostnames: false,
+            domain_to_ascii: false,
+            percent_encode_components: false,
+            remove_dot_segments: false,
+        }
+    }
+
+    /// The input is read the way browsers read what is typed in their address bar
+    pub fn whatwg() -> Self {
+        ParserOptions {
+            trim_c0_control_or_space: true,
+            strip_tab_and_newline: true,
+            backslash_as_slash: true,
+            ignore_authority_slashes: true,
+            remove_default_port: true,
+            ipv4_mode: Ipv4Mode::Whatwg,
+            lenient_hostnames: true,
+            lowercase_hostnames: true,
+            domain_to_ascii: true,
+            percent_encode_components: true,
+            remove_dot_segments: true,
+        }
+    }
+
+    // The WHATWG standard keeps the hosts of the other schemes as they are written,
+    // "foo://EXAMPLE.org" keeps its host
+    pub(crate) fn for_scheme(mut self, scheme: &Scheme<'_>) -> Self {
+        if !scheme.is_special() {
+            self.lowercase_hostnames = false;
+            self.domain_to_ascii = false;
+        }
+        self
+    }
+
+    /// Applies the cleaning rules to the input. When it changes, each byte of
+    /// the cleaned input comes with its position in the original one, so that
+    /// what is left to parse and the errors can point into the original input.
+    pub(crate) fn clean<'a>(&self, input: &'a str) -> Cleaned<'a> {
+        let unchanged = Cleaned {
+            text: Cow::Borrowed(input),
+            origins: Vec::new(),
+        };
+        if !(self.trim_c0_control_or_space
+            || self.strip_tab_and_newline
+            || self.backslash_as_slash
+            || self.ignore_authority_slashes
+            || self.percent_encode_components)
+        {
+            return unchanged;
+        }
+
+        let is_c0_control_or_space = |c: char| c <= ' ';
+        let (start, end) = if self.trim_c0_control_or_space {
+            let trimmed = input.trim_start_matches(is_c0_control_or_space);
+            let start = input.len() - trimmed.len();
+            (
+                start,
+                start + trimmed.trim_end_matches(is_c0_control_or_space).len(),
+            )
+        } else {
+            (0, input.len())
+        };
+
+        let mut text = String::with_capacity(end - start);
+        let mut origins = Vec::with_capacity(end - start);
+        for (i, c) in input[start..end].char_indices() {
+            if self.strip_tab_and_newline && matches!(c, '\t' | '\n' | '\r') {
+                continue;
+            }
+            text.push(c);
+            origins.extend((0..c.len_utf8()).map(|byte| start + i + byte));
+        }
+
+        if self.backslash_as_slash
+            && scheme_parser(&text).is_ok_and(|(_, scheme)| scheme.is_special())
+        {
+            // the query and the fragment keep their backslashes
+            let end_of_path = text.find(['?', '#']).unwrap_or(text.len());
+            text = text[..end_of_path].replace('\\', "/") + &text[end_of_path..];
+        }
+
+        if self.ignore_authority_slashes {
+            if let Ok((rest, scheme)) = scheme_parser(&text) {
+                let after_scheme = text.len() - rest.len();
+                let slashes = rest.len() - rest.trim_start_matches('/').len();
+                if scheme.is_special() && scheme.as_str() != "file" && slashes != 2 {
+                    // the authority starts after exactly two slashes, which take the
+                    // place of those that were there
+                    let origin = origins.get(after_scheme).copied().unwrap_or(end);
+                    text.replace_range(after_scheme..after_scheme + slashes, "//");
+                    origins.splice(after_scheme..after_scheme + slashes, [origin, origin]);
+                }
+            }
+        }
+
+        if self.percent_encode_components {
+            if let Some((encoded, encoded_origins)) = encode_components(&text, &origins) {
+                text = encoded;
+                origins = encoded_origins;
+            }
+        }
+
+        if text == input {
+            unchanged
+        } else {
+            Cleaned {
+                text: Cow::Owned(text),
+                origins,
+            }
+        }
+    }
+}
+
+// Percent-encodes what the path, the query and the fragment can not hold, each
+// byte that is added coming from the code point it encodes. The authority is left
+// to the host parsers. Gives nothing when there is nothing to encode.
+fn encode_components(text: &str, origins: &[usize]) -> Option<(String, Vec<usize>)> {
+    let (rest, _) = scheme_parser(text).ok()?;
+    let mut start = text.len() - rest.len();
+    if let Some(authority) = rest.strip_prefix("//") {
+        start += 2 + authority.find(['/', '?', '#']).unwrap_or(authority.len());
+    }
+
+    let components = &text[start..];
+    let is_hex = |at: usize| {
+        components
+            .as_bytes()
+            .get(at)
+            .is_some_and(u8::is_ascii_hexdigit)
+    };
+    let mut is_allowed: fn(char) -> bool = |c| is_pchar(c) || c == '/';
+    let mut in_fragment = false;
+    let mut encoded = String::new();
+    let mut encoded_origins = Vec::new();
+    for (i, c) in components.char_indices() {
+        let keep = match c {
+            '?' if !in_fragment => {
+                is_allowed = is_query_char;
+                true
+            }
+            '#' if !in_fragment => {
+                is_allowed = is_fragment_char;
+                in_fragment = true;
+                true
+            }
+            '%' => is_hex(i + 1) && is_hex(i + 2),
+            c => is_allowed(c),
+        };
+        if keep && encoded.is_empty() {
+            continue;
+        }
+        if encoded.is_empty() {
+            // the first code point to encode
+            encoded.push_str(&text[..start + i]);
+            encoded_origins.extend_from_slice(&origins[..start + i]);
+        }
+        let origin = origins[start + i];
+        if keep {
+            encoded.push(c);
+            encoded_origins.extend((0..c.len_utf8()).map(|byte| origin + byte));
+        } else {
+            for byte in c.encode_utf8(&mut [0; 4]).bytes() {
+                let _ = write!(encoded, "%{:02X}", byte);
+                encoded_origins.extend([origin; 3]);
+            }
+        }
+    }
+    (!encoded.is_empty()).then_some((encoded, encoded_origins))
+}
+
+/// The input once cleaned, see `ParserOptions::clean`
+pub(crate) struct Cleaned<'a> {
+    pub text: Cow<'a, str>,
+    origins: Vec<usize>,
+}
+
+impl<'a> Cleaned<'a> {
+    /// Gives the part of the original input that matches a part of the cleaned
+    /// one, both running to the end. What was trimmed at the end is dropped.
+    pub fn original<'b>(&self, original: &'b str, rest: &str) -> &'b str {
+        if let Cow::Borrowed(_) = self.text {
+            return &original[original.len() - rest.len()..];
+        }
+        let consumed = self.text.len() - rest.len();
+        match self.origins.get(consumed) {
+            Some(&position) => &original[position..],
+            None => &original[original.len()..],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_clean() {
+        let whatwg = ParserOptions::whatwg();
+
+        let input = " \u{0}http://exa\tmple.org/a\\b?c\\d\r\n ";
+        let cleaned = whatwg.clean(input);
+        // the query keeps its backslash, percent-encoded
+        assert_eq!(cleaned.text, "http://example.org/a/b?c%5Cd");
+        assert_eq!(cleaned.original(input, "/a/b?c%5Cd"), "/a\\b?c\\d\r\n ");
+        assert_eq!(cleaned.original(input, "5Cd"), "\\d\r\n ");
+        assert_eq!(cleaned.original(input, ""), "");
+
+        // only the special schemes read "\" as "/"
+        assert_eq!(whatwg.clean("foo://a\\b").text, "foo://a\\b");
+        assert_eq!(whatwg.clean("file:\\\\a").text, "file://a");
+
+        let input = "http://example.org/";
+        assert!(matches!(whatwg.clean(input).text, Cow::Borrowed(_)));
+
+        // the slashes that start the authority of a special scheme
+        let input = "https:///example.org/";
+        let cleaned = whatwg.clean(input);
+        assert_eq!(cleaned.text, "https://example.org/");
+        assert_eq!(cleaned.original(input, "example.org/"), "example.org/");
+        assert_eq!(cleaned.original(input, "//example.org/"), "///example.org/");
+        assert_eq!(whatwg.clean("http:a").text, "http://a");
+        assert_eq!(whatwg.clean("http:").text, "http://");
+        assert_eq!(whatwg.clean("file:/a").text, "file:/a");
+        assert_eq!(whatwg.clean("foo:a").text, "foo:a");
+
+        // what the path, the query and the fragment can not hold
+        let input = "http://example.org/a b/é?q=ü|#a#b";
+        let cleaned = whatwg.clean(input);
+        assert_eq!(
+            cleaned.text,
+            "http://example.org/a%20b/%C3%A9?q=%C3%BC%7C#a%23b"
+        );
+        assert_eq!(
+            cleaned.original(input, "%A9?q=%C3%BC%7C#a%23b"),
+            "é?q=ü|#a#b"
+        );
+        assert_eq!(cleaned.original(input, "?q=%C3%BC%7C#a%23b"), "?q=ü|#a#b");
+        assert_eq!(whatwg.clean("http://a.b/c%").text, "http://a.b/c%25");
+        assert_eq!(
+            whatwg.clean("http://a.b/%2e%zz").text,
+            "http://a.b/%2e%25zz"
+        );
+        assert_eq!(whatwg.clean("mailto:a b").text, "mailto:a%20b");
+        assert!(matches!(
+            whatwg.clean("http://a.b/c%20?d=e#f").text,
+            Cow::Borrowed(_)
+        ));
+
+        let rfc3986 = ParserOptions::rfc3986();
+        assert_eq!(rfc3986.clean(" http://a\\b ").text, " http://a\\b ");
+    }
+}
@@ -1,84 +1,275 @@
+use std::{borrow::Cow, fmt, str::Split};
+
 use nom::{
     bytes::complete::tag,
-    combinator::opt,
-    error::{context, ErrorKind},
-    multi::many0,
-    sequence::{terminated, tuple},
-    AsChar, InputTakeAtPosition,
+    combinator::{opt, recognize},
+    error::context,
+    multi::{many0_count, many1_count},
+    sequence::{preceded, tuple},
 };
 
-use crate::uri::CustomResult;
+use crate::uri::{
+    percent::{is_pchar, pct_encoded_chars},
+    CustomResult,
+};
 
-// What does this even do?
-pub fn url_code_points<T>(input: T) -> CustomResult<T, T>
-where
-    T: InputTakeAtPosition,
-    <T as InputTakeAtPosition>::Item: AsChar,
-{
-    input.split_at_position1_complete(
-        |item| {
-            let char_item = item.as_char();
-            !(char_item == '-') && !char_item.is_alphanum() && !(char_item == '.')
-            // ... actual ascii code points and url encoding...:
-            // https://infra.spec.whatwg.org/#ascii-code-point
-        },
-        ErrorKind::AlphaNumeric,
-    )
+/// A path, which may not start with a slash in a relative reference ("../a/b")
+/// or in a URI without authority ("mailto:someone@example.org").
+/// It keeps the slice of the input, and splits it into segments when asked:
+/// a trailing slash gives an empty last segment, "/a/b/" gives ["a", "b", ""],
+/// and "/a//b" gives ["a", "", "b"].
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Path<'a> {
+    /// The segments after the first slash: "/a/b" gives Absolute("a/b"), "/" gives Absolute("")
+    Absolute(Cow<'a, str>),
+    /// "../a/b" gives Relative("../a/b")
+    Relative(Cow<'a, str>),
+}
+
+impl<'a> Path<'a> {
+    /// Joins the segments with slashes, which they must not contain
+    pub fn absolute(segments: Vec<&str>) -> Self {
+        Path::Absolute(Cow::Owned(segments.join("/")))
+    }
+
+    pub fn relative(segments: Vec<&str>) -> Self {
+        Path::Relative(Cow::Owned(segments.join("/")))
+    }
+
+    /// The segments joined with slashes, without the first slash of an absolute path
+    pub fn as_str(&self) -> &str {
+        match self {
+            Path::Absolute(segments) | Path::Relative(segments) => segments,
+        }
+    }
+
+    pub fn segments(&self) -> Split<'_, char> {
+        self.as_str().split('/')
+    }
+
+    // The same kind of path, with other segments
+    pub(crate) fn with_segments<'b>(&self, segments: Cow<'b, str>) -> Path<'b> {
+        match self {
+            Path::Absolute(_) => Path::Absolute(segments),
+            Path::Relative(_) => Path::Relative(segments),
+        }
+    }
+
+    /// Copies the segments, to outlive the input
+    pub fn into_owned(self) -> Path<'static> {
+        let segments = Cow::Owned(self.as_str().to_string());
+        self.with_segments(segments)
+    }
+
+    pub fn is_absolute(&self) -> bool {
+        matches!(self, Path::Absolute(_))
+    }
+
+    // Without authority, the "//" of "//a" would be read as the start of one
+    pub(crate) fn starts_with_empty_segment(&self) -> bool {
+        matches!(self, Path::Absolute(segments) if segments.starts_with('/'))
+    }
+
+    // Puts a "." segment in front of a path that starts with an empty segment,
+    // as RFC 3986 section 5.3 suggests: "//a" becomes "/.//a", the same path
+    // once the dot segments are removed, and it can be read back without authority
+    pub(crate) fn without_authority(self) -> Self {
+        match self {
+            Path::Absolute(segments) if segments.starts_with('/') => {
+                Path::Absolute(Cow::Owned(format!("./{}", segments)))
+            }
+            path => path,
+        }
+    }
+
+    /// "/users/" has one, "/users" has none
+    pub fn has_trailing_slash(&self) -> bool {
+        match self {
+            Path::Absolute(segments) => segments.is_empty() || segments.ends_with('/'),
+            Path::Relative(segments) => segments.ends_with('/'),
+        }
+    }
+}
+
+impl fmt::Display for Path<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_absolute() {
+            f.write_str("/")?;
+        }
+        f.write_str(self.as_str())
+    }
 }
 
-// Converts "/path/to/my/blog/index.php"
-// to vec!["path", "to", "my", "blog", "index.php"]
-pub fn path_parser(input: &str) -> CustomResult<&str, Vec<&str>> {
+// A path segment is made of pchars: letters, digits, "-._~", "!$&'()*+,;=", ":", "@",
+// and percent-encoded characters like "%20"
+pub fn segment_parser(input: &str) -> CustomResult<&str, &str> {
+    pct_encoded_chars(is_pchar)(input)
+}
+
+// Converts "/path/to/my/blog/index.php" to Path::Absolute("path/to/my/blog/index.php").
+// Segments may be empty: "/path/to/" ends with an empty segment
+// and "/a//b" has one in the middle
+pub fn path_parser(input: &str) -> CustomResult<&str, Path<'_>> {
     context(
         "path",
-        tuple((
-            // detect the start of the path
-            tag("/"),
-            // gather all path elements into a vector
-            many0(
-                // a path element is anything matching url_code_points followed by a /
-                terminated(url_code_points, tag("/")),
-            ),
-            // Detect anything that follows the last slash, for example, "index.php"
-            opt(url_code_points),
-        )),
+        // path-abempty = *( "/" segment ), with at least one "/"
+        recognize(many1_count(preceded(tag("/"), opt(segment_parser)))),
     )(input)
-    .map(|(next_input, res)| {
-        // res looks like this: ("/", Vec<&str>, Option<str>)
-        let mut path: Vec<&str> = res.1.iter().map(|p| p.to_owned()).collect();
-        if let Some(last) = res.2 {
-            path.push(last);
-        }
-        (next_input, path)
-    })
+    .map(|(next_input, path)| (next_input, Path::Absolute(Cow::Borrowed(&path[1..]))))
+}
+
+// Converts "../img/a.png" to Path::Relative("../img/a.png").
+// The first segment can not contain a colon, or "a:b" would look like a scheme.
+pub fn relative_path_parser(input: &str) -> CustomResult<&str, Path<'_>> {
+    context(
+        "relative path",
+        segments_parser(|c| is_pchar(c) && c != ':'),
+    )(input)
+}
+
+// Converts the "someone@example.org" of "mailto:someone@example.org",
+// the path of a URI without authority, that does not start with a slash
+pub fn rootless_path_parser(input: &str) -> CustomResult<&str, Path<'_>> {
+    context("rootless path", segments_parser(is_pchar))(input)
+}
+
+// A first segment made of the given characters, followed by regular segments
+fn segments_parser<'a, F>(
+    is_first_char: F,
+) -> impl FnMut(&'a str) -> CustomResult<&'a str, Path<'a>>
+where
+    F: Fn(char) -> bool + Copy,
+{
+    move |input| {
+        recognize(tuple((
+            pct_encoded_chars(is_first_char),
+            // "a/b/" ends with an empty segment
+            many0_count(preceded(tag("/"), opt(segment_parser))),
+        )))(input)
+        .map(|(next_input, path)| (next_input, Path::Relative(Cow::Borrowed(path))))
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    // The segments of the path parsed from the input, and what is left
+    fn segments(
+        parser: fn(&str) -> CustomResult<&str, Path<'_>>,
+        input: &str,
+    ) -> (&str, Vec<&str>) {
+        let (rest, path) = parser(input).unwrap();
+        match path {
+            Path::Absolute(Cow::Borrowed(path)) | Path::Relative(Cow::Borrowed(path)) => {
+                (rest, path.split('/').collect())
+            }
+            _ => panic!("{} was copied", input),
+        }
+    }
+
     #[test]
     fn test_path_parser() {
-        assert_eq!(path_parser("/a/b/c?d"), Ok(("?d", vec!["a", "b", "c"])));
+        assert_eq!(
+            segments(path_parser, "/a/b/c?d"),
+            ("?d", vec!["a", "b", "c"])
+        );
+
+        assert_eq!(
+            segments(path_parser, "/a/1234/c.txt?d"),
+            ("?d", vec!["a", "1234", "c.txt"])
+        );
+
+        assert_eq!(
+            segments(path_parser, "/a/b-c-d/c/?d"),
+            ("?d", vec!["a", "b-c-d", "c", ""])
+        );
+
+        assert_eq!(
+            segments(path_parser, "/a/1234/c/?d"),
+            ("?d", vec!["a", "1234", "c", ""])
+        );
+
+        assert_eq!(segments(path_parser, "/?d"), ("?d", vec![""]));
+
+        assert_eq!(
+            segments(path_parser, "/hello%20world/%2Fslash#d"),
+            ("#d", vec!["hello%20world", "%2Fslash"])
+        );
+
+        assert_eq!(
+            segments(path_parser, "/a;b=c/~user/x:y@z?d"),
+            ("?d", vec!["a;b=c", "~user", "x:y@z"])
+        );
 
         assert_eq!(
-            path_parser("/a/1234/c.txt?d"),
-            Ok(("?d", vec!["a", "1234", "c.txt"]))
+            segments(path_parser, "/_!$'()*+,/a//b//"),
+            ("", vec!["_!$'()*+,", "a", "", "b", "", ""])
         );
 
+        assert_eq!(segments(path_parser, "//"), ("", vec!["", ""]));
         assert_eq!(
-            path_parser("/a/b-c-d/c/?d"),
-            Ok(("?d", vec!["a", "b-c-d", "c"]))
+            path_parser("/a/b?c"),
+            Ok(("?c", Path::absolute(vec!["a", "b"])))
         );
+        assert!(path_parser("a/b").is_err());
+    }
+
+    #[test]
+    fn test_trailing_slash() {
+        let (_, users) = path_parser("/users").unwrap();
+        let (_, users_dir) = path_parser("/users/").unwrap();
+        assert_ne!(users, users_dir);
+        assert!(!users.has_trailing_slash());
+        assert!(users_dir.has_trailing_slash());
+        assert!(Path::absolute(vec![""]).has_trailing_slash());
+        assert!(Path::relative(vec!["a", ""]).has_trailing_slash());
+        assert!(!Path::relative(vec!["a"]).has_trailing_slash());
+    }
+
+    #[test]
+    fn test_starts_with_empty_segment() {
+        assert!(Path::absolute(vec!["", "a"]).starts_with_empty_segment());
+        assert!(!Path::absolute(vec![""]).starts_with_empty_segment());
+        assert_eq!(
+            Path::absolute(vec!["", "a"]).without_authority(),
+            Path::absolute(vec![".", "", "a"])
+        );
+    }
 
+    #[test]
+    fn test_relative_path_parser() {
+        assert_eq!(
+            segments(relative_path_parser, "../img/a.png?d"),
+            ("?d", vec!["..", "img", "a.png"])
+        );
         assert_eq!(
-            path_parser("/a/1234/c/?d"),
-            Ok(("?d", vec!["a", "1234", "c"]))
+            segments(relative_path_parser, "a/b/#d"),
+            ("#d", vec!["a", "b", ""])
         );
+        assert_eq!(
+            segments(relative_path_parser, "a@b/c:d"),
+            ("", vec!["a@b", "c:d"])
+        );
+        // a colon in the first segment ends the path
+        assert_eq!(segments(relative_path_parser, "a:b/c"), (":b/c", vec!["a"]));
+        assert!(relative_path_parser("/a").is_err());
+    }
 
+    #[test]
+    fn test_rootless_path_parser() {
+        assert_eq!(
+            segments(rootless_path_parser, "someone@example.org?d"),
+            ("?d", vec!["someone@example.org"])
+        );
+        assert_eq!(
+            segments(rootless_path_parser, "a:b/c/"),
+            ("", vec!["a:b", "c", ""])
+        );
         assert_eq!(
-            path_parser("/a/1234/c.txt?d"),
-            Ok(("?d", vec!["a", "1234", "c.txt"]))
+            segments(rootless_path_parser, "a//b"),
+            ("", vec!["a", "", "b"])
         );
+        assert!(rootless_path_parser("/a").is_err());
     }
 }
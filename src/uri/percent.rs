@@ -0,0 +1,293 @@
+use std::borrow::Cow;
+
+use nom::{
+    branch::alt,
+    bytes::complete::{tag, take_while1},
+    character::complete::satisfy,
+    combinator::recognize,
+    error::context,
+    multi::many1_count,
+    sequence::tuple,
+};
+
+use crate::uri::{
+    error::{char_length, ParseError, UriError},
+    CustomResult,
+};
+
+// unreserved = ALPHA / DIGIT / "-" / "." / "_" / "~"
+pub fn is_unreserved(c: char) -> bool {
+    c.is_ascii_alphanumeric() || c == '-' || c == '.' || c == '_' || c == '~'
+}
+
+// sub-delims = "!" / "$" / "&" / "'" / "(" / ")" / "*" / "+" / "," / ";" / "="
+pub fn is_sub_delim(c: char) -> bool {
+    "!$&'()*+,;=".contains(c)
+}
+
+// pchar = unreserved / pct-encoded / sub-delims / ":" / "@"
+// (the pct-encoded part is handled by the parsers)
+pub fn is_pchar(c: char) -> bool {
+    is_unreserved(c) || is_sub_delim(c) || c == ':' || c == '@'
+}
+
+// query = *( pchar / "/" / "?" )
+pub fn is_query_char(c: char) -> bool {
+    is_pchar(c) || c == '/' || c == '?'
+}
+
+// fragment = *( pchar / "/" / "?" )
+pub fn is_fragment_char(c: char) -> bool {
+    is_pchar(c) || c == '/' || c == '?'
+}
+
+/// Recognizes one "%HH" triplet, like the "%20" of "hello%20world".
+/// A "%" that is not followed by two hex digits is a failure.
+pub fn pct_encoded(input: &str) -> CustomResult<&str, &str> {
+    context("percent encoding", triplet_parser)(input)
+}
+
+fn triplet_parser(input: &str) -> CustomResult<&str, &str> {
+    recognize(tuple((
+        tag("%"),
+        satisfy(|c| c.is_ascii_hexdigit()),
+        satisfy(|c| c.is_ascii_hexdigit()),
+    )))(input)
+    .map_err(|e| match e {
+        // a "%" can only start a triplet
+        nom::Err::Error(_) if input.starts_with('%') => {
+            let span = 0..char_length(input, 3);
+            nom::Err::Failure(UriError::new(
+                input,
+                ParseError::InvalidPercentEncoding { span },
+            ))
+        }
+        e => e,
+    })
+}
+
+/// Recognizes at least one character, each of them either allowed as is
+/// or percent-encoded.
+pub fn pct_encoded_chars<'a, F>(
+    is_allowed: F,
+) -> impl FnMut(&'a str) -> CustomResult<&'a str, &'a str>
+where
+    F: Fn(char) -> bool + Copy,
+{
+    move |input| recognize(many1_count(alt((take_while1(is_allowed), pct_encoded))))(input)
+}
+
+/// The characters that are left as they are when encoding a component.
+/// Everything else, including "%", is encoded.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum EncodeSet {
+    /// the user or the password, where ":" and "@" are delimiters
+    Userinfo,
+    /// one segment of the path, where "/" is the delimiter
+    PathSegment,
+    /// a query key or value, where "&", "=" and "+" have a meaning
+    Query,
+    /// the fragment, where anything goes
+    Fragment,
+}
+
+impl EncodeSet {
+    pub fn keeps(self, c: char) -> bool {
+        match self {
+            EncodeSet::Userinfo => is_unreserved(c) || is_sub_delim(c),
+            EncodeSet::PathSegment => is_pchar(c),
+            EncodeSet::Query => is_query_char(c) && !"&=+".contains(c),
+            EncodeSet::Fragment => is_fragment_char(c),
+        }
+    }
+}
+
+/// Encodes every character that the set does not keep,
+/// non-ASCII ones are encoded as their UTF-8 bytes: "é" gives "%C3%A9"
+pub fn percent_encode(input: &str, set: EncodeSet) -> Cow<'_, str> {
+    if input.chars().all(|c| set.keeps(c)) {
+        return Cow::Borrowed(input);
+    }
+
+    let mut encoded = String::with_capacity(input.len() * 3);
+    for c in input.chars() {
+        if set.keeps(c) {
+            encoded.push(c);
+        } else {
+            let mut buffer = [0u8; 4];
+            for byte in c.encode_utf8(&mut buffer).bytes() {
+                encoded.push_str(&format!("%{:02X}", byte));
+            }
+        }
+    }
+    Cow::Owned(encoded)
+}
+
+fn hex_value(byte: u8) -> Option<u8> {
+    (byte as char).to_digit(16).map(|d| d as u8)
+}
+
+/// Decodes the "%HH" triplets. Since the decoded bytes may not be valid UTF-8,
+/// this returns bytes. A "%" that does not start a valid triplet is kept as is.
+pub fn percent_decode(input: &str) -> Cow<'_, [u8]> {
+    if !input.contains('%') {
+        return Cow::Borrowed(input.as_bytes());
+    }
+
+    let bytes = input.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        if bytes[i] == b'%' && i + 2 < bytes.len() {
+            if let (Some(high), Some(low)) = (hex_value(bytes[i + 1]), hex_value(bytes[i + 2])) {
+                decoded.push(high * 16 + low);
+                i += 3;
+                continue;
+            }
+        }
+        decoded.push(bytes[i]);
+        i += 1;
+    }
+    Cow::Owned(decoded)
+}
+
+/// Decodes the "%HH" triplets into a string,
+/// invalid UTF-8 sequences are replaced with "�"
+pub fn percent_decode_str(input: &str) -> Cow<'_, str> {
+    match percent_decode(input) {
+        Cow::Borrowed(_) => Cow::Borrowed(input),
+        Cow::Owned(bytes) => Cow::Owned(String::from_utf8_lossy(&bytes).into_owned()),
+    }
+}
+
+/// Normalizes the "%HH" triplets of an already encoded component:
+/// hex digits are uppercased, "%3a" gives "%3A",
+/// and unreserved characters are decoded, "%7E" gives "~"
+pub fn normalize_percent_encoding(input: &str) -> Cow<'_, str> {
+    if !input.contains('%') {
+        return Cow::Borrowed(input);
+    }
+
+    let bytes = input.as_bytes();
+    let mut normalized = String::with_capacity(input.len());
+    let mut start = 0; // the start of the characters not copied yet
+    let mut i = 0;
+    while i + 2 < bytes.len() {
+        if bytes[i] != b'%' {
+            i += 1;
+            continue;
+        }
+        if let (Some(high), Some(low)) = (hex_value(bytes[i + 1]), hex_value(bytes[i + 2])) {
+            normalized.push_str(&input[start..i]);
+            let c = (high * 16 + low) as char;
+            if is_unreserved(c) {
+                normalized.push(c);
+            } else {
+                normalized.push_str(&input[i..i + 3].to_ascii_uppercase());
+            }
+            start = i + 3;
+            i += 3;
+        } else {
+            i += 1;
+        }
+    }
+    normalized.push_str(&input[start..]);
+
+    if normalized == input {
+        Cow::Borrowed(input)
+    } else {
+        Cow::Owned(normalized)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::uri::{
+        path::path_parser,
+        query::{fragment_parser, query_pairs, query_parser, QueryParam},
+    };
+
+    #[test]
+    fn test_pct_encoded_chars() {
+        let mut path_chars = pct_encoded_chars(is_pchar);
+        assert_eq!(path_chars("a%20b/c"), Ok(("/c", "a%20b")));
+        assert_eq!(path_chars("%2Fb?c"), Ok(("?c", "%2Fb")));
+        // an invalid triplet is a failure, pointing at it
+        assert_eq!(
+            path_chars("a%2zb"),
+            Err(nom::Err::Failure(
+                UriError::new("%2zb", ParseError::InvalidPercentEncoding { span: 0..3 })
+                    .with_contexts(&["percent encoding"])
+            ))
+        );
+        assert!(path_chars("%zz").is_err());
+    }
+
+    #[test]
+    fn test_percent_encode() {
+        assert_eq!(
+            percent_encode("some-thing_1.2~3", EncodeSet::PathSegment),
+            Cow::Borrowed("some-thing_1.2~3")
+        );
+        assert_eq!(percent_encode("a b/c", EncodeSet::PathSegment), "a%20b%2Fc");
+        assert_eq!(percent_encode("a:b@c", EncodeSet::PathSegment), "a:b@c");
+        assert_eq!(percent_encode("a:b@c", EncodeSet::Userinfo), "a%3Ab%40c");
+        assert_eq!(percent_encode("a=b&c+d", EncodeSet::Query), "a%3Db%26c%2Bd");
+        assert_eq!(percent_encode("/a?b#c", EncodeSet::Fragment), "/a?b%23c");
+        assert_eq!(percent_encode("100%", EncodeSet::Fragment), "100%25");
+        assert_eq!(percent_encode("café", EncodeSet::Query), "caf%C3%A9");
+    }
+
+    #[test]
+    fn test_percent_decode() {
+        assert_eq!(percent_decode_str("a%20b%2Fc"), "a b/c");
+        assert_eq!(percent_decode_str("caf%c3%a9"), "café");
+        assert_eq!(percent_decode_str("no-escape"), Cow::Borrowed("no-escape"));
+        // invalid triplets are kept as they are
+        assert_eq!(percent_decode_str("100%"), "100%");
+        assert_eq!(percent_decode_str("%zz%4"), "%zz%4");
+        // not UTF-8
+        assert_eq!(percent_decode("%FF%00").as_ref(), &[0xff, 0x00]);
+        assert_eq!(percent_decode_str("%FFa"), "\u{fffd}a");
+    }
+
+    #[test]
+    fn test_normalize_percent_encoding() {
+        assert_eq!(normalize_percent_encoding("%7e%7E"), "~~");
+        assert_eq!(normalize_percent_encoding("a%3ab%2f"), "a%3Ab%2F");
+        assert_eq!(normalize_percent_encoding("%41%2D%5f"), "A-_");
+        assert_eq!(normalize_percent_encoding("a%20b"), Cow::Borrowed("a%20b"));
+        assert_eq!(normalize_percent_encoding("100%"), "100%");
+        assert_eq!(normalize_percent_encoding("%%7e"), "%~");
+    }
+
+    #[test]
+    fn test_encoding_then_parsing_round_trips() {
+        let raw = "a b/c?d#e%f&g=h+i é";
+
+        let segment = percent_encode(raw, EncodeSet::PathSegment);
+        let path = format!("/{}/end", segment);
+        let (_, parsed) = path_parser(&path).unwrap();
+        let segments: Vec<_> = parsed.segments().collect();
+        assert_eq!(segments, vec![segment.as_ref(), "end"]);
+        assert_eq!(percent_decode_str(segments[0]), raw);
+
+        let value = percent_encode(raw, EncodeSet::Query);
+        let query = format!("?{}={}", value, value);
+        let (rest, parsed) = query_parser(&query).unwrap();
+        assert_eq!(rest, "");
+        let params: Vec<_> = query_pairs(parsed).collect();
+        assert_eq!(
+            params,
+            vec![QueryParam::new(value.as_ref(), value.as_ref())]
+        );
+        assert_eq!(percent_decode_str(&params[0].key), raw);
+
+        let fragment = percent_encode(raw, EncodeSet::Fragment);
+        let fragment = format!("#{}", fragment);
+        let (rest, parsed) = fragment_parser(&fragment).unwrap();
+        assert_eq!(rest, "");
+        assert_eq!(percent_decode_str(parsed), raw);
+    }
+}
@@ -1,26 +1,35 @@
-use nom::{
-    bytes::complete::tag, character::complete::digit1, combinator::map_res, error::context,
-    sequence::tuple,
+use nom::{bytes::complete::tag, character::complete::digit0, error::context, sequence::tuple};
+
+use crate::uri::{
+    error::{ParseError, UriError},
+    CustomResult,
 };
 
-use crate::uri::CustomResult;
+// The port may be empty, port = *DIGIT: "http://example.org:/a" has no port
+pub fn port_parser(input: &str) -> CustomResult<&str, Option<u16>> {
+    context("port", port_number_parser)(input)
+}
 
-pub fn port_parser(input: &str) -> CustomResult<&str, u16> {
-    context(
-        "port",
-        tuple((
-            // find the beginning of the port field
-            tag(":"),
-            //  parse_a_u16
-            map_res(
-                digit1,            // recognize ASCII chars from 0 to 9
-                str::parse::<u16>, // parse them into u16
-            ),
-        )),
-    )(input)
-    // the value returned is ("next_input", (":", 8080))
-    // we got to extract the port from this inner tuple
-    .map(|(next_input, res)| (next_input, res.1))
+fn port_number_parser(input: &str) -> CustomResult<&str, Option<u16>> {
+    tuple((
+        // find the beginning of the port field
+        tag(":"),
+        // recognize ASCII chars from 0 to 9
+        digit0,
+    ))(input)
+    // the value returned is ("next_input", (":", "8080"))
+    // we got to parse the port from this inner tuple
+    .and_then(|(next_input, (_, digits))| match digits.parse::<u16>() {
+        Ok(port) => Ok((next_input, Some(port))),
+        Err(_) if digits.is_empty() => Ok((next_input, None)),
+        // the digits can not be anything else than a port that does not fit in 16 bits
+        Err(_) => Err(nom::Err::Failure(UriError::new(
+            input.strip_prefix(':').unwrap_or(input),
+            ParseError::PortOutOfRange {
+                span: 0..digits.len(),
+            },
+        ))),
+    })
 }
 
 #[cfg(test)]
@@ -29,7 +38,16 @@ mod tests {
 
     #[test]
     fn test_port_parser() {
-        assert_eq!(port_parser(":8080"), Ok(("", 8080u16)));
-        assert_eq!(port_parser(":60"), Ok(("", 60u16)));
+        assert_eq!(port_parser(":8080"), Ok(("", Some(8080u16))));
+        assert_eq!(port_parser(":60"), Ok(("", Some(60u16))));
+        assert_eq!(port_parser(":/a"), Ok(("/a", None)));
+        assert_eq!(port_parser(":"), Ok(("", None)));
+        assert_eq!(
+            port_parser(":65536/a"),
+            Err(nom::Err::Failure(
+                UriError::new("65536/a", ParseError::PortOutOfRange { span: 0..5 })
+                    .with_contexts(&["port"])
+            ))
+        );
     }
 }
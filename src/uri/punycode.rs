@@ -0,0 +1,187 @@
+//! Punycode, as described in [RFC 3492](https://www.rfc-editor.org/rfc/rfc3492):
+//! the encoding that turns the "münchen" label of an internationalized domain name
+//! into the ASCII "mnchen-3ya", written "xn--mnchen-3ya" in a hostname.
+
+const BASE: u32 = 36;
+const T_MIN: u32 = 1;
+const T_MAX: u32 = 26;
+const SKEW: u32 = 38;
+const DAMP: u32 = 700;
+const INITIAL_BIAS: u32 = 72;
+const INITIAL_N: u32 = 128;
+
+// The bias adaptation function of RFC 3492 section 6.1
+fn adapt(delta: u32, num_points: u32, first_time: bool) -> u32 {
+    let mut delta = if first_time { delta / DAMP } else { delta / 2 };
+    delta += delta / num_points;
+    let mut k = 0;
+    while delta > ((BASE - T_MIN) * T_MAX) / 2 {
+        delta /= BASE - T_MIN;
+        k += BASE;
+    }
+    k + (BASE - T_MIN + 1) * delta / (delta + SKEW)
+}
+
+fn threshold(k: u32, bias: u32) -> u32 {
+    if k <= bias {
+        T_MIN
+    } else if k >= bias + T_MAX {
+        T_MAX
+    } else {
+        k - bias
+    }
+}
+
+// 0..=25 are "a".."z", 26..=35 are "0".."9"
+fn encode_digit(digit: u32) -> char {
+    if digit < 26 {
+        (b'a' + digit as u8) as char
+    } else {
+        (b'0' + (digit - 26) as u8) as char
+    }
+}
+
+fn decode_digit(byte: u8) -> Option<u32> {
+    match byte {
+        b'a'..=b'z' => Some((byte - b'a') as u32),
+        b'A'..=b'Z' => Some((byte - b'A') as u32),
+        b'0'..=b'9' => Some((byte - b'0') as u32 + 26),
+        _ => None,
+    }
+}
+
+/// Encodes a label, without the "xn--" prefix: "bücher" gives "bcher-kva".
+/// Returns `None` if the label is too long to be encoded without overflow.
+pub fn encode(input: &str) -> Option<String> {
+    let code_points: Vec<u32> = input.chars().map(|c| c as u32).collect();
+
+    // the ASCII characters are copied first, followed by a delimiter
+    let mut output: String = input.chars().filter(char::is_ascii).collect();
+    let basic_count = output.len() as u32;
+    if basic_count > 0 {
+        output.push('-');
+    }
+
+    let mut n = INITIAL_N;
+    let mut delta: u32 = 0;
+    let mut bias = INITIAL_BIAS;
+    let mut handled = basic_count;
+    while (handled as usize) < code_points.len() {
+        // the smallest code point that is not handled yet
+        let m = code_points.iter().copied().filter(|&c| c >= n).min()?;
+        delta = delta.checked_add((m - n).checked_mul(handled + 1)?)?;
+        n = m;
+
+        for &c in &code_points {
+            if c < n {
+                delta = delta.checked_add(1)?;
+            }
+            if c == n {
+                // write delta as a variable-length integer
+                let mut q = delta;
+                let mut k = BASE;
+                loop {
+                    let t = threshold(k, bias);
+                    if q < t {
+                        break;
+                    }
+                    output.push(encode_digit(t + (q - t) % (BASE - t)));
+                    q = (q - t) / (BASE - t);
+                    k += BASE;
+                }
+                output.push(encode_digit(q));
+                bias = adapt(delta, handled + 1, handled == basic_count);
+                delta = 0;
+                handled += 1;
+            }
+        }
+        delta = delta.checked_add(1)?;
+        n += 1;
+    }
+    Some(output)
+}
+
+/// Decodes a label, without the "xn--" prefix: "bcher-kva" gives "bücher".
+/// Returns `None` if the input is not valid Punycode.
+pub fn decode(input: &str) -> Option<String> {
+    // everything before the last delimiter is copied as is
+    let (basic, extended) = match input.rfind('-') {
+        Some(position) => (&input[..position], &input[position + 1..]),
+        None => ("", input),
+    };
+    if !basic.is_ascii() {
+        return None;
+    }
+
+    let mut output: Vec<char> = basic.chars().collect();
+    let mut n = INITIAL_N;
+    let mut i: u32 = 0;
+    let mut bias = INITIAL_BIAS;
+    let mut digits = extended.bytes().peekable();
+    while digits.peek().is_some() {
+        let old_i = i;
+        let mut weight: u32 = 1;
+        let mut k = BASE;
+        loop {
+            let digit = decode_digit(digits.next()?)?;
+            i = i.checked_add(digit.checked_mul(weight)?)?;
+            let t = threshold(k, bias);
+            if digit < t {
+                break;
+            }
+            weight = weight.checked_mul(BASE - t)?;
+            k += BASE;
+        }
+
+        let length = output.len() as u32 + 1;
+        bias = adapt(i - old_i, length, old_i == 0);
+        n = n.checked_add(i / length)?;
+        i %= length;
+        // basic code points can not be encoded
+        if n < INITIAL_N {
+            return None;
+        }
+        output.insert(i as usize, char::from_u32(n)?);
+        i += 1;
+    }
+    Some(output.into_iter().collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // some samples of RFC 3492 section 7.1, and common labels
+    const SAMPLES: [(&str, &str); 6] = [
+        ("bücher", "bcher-kva"),
+        ("münchen", "mnchen-3ya"),
+        ("español", "espaol-zwa"),
+        ("他们为什么不说中文", "ihqwcrb4cv8a8dqg056pqjye"),
+        ("3年B組金八先生", "3B-ww4c5e180e575a65lsy2b"),
+        ("そのスピードで", "d9juau41awczczp"),
+    ];
+
+    #[test]
+    fn test_encode() {
+        for (unicode, ascii) in SAMPLES {
+            assert_eq!(encode(unicode).as_deref(), Some(ascii), "{}", unicode);
+        }
+        assert_eq!(encode("abc").as_deref(), Some("abc-"));
+        assert_eq!(encode("").as_deref(), Some(""));
+    }
+
+    #[test]
+    fn test_decode() {
+        for (unicode, ascii) in SAMPLES {
+            assert_eq!(decode(ascii).as_deref(), Some(unicode), "{}", ascii);
+        }
+        // the case of the ASCII characters is kept
+        assert_eq!(decode("MNCHEN-3YA").as_deref(), Some("MüNCHEN"));
+        // not a digit
+        assert_eq!(decode("mnchen-3y!"), None);
+        // ends in the middle of a number
+        assert_eq!(decode("mnchen-3y9"), None);
+        // overflows
+        assert_eq!(decode("99999999999"), None);
+    }
+}
@@ -1,80 +1,166 @@
-use nom::{bytes::complete::tag, error::context, multi::many0, sequence::tuple};
+use std::{borrow::Cow, fmt};
 
-use crate::uri::{path::url_code_points, CustomResult};
+use nom::{
+    bytes::complete::tag,
+    combinator::opt,
+    error::context,
+    sequence::{preceded, tuple},
+};
 
-#[derive(Debug, PartialEq, Eq)]
+use crate::uri::{
+    percent::{is_fragment_char, is_query_char, pct_encoded_chars},
+    CustomResult,
+};
+
+/// One pair of a query: "?key=value" has a value, "?flag" has none
+/// and "?key=" has an empty one
+#[derive(Debug, PartialEq, Eq, Clone)]
 pub struct QueryParam<'a> {
-    pub key: &'a str,
-    pub value: &'a str,
+    pub key: Cow<'a, str>,
+    pub value: Option<Cow<'a, str>>,
 }
 
 impl<'a> QueryParam<'a> {
-    pub fn new(key: &'a str, value: &'a str) -> Self {
-        QueryParam { key, value }
+    pub fn new(key: impl Into<Cow<'a, str>>, value: impl Into<Cow<'a, str>>) -> Self {
+        QueryParam {
+            key: key.into(),
+            value: Some(value.into()),
+        }
+    }
+
+    /// A key without value, like the "flag" of "?flag"
+    pub fn flag(key: impl Into<Cow<'a, str>>) -> Self {
+        QueryParam {
+            key: key.into(),
+            value: None,
+        }
     }
 }
 
-// converts "?bla=5&blub=val#yay" into a vector of QueryParam
-// vec![
-//     QueryParam { key: "bla", value: "5" },
-//     QueryParam { key: "blub", value: "val" }
-// ]
-pub fn query_params_parser(input: &str) -> CustomResult<&str, Vec<QueryParam>> {
+/// Writes "key=value", or only "key" when there is no value
+impl fmt::Display for QueryParam<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.key)?;
+        if let Some(value) = &self.value {
+            write!(f, "={}", value)?;
+        }
+        Ok(())
+    }
+}
+
+// query = *( pchar / "/" / "?" )
+// The query is kept as it is written, without the "?": "?bla=5&blub=val#yay" gives "bla=5&blub=val".
+// It may be empty, as in "http://example.org?"
+pub fn query_parser(input: &str) -> CustomResult<&str, &str> {
     context(
-        "query params",
-        tuple((
-            tag("?"),
-            url_code_points, // index 1 in the produced tuple
-            tag("="),
-            url_code_points, // index 3
-            many0(
-                // index 4, this will be a vector of tuples such as: ("&", str, "=", str)
-                tuple((tag("&"), url_code_points, tag("="), url_code_points)),
-            ),
-        )),
+        "query",
+        preceded(tag("?"), opt(pct_encoded_chars(is_query_char))),
     )(input)
-    .map(|(next_input, res)| {
-        let mut query_params = Vec::new();
+    .map(|(next_input, query)| (next_input, query.unwrap_or("")))
+}
 
-        query_params.push(QueryParam::new(res.1, res.3));
+// converts "?bla=5&blub=val#yay" into a vector of QueryParam, as they are written
+// vec![
+//     QueryParam { key: "bla", value: Some("5") },
+//     QueryParam { key: "blub", value: Some("val") }
+// ]
+pub fn query_params_parser(input: &str) -> CustomResult<&str, Vec<QueryParam<'_>>> {
+    query_parser(input).map(|(next_input, query)| (next_input, query_pairs(query).collect()))
+}
 
-        for qp in res.4 {
-            query_params.push(QueryParam::new(qp.1, qp.3));
-        }
-        (next_input, query_params)
-    })
+/// Splits a query into its pairs, in order and with the duplicates:
+/// "a=1&&b&a=" gives `a=1`, `b` without value and `a` with an empty value.
+/// The empty pairs of "&&" are skipped, and ";" is not taken as a separator.
+pub fn query_pairs(query: &str) -> impl Iterator<Item = QueryParam<'_>> {
+    query
+        .split('&')
+        .filter(|pair| !pair.is_empty())
+        .map(|pair| match pair.split_once('=') {
+            Some((key, value)) => QueryParam::new(key, value),
+            None => QueryParam::flag(pair),
+        })
 }
 
-// This parses the fragment, the #inner-link at the end of an URI
+// This parses the fragment, the #inner-link at the end of an URI.
+// It may be empty, as in "http://example.org#"
 pub fn fragment_parser(input: &str) -> CustomResult<&str, &str> {
     context(
         "fragment",
-        // create a tuple of the form ("#", ("", "fragment"))
+        // create a tuple of the form ("#", Some("fragment"))
         tuple((
-            tag("#"),        // detect the start of the fragment
-            url_code_points, // get the line that follows
+            tag("#"),                                 // detect the start of the fragment
+            opt(pct_encoded_chars(is_fragment_char)), // get the line that follows
         )),
     )(input)
-    .map(|(next_input, res)| (next_input, res.1))
+    .map(|(next_input, res)| (next_input, res.1.unwrap_or("")))
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::uri::error::{ParseError, UriError};
+
+    #[test]
+    fn test_query_parser() {
+        assert_eq!(
+            query_parser("?bla=5&blub=val#yay"),
+            Ok(("#yay", "bla=5&blub=val"))
+        );
+        assert_eq!(query_parser("?flag"), Ok(("", "flag")));
+        assert_eq!(query_parser("?a="), Ok(("", "a=")));
+        assert_eq!(query_parser("?a=1&&b=2"), Ok(("", "a=1&&b=2")));
+        assert_eq!(query_parser("?q=a+b"), Ok(("", "q=a+b")));
+        assert_eq!(query_parser("?x=1;y=2"), Ok(("", "x=1;y=2")));
+        assert_eq!(
+            query_parser("?redirect=/a/b?c"),
+            Ok(("", "redirect=/a/b?c"))
+        );
+        assert_eq!(
+            query_parser("?q=hello%20world"),
+            Ok(("", "q=hello%20world"))
+        );
+        assert_eq!(query_parser("?#top"), Ok(("#top", "")));
+        assert_eq!(query_parser("?a b"), Ok((" b", "a")));
+    }
 
     #[test]
     fn test_query_params_parser() {
         assert_eq!(
-            query_params_parser("?bla=5&blub=val#yay"),
+            query_params_parser("?bla=5&blub=a+b&flag#yay"),
             Ok((
                 "#yay",
-                vec![QueryParam::new("bla", "5"), QueryParam::new("blub", "val")]
+                vec![
+                    QueryParam::new("bla", "5"),
+                    QueryParam::new("blub", "a+b"),
+                    QueryParam::flag("flag"),
+                ]
             ))
         );
+        assert_eq!(query_params_parser("?"), Ok(("", vec![])));
+        assert!(query_params_parser("bla=5").is_err());
+    }
+
+    #[test]
+    fn test_query_pairs() {
+        let pairs: Vec<_> = query_pairs("bla=5&flag&empty=&&bla=6&x=1;y=2&=v&a=b=c").collect();
+        assert_eq!(
+            pairs,
+            vec![
+                QueryParam::new("bla", "5"),
+                QueryParam::flag("flag"),
+                QueryParam::new("empty", ""),
+                QueryParam::new("bla", "6"),
+                QueryParam::new("x", "1;y=2"),
+                QueryParam::new("", "v"),
+                QueryParam::new("a", "b=c"),
+            ]
+        );
+        assert_eq!(query_pairs("").count(), 0);
 
+        let written: Vec<_> = pairs.iter().map(ToString::to_string).collect();
         assert_eq!(
-            query_params_parser("?bla-blub=arr-arr#yay"),
-            Ok(("#yay", vec![QueryParam::new("bla-blub", "arr-arr")]))
+            written.join("&"),
+            "bla=5&flag&empty=&bla=6&x=1;y=2&=v&a=b=c"
         );
     }
 
@@ -82,5 +168,16 @@ mod tests {
     fn test_fragment_parser() {
         assert_eq!(fragment_parser("#bla"), Ok(("", "bla")));
         assert_eq!(fragment_parser("#bla-blub"), Ok(("", "bla-blub")));
+        assert_eq!(fragment_parser("#a%20b/c?d"), Ok(("", "a%20b/c?d")));
+        assert_eq!(fragment_parser("#"), Ok(("", "")));
+        // a "%" that does not start a triplet is an error, not the end of the fragment
+        let invalid = |input, length| {
+            let error = ParseError::InvalidPercentEncoding { span: 0..length };
+            Err(nom::Err::Failure(
+                UriError::new(input, error).with_contexts(&["percent encoding", "fragment"]),
+            ))
+        };
+        assert_eq!(fragment_parser("#a%2"), invalid("%2", 2));
+        assert_eq!(fragment_parser("#%zz"), invalid("%zz", 3));
     }
 }
@@ -0,0 +1,337 @@
+use std::{borrow::Cow, fmt};
+
+use nom::{
+    self,
+    branch::alt,
+    bytes::complete::tag,
+    combinator::{map, not, opt},
+    error::context,
+    sequence::{preceded, tuple},
+};
+
+use crate::uri::{
+    authority::Authority,
+    authority_and_path_parser,
+    host::Host,
+    path::{path_parser, relative_path_parser, Path},
+    query::{fragment_parser, query_pairs, query_parser, QueryParam},
+    uri_parser, write_components, CustomResult, HierPart, URI,
+};
+
+/// A reference without a scheme, like the "../img/a.png" of an href.
+/// It only makes sense relative to a base URI.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct RelativeRef<'a> {
+    pub(crate) authority: Option<Authority<'a>>, // the "user:password@" of "//user:password@host"
+    pub(crate) host: Option<Host<'a>>,           // only in network paths: "//cdn.example.com/x.js"
+    pub(crate) port: Option<u16>,                // optional ":8080" after the host
+    pub(crate) path: Option<Path<'a>>,           // "/a/b" or "../a/b"
+    pub(crate) query: Option<Cow<'a, str>>,      // "?page=2"
+    pub(crate) fragment: Option<Cow<'a, str>>,   // "#top"
+}
+
+impl<'a> RelativeRef<'a> {
+    pub fn userinfo(&self) -> Option<&Authority<'a>> {
+        self.authority.as_ref()
+    }
+
+    pub fn host(&self) -> Option<&Host<'a>> {
+        self.host.as_ref()
+    }
+
+    pub fn port(&self) -> Option<u16> {
+        self.port
+    }
+
+    pub fn path(&self) -> Option<&Path<'a>> {
+        self.path.as_ref()
+    }
+
+    pub fn query(&self) -> Option<&str> {
+        self.query.as_deref()
+    }
+
+    pub fn query_pairs(&self) -> impl Iterator<Item = QueryParam<'_>> {
+        self.query.as_deref().into_iter().flat_map(query_pairs)
+    }
+
+    pub fn fragment(&self) -> Option<&str> {
+        self.fragment.as_deref()
+    }
+
+    /// Copies the borrowed components, to outlive the input
+    pub fn into_owned(self) -> RelativeRef<'static> {
+        let owned = |text: Cow<'a, str>| Cow::Owned(text.into_owned());
+        RelativeRef {
+            authority: self.authority.map(Authority::into_owned),
+            host: self.host.map(Host::into_owned),
+            port: self.port,
+            path: self.path.map(Path::into_owned),
+            query: self.query.map(owned),
+            fragment: self.fragment.map(owned),
+        }
+    }
+}
+
+impl fmt::Display for RelativeRef<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // a colon in the first segment, as in "a:b", would be read as a scheme
+        if let (None, Some(Path::Relative(segments))) = (&self.host, &self.path) {
+            if segments
+                .split('/')
+                .next()
+                .is_some_and(|first| first.contains(':'))
+            {
+                f.write_str("./")?;
+            }
+        }
+        write_components(
+            f,
+            (
+                self.authority.as_ref(),
+                self.host.as_ref(),
+                self.port,
+                self.path.as_ref(),
+            ),
+            self.query.as_deref(),
+            self.fragment.as_deref(),
+        )
+    }
+}
+
+/// Anything that may be found in an href: either a full URI or a relative reference
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum UriReference<'a> {
+    URI(URI<'a>),
+    Relative(RelativeRef<'a>),
+}
+
+impl UriReference<'_> {
+    /// Copies the borrowed components, to outlive the input
+    pub fn into_owned(self) -> UriReference<'static> {
+        match self {
+            UriReference::URI(uri) => UriReference::URI(uri.into_owned()),
+            UriReference::Relative(relative) => UriReference::Relative(relative.into_owned()),
+        }
+    }
+}
+
+impl fmt::Display for UriReference<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            UriReference::URI(uri) => write!(f, "{}", uri),
+            UriReference::Relative(relative) => write!(f, "{}", relative),
+        }
+    }
+}
+
+// The relative part is one of:
+// - a network path:  "//cdn.example.com/x.js"
+// - an absolute path: "/a/b"
+// - a relative path: "../a/b"
+// - nothing at all, as in "?page=2" or "#top"
+fn local_path_parser(input: &str) -> CustomResult<&str, HierPart<'_>> {
+    opt(alt((
+        // "//" starts a network path, never an absolute one
+        preceded(not(tag("//")), path_parser),
+        relative_path_parser,
+    )))(input)
+    .map(|(next_input, path)| (next_input, (None, None, None, path)))
+}
+
+pub fn relative_ref_parser(input: &str) -> CustomResult<&str, RelativeRef<'_>> {
+    context(
+        "relative reference",
+        tuple((
+            alt((authority_and_path_parser, local_path_parser)),
+            opt(query_parser),
+            opt(fragment_parser),
+        )),
+    )(input)
+    .map(|(next_input, res)| {
+        let ((authority, host, port, path), query, fragment) = res;
+        (
+            next_input,
+            RelativeRef {
+                authority,
+                host,
+                port,
+                path,
+                query: query.map(Cow::Borrowed),
+                fragment: fragment.map(Cow::Borrowed),
+            },
+        )
+    })
+}
+
+/// Parses a full URI if there is a scheme, a relative reference otherwise
+pub fn uri_reference_parser(input: &str) -> CustomResult<&str, UriReference<'_>> {
+    context(
+        "uri reference",
+        alt((
+            map(uri_parser, UriReference::URI),
+            map(relative_ref_parser, UriReference::Relative),
+        )),
+    )(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::uri::scheme::Scheme;
+
+    #[test]
+    fn test_relative_ref_parser() {
+        assert_eq!(
+            relative_ref_parser("../img/a.png"),
+            Ok((
+                "",
+                RelativeRef {
+                    authority: None,
+                    host: None,
+                    port: None,
+                    path: Some(Path::relative(vec!["..", "img", "a.png"])),
+                    query: None,
+                    fragment: None
+                }
+            ))
+        );
+
+        assert_eq!(
+            relative_ref_parser("//user@cdn.example.com:8443/x.js?v=2"),
+            Ok((
+                "",
+                RelativeRef {
+                    authority: Some(Authority {
+                        user: "user".into(),
+                        password: None
+                    }),
+                    host: Some(Host::HOST("cdn.example.com".into())),
+                    port: Some(8443),
+                    path: Some(Path::absolute(vec!["x.js"])),
+                    query: Some("v=2".into()),
+                    fragment: None
+                }
+            ))
+        );
+
+        assert_eq!(
+            relative_ref_parser("/a/b#top"),
+            Ok((
+                "",
+                RelativeRef {
+                    authority: None,
+                    host: None,
+                    port: None,
+                    path: Some(Path::absolute(vec!["a", "b"])),
+                    query: None,
+                    fragment: Some("top".into())
+                }
+            ))
+        );
+
+        assert_eq!(
+            relative_ref_parser("?page=2"),
+            Ok((
+                "",
+                RelativeRef {
+                    authority: None,
+                    host: None,
+                    port: None,
+                    path: None,
+                    query: Some("page=2".into()),
+                    fragment: None
+                }
+            ))
+        );
+
+        assert_eq!(
+            relative_ref_parser("#top"),
+            Ok((
+                "",
+                RelativeRef {
+                    authority: None,
+                    host: None,
+                    port: None,
+                    path: None,
+                    query: None,
+                    fragment: Some("top".into())
+                }
+            ))
+        );
+
+        // the empty reference is a valid one
+        assert_eq!(
+            relative_ref_parser(""),
+            Ok((
+                "",
+                RelativeRef {
+                    authority: None,
+                    host: None,
+                    port: None,
+                    path: None,
+                    query: None,
+                    fragment: None
+                }
+            ))
+        );
+    }
+
+    #[test]
+    fn test_uri_reference_parser() {
+        let (rest, reference) = uri_reference_parser("https://example.org/a").unwrap();
+        assert_eq!(rest, "");
+        match reference {
+            UriReference::URI(uri) => assert_eq!(uri.scheme(), &Scheme::HTTPS),
+            UriReference::Relative(_) => panic!("expected a URI"),
+        }
+
+        let (rest, reference) = uri_reference_parser("a/b?c=d").unwrap();
+        assert_eq!(rest, "");
+        match reference {
+            UriReference::URI(_) => panic!("expected a relative reference"),
+            UriReference::Relative(relative) => {
+                assert_eq!(relative.path(), Some(&Path::relative(vec!["a", "b"])));
+                assert_eq!(relative.query(), Some("c=d"));
+                assert_eq!(
+                    relative.query_pairs().collect::<Vec<_>>(),
+                    vec![QueryParam::new("c", "d")]
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_display() {
+        for input in [
+            "../img/a.png",
+            "//user@cdn.example.com:8443/x.js?v=2",
+            "/a/b#top",
+            "?page=2",
+            "#top",
+            "#",
+            "?#",
+            "",
+            "http://example.org/a/",
+        ] {
+            let (rest, reference) = uri_reference_parser(input).unwrap();
+            assert_eq!(rest, "");
+            assert_eq!(reference.to_string(), input);
+        }
+
+        // "a:b" would be a scheme
+        let reference = RelativeRef {
+            authority: None,
+            host: None,
+            port: None,
+            path: Some(Path::relative(vec!["a:b", "c"])),
+            query: None,
+            fragment: None,
+        };
+        assert_eq!(reference.to_string(), "./a:b/c");
+        assert_eq!(
+            relative_ref_parser("./a:b/c").unwrap().1.path(),
+            Some(&Path::relative(vec![".", "a:b", "c"]))
+        );
+    }
+}
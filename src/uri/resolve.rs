@@ -0,0 +1,309 @@
+//! Reference resolution, as described in
+//! [RFC 3986 section 5](https://www.rfc-editor.org/rfc/rfc3986#section-5):
+//! turning a relative reference like "../img/a.png" into a full URI,
+//! relative to a base URI, the way browsers follow links and redirects.
+
+use std::borrow::Cow;
+
+use crate::uri::{path::Path, reference::UriReference, URI};
+
+impl<'a> URI<'a> {
+    /// Resolves a reference against this URI, taken as the base.
+    ///
+    /// ```
+    /// use uri_parser::{uri_parser, uri_reference_parser};
+    ///
+    /// let (_, base) = uri_parser("http://a/b/c/d;p?q").unwrap();
+    /// let (_, reference) = uri_reference_parser("../g").unwrap();
+    /// let (_, expected) = uri_parser("http://a/b/g").unwrap();
+    ///
+    /// assert_eq!(base.join(&reference), expected);
+    /// ```
+    pub fn join<'b>(&self, reference: &UriReference<'b>) -> URI<'b>
+    where
+        'a: 'b,
+    {
+        let relative = match reference {
+            // a reference with a scheme is already a full URI
+            UriReference::URI(uri) => {
+                let mut target = uri.clone();
+                target.path = target.path.map(remove_dot_segments);
+                if target.host.is_none() {
+                    target.path = target.path.map(Path::without_authority);
+                }
+                return target;
+            }
+            UriReference::Relative(relative) => relative,
+        };
+
+        let mut target = URI {
+            scheme: self.scheme.clone(),
+            authority: self.authority.clone(),
+            host: self.host.clone(),
+            port: self.port,
+            path: self.path.clone(),
+            query: self.query.clone(),
+            fragment: relative.fragment.clone(),
+        };
+
+        if relative.host.is_some() {
+            // a network path, "//g", only keeps the scheme of the base
+            target.authority = relative.authority.clone();
+            target.host = relative.host.clone();
+            target.port = relative.port;
+            target.path = relative.path.clone().map(remove_dot_segments);
+            target.query = relative.query.clone();
+        } else if let Some(path) = &relative.path {
+            target.path = Some(match path {
+                Path::Absolute(_) => remove_dot_segments(path.clone()),
+                Path::Relative(segments) => remove_dot_segments(self.merge(segments)),
+            });
+            target.query = relative.query.clone();
+        } else if relative.query.is_some() {
+            // "?y" only replaces the query
+            target.query = relative.query.clone();
+        }
+
+        // "foo:/a/b" joined with "..//c" gives the path "//c", written "/.//c"
+        if target.host.is_none() {
+            target.path = target.path.map(Path::without_authority);
+        }
+        target
+    }
+
+    // Appends a relative path to the directory of the base path:
+    // "/b/c/d;p" merged with "g/h" gives "/b/c/g/h"
+    fn merge<'b>(&self, segments: &Cow<'b, str>) -> Path<'b>
+    where
+        'a: 'b,
+    {
+        match &self.path {
+            // with an authority and no path, the path is "/"
+            None if self.host.is_some() => Path::Absolute(segments.clone()),
+            None => Path::Relative(segments.clone()),
+            Some(base) => {
+                // everything but the last segment, which is the "file name"
+                let base = base.as_str();
+                let directory = base.rfind('/').map_or("", |end| &base[..=end]);
+                let merged = format!("{}{}", directory, segments);
+                self.path
+                    .as_ref()
+                    .unwrap()
+                    .with_segments(Cow::Owned(merged))
+            }
+        }
+    }
+}
+
+/// Interprets the "." and ".." segments of a path:
+/// "/a/b/c/./../../g" gives "/a/g", "/a/b/.." gives "/a/"
+pub fn remove_dot_segments(path: Path<'_>) -> Path<'_> {
+    remove_dots(path, |segment| match segment {
+        "." => Some(Dot::Current),
+        ".." => Some(Dot::Parent),
+        _ => None,
+    })
+}
+
+// The same, with the dot segments that browsers see: "%2e" is "." and "%2E%2e" is ".."
+pub(crate) fn remove_whatwg_dot_segments(path: Path<'_>) -> Path<'_> {
+    remove_dots(path, |segment| {
+        let is_dot = |part: &str| part == "." || part.eq_ignore_ascii_case("%2e");
+        match segment.len() {
+            1 | 3 if is_dot(segment) => Some(Dot::Current),
+            2 | 4 | 6 => (1..segment.len())
+                .filter(|&at| segment.is_char_boundary(at))
+                .any(|at| is_dot(&segment[..at]) && is_dot(&segment[at..]))
+                .then_some(Dot::Parent),
+            _ => None,
+        }
+    })
+}
+
+enum Dot {
+    Current,
+    Parent,
+}
+
+fn remove_dots(path: Path<'_>, dot: impl Fn(&str) -> Option<Dot>) -> Path<'_> {
+    if !path.segments().any(|segment| dot(segment).is_some()) {
+        return path;
+    }
+
+    let mut output: Vec<&str> = Vec::new();
+    let last = path.segments().count() - 1;
+    for (i, segment) in path.segments().enumerate() {
+        match dot(segment) {
+            Some(Dot::Current) => {}
+            Some(Dot::Parent) => {
+                output.pop();
+            }
+            None => {
+                output.push(segment);
+                continue;
+            }
+        }
+        // "/a/." and "/a/b/.." both designate the directory "/a/"
+        if i == last {
+            output.push("");
+        }
+    }
+
+    let output = Cow::Owned(output.join("/"));
+    path.with_segments(output)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::uri::{reference::uri_reference_parser, uri_parser};
+
+    fn assert_resolves(base: &str, examples: &[(&str, &str)]) {
+        let (_, base) = uri_parser(base).unwrap();
+        for (reference, expected) in examples {
+            let (rest, parsed_reference) = uri_reference_parser(reference).unwrap();
+            assert_eq!(rest, "", "{} should be fully parsed", reference);
+            let (rest, expected_uri) = uri_parser(expected).unwrap();
+            assert_eq!(rest, "", "{} should be fully parsed", expected);
+
+            assert_eq!(
+                base.join(&parsed_reference),
+                expected_uri,
+                "{} should resolve to {}",
+                reference,
+                expected
+            );
+        }
+    }
+
+    // RFC 3986, section 5.4.1
+    #[test]
+    fn test_normal_examples() {
+        assert_resolves(
+            "http://a/b/c/d;p?q",
+            &[
+                ("g:h", "g:h"),
+                ("g", "http://a/b/c/g"),
+                ("./g", "http://a/b/c/g"),
+                ("g/", "http://a/b/c/g/"),
+                ("/g", "http://a/g"),
+                ("//g", "http://g"),
+                ("?y", "http://a/b/c/d;p?y"),
+                ("g?y", "http://a/b/c/g?y"),
+                ("#s", "http://a/b/c/d;p?q#s"),
+                ("g#s", "http://a/b/c/g#s"),
+                ("g?y#s", "http://a/b/c/g?y#s"),
+                (";x", "http://a/b/c/;x"),
+                ("g;x", "http://a/b/c/g;x"),
+                ("g;x?y#s", "http://a/b/c/g;x?y#s"),
+                ("", "http://a/b/c/d;p?q"),
+                (".", "http://a/b/c/"),
+                ("./", "http://a/b/c/"),
+                ("..", "http://a/b/"),
+                ("../", "http://a/b/"),
+                ("../g", "http://a/b/g"),
+                ("../..", "http://a/"),
+                ("../../", "http://a/"),
+                ("../../g", "http://a/g"),
+            ],
+        );
+    }
+
+    // RFC 3986, section 5.4.2
+    #[test]
+    fn test_abnormal_examples() {
+        assert_resolves(
+            "http://a/b/c/d;p?q",
+            &[
+                ("../../../g", "http://a/g"),
+                ("../../../../g", "http://a/g"),
+                ("/./g", "http://a/g"),
+                ("/../g", "http://a/g"),
+                ("g.", "http://a/b/c/g."),
+                (".g", "http://a/b/c/.g"),
+                ("g..", "http://a/b/c/g.."),
+                ("..g", "http://a/b/c/..g"),
+                ("./../g", "http://a/b/g"),
+                ("./g/.", "http://a/b/c/g/"),
+                ("g/./h", "http://a/b/c/g/h"),
+                ("g/../h", "http://a/b/c/h"),
+                ("g;x=1/./y", "http://a/b/c/g;x=1/y"),
+                ("g;x=1/../y", "http://a/b/c/y"),
+                ("g?y/./x", "http://a/b/c/g?y/./x"),
+                ("g?y/../x", "http://a/b/c/g?y/../x"),
+                ("g#s/./x", "http://a/b/c/g#s/./x"),
+                ("g#s/../x", "http://a/b/c/g#s/../x"),
+                // strict parsers take the scheme as is
+                ("http:g", "http:g"),
+            ],
+        );
+    }
+
+    #[test]
+    fn test_base_without_path() {
+        assert_resolves(
+            "http://example.org",
+            &[
+                ("a/b", "http://example.org/a/b"),
+                ("../a", "http://example.org/a"),
+                ("?page=2", "http://example.org?page=2"),
+            ],
+        );
+    }
+
+    #[test]
+    fn test_base_without_authority() {
+        // "//c" would be read as a host, the result keeps a "." segment
+        assert_resolves(
+            "foo:/a/b",
+            &[
+                ("..//c", "foo:/.//c"),
+                ("/.//c", "foo:/.//c"),
+                ("foo:/a/..//c", "foo:/.//c"),
+                ("c", "foo:/a/c"),
+            ],
+        );
+        let (_, base) = uri_parser("foo:/a/b").unwrap();
+        let (_, reference) = uri_reference_parser("..//c").unwrap();
+        let joined = base.join(&reference);
+        assert_eq!(uri_parser(&joined.to_string()), Ok(("", joined)));
+    }
+
+    #[test]
+    fn test_remove_dot_segments() {
+        assert_eq!(
+            remove_dot_segments(Path::absolute(vec!["a", "b", "c", ".", "..", "..", "g"])),
+            Path::absolute(vec!["a", "g"])
+        );
+        assert_eq!(
+            remove_dot_segments(Path::relative(vec!["mid", "content=5", "..", "6"])),
+            Path::relative(vec!["mid", "6"])
+        );
+        assert_eq!(
+            remove_dot_segments(Path::absolute(vec![".."])),
+            Path::absolute(vec![""])
+        );
+        assert_eq!(
+            remove_dot_segments(Path::absolute(vec!["a", "b", ".."])),
+            Path::absolute(vec!["a", ""])
+        );
+        assert_eq!(
+            remove_dot_segments(Path::absolute(vec!["a", "%2e", "b"])),
+            Path::absolute(vec!["a", "%2e", "b"])
+        );
+        assert_eq!(
+            remove_whatwg_dot_segments(Path::absolute(vec![
+                "a", "b", "%2e", "%2E%2e", ".%2E", "c"
+            ])),
+            Path::absolute(vec!["c"])
+        );
+        assert_eq!(
+            remove_whatwg_dot_segments(Path::absolute(vec!["a", "%2e."])),
+            Path::absolute(vec![""])
+        );
+        assert_eq!(
+            remove_whatwg_dot_segments(Path::absolute(vec!["a", "%2e%2f", "..."])),
+            Path::absolute(vec!["a", "%2e%2f", "..."])
+        );
+    }
+}
@@ -1,65 +1,216 @@
-use nom::{self, bytes::complete::tag_no_case, error::context};
+use std::{borrow::Cow, convert::TryFrom, error::Error, fmt};
 
-use crate::uri::CustomResult;
+use nom::{
+    self,
+    bytes::complete::{tag, take_while},
+    character::complete::satisfy,
+    combinator::{map_res, recognize},
+    error::context,
+    sequence::{pair, terminated},
+};
 
-/// The scheme is the beginning of a URI, either http or https
-#[derive(Debug, PartialEq, Eq)]
-pub enum Scheme {
+use crate::uri::{
+    error::{ParseError, UriError},
+    CustomResult,
+};
+
+/// The scheme is the beginning of a URI, like http, https or ftp
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Scheme<'a> {
     HTTP,
     HTTPS,
+    /// Any other scheme, always lowercase: "ftp", "git+ssh", "s3"...
+    /// It is only copied when the input has uppercase letters.
+    Other(Cow<'a, str>),
 }
 
-impl From<&str> for Scheme {
-    fn from(input: &str) -> Self {
-        match input.to_lowercase().as_str() {
-            "http://" => Scheme::HTTP,
-            "https://" => Scheme::HTTPS,
-            _ => unimplemented!("No other schemes supported"),
+impl Scheme<'_> {
+    pub fn as_str(&self) -> &str {
+        match self {
+            Scheme::HTTP => "http",
+            Scheme::HTTPS => "https",
+            Scheme::Other(scheme) => scheme,
+        }
+    }
+
+    /// The port used when none is given, for the schemes we know about
+    pub fn default_port(&self) -> Option<u16> {
+        match self.as_str() {
+            "http" | "ws" => Some(80),
+            "https" | "wss" => Some(443),
+            "ftp" => Some(21),
+            _ => None,
+        }
+    }
+
+    /// The schemes the WHATWG URL standard handles apart: http, https, ws, wss, ftp and file
+    pub fn is_special(&self) -> bool {
+        matches!(
+            self.as_str(),
+            "http" | "https" | "ws" | "wss" | "ftp" | "file"
+        )
+    }
+
+    /// Copies the name of the scheme, to outlive the input
+    pub fn into_owned(self) -> Scheme<'static> {
+        match self {
+            Scheme::HTTP => Scheme::HTTP,
+            Scheme::HTTPS => Scheme::HTTPS,
+            Scheme::Other(scheme) => Scheme::Other(Cow::Owned(scheme.into_owned())),
+        }
+    }
+
+    // The same scheme, borrowing the name instead of copying it
+    pub(crate) fn by_ref(&self) -> Scheme<'_> {
+        match self {
+            Scheme::HTTP => Scheme::HTTP,
+            Scheme::HTTPS => Scheme::HTTPS,
+            Scheme::Other(scheme) => Scheme::Other(Cow::Borrowed(scheme)),
         }
     }
 }
 
+impl fmt::Display for Scheme<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Returned when a string does not follow the scheme grammar
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct InvalidScheme(pub String);
+
+impl fmt::Display for InvalidScheme {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid scheme: {:?}", self.0)
+    }
+}
+
+impl Error for InvalidScheme {}
+
+// scheme = ALPHA *( ALPHA / DIGIT / "+" / "-" / "." )
+fn is_scheme_char(c: char) -> bool {
+    c.is_ascii_alphanumeric() || c == '+' || c == '-' || c == '.'
+}
+
+/// Converts a scheme name, like "https" or "Git+SSH", without the ":"
+impl<'a> TryFrom<&'a str> for Scheme<'a> {
+    type Error = InvalidScheme;
+
+    fn try_from(input: &'a str) -> Result<Self, Self::Error> {
+        let mut chars = input.chars();
+        let starts_with_letter = chars.next().is_some_and(|c| c.is_ascii_alphabetic());
+        if !starts_with_letter || !chars.all(is_scheme_char) {
+            return Err(InvalidScheme(input.to_string()));
+        }
+
+        // schemes are case-insensitive, we keep the lowercase form
+        Ok(if input.eq_ignore_ascii_case("http") {
+            Scheme::HTTP
+        } else if input.eq_ignore_ascii_case("https") {
+            Scheme::HTTPS
+        } else if input.bytes().any(|b| b.is_ascii_uppercase()) {
+            Scheme::Other(Cow::Owned(input.to_ascii_lowercase()))
+        } else {
+            Scheme::Other(Cow::Borrowed(input))
+        })
+    }
+}
+
 pub fn scheme_parser(
     input: &str, // the input will be an URI
 ) -> CustomResult<
-    &str,   // the rest of the unparsed URI, same type as the input
-    Scheme, // the scheme of the URI
+    &str,       // the rest of the unparsed URI, same type as the input
+    Scheme<'_>, // the scheme of the URI, borrowed from the input
 > {
     context(
         "scheme parsing error",
-        nom::branch::alt((
-            tag_no_case("HTTP://"),  // either one of those parser is chosen
-            tag_no_case("HTTPS://"), // tag_no_case means: "parse"
-        )),
+        terminated(
+            map_res(
+                // recognize returns the consumed slice instead of the parsers' output
+                recognize(pair(
+                    satisfy(|c| c.is_ascii_alphabetic()), // a scheme starts with a letter
+                    take_while(is_scheme_char),
+                )),
+                // this converts "http" into Scheme::HTTP
+                Scheme::try_from,
+            ),
+            tag(":"), // the scheme ends with a colon
+        ),
     )(input)
-    // this line is just here to convert "http" into Sheme::HTTP
-    .map(|(next_input, scheme_str)| (next_input, scheme_str.into()))
+    .map_err(|e| {
+        e.map(|e: UriError<&str>| {
+            // the culprit is the scheme as far as it goes
+            let length = input
+                .find(|c: char| !(c.is_ascii_alphanumeric() || "+-.".contains(c)))
+                .unwrap_or(input.len());
+            let span = 0..length.max(1).min(input.len());
+            e.explain(input, ParseError::InvalidScheme { span })
+        })
+    })
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use nom::{
-        error::{ErrorKind, VerboseError, VerboseErrorKind},
-        Err as NomErr,
-    };
+    use nom::Err as NomErr;
 
     #[test]
     fn test_scheme_parser() {
-        assert_eq!(scheme_parser("https://yay"), Ok(("yay", Scheme::HTTPS)));
-        assert_eq!(scheme_parser("http://yay"), Ok(("yay", Scheme::HTTP)));
+        assert_eq!(scheme_parser("https://yay"), Ok(("//yay", Scheme::HTTPS)));
+        assert_eq!(scheme_parser("http://yay"), Ok(("//yay", Scheme::HTTP)));
+        assert_eq!(scheme_parser("HTTP://yay"), Ok(("//yay", Scheme::HTTP)));
         assert_eq!(
             scheme_parser("bla://yay"),
-            Err(NomErr::Error(VerboseError {
-                errors: vec![
-                    ("bla://yay", VerboseErrorKind::Nom(ErrorKind::Tag)),
-                    ("bla://yay", VerboseErrorKind::Nom(ErrorKind::Alt)),
-                    (
-                        "bla://yay",
-                        VerboseErrorKind::Context("scheme parsing error")
-                    ),
-                ]
-            }))
+            Ok(("//yay", Scheme::Other("bla".into())))
+        );
+        assert_eq!(
+            scheme_parser("Git+SSH://yay"),
+            Ok(("//yay", Scheme::Other("git+ssh".into())))
+        );
+        assert_eq!(
+            scheme_parser("s3://bucket"),
+            Ok(("//bucket", Scheme::Other("s3".into())))
+        );
+        assert_eq!(
+            scheme_parser("1bla://yay"),
+            Err(NomErr::Error(
+                UriError::new("1bla://yay", ParseError::InvalidScheme { span: 0..4 })
+                    .with_contexts(&["scheme parsing error"])
+            ))
+        );
+        assert_eq!(
+            scheme_parser("bl_a://yay"),
+            Err(NomErr::Error(
+                UriError::new("bl_a://yay", ParseError::InvalidScheme { span: 0..2 })
+                    .with_contexts(&["scheme parsing error"])
+            ))
+        );
+    }
+
+    #[test]
+    fn test_default_port() {
+        assert_eq!(Scheme::HTTP.default_port(), Some(80));
+        assert_eq!(Scheme::HTTPS.default_port(), Some(443));
+        assert_eq!(Scheme::Other("wss".into()).default_port(), Some(443));
+        assert_eq!(Scheme::Other("git+ssh".into()).default_port(), None);
+    }
+
+    #[test]
+    fn test_is_special() {
+        assert!(Scheme::HTTPS.is_special());
+        assert!(Scheme::Other("file".into()).is_special());
+        assert!(!Scheme::Other("mailto".into()).is_special());
+    }
+
+    #[test]
+    fn test_scheme_try_from() {
+        assert_eq!(Scheme::try_from("wss"), Ok(Scheme::Other("wss".into())));
+        assert_eq!(Scheme::try_from("HTTPS"), Ok(Scheme::HTTPS));
+        assert_eq!(
+            Scheme::try_from("http://"),
+            Err(InvalidScheme("http://".to_string()))
         );
+        assert_eq!(Scheme::try_from(""), Err(InvalidScheme("".to_string())));
     }
 }
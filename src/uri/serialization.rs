@@ -0,0 +1,632 @@
+//! Serde support, behind the "serde" feature.
+//!
+//! A URI and its components are written as their canonical string, and read
+//! back with the parsers, so that a config file can not hold an invalid URI.
+//! `Structured` writes a URI or a userinfo as a map of its components instead.
+//!
+//! ```
+//! use uri_parser::{Structured, URI};
+//!
+//! let uri = URI::parse("https://user@example.org:8080/a?b=c").unwrap();
+//! assert_eq!(
+//!     serde_json::to_string(&uri).unwrap(),
+//!     r#""https://user@example.org:8080/a?b=c""#
+//! );
+//! assert_eq!(
+//!     serde_json::to_string(&Structured(uri)).unwrap(),
+//!     r#"{"scheme":"https","userinfo":"user","host":"example.org","port":8080,"path":"/a","query":"b=c"}"#
+//! );
+//!
+//! assert!(serde_json::from_str::<URI>(r#""http://example.org:99999""#).is_err());
+//! ```
+
+use std::{borrow::Cow, convert::TryFrom, fmt};
+
+use serde::{
+    de::{self, MapAccess, SeqAccess, Visitor},
+    ser::SerializeStruct,
+    Deserialize, Deserializer, Serialize, Serializer,
+};
+
+use crate::uri::{
+    authority::{authority_parser, Authority},
+    builder::parse_host,
+    host::Host,
+    path::{path_parser, Path},
+    path_without_authority_parser,
+    query::{fragment_parser, query_pairs, query_parser, QueryParam},
+    scheme::Scheme,
+    write_path, CustomResult, URI,
+};
+
+/// Writes a URI or a userinfo as a map of its components, rather than as a string
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Structured<T>(pub T);
+
+// A string borrowed from the input when the format allows it, to parse it without copy
+enum Text<'de> {
+    Borrowed(&'de str),
+    Owned(String),
+}
+
+impl Text<'_> {
+    fn as_str(&self) -> &str {
+        match self {
+            Text::Borrowed(text) => text,
+            Text::Owned(text) => text,
+        }
+    }
+}
+
+struct TextVisitor(&'static str);
+
+impl<'de> Visitor<'de> for TextVisitor {
+    type Value = Text<'de>;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.0)
+    }
+
+    fn visit_borrowed_str<E: de::Error>(self, text: &'de str) -> Result<Self::Value, E> {
+        Ok(Text::Borrowed(text))
+    }
+
+    fn visit_str<E: de::Error>(self, text: &str) -> Result<Self::Value, E> {
+        Ok(Text::Owned(text.to_string()))
+    }
+
+    fn visit_string<E: de::Error>(self, text: String) -> Result<Self::Value, E> {
+        Ok(Text::Owned(text))
+    }
+}
+
+fn deserialize_text<'de, D: Deserializer<'de>>(
+    deserializer: D,
+    expecting: &'static str,
+) -> Result<Text<'de>, D::Error> {
+    deserializer.deserialize_str(TextVisitor(expecting))
+}
+
+impl Serialize for URI<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+/// The URI is owned, so that `URI<'static>` can be read from any source
+impl<'de, 'a> Deserialize<'de> for URI<'a> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let text = deserialize_text(deserializer, "a URI")?;
+        URI::parse(text.as_str())
+            .map(URI::into_owned)
+            .map_err(de::Error::custom)
+    }
+}
+
+impl Serialize for Scheme<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de, 'a> Deserialize<'de> for Scheme<'a> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let text = deserialize_text(deserializer, "a scheme")?;
+        Scheme::try_from(text.as_str())
+            .map(Scheme::into_owned)
+            .map_err(de::Error::custom)
+    }
+}
+
+impl Serialize for Host<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+/// An IPv6 may be written with or without brackets, "::1" or "[::1]"
+impl<'de, 'a> Deserialize<'de> for Host<'a> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let text = deserialize_text(deserializer, "a host")?;
+        parse_host(text.as_str()).map_err(de::Error::custom)
+    }
+}
+
+/// Written "user:password", as in the URI
+impl Serialize for Authority<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+fn parse_authority<E: de::Error>(userinfo: &str) -> Result<Authority<'static>, E> {
+    // the parser reads the userinfo up to the "@"
+    let terminated = format!("{}@", userinfo);
+    match authority_parser(&terminated) {
+        Ok(("", authority)) => Ok(authority.into_owned()),
+        _ => Err(E::custom(format!("invalid userinfo: {:?}", userinfo))),
+    }
+}
+
+impl<'de, 'a> Deserialize<'de> for Authority<'a> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let text = deserialize_text(deserializer, "a userinfo")?;
+        parse_authority(text.as_str())
+    }
+}
+
+/// Written "key=value", or "key" for a flag
+impl Serialize for QueryParam<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de, 'a> Deserialize<'de> for QueryParam<'a> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let text = deserialize_text(deserializer, "a query parameter")?;
+        let text = text.as_str();
+        let query = format!("?{}", text);
+        let mut pairs = query_pairs(text);
+        match (query_parser(&query), pairs.next(), pairs.next()) {
+            (Ok(("", _)), Some(param), None) => Ok(QueryParam {
+                key: Cow::Owned(param.key.into_owned()),
+                value: param.value.map(|value| Cow::Owned(value.into_owned())),
+            }),
+            _ => Err(de::Error::custom(format!(
+                "invalid query parameter: {:?}",
+                text
+            ))),
+        }
+    }
+}
+
+// The path of a URI, as it is written in the URI
+struct UriPath<'p> {
+    path: &'p Path<'p>,
+    after_authority: bool,
+}
+
+impl fmt::Display for UriPath<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_path(f, self.path, self.after_authority)
+    }
+}
+
+// A component that is missing is left out of a map, but formats like bincode
+// read the fields by position: there, every field is written as an option
+fn serialize_component<M, T>(
+    map: &mut M,
+    key: &'static str,
+    value: Option<T>,
+    compact: bool,
+) -> Result<(), M::Error>
+where
+    M: SerializeStruct,
+    T: Serialize,
+{
+    match value {
+        _ if compact => map.serialize_field(key, &value),
+        Some(value) => map.serialize_field(key, &value),
+        None => map.skip_field(key),
+    }
+}
+
+/// The components that are missing are left out of the map, in the formats that
+/// are human-readable
+impl Serialize for Structured<URI<'_>> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let uri = &self.0;
+        let compact = !serializer.is_human_readable();
+        let mut map = serializer.serialize_struct("URI", 7)?;
+        map.serialize_field("scheme", uri.scheme())?;
+        let path = uri.path().map(|path| {
+            UriPath {
+                path,
+                after_authority: uri.host().is_some(),
+            }
+            .to_string()
+        });
+        serialize_component(&mut map, "userinfo", uri.userinfo(), compact)?;
+        serialize_component(&mut map, "host", uri.host(), compact)?;
+        serialize_component(&mut map, "port", uri.port(), compact)?;
+        serialize_component(&mut map, "path", path, compact)?;
+        serialize_component(&mut map, "query", uri.query(), compact)?;
+        serialize_component(&mut map, "fragment", uri.fragment(), compact)?;
+        map.end()
+    }
+}
+
+const URI_FIELDS: &[&str] = &[
+    "scheme", "userinfo", "host", "port", "path", "query", "fragment",
+];
+
+struct StructuredUriVisitor;
+
+impl<'de> Visitor<'de> for StructuredUriVisitor {
+    type Value = URI<'static>;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("a map of the components of a URI")
+    }
+
+    // the fields in order, each of them but the scheme being an option
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let mut texts: [Option<String>; 7] = Default::default();
+        let mut port: Option<u16> = None;
+        for index in 0..URI_FIELDS.len() {
+            let missing = || de::Error::invalid_length(index, &"7 components");
+            match index {
+                0 => texts[0] = Some(seq.next_element()?.ok_or_else(missing)?),
+                3 => port = seq.next_element()?.ok_or_else(missing)?,
+                _ => texts[index] = seq.next_element()?.ok_or_else(missing)?,
+            }
+        }
+        build_uri(texts, port)
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+        let mut texts: [Option<String>; 7] = Default::default();
+        let mut port: Option<u16> = None;
+        while let Some(key) = map.next_key::<String>()? {
+            let index = URI_FIELDS
+                .iter()
+                .position(|field| *field == key)
+                .ok_or_else(|| de::Error::unknown_field(&key, URI_FIELDS))?;
+            if index == 3 {
+                port = Some(map.next_value()?);
+            } else {
+                texts[index] = Some(map.next_value()?);
+            }
+        }
+        build_uri(texts, port)
+    }
+}
+
+// A field read whole by the parser of its component, the text being written
+// the way it is in a URI: with the "?" of a query for instance.
+// The error names the field, so that no field can carry another one.
+fn parse_field<'t, T, E: de::Error>(
+    field: &str,
+    text: &'t str,
+    mut parser: impl FnMut(&'t str) -> CustomResult<&'t str, T>,
+) -> Result<T, E> {
+    match parser(text) {
+        Ok(("", parsed)) => Ok(parsed),
+        _ => Err(de::Error::custom(format_args!(
+            "invalid {}: {:?}",
+            field, text
+        ))),
+    }
+}
+
+// Each component is checked by its own parser, then put in the URI
+fn build_uri<E: de::Error>(
+    texts: [Option<String>; 7],
+    port: Option<u16>,
+) -> Result<URI<'static>, E> {
+    let [scheme, userinfo, host, _, path, query, fragment] = texts;
+    let scheme = scheme.ok_or_else(|| de::Error::missing_field("scheme"))?;
+    let scheme = Scheme::try_from(scheme.as_str())
+        .map_err(|_| de::Error::custom(format_args!("invalid scheme: {:?}", scheme)))?
+        .into_owned();
+
+    let host = match host {
+        Some(host) => Some(
+            parse_host(&host)
+                .map_err(|_| de::Error::custom(format_args!("invalid host: {:?}", host)))?,
+        ),
+        None if userinfo.is_some() || port.is_some() => {
+            return Err(de::Error::custom("a userinfo or a port requires a host"))
+        }
+        None => None,
+    };
+    let authority = userinfo.as_deref().map(parse_authority).transpose()?;
+
+    // after a host, the path starts with a slash
+    let path = match path.as_deref() {
+        None | Some("") => None,
+        Some(path) if host.is_some() => Some(parse_field("path", path, path_parser)?),
+        Some(path) => parse_field("path", path, path_without_authority_parser)?.3,
+    };
+    let path = path.map(Path::into_owned);
+
+    let query = match query {
+        Some(query) => {
+            let written = format!("?{}", query);
+            parse_field("query", &written, query_parser)?;
+            Some(Cow::Owned(query))
+        }
+        None => None,
+    };
+    let fragment = match fragment {
+        Some(fragment) => {
+            let written = format!("#{}", fragment);
+            parse_field("fragment", &written, fragment_parser)?;
+            Some(Cow::Owned(fragment))
+        }
+        None => None,
+    };
+
+    Ok(URI {
+        scheme,
+        authority,
+        host,
+        port,
+        path,
+        query,
+        fragment,
+    })
+}
+
+impl<'de, 'a> Deserialize<'de> for Structured<URI<'a>> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer
+            .deserialize_struct("URI", URI_FIELDS, StructuredUriVisitor)
+            .map(Structured)
+    }
+}
+
+/// Written {"user": "...", "password": "..."}, without the password if there is none
+impl Serialize for Structured<Authority<'_>> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let compact = !serializer.is_human_readable();
+        let mut map = serializer.serialize_struct("Authority", 2)?;
+        map.serialize_field("user", &self.0.user)?;
+        serialize_component(&mut map, "password", self.0.password.as_ref(), compact)?;
+        map.end()
+    }
+}
+
+const AUTHORITY_FIELDS: &[&str] = &["user", "password"];
+
+struct StructuredAuthorityVisitor;
+
+impl<'de> Visitor<'de> for StructuredAuthorityVisitor {
+    type Value = Authority<'static>;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("a map with a user and a password")
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let user: String = seq
+            .next_element()?
+            .ok_or_else(|| de::Error::invalid_length(0, &"a user and a password"))?;
+        let password: Option<String> = seq
+            .next_element()?
+            .ok_or_else(|| de::Error::invalid_length(1, &"a user and a password"))?;
+        build_authority(user, password)
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+        let mut user: Option<String> = None;
+        let mut password: Option<String> = None;
+        while let Some(key) = map.next_key::<String>()? {
+            match key.as_str() {
+                "user" => user = Some(map.next_value()?),
+                "password" => password = Some(map.next_value()?),
+                _ => return Err(de::Error::unknown_field(&key, AUTHORITY_FIELDS)),
+            }
+        }
+        let user = user.ok_or_else(|| de::Error::missing_field("user"))?;
+        build_authority(user, password)
+    }
+}
+
+fn build_authority<E: de::Error>(
+    user: String,
+    password: Option<String>,
+) -> Result<Authority<'static>, E> {
+    // a ":" in the user would be read as the start of the password
+    if user.contains(':') {
+        return Err(de::Error::custom(format!("invalid user: {:?}", user)));
+    }
+    match password {
+        Some(password) => parse_authority(&format!("{}:{}", user, password)),
+        None => parse_authority(&user),
+    }
+}
+
+impl<'de, 'a> Deserialize<'de> for Structured<Authority<'a>> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer
+            .deserialize_struct("Authority", AUTHORITY_FIELDS, StructuredAuthorityVisitor)
+            .map(Structured)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::BTreeMap;
+
+    #[test]
+    fn test_string() {
+        let uri = URI::parse("https://user:pw@[::1]:8080/a/b?c=d#e").unwrap();
+        let json = serde_json::to_string(&uri).unwrap();
+        assert_eq!(json, r#""https://user:pw@[::1]:8080/a/b?c=d#e""#);
+        assert_eq!(serde_json::from_str::<URI>(&json).unwrap(), uri);
+
+        let uri: URI = serde_json::from_str(r#""http:\/\/example.org\/a""#).unwrap();
+        assert_eq!(uri.to_string(), "http://example.org/a");
+
+        let error = serde_json::from_str::<URI>(r#""http://example.org:99999""#).unwrap_err();
+        assert_eq!(error.to_string(), "port out of range, at bytes 19..24");
+
+        assert_eq!(serde_json::to_string(&Scheme::HTTPS).unwrap(), r#""https""#);
+        assert_eq!(
+            serde_json::from_str::<Scheme>(r#""Git+SSH""#).unwrap(),
+            Scheme::Other("git+ssh".into())
+        );
+        assert!(serde_json::from_str::<Scheme>(r#""1http""#).is_err());
+
+        assert_eq!(
+            serde_json::from_str::<Host>(r#""::1""#).unwrap(),
+            Host::IPV6 {
+                address: [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1],
+                zone: None
+            }
+        );
+        assert_eq!(
+            serde_json::to_string(&Host::IP([127, 0, 0, 1])).unwrap(),
+            r#""127.0.0.1""#
+        );
+        assert!(serde_json::from_str::<Host>(r#""exa mple.org""#).is_err());
+
+        let authority: Authority = serde_json::from_str(r#""user:p%40ss""#).unwrap();
+        assert_eq!(authority.decoded_password().as_deref(), Some("p@ss"));
+        assert!(serde_json::from_str::<Authority>(r#""us@er""#).is_err());
+
+        let param: QueryParam = serde_json::from_str(r#""q=a+b""#).unwrap();
+        assert_eq!(param, QueryParam::new("q", "a+b"));
+        assert_eq!(serde_json::to_string(&param).unwrap(), r#""q=a+b""#);
+        assert!(serde_json::from_str::<QueryParam>(r#""a=1&b=2""#).is_err());
+        assert!(serde_json::from_str::<QueryParam>(r#""a=1#b""#).is_err());
+    }
+
+    #[test]
+    fn test_structured() {
+        for input in [
+            "https://user:pw@example.org:8080/a/b?c=d#e",
+            "mailto:someone@example.org",
+            "file:/.//a",
+            "http://example.org?",
+        ] {
+            let uri = URI::parse(input).unwrap();
+            let json = serde_json::to_string(&Structured(uri.clone())).unwrap();
+            let read: Structured<URI> = serde_json::from_str(&json).unwrap();
+            assert_eq!(read.0, uri, "{}", json);
+        }
+
+        // the components are checked by the parser
+        assert!(
+            serde_json::from_str::<Structured<URI>>(r#"{"scheme":"http","host":"0x7f.1"}"#)
+                .is_err()
+        );
+        assert!(serde_json::from_str::<Structured<URI>>(r#"{"scheme":"http","port":80}"#).is_err());
+        assert!(serde_json::from_str::<Structured<URI>>(r#"{"host":"a.org"}"#).is_err());
+
+        // no field can carry another one
+        for (json, message) in [
+            (r#"{"scheme":"http","host":"a.org:8080"}"#, "invalid host"),
+            (
+                r#"{"scheme":"http","host":"evil.org#","path":"/a"}"#,
+                "invalid host",
+            ),
+            (
+                r#"{"scheme":"http","host":"a.org/admin?x=1"}"#,
+                "invalid host",
+            ),
+            (
+                r#"{"scheme":"http","userinfo":"a@b.org"}"#,
+                "a userinfo or a port requires a host",
+            ),
+            (
+                r#"{"scheme":"http","host":"a.org","userinfo":"a@b"}"#,
+                "invalid userinfo",
+            ),
+            (
+                r#"{"scheme":"http","host":"a.org","path":"?q=1"}"#,
+                "invalid path",
+            ),
+            (r#"{"scheme":"mailto","path":"a?q=1"}"#, "invalid path"),
+            (r#"{"scheme":"http","path":"//a.org"}"#, "invalid path"),
+            (
+                r#"{"scheme":"http","host":"a.org","query":"a#b"}"#,
+                "invalid query",
+            ),
+            (
+                r#"{"scheme":"http","host":"a.org","fragment":"a#b"}"#,
+                "invalid fragment",
+            ),
+        ] {
+            let error = serde_json::from_str::<Structured<URI>>(json).unwrap_err();
+            assert!(
+                error.to_string().starts_with(message),
+                "{}: {}",
+                json,
+                error
+            );
+        }
+
+        let authority = Authority {
+            user: "user".into(),
+            password: Some("pw".into()),
+        };
+        let json = serde_json::to_string(&Structured(authority.clone())).unwrap();
+        assert_eq!(json, r#"{"user":"user","password":"pw"}"#);
+        let read: Structured<Authority> = serde_json::from_str(&json).unwrap();
+        assert_eq!(read.0, authority);
+        assert!(serde_json::from_str::<Structured<Authority>>(r#"{"user":"a:b"}"#).is_err());
+    }
+
+    #[test]
+    fn test_structured_sequence() {
+        // bincode reads the fields by position, every one of them being written
+        for input in [
+            "https://user:pw@example.org:8080/a/b?c=d#e",
+            "mailto:someone@example.org",
+            "file:/.//a",
+            "http://example.org?",
+        ] {
+            let uri = URI::parse(input).unwrap();
+            let bytes = bincode::serialize(&Structured(uri.clone())).unwrap();
+            let read: Structured<URI> = bincode::deserialize(&bytes).unwrap();
+            assert_eq!(read.0, uri, "{}", input);
+        }
+        let bytes = bincode::serialize(&Structured(URI::parse("http://a.org").unwrap())).unwrap();
+        assert!(bincode::deserialize::<Structured<URI>>(&bytes[..bytes.len() - 1]).is_err());
+
+        for password in [Some("pw".into()), None] {
+            let authority = Authority {
+                user: "user".into(),
+                password,
+            };
+            let bytes = bincode::serialize(&Structured(authority.clone())).unwrap();
+            let read: Structured<Authority> = bincode::deserialize(&bytes).unwrap();
+            assert_eq!(read.0, authority);
+        }
+
+        // a struct may also be written as an array in JSON
+        let read: Structured<URI> =
+            serde_json::from_str(r#"["http",null,"a.org",8080,"/b",null,null]"#).unwrap();
+        assert_eq!(read.0.to_string(), "http://a.org:8080/b");
+        assert!(serde_json::from_str::<Structured<URI>>(r#"["http",null,"a.org"]"#).is_err());
+        let read: Structured<Authority> = serde_json::from_str(r#"["user",null]"#).unwrap();
+        assert_eq!(read.0.user, "user");
+    }
+
+    #[test]
+    fn test_toml_config() {
+        let endpoints: BTreeMap<String, URI> = toml::from_str(
+            r#"
+            api = "https://api.example.org/v1"
+            backup = "https://backup.example.org:8443/v1"
+            "#,
+        )
+        .unwrap();
+        assert_eq!(
+            endpoints["api"].host(),
+            Some(&Host::HOST("api.example.org".into()))
+        );
+        assert_eq!(endpoints["backup"].port(), Some(8443));
+
+        let endpoints: BTreeMap<String, Structured<URI>> = toml::from_str(
+            r#"
+            [backup]
+            scheme = "https"
+            host = "backup.example.org"
+            port = 8443
+            path = "/v1"
+            "#,
+        )
+        .unwrap();
+        assert_eq!(
+            endpoints["backup"].0.to_string(),
+            "https://backup.example.org:8443/v1"
+        );
+
+        assert!(toml::from_str::<BTreeMap<String, URI>>(r#"api = "https://a.org:99999""#).is_err());
+    }
+}
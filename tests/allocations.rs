@@ -0,0 +1,71 @@
+//! Counts the heap allocations of the parsers. The allocator is global to the
+//! test binary, so it lives in a file of its own, and counts per thread.
+
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    cell::Cell,
+};
+
+use uri_parser::{uri::host::ip_or_hostname_parser, uri_parser, Host};
+
+struct CountingAllocator;
+
+thread_local! {
+    static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.with(|count| count.set(count.get() + 1));
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+// The number of allocations made by `f`, and what it gives
+fn allocations<T>(f: impl FnOnce() -> T) -> (usize, T) {
+    let before = ALLOCATIONS.with(Cell::get);
+    let result = f();
+    (ALLOCATIONS.with(Cell::get) - before, result)
+}
+
+#[test]
+fn test_hosts_without_allocation() {
+    for host in [
+        "localhost",
+        "en.wikipedia.org",
+        "some-subsite.some-domain.example.org",
+    ] {
+        let (count, result) = allocations(|| ip_or_hostname_parser(host));
+        assert_eq!(result, Ok(("", Host::HOST(host.into()))));
+        assert_eq!(count, 0, "{}", host);
+    }
+
+    let (count, result) = allocations(|| ip_or_hostname_parser("192.168.0.1:8080"));
+    assert_eq!(result, Ok((":8080", Host::IP([192, 168, 0, 1]))));
+    assert_eq!(count, 0);
+}
+
+#[test]
+fn test_uri_without_allocation() {
+    // the path and the scheme borrow the input too
+    for input in [
+        "http://localhost",
+        "https://en.wikipedia.org:8080?q=1#top",
+        "https://user:p%40ss@[::1]?a=b",
+        "http://example.org/docs/index.html",
+        "ftp://ftp.example.org/pub/file.txt",
+        "ftp:/pub/",
+        "mailto:someone@example.org",
+    ] {
+        let (count, result) = allocations(|| uri_parser(input));
+        assert!(result.is_ok(), "{}", input);
+        assert_eq!(count, 0, "{}", input);
+    }
+}